@@ -58,6 +58,17 @@ pub fn get_transition_constraint_count() -> usize {
         + memory::get_transition_constraint_count()
 }
 
+/// Returns names and number of constraints of the groups of chiplets transition constraints, in
+/// the order in which the constraints are enforced by [enforce_constraints].
+pub fn get_transition_constraint_groups() -> [(&'static str, usize); 4] {
+    [
+        ("chiplets.selectors", NUM_CONSTRAINTS),
+        ("chiplets.hasher", hasher::get_transition_constraint_count()),
+        ("chiplets.bitwise", bitwise::get_transition_constraint_count()),
+        ("chiplets.memory", memory::get_transition_constraint_count()),
+    ]
+}
+
 /// Enforces constraints for the chiplets module and all chiplet components.
 pub fn enforce_constraints<E: FieldElement<BaseField = Felt>>(
     frame: &EvaluationFrame<E>,
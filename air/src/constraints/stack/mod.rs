@@ -89,6 +89,23 @@ pub fn get_transition_constraint_count() -> usize {
         + NUM_GENERAL_CONSTRAINTS
 }
 
+/// Returns names and number of constraints of the groups of stack transition constraints, in the
+/// order in which the constraints are enforced by [enforce_constraints].
+pub fn get_transition_constraint_groups() -> [(&'static str, usize); 7] {
+    [
+        ("stack.overflow", overflow::get_transition_constraint_count()),
+        ("stack.system_ops", system_ops::get_transition_constraint_count()),
+        ("stack.field_ops", field_ops::get_transition_constraint_count()),
+        (
+            "stack.stack_manipulation",
+            stack_manipulation::get_transition_constraint_count(),
+        ),
+        ("stack.u32_ops", u32_ops::get_transition_constraint_count()),
+        ("stack.io_ops", io_ops::get_transition_constraint_count()),
+        ("stack.general", NUM_GENERAL_CONSTRAINTS),
+    ]
+}
+
 /// Enforces constraints for the stack module and all stack operations.
 pub fn enforce_constraints<E: FieldElement<BaseField = Felt>>(
     frame: &EvaluationFrame<E>,
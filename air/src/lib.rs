@@ -7,10 +7,11 @@ extern crate alloc;
 extern crate std;
 
 use alloc::vec::Vec;
+use core::ops::Range;
 
 use vm_core::{
     utils::{ByteReader, ByteWriter, Deserializable, Serializable},
-    ExtensionOf, ProgramInfo, StackInputs, StackOutputs, ONE, ZERO,
+    ExtensionOf, OutputRegion, ProgramInfo, StackInputs, StackOutputs, ONE, ZERO,
 };
 use winter_air::{
     Air, AirContext, Assertion, AuxTraceRandElements, EvaluationFrame,
@@ -60,6 +61,30 @@ impl ProcessorAir {
     pub fn last_step(&self) -> usize {
         self.trace_length() - self.context().num_transition_exemptions()
     }
+
+    /// Returns names and index ranges of the groups of main trace transition constraints, in the
+    /// order in which they are evaluated by [ProcessorAir::evaluate_transition].
+    pub fn main_transition_constraint_groups() -> Vec<(&'static str, Range<usize>)> {
+        let groups = [("system", 1)]
+            .into_iter()
+            .chain(stack::get_transition_constraint_groups())
+            .chain([("range", range::get_transition_constraint_count())])
+            .chain(chiplets::get_transition_constraint_groups());
+
+        let mut offset = 0;
+        groups
+            .map(|(name, count)| {
+                offset += count;
+                (name, offset - count..offset)
+            })
+            .collect()
+    }
+
+    /// Returns names and index ranges of the groups of auxiliary trace transition constraints, in
+    /// the order in which they are evaluated by [ProcessorAir::evaluate_aux_transition].
+    pub fn aux_transition_constraint_groups() -> Vec<(&'static str, Range<usize>)> {
+        vec![("range", 0..range::NUM_AUX_CONSTRAINTS)]
+    }
 }
 
 impl Air for ProcessorAir {
@@ -260,6 +285,7 @@ pub struct PublicInputs {
     program_info: ProgramInfo,
     stack_inputs: StackInputs,
     stack_outputs: StackOutputs,
+    output_region: Option<OutputRegion>,
 }
 
 impl PublicInputs {
@@ -272,8 +298,23 @@ impl PublicInputs {
             program_info,
             stack_inputs,
             stack_outputs,
+            output_region: None,
         }
     }
+
+    /// Adds the output region committed to by the program to these public inputs.
+    ///
+    /// The region is expected to be committed to by the stack outputs (see
+    /// [OutputRegion::is_committed_to]); this is not checked here.
+    pub fn with_output_region(mut self, output_region: OutputRegion) -> Self {
+        self.output_region = Some(output_region);
+        self
+    }
+
+    /// Returns the output region included in these public inputs, if any.
+    pub fn output_region(&self) -> Option<&OutputRegion> {
+        self.output_region.as_ref()
+    }
 }
 
 impl vm_core::ToElements<Felt> for PublicInputs {
@@ -281,6 +322,9 @@ impl vm_core::ToElements<Felt> for PublicInputs {
         let mut result = self.program_info.to_elements();
         result.append(&mut self.stack_inputs.to_elements());
         result.append(&mut self.stack_outputs.to_elements());
+        if let Some(output_region) = &self.output_region {
+            result.append(&mut output_region.to_elements());
+        }
         result
     }
 }
@@ -293,6 +337,7 @@ impl Serializable for PublicInputs {
         self.program_info.write_into(target);
         self.stack_inputs.write_into(target);
         self.stack_outputs.write_into(target);
+        self.output_region.write_into(target);
     }
 }
 
@@ -301,11 +346,13 @@ impl Deserializable for PublicInputs {
         let program_info = ProgramInfo::read_from(source)?;
         let stack_inputs = StackInputs::read_from(source)?;
         let stack_outputs = StackOutputs::read_from(source)?;
+        let output_region = Option::<OutputRegion>::read_from(source)?;
 
         Ok(PublicInputs {
             program_info,
             stack_inputs,
             stack_outputs,
+            output_region,
         })
     }
 }
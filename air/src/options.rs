@@ -152,6 +152,7 @@ pub struct ExecutionOptions {
     expected_cycles: u32,
     enable_tracing: bool,
     enable_debugging: bool,
+    enable_output_region: bool,
 }
 
 impl Default for ExecutionOptions {
@@ -161,6 +162,7 @@ impl Default for ExecutionOptions {
             expected_cycles: MIN_TRACE_LEN as u32,
             enable_tracing: false,
             enable_debugging: false,
+            enable_output_region: false,
         }
     }
 }
@@ -194,6 +196,7 @@ impl ExecutionOptions {
             expected_cycles,
             enable_tracing,
             enable_debugging: false,
+            enable_output_region: false,
         })
     }
 
@@ -214,6 +217,17 @@ impl ExecutionOptions {
         self
     }
 
+    /// Enables extraction of the output region committed to by the program.
+    ///
+    /// When enabled, the VM expects the stack at the end of execution to be in the state left by
+    /// `std::sys::commit_output_region` (i.e., `[COM, start_addr, num_words, ...]`), and reads the
+    /// contents of the committed memory region so that they can be included in the public inputs
+    /// of the execution proof.
+    pub fn with_output_region(mut self) -> Self {
+        self.enable_output_region = true;
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -240,4 +254,10 @@ impl ExecutionOptions {
     pub fn enable_debugging(&self) -> bool {
         self.enable_debugging
     }
+
+    /// Returns a flag indicating whether the VM should extract the output region committed to by
+    /// the program.
+    pub fn enable_output_region(&self) -> bool {
+        self.enable_output_region
+    }
 }
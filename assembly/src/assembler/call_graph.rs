@@ -0,0 +1,81 @@
+use super::{CodeBody, Instruction, Node, ProcedureAst};
+use alloc::{collections::BTreeSet, vec::Vec};
+
+// CALL GRAPH
+// ================================================================================================
+
+/// A graph of invocations between the local procedures of a single module.
+///
+/// Invocations of imported procedures are not included in the graph, as imported modules are
+/// compiled on demand when their procedures are first invoked. Thus, a procedure of an imported
+/// module is compiled only if it is reachable from a compiled procedure of the importing module.
+pub(crate) struct CallGraph {
+    /// Indexes of local procedures invoked by each local procedure (via `exec`, `call` or
+    /// `procref` instructions).
+    callees: Vec<BTreeSet<u16>>,
+}
+
+impl CallGraph {
+    /// Returns the call graph of the specified local procedures.
+    pub fn new(procs: &[ProcedureAst]) -> Self {
+        let callees = procs
+            .iter()
+            .map(|proc| {
+                let mut callees = BTreeSet::new();
+                collect_local_callees(&proc.body, &mut callees);
+                callees
+            })
+            .collect();
+        Self { callees }
+    }
+
+    /// Returns a vector which specifies for each local procedure whether it is reachable from the
+    /// specified procedures (i.e., whether it is one of them or is invoked by them, directly or
+    /// transitively).
+    pub fn reachable_from<I>(&self, roots: I) -> Vec<bool>
+    where
+        I: IntoIterator<Item = u16>,
+    {
+        let mut reachable = vec![false; self.callees.len()];
+        let mut stack = roots.into_iter().collect::<Vec<_>>();
+        while let Some(proc_idx) = stack.pop() {
+            // invalid indexes are ignored here; they are reported when the invocations are
+            // compiled
+            match reachable.get_mut(proc_idx as usize) {
+                Some(is_reachable) if !*is_reachable => *is_reachable = true,
+                _ => continue,
+            }
+            stack.extend(self.callees[proc_idx as usize].iter().copied());
+        }
+        reachable
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Adds the indexes of all local procedures invoked from the specified code body to `callees`.
+pub(crate) fn collect_local_callees(body: &CodeBody, callees: &mut BTreeSet<u16>) {
+    for node in body.nodes() {
+        match node {
+            Node::Instruction(
+                Instruction::ExecLocal(idx)
+                | Instruction::CallLocal(idx)
+                | Instruction::ProcRefLocal(idx),
+            ) => {
+                callees.insert(*idx);
+            }
+            Node::Instruction(_) => (),
+            Node::IfElse {
+                true_case,
+                false_case,
+            } => {
+                collect_local_callees(true_case, callees);
+                collect_local_callees(false_case, callees);
+            }
+            Node::Repeat { body, .. } | Node::While { body } => {
+                collect_local_callees(body, callees);
+            }
+        }
+    }
+}
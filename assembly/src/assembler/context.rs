@@ -2,9 +2,9 @@ use super::{
     AssemblyError, CallSet, CodeBlock, CodeBlockTable, Kernel, LibraryPath, NamedProcedure,
     Procedure, ProcedureCache, ProcedureId, ProcedureName, RpoDigest,
 };
-use crate::ast::{ModuleAst, ProgramAst};
+use crate::ast::{ModuleAst, ProgramAst, StackEffect};
 use alloc::collections::BTreeMap;
-use alloc::string::ToString;
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 
 // ASSEMBLY CONTEXT
@@ -96,6 +96,7 @@ impl AssemblyContext {
         module_context
             .compiled_procs
             .get(idx as usize)
+            .and_then(|named_proc| named_proc.as_ref())
             .map(|named_proc| named_proc.inner())
             .ok_or_else(|| AssemblyError::local_proc_not_found(idx, &module_context.path))
     }
@@ -142,7 +143,10 @@ impl AssemblyContext {
     ///
     /// This pops the module off the module stack and return all local procedures of the module
     /// (both exported and internal) together with the combined callset of module's procedures.
-    pub fn complete_module(&mut self) -> Result<(Vec<NamedProcedure>, CallSet), AssemblyError> {
+    /// Procedures which were skipped (see [Self::skip_proc]) are returned as `None`.
+    pub fn complete_module(
+        &mut self,
+    ) -> Result<(Vec<Option<NamedProcedure>>, CallSet), AssemblyError> {
         let module_ctx = self.module_stack.pop().expect("no modules");
         if self.is_kernel && self.module_stack.is_empty() {
             // if we are compiling a kernel and this is the last module on the module stack, then
@@ -151,6 +155,7 @@ impl AssemblyContext {
             let proc_roots = module_ctx
                 .compiled_procs
                 .iter()
+                .flatten()
                 .filter(|proc| proc.is_export())
                 .map(|proc| proc.mast_root())
                 .collect::<Vec<_>>();
@@ -186,8 +191,20 @@ impl AssemblyContext {
 
     /// Completes compilation of the current procedure and adds the compiled procedure to the list
     /// of the current module's compiled procedures.
-    pub fn complete_proc(&mut self, code: CodeBlock) {
-        self.module_stack.last_mut().expect("no modules").complete_proc(code);
+    pub fn complete_proc(&mut self, code: CodeBlock, stack_effect: Option<StackEffect>) {
+        self.module_stack
+            .last_mut()
+            .expect("no modules")
+            .complete_proc(code, stack_effect);
+    }
+
+    /// Skips compilation of the next local procedure of the current module.
+    ///
+    /// This is used for procedures which are not reachable from the roots of the compilation. A
+    /// skipped procedure cannot be invoked, but it still occupies its index in the list of the
+    /// module's local procedures.
+    pub fn skip_proc(&mut self) {
+        self.module_stack.last_mut().expect("no modules").compiled_procs.push(None);
     }
 
     // CALL PROCESSORS
@@ -335,6 +352,25 @@ impl AssemblyContext {
             .map(|p| p.name().as_ref())
             .expect("library compilation mode is currently not supported!")
     }
+
+    /// Returns the path of the module of the current procedure, or None if the procedure belongs
+    /// to the executable module.
+    pub(crate) fn current_module_path(&self) -> Option<&LibraryPath> {
+        self.module_stack
+            .last()
+            .filter(|module| !module.is_executable())
+            .map(|module| &module.path)
+    }
+
+    /// Returns the name of the current procedure qualified with the path of its module, or just
+    /// the name of the procedure if it belongs to the executable module.
+    pub(crate) fn current_context_qualified_name(&self) -> String {
+        let name = self.current_context_name();
+        match self.current_module_path() {
+            Some(module_path) => format!("{module_path}::{name}"),
+            None => name.to_string(),
+        }
+    }
 }
 
 // MODULE CONTEXT
@@ -347,8 +383,9 @@ struct ModuleContext {
     /// A stack of procedures which are in the process of being compiled. The procedure which
     /// is currently being compiled is at the top of this list.
     proc_stack: Vec<ProcedureContext>,
-    /// List of local procedures which have already been compiled for this module.
-    compiled_procs: Vec<NamedProcedure>,
+    /// List of local procedures which have already been compiled for this module; procedures
+    /// which were skipped are set to `None`.
+    compiled_procs: Vec<Option<NamedProcedure>>,
     /// Fully qualified path of this module.
     path: LibraryPath,
     /// A combined callset of all procedure callsets in this module.
@@ -406,6 +443,7 @@ impl ModuleContext {
     pub fn find_local_proc(&self, mast_root: &RpoDigest) -> Option<&Procedure> {
         self.compiled_procs
             .iter()
+            .flatten()
             .find(|proc| proc.mast_root() == *mast_root)
             .map(|proc| proc.inner())
     }
@@ -427,7 +465,7 @@ impl ModuleContext {
     ) -> Result<(), AssemblyError> {
         // make sure a procedure with this name has not been compiled yet and is also not currently
         // on the stack of procedures being compiled
-        if self.compiled_procs.iter().any(|p| p.name() == name)
+        if self.compiled_procs.iter().flatten().any(|p| p.name() == name)
             || self.proc_stack.iter().any(|p| &p.name == name)
         {
             return Err(AssemblyError::duplicate_proc_name(name, &self.path));
@@ -443,11 +481,11 @@ impl ModuleContext {
     /// compiled procedure, and adds it to the list of compiled procedures.
     ///
     /// This also updates module callset to include the callset of the newly compiled procedure.
-    pub fn complete_proc(&mut self, code: CodeBlock) {
+    pub fn complete_proc(&mut self, code: CodeBlock, stack_effect: Option<StackEffect>) {
         let proc_context = self.proc_stack.pop().expect("no procedures");
-        let proc = proc_context.into_procedure(code);
+        let proc = proc_context.into_procedure(code, stack_effect);
         self.callset.append(proc.callset());
-        self.compiled_procs.push(proc);
+        self.compiled_procs.push(Some(proc));
     }
 
     // CALL PROCESSORS
@@ -471,6 +509,7 @@ impl ModuleContext {
         let called_proc = self
             .compiled_procs
             .get(proc_idx as usize)
+            .and_then(|proc| proc.as_ref())
             .ok_or_else(|| AssemblyError::local_proc_not_found(proc_idx, &self.path))?;
 
         // get the context of the procedure currently being compiled
@@ -562,7 +601,11 @@ impl ProcedureContext {
         &self.name
     }
 
-    pub fn into_procedure(self, code_root: CodeBlock) -> NamedProcedure {
+    pub fn into_procedure(
+        self,
+        code_root: CodeBlock,
+        stack_effect: Option<StackEffect>,
+    ) -> NamedProcedure {
         let Self {
             name,
             is_export,
@@ -570,6 +613,6 @@ impl ProcedureContext {
             callset,
         } = self;
 
-        NamedProcedure::new(name, is_export, num_locals as u32, code_root, callset)
+        NamedProcedure::new(name, is_export, num_locals as u32, code_root, callset, stack_effect)
     }
 }
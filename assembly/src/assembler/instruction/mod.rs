@@ -4,7 +4,7 @@ use super::{
 };
 use crate::utils::bound_into_included_u64;
 use core::ops::RangeBounds;
-use vm_core::{Decorator, FieldElement};
+use vm_core::{Decorator, FieldElement, SourceLocation};
 
 mod adv_ops;
 mod crypto_ops;
@@ -24,6 +24,7 @@ impl Assembler {
     pub(super) fn compile_instruction(
         &self,
         instruction: &Instruction,
+        location: Option<&SourceLocation>,
         span: &mut SpanBuilder,
         ctx: &mut AssemblyContext,
     ) -> Result<Option<CodeBlock>, AssemblyError> {
@@ -33,7 +34,7 @@ impl Assembler {
         // this will allow us to map the instruction to the sequence of operations which were
         // executed as a part of this instruction.
         if self.in_debug_mode() {
-            span.track_instruction(instruction, ctx);
+            span.track_instruction(instruction, location, ctx);
         }
 
         let result = match instruction {
@@ -303,7 +304,7 @@ impl Assembler {
             Instruction::Breakpoint => {
                 if self.in_debug_mode() {
                     span.add_op(Noop)?;
-                    span.track_instruction(instruction, ctx);
+                    span.track_instruction(instruction, location, ctx);
                 }
                 Ok(None)
             }
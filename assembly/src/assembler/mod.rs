@@ -1,15 +1,18 @@
 use super::{
-    ast::{instrument, Instruction, ModuleAst, Node, ProcedureAst, ProgramAst},
+    ast::{instrument, CodeBody, Instruction, ModuleAst, Node, ProcedureAst, ProgramAst},
     crypto::hash::RpoDigest,
     AssemblyError, CallSet, CodeBlock, CodeBlockTable, Felt, Kernel, Library, LibraryError,
     LibraryPath, Module, NamedProcedure, Operation, Procedure, ProcedureId, ProcedureName, Program,
     ONE, ZERO,
 };
-use alloc::collections::BTreeMap;
+use alloc::collections::{BTreeMap, BTreeSet};
 use alloc::vec::Vec;
 use core::{borrow::Borrow, cell::RefCell};
 use vm_core::{Decorator, DecoratorList};
 
+mod call_graph;
+pub(crate) use call_graph::{collect_local_callees, CallGraph};
+
 mod instruction;
 
 mod module_provider;
@@ -18,6 +21,11 @@ use module_provider::ModuleProvider;
 mod span_builder;
 use span_builder::SpanBuilder;
 
+mod stack_effect;
+
+mod optimizer;
+pub use optimizer::{OptimizationLevel, OptimizationStats};
+
 mod context;
 pub use context::AssemblyContext;
 
@@ -35,12 +43,23 @@ mod tests;
 /// - If `with_kernel()` or `with_kernel_module()` methods are not used, the assembler will be
 ///   instantiated with a default empty kernel. Programs compiled using such assembler
 ///   cannot make calls to kernel procedures via `syscall` instruction.
+/// - If `with_optimization_level()` method is not used, no optimizations are applied to the
+///   compiled code.
+///
+/// Only the procedures which are reachable from the roots of the compilation are compiled: when
+/// compiling a program, these are the procedures invoked from the program body; when compiling a
+/// module, these are the procedures exported from the module; and when compiling an imported
+/// module on demand, this is the imported procedure. Unreachable procedures are dropped without
+/// being compiled, and thus, they neither get into the compiled MAST nor into the code block table
+/// of the program, and errors in them are not reported.
 #[derive(Default)]
 pub struct Assembler {
     kernel: Kernel,
     module_provider: ModuleProvider,
     proc_cache: RefCell<ProcedureCache>,
     in_debug_mode: bool,
+    optimization_level: OptimizationLevel,
+    optimization_stats: RefCell<OptimizationStats>,
 }
 
 impl Assembler {
@@ -53,6 +72,15 @@ impl Assembler {
         self
     }
 
+    /// Sets the optimizations which the assembler applies to the compiled code.
+    ///
+    /// The optimization level should be set before any code is compiled (including kernels),
+    /// since procedures compiled before are cached and reused as is.
+    pub fn with_optimization_level(mut self, optimization_level: OptimizationLevel) -> Self {
+        self.optimization_level = optimization_level;
+        self
+    }
+
     /// Adds the library to provide modules for the compilation.
     pub fn with_library<L>(mut self, library: &L) -> Result<Self, AssemblyError>
     where
@@ -108,6 +136,17 @@ impl Assembler {
         self.in_debug_mode
     }
 
+    /// Returns the optimizations which this assembler applies to the compiled code.
+    pub fn optimization_level(&self) -> OptimizationLevel {
+        self.optimization_level
+    }
+
+    /// Returns the statistics of the optimizations applied to all code compiled by this assembler
+    /// so far (including the procedures of kernels and libraries).
+    pub fn optimization_stats(&self) -> OptimizationStats {
+        *self.optimization_stats.borrow()
+    }
+
     /// Returns a reference to the kernel for this assembler.
     ///
     /// If the assembler was instantiated without a kernel, the internal kernel will be empty.
@@ -172,16 +211,24 @@ impl Assembler {
             return Err(AssemblyError::InvalidProgramAssemblyContext);
         }
 
-        // compile all local procedures; this will add the procedures to the specified context
-        for proc_ast in program.procedures() {
+        // compile all local procedures reachable from the program body; this will add the
+        // procedures to the specified context
+        let mut body_callees = BTreeSet::new();
+        collect_local_callees(program.body(), &mut body_callees);
+        let reachable = CallGraph::new(program.procedures()).reachable_from(body_callees);
+        for (proc_ast, is_reachable) in program.procedures().iter().zip(reachable) {
             if proc_ast.is_export {
                 return Err(AssemblyError::exported_proc_in_program(&proc_ast.name));
             }
-            self.compile_procedure(proc_ast, context)?;
+            if is_reachable {
+                self.compile_procedure(proc_ast, context)?;
+            } else {
+                context.skip_proc();
+            }
         }
 
         // compile the program body
-        let program_root = self.compile_body(program.body().nodes().iter(), context, None)?;
+        let program_root = self.compile_body(program.body(), context, None)?;
 
         Ok(program_root)
     }
@@ -192,6 +239,8 @@ impl Assembler {
     /// Compiles all procedures in the specified module and adds them to the procedure cache.
     /// Returns a vector of procedure digests for all exported procedures in the module.
     ///
+    /// Internal procedures which are not reachable from the exported procedures are not compiled.
+    ///
     /// # Errors
     /// - If a module with the same path already exists in the module stack of the
     ///   [AssemblyContext].
@@ -205,12 +254,35 @@ impl Assembler {
         path: Option<&LibraryPath>,
         context: &mut AssemblyContext,
     ) -> Result<Vec<RpoDigest>, AssemblyError> {
+        self.compile_module_exports(module, path, None, context)
+    }
+
+    /// Compiles the exported procedure with the specified name, or all exported procedures if the
+    /// name is not provided, together with the procedures they depend on, and adds them to the
+    /// procedure cache. Returns a vector of procedure digests for the compiled exported
+    /// procedures.
+    ///
+    /// A module may be compiled several times (e.g., once for every procedure imported from it);
+    /// procedures which are already in the procedure cache are not added to it again.
+    fn compile_module_exports(
+        &self,
+        module: &ModuleAst,
+        path: Option<&LibraryPath>,
+        export_name: Option<&ProcedureName>,
+        context: &mut AssemblyContext,
+    ) -> Result<Vec<RpoDigest>, AssemblyError> {
+        let is_root = |name: &ProcedureName| export_name.map_or(true, |export| export == name);
+
         // a variable to track MAST roots of all procedures exported from this module
         let mut proc_roots = Vec::new();
         context.begin_module(path.unwrap_or(&LibraryPath::anon_path()), module)?;
 
         // process all re-exported procedures
         for reexporteed_proc in module.reexported_procs().iter() {
+            if !is_root(reexporteed_proc.name()) {
+                continue;
+            }
+
             // make sure the re-exported procedure is loaded into the procedure cache
             let ref_proc_id = reexporteed_proc.proc_id();
             self.ensure_procedure_is_in_cache(&ref_proc_id, context).map_err(|_| {
@@ -222,10 +294,14 @@ impl Assembler {
             let proc_mast_root = if let Some(path) = path {
                 let proc_name = reexporteed_proc.name();
                 let alias_proc_id = ProcedureId::from_name(proc_name, path);
-                self.proc_cache
+                let mut proc_cache = self
+                    .proc_cache
                     .try_borrow_mut()
-                    .map_err(|_| AssemblyError::InvalidCacheLock)?
-                    .insert_proc_alias(alias_proc_id, ref_proc_id)?
+                    .map_err(|_| AssemblyError::InvalidCacheLock)?;
+                match proc_cache.get_by_id(&alias_proc_id) {
+                    Some(proc) => proc.mast_root(),
+                    None => proc_cache.insert_proc_alias(alias_proc_id, ref_proc_id)?,
+                }
             } else {
                 self.proc_cache
                     .try_borrow_mut()
@@ -239,11 +315,19 @@ impl Assembler {
             proc_roots.push(proc_mast_root);
         }
 
-        // compile all local (internal end exported) procedures in the module; once the compilation
-        // is complete, we get all compiled procedures (and their combined callset) from the
-        // context
-        for proc_ast in module.procs().iter() {
-            self.compile_procedure(proc_ast, context)?;
+        // compile all local (internal end exported) procedures in the module which are reachable
+        // from the compiled exports; once the compilation is complete, we get all compiled
+        // procedures (and their combined callset) from the context
+        let roots = module.procs().iter().enumerate().filter_map(|(proc_index, proc)| {
+            (proc.is_export && is_root(&proc.name)).then_some(proc_index as u16)
+        });
+        let reachable = CallGraph::new(module.procs()).reachable_from(roots);
+        for (proc_ast, is_reachable) in module.procs().iter().zip(reachable) {
+            if is_reachable {
+                self.compile_procedure(proc_ast, context)?;
+            } else {
+                context.skip_proc();
+            }
         }
         let (module_procs, module_callset) = context.complete_module()?;
 
@@ -253,6 +337,7 @@ impl Assembler {
         // - a procedure is present in the combined callset - i.e., it is an internal procedure
         //   which has been invoked via a local call instruction.
         for (proc_index, proc) in module_procs.into_iter().enumerate() {
+            let Some(proc) = proc else { continue };
             if proc.is_export() {
                 proc_roots.push(proc.mast_root());
             }
@@ -262,10 +347,13 @@ impl Assembler {
                 let proc_id = build_procedure_id(path, &proc, proc_index);
 
                 // this is safe because we fail if the cache is borrowed.
-                self.proc_cache
+                let mut proc_cache = self
+                    .proc_cache
                     .try_borrow_mut()
-                    .map_err(|_| AssemblyError::InvalidCacheLock)?
-                    .insert(proc, proc_id)?;
+                    .map_err(|_| AssemblyError::InvalidCacheLock)?;
+                if !proc_id.is_some_and(|proc_id| proc_cache.contains_id(&proc_id)) {
+                    proc_cache.insert(proc, proc_id)?;
+                }
             }
         }
 
@@ -292,12 +380,29 @@ impl Assembler {
                 prologue: vec![Operation::Push(num_locals), Operation::FmpUpdate],
                 epilogue: vec![Operation::Push(-num_locals), Operation::FmpUpdate],
             };
-            self.compile_body(proc.body.nodes().iter(), context, Some(wrapper))?
+            self.compile_body(&proc.body, context, Some(wrapper))?
         } else {
-            self.compile_body(proc.body.nodes().iter(), context, None)?
+            self.compile_body(&proc.body, context, None)?
         };
 
-        context.complete_proc(code);
+        // the body of a procedure with a declared signature is checked strictly, and its stack
+        // effect must match the declared one; callers of the procedure then rely on the declared
+        // stack effect, which is trusted if the actual one cannot be inferred (e.g., due to dynamic
+        // calls)
+        let strict = proc.signature.is_some();
+        let stack_effect =
+            match (proc.signature, self.infer_stack_effect(&proc.body, context, strict)?) {
+                (Some(declared), Some(inferred)) if !declared.accepts(&inferred) => {
+                    return Err(AssemblyError::proc_signature_mismatch(
+                        &context.current_context_qualified_name(),
+                        declared,
+                        inferred,
+                    ));
+                }
+                (signature, inferred) => signature.or(inferred),
+            };
+
+        context.complete_proc(code, stack_effect);
 
         Ok(())
     }
@@ -306,23 +411,24 @@ impl Assembler {
     // --------------------------------------------------------------------------------------------
 
     /// TODO: add comments
-    fn compile_body<A, N>(
+    fn compile_body(
         &self,
-        body: A,
+        body: &CodeBody,
         context: &mut AssemblyContext,
         wrapper: Option<BodyWrapper>,
-    ) -> Result<CodeBlock, AssemblyError>
-    where
-        A: Iterator<Item = N>,
-        N: Borrow<Node>,
-    {
+    ) -> Result<CodeBlock, AssemblyError> {
         let mut blocks: Vec<CodeBlock> = Vec::new();
-        let mut span = SpanBuilder::new(wrapper);
+        let mut span = SpanBuilder::new(wrapper, self.optimization_level);
 
-        for node in body {
-            match node.borrow() {
+        // source locations are bound to nodes by position; they may be absent altogether if the
+        // body was constructed without them (e.g., deserialized without locations)
+        for (node_idx, node) in body.nodes().iter().enumerate() {
+            let location = body.source_locations().get(node_idx);
+            match node {
                 Node::Instruction(inner) => {
-                    if let Some(block) = self.compile_instruction(inner, &mut span, context)? {
+                    if let Some(block) =
+                        self.compile_instruction(inner, location, &mut span, context)?
+                    {
                         span.extract_span_into(&mut blocks);
                         blocks.push(block);
                     }
@@ -334,12 +440,12 @@ impl Assembler {
                 } => {
                     span.extract_span_into(&mut blocks);
 
-                    let true_case = self.compile_body(true_case.nodes().iter(), context, None)?;
+                    let true_case = self.compile_body(true_case, context, None)?;
 
                     // else is an exception because it is optional; hence, will have to be replaced
                     // by noop span
                     let false_case = if !false_case.nodes().is_empty() {
-                        self.compile_body(false_case.nodes().iter(), context, None)?
+                        self.compile_body(false_case, context, None)?
                     } else {
                         CodeBlock::new_span(vec![Operation::Noop])
                     };
@@ -352,7 +458,7 @@ impl Assembler {
                 Node::Repeat { times, body } => {
                     span.extract_span_into(&mut blocks);
 
-                    let block = self.compile_body(body.nodes().iter(), context, None)?;
+                    let block = self.compile_body(body, context, None)?;
 
                     for _ in 0..*times {
                         blocks.push(block.clone());
@@ -362,7 +468,7 @@ impl Assembler {
                 Node::While { body } => {
                     span.extract_span_into(&mut blocks);
 
-                    let block = self.compile_body(body.nodes().iter(), context, None)?;
+                    let block = self.compile_body(body, context, None)?;
                     let block = CodeBlock::new_loop(block);
 
                     blocks.push(block);
@@ -370,7 +476,7 @@ impl Assembler {
             }
         }
 
-        span.extract_final_span_into(&mut blocks);
+        *self.optimization_stats.borrow_mut() += span.extract_final_span_into(&mut blocks);
         Ok(if blocks.is_empty() {
             CodeBlock::new_span(vec![Operation::Noop])
         } else {
@@ -395,12 +501,29 @@ impl Assembler {
         context: &mut AssemblyContext,
     ) -> Result<(), AssemblyError> {
         if !self.proc_cache.borrow().contains_id(proc_id) {
-            // if procedure is not in cache, try to get its module and compile it
+            // if procedure is not in cache, try to get its module and compile it; only the
+            // procedure itself and the procedures it depends on are compiled
             let module = self.module_provider.get_module(proc_id).ok_or_else(|| {
                 let proc_name = context.get_imported_procedure_name(proc_id);
                 AssemblyError::imported_proc_module_not_found(proc_id, proc_name)
             })?;
-            self.compile_module(&module.ast, Some(&module.path), context)?;
+            let export_name = module
+                .ast
+                .procs()
+                .iter()
+                .filter(|proc| proc.is_export)
+                .map(|proc| &proc.name)
+                .chain(module.ast.reexported_procs().iter().map(|proc| proc.name()))
+                .find(|name| ProcedureId::from_name(name, &module.path) == *proc_id)
+                .ok_or_else(|| {
+                    AssemblyError::imported_proc_not_found_in_module(proc_id, &module.path)
+                })?;
+            self.compile_module_exports(
+                &module.ast,
+                Some(&module.path),
+                Some(export_name),
+                context,
+            )?;
             // if the procedure is still not in cache, then there was some error
             if !self.proc_cache.borrow().contains_id(proc_id) {
                 return Err(AssemblyError::imported_proc_not_found_in_module(
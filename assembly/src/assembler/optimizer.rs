@@ -0,0 +1,343 @@
+use super::{Decorator, DecoratorList, Felt, Operation, ONE, ZERO};
+use alloc::vec::Vec;
+use core::ops::AddAssign;
+
+// OPTIMIZATION LEVEL
+// ================================================================================================
+
+/// Specifies the optimizations which the assembler applies to the compiled code.
+///
+/// Optimizations change the MAST roots of the compiled procedures and programs, and thus a program
+/// compiled with optimizations enabled has a different hash than the same program compiled without
+/// them.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OptimizationLevel {
+    /// Operations are emitted exactly as the instructions are lowered.
+    #[default]
+    None,
+    /// Sequences of operations within SPAN blocks are rewritten into shorter equivalent sequences
+    /// (e.g., `SWAP SWAP` is removed and `PAD EQ` is replaced with `EQZ`).
+    ///
+    /// Operations are never rewritten across decorators other than `AsmOp` decorators (e.g.,
+    /// advice injectors, events or traces), since such decorators may observe the state of the
+    /// stack, nor across instructions marked as breakpoints. `AsmOp` decorators are updated to
+    /// reflect the rewritten operations; instructions all of whose operations were removed are no
+    /// longer mapped to any operations.
+    Peephole,
+}
+
+// OPTIMIZATION STATS
+// ================================================================================================
+
+/// Statistics about the optimizations applied by the assembler.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct OptimizationStats {
+    num_rewrites: usize,
+    num_removed_ops: usize,
+}
+
+impl OptimizationStats {
+    /// Returns the number of rewrites of operation sequences applied by the optimizer.
+    pub fn num_rewrites(&self) -> usize {
+        self.num_rewrites
+    }
+
+    /// Returns the number of cycles saved by the optimizer.
+    ///
+    /// This is the number of operations removed from the compiled SPAN blocks; each removed
+    /// operation saves one cycle every time its SPAN block is executed (not accounting for the
+    /// NOOPs which may be needed to pad operation batches).
+    pub fn cycles_saved(&self) -> usize {
+        self.num_removed_ops
+    }
+}
+
+impl AddAssign for OptimizationStats {
+    fn add_assign(&mut self, rhs: Self) {
+        self.num_rewrites += rhs.num_rewrites;
+        self.num_removed_ops += rhs.num_removed_ops;
+    }
+}
+
+// PEEPHOLE OPTIMIZER
+// ================================================================================================
+
+/// Rewrites the specified operations of a SPAN block into shorter equivalent sequences, and
+/// updates the positions of the specified decorators of the block accordingly.
+///
+/// Rewrites are applied repeatedly until no more rewrites are possible. If all operations of a
+/// non-empty block are removed, a single NOOP is retained so that the block remains valid.
+pub(super) fn optimize_span(
+    ops: &mut Vec<Operation>,
+    decorators: &mut DecoratorList,
+) -> OptimizationStats {
+    let num_ops = ops.len();
+    let mut stats = OptimizationStats::default();
+    loop {
+        let num_rewrites = optimize_pass(ops, decorators);
+        if num_rewrites == 0 {
+            break;
+        }
+        stats.num_rewrites += num_rewrites;
+    }
+
+    if ops.is_empty() && num_ops > 0 {
+        ops.push(Operation::Noop);
+    }
+    stats.num_removed_ops = num_ops - ops.len();
+    stats
+}
+
+/// Applies a single pass of rewrites to the specified operations and returns the number of
+/// applied rewrites.
+fn optimize_pass(ops: &mut Vec<Operation>, decorators: &mut DecoratorList) -> usize {
+    // determine which operations cannot be rewritten together with the preceding operation
+    // (because a decorator which may observe the stack is located between them), and which
+    // operations belong to breakpoint instructions
+    let mut barriers = vec![false; ops.len() + 1];
+    let mut breakpoints = vec![false; ops.len()];
+    for (pos, decorator) in decorators.iter() {
+        match decorator {
+            Decorator::AsmOp(asmop) if asmop.should_break() => {
+                let end = (pos + asmop.num_cycles() as usize).min(ops.len());
+                breakpoints[*pos..end].fill(true);
+            }
+            Decorator::AsmOp(_) => (),
+            _ => barriers[*pos] = true,
+        }
+    }
+
+    // rewrite the operations; `new_positions[i]` is the number of rewritten operations which
+    // precede the i-th original operation
+    let mut rewritten = Vec::with_capacity(ops.len());
+    let mut new_positions = Vec::with_capacity(ops.len() + 1);
+    let mut num_rewrites = 0;
+    let mut pos = 0;
+    while pos < ops.len() {
+        let rewrite = rewrite(&ops[pos..]).filter(|(len, replacement)| {
+            replacement.len() < *len
+                && !barriers[pos + 1..pos + len].contains(&true)
+                && !breakpoints[pos..pos + len].contains(&true)
+        });
+        match rewrite {
+            Some((len, replacement)) => {
+                new_positions.push(rewritten.len());
+                rewritten.extend_from_slice(&replacement);
+                new_positions.resize(new_positions.len() + len - 1, rewritten.len());
+                num_rewrites += 1;
+                pos += len;
+            }
+            None => {
+                new_positions.push(rewritten.len());
+                rewritten.push(ops[pos]);
+                pos += 1;
+            }
+        }
+    }
+    new_positions.push(rewritten.len());
+
+    if num_rewrites > 0 {
+        *ops = rewritten;
+        update_decorators(decorators, &new_positions);
+    }
+    num_rewrites
+}
+
+/// Moves the decorators to the positions of the rewritten operations, updates the number of
+/// cycles of `AsmOp` decorators and removes `AsmOp` decorators which no longer map to any
+/// operations.
+fn update_decorators(decorators: &mut DecoratorList, new_positions: &[usize]) {
+    decorators.retain_mut(|(pos, decorator)| {
+        let new_pos = new_positions[*pos];
+        if let Decorator::AsmOp(asmop) = decorator {
+            let end = (*pos + asmop.num_cycles() as usize).min(new_positions.len() - 1);
+            let num_cycles = new_positions[end] - new_pos;
+            if num_cycles == 0 {
+                return false;
+            }
+            asmop.set_num_cycles(num_cycles as u8);
+        }
+        *pos = new_pos;
+        true
+    });
+}
+
+// REWRITE RULES
+// ================================================================================================
+
+/// Returns the length of the sequence of operations at the start of `ops` which can be rewritten,
+/// together with the equivalent shorter sequence, if any.
+fn rewrite(ops: &[Operation]) -> Option<(usize, Vec<Operation>)> {
+    use Operation::*;
+
+    // rewrites of constants followed by operations consuming them
+    if let Some((value, len)) = constant(ops) {
+        let rest = &ops[len..];
+        if let Some((other, other_len)) = constant(rest) {
+            // fold binary operations on two constants
+            let folded = match rest.get(other_len) {
+                Some(Add) => Some(value + other),
+                Some(Mul) => Some(value * other),
+                Some(Eq) => Some(if value == other { ONE } else { ZERO }),
+                _ => None,
+            };
+            if let Some(folded) = folded {
+                return Some((len + other_len + 1, push_constant(folded)));
+            }
+        }
+        let replacement = match rest.first() {
+            Some(Drop) => vec![],
+            Some(Incr) => push_constant(value + ONE),
+            Some(Neg) => push_constant(-value),
+            Some(Add) if value == ZERO => vec![],
+            Some(Add) if value == ONE => vec![Incr],
+            Some(Mul) if value == ONE => vec![],
+            Some(Eq) if value == ZERO => vec![Eqz],
+            _ => return None,
+        };
+        return Some((len + 1, replacement));
+    }
+
+    let replacement = match ops {
+        [Noop, ..] => return Some((1, vec![])),
+        // values which are duplicated and immediately dropped
+        [Dup0 | Dup1 | Dup2 | Dup3 | Dup4 | Dup5 | Dup6 | Dup7 | Dup9 | Dup11 | Dup13 | Dup15, Drop, ..] =>
+        {
+            vec![]
+        }
+        // stack permutations which cancel each other out
+        [Swap, Swap, ..]
+        | [SwapW, SwapW, ..]
+        | [SwapW2, SwapW2, ..]
+        | [SwapW3, SwapW3, ..]
+        | [SwapDW, SwapDW, ..]
+        | [MovUp2, MovDn2, ..]
+        | [MovDn2, MovUp2, ..]
+        | [MovUp3, MovDn3, ..]
+        | [MovDn3, MovUp3, ..]
+        | [MovUp4, MovDn4, ..]
+        | [MovDn4, MovUp4, ..]
+        | [MovUp5, MovDn5, ..]
+        | [MovDn5, MovUp5, ..]
+        | [MovUp6, MovDn6, ..]
+        | [MovDn6, MovUp6, ..]
+        | [MovUp7, MovDn7, ..]
+        | [MovDn7, MovUp7, ..]
+        | [MovUp8, MovDn8, ..]
+        | [MovDn8, MovUp8, ..]
+        | [Neg, Neg, ..] => vec![],
+        // swaps of the operands of commutative operations
+        [Swap, op @ (Add | Mul | Eq), ..] => vec![*op],
+        _ => return None,
+    };
+    Some((2, replacement))
+}
+
+/// Returns the constant pushed onto the stack by the operations at the start of `ops` together
+/// with the number of these operations, if any.
+fn constant(ops: &[Operation]) -> Option<(Felt, usize)> {
+    match ops {
+        [Operation::Pad, Operation::Incr, ..] => Some((ONE, 2)),
+        [Operation::Pad, ..] => Some((ZERO, 1)),
+        [Operation::Push(value), ..] => Some((*value, 1)),
+        _ => None,
+    }
+}
+
+/// Returns the shortest sequence of operations which pushes the specified constant onto the stack.
+fn push_constant(value: Felt) -> Vec<Operation> {
+    if value == ZERO {
+        vec![Operation::Pad]
+    } else if value == ONE {
+        vec![Operation::Pad, Operation::Incr]
+    } else {
+        vec![Operation::Push(value)]
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{optimize_span, Decorator, Felt, Operation};
+    use alloc::{string::ToString, vec::Vec};
+    use vm_core::{AdviceInjector, AssemblyOp};
+
+    fn asmop(op: &str, num_cycles: u8, should_break: bool) -> Decorator {
+        Decorator::AsmOp(AssemblyOp::new(
+            "#main".to_string(),
+            num_cycles,
+            op.to_string(),
+            should_break,
+        ))
+    }
+
+    fn num_cycles(decorators: &[(usize, Decorator)]) -> Vec<(usize, u8)> {
+        decorators
+            .iter()
+            .filter_map(|(pos, decorator)| match decorator {
+                Decorator::AsmOp(asmop) => Some((*pos, asmop.num_cycles())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn peephole_rewrites() {
+        use Operation::*;
+
+        let cases = [
+            (vec![Swap, Swap, Add], vec![Add]),
+            (vec![Dup0, Drop, Dup3, Drop, Mul], vec![Mul]),
+            (vec![MovUp2, MovDn2, Pad, Add], vec![Noop]),
+            (vec![Pad, Incr, Add], vec![Incr]),
+            (vec![Pad, Eq, Pad, Incr, Mul], vec![Eqz]),
+            (vec![Push(Felt::new(3)), Push(Felt::new(4)), Mul, Drop], vec![Noop]),
+            (vec![Push(Felt::new(3)), Pad, Incr, Add, Add], vec![Push(Felt::new(4)), Add]),
+            (vec![Push(Felt::new(5)), Swap, Add, Neg, Neg], vec![Push(Felt::new(5)), Add]),
+            (vec![Pad, Not, Not], vec![Pad, Not, Not]),
+        ];
+        for (ops, expected) in cases {
+            let mut optimized = ops.clone();
+            let stats = optimize_span(&mut optimized, &mut Vec::new());
+            assert_eq!(optimized, expected, "{ops:?}");
+            assert_eq!(stats.cycles_saved(), ops.len() - expected.len());
+        }
+    }
+
+    #[test]
+    fn peephole_decorators() {
+        use Operation::*;
+
+        // asmops are mapped to the rewritten operations, and asmops of removed operations are
+        // removed
+        let mut ops = vec![Pad, Incr, Add, Swap, Swap, Mul];
+        let mut decorators = vec![
+            (0, asmop("push.1", 2, false)),
+            (2, asmop("add", 1, false)),
+            (3, asmop("swap", 1, false)),
+            (4, asmop("swap", 1, false)),
+            (5, asmop("mul", 1, false)),
+        ];
+        optimize_span(&mut ops, &mut decorators);
+        assert_eq!(ops, vec![Incr, Mul]);
+        assert_eq!(num_cycles(&decorators), vec![(0, 1), (1, 1)]);
+
+        // operations are not rewritten across decorators which may observe the stack, but may be
+        // rewritten right after them
+        let mut ops = vec![Dup0, Drop, Dup0, Drop];
+        let injector = Decorator::Advice(AdviceInjector::MemToMap);
+        let mut decorators = vec![(1, injector.clone()), (2, injector)];
+        optimize_span(&mut ops, &mut decorators);
+        assert_eq!(ops, vec![Dup0, Drop]);
+        assert_eq!(decorators.iter().map(|(pos, _)| *pos).collect::<Vec<_>>(), vec![1, 2]);
+
+        // breakpoints are preserved
+        let mut ops = vec![Noop, Swap, Swap];
+        let mut decorators = vec![(0, asmop("breakpoint", 1, true))];
+        optimize_span(&mut ops, &mut decorators);
+        assert_eq!(ops, vec![Noop]);
+        assert_eq!(num_cycles(&decorators), vec![(0, 1)]);
+    }
+}
@@ -1,10 +1,10 @@
 use super::{
-    AssemblyContext, AssemblyError, BodyWrapper, Borrow, CodeBlock, Decorator, DecoratorList,
-    Instruction, Operation,
+    optimizer::optimize_span, AssemblyContext, AssemblyError, BodyWrapper, Borrow, CodeBlock,
+    Decorator, DecoratorList, Instruction, Operation, OptimizationLevel, OptimizationStats,
 };
 use alloc::string::ToString;
 use alloc::vec::Vec;
-use vm_core::{AdviceInjector, AssemblyOp};
+use vm_core::{AdviceInjector, AssemblyOp, SourceLocation};
 
 // SPAN BUILDER
 // ================================================================================================
@@ -16,31 +16,42 @@ use vm_core::{AdviceInjector, AssemblyOp};
 ///
 /// The same span builder can be used to construct many blocks. It is expected that when the last
 /// SPAN block in a procedure's body is constructed `extract_final_span_into()` will be used.
+///
+/// If peephole optimization is enabled, the operations of each SPAN block are optimized when the
+/// block is extracted from the builder.
 #[derive(Default)]
 pub struct SpanBuilder {
     ops: Vec<Operation>,
     decorators: DecoratorList,
     epilogue: Vec<Operation>,
     last_asmop_pos: usize,
+    optimization_level: OptimizationLevel,
+    optimization_stats: OptimizationStats,
 }
 
 impl SpanBuilder {
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
-    /// Returns a new [SpanBuilder] instantiated with the specified optional wrapper.
+    /// Returns a new [SpanBuilder] instantiated with the specified optional wrapper, which
+    /// applies the specified optimizations to the extracted SPAN blocks.
     ///
     /// If the wrapper is provided, the prologue of the wrapper is immediately appended to the
     /// vector of span operations. The epilogue of the wrapper is appended to the list of
     /// operations upon consumption of the builder via `extract_final_span_into()` method.
-    pub(super) fn new(wrapper: Option<BodyWrapper>) -> Self {
+    pub(super) fn new(wrapper: Option<BodyWrapper>, optimization_level: OptimizationLevel) -> Self {
         match wrapper {
             Some(wrapper) => Self {
                 ops: wrapper.prologue,
                 decorators: Vec::new(),
                 epilogue: wrapper.epilogue,
                 last_asmop_pos: 0,
+                optimization_level,
+                optimization_stats: OptimizationStats::default(),
+            },
+            None => Self {
+                optimization_level,
+                ..Self::default()
             },
-            None => Self::default(),
         }
     }
 
@@ -100,12 +111,23 @@ impl SpanBuilder {
     ///
     /// This indicates that the provided instruction should be tracked and the cycle count for
     /// this instruction will be computed when the call to set_instruction_cycle_count() is made.
-    pub fn track_instruction(&mut self, instruction: &Instruction, ctx: &AssemblyContext) {
+    pub fn track_instruction(
+        &mut self,
+        instruction: &Instruction,
+        location: Option<&SourceLocation>,
+        ctx: &AssemblyContext,
+    ) {
         let context_name = ctx.current_context_name().to_string();
         let num_cycles = 0;
         let op = instruction.to_string();
         let should_break = instruction.should_break();
-        let op = AssemblyOp::new(context_name, num_cycles, op, should_break);
+        let mut op = AssemblyOp::new(context_name, num_cycles, op, should_break);
+        if let Some(module_path) = ctx.current_module_path() {
+            op = op.with_module_path(module_path.to_string());
+        }
+        if let Some(location) = location {
+            op = op.with_location(*location);
+        }
         self.push_decorator(Decorator::AsmOp(op));
         self.last_asmop_pos = self.decorators.len() - 1;
     }
@@ -142,6 +164,10 @@ impl SpanBuilder {
     /// This consumes all operations and decorators in the builder, but does not touch the
     /// operations in the epilogue of the builder.
     pub fn extract_span_into(&mut self, target: &mut Vec<CodeBlock>) {
+        if self.optimization_level == OptimizationLevel::Peephole {
+            self.optimization_stats += optimize_span(&mut self.ops, &mut self.decorators);
+        }
+
         if !self.ops.is_empty() {
             let ops = self.ops.drain(..).collect();
             let decorators = self.decorators.drain(..).collect();
@@ -160,9 +186,11 @@ impl SpanBuilder {
     /// The main differences from the `extract_span_int()` method above are:
     /// - Operations contained in the epilogue of the span builder are appended to the list of
     ///   ops which go into the new SPAN block.
-    /// - The span builder is consumed in the process.
-    pub fn extract_final_span_into(mut self, target: &mut Vec<CodeBlock>) {
+    /// - The span builder is consumed in the process, and the statistics of the optimizations
+    ///   applied to all SPAN blocks extracted from the builder are returned.
+    pub fn extract_final_span_into(mut self, target: &mut Vec<CodeBlock>) -> OptimizationStats {
         self.ops.append(&mut self.epilogue);
         self.extract_span_into(target);
+        self.optimization_stats
     }
 }
@@ -0,0 +1,131 @@
+use super::{Assembler, AssemblyContext, AssemblyError, CodeBody, Instruction, Node, Procedure};
+use crate::ast::{SourceLocation, StackEffect};
+
+/// Stack effect of popping the condition of a conditional or a loop off the stack.
+const CONDITION: StackEffect = StackEffect::new(1, 0);
+
+/// Stack effect of a `call` or a `syscall` to a procedure which decreases the stack depth: when
+/// such a procedure returns, the top 16 elements of the stack are replaced with its outputs
+/// followed by zeros.
+const SHRINKING_CALL: StackEffect = StackEffect::new(16, 16);
+
+// STACK EFFECT INFERENCE
+// ================================================================================================
+
+impl Assembler {
+    /// Returns the stack effect of the specified code body, or None if the stack effect cannot be
+    /// inferred (e.g., because the body makes dynamic calls).
+    ///
+    /// This must be invoked after the body has been compiled, since stack effects of the invoked
+    /// procedures are looked up in the procedure cache and the provided context.
+    ///
+    /// When `strict` is set, the body is required to have a well-defined stack effect; otherwise,
+    /// the stack effect of a body which violates the requirements below is treated as unknown.
+    ///
+    /// # Errors
+    /// Returns an error if `strict` is set and:
+    /// - Branches of an `if.true` statement change the stack depth differently.
+    /// - The body of a `while.true` loop does not push exactly one element onto the stack (i.e.,
+    ///   the condition for the next iteration).
+    /// - A procedure which increases the stack depth is invoked via `call` or `syscall`.
+    pub(super) fn infer_stack_effect(
+        &self,
+        body: &CodeBody,
+        context: &AssemblyContext,
+        strict: bool,
+    ) -> Result<Option<StackEffect>, AssemblyError> {
+        // nodes following a node with unknown stack effect are still checked for errors
+        let mut effect = Some(StackEffect::empty());
+        for (node_idx, node) in body.nodes().iter().enumerate() {
+            let location = body.source_locations().get(node_idx).copied().unwrap_or_default();
+            let node_effect = match node {
+                Node::Instruction(instruction) => {
+                    self.infer_instruction_stack_effect(instruction, location, context, strict)?
+                }
+                Node::IfElse {
+                    true_case,
+                    false_case,
+                } => {
+                    let true_effect = self.infer_stack_effect(true_case, context, strict)?;
+                    let false_effect = self.infer_stack_effect(false_case, context, strict)?;
+                    match (true_effect, false_effect) {
+                        (Some(true_effect), Some(false_effect)) => {
+                            match true_effect.join(&false_effect) {
+                                Some(branches) => Some(CONDITION.then(&branches)),
+                                None if strict => {
+                                    return Err(AssemblyError::unbalanced_branches(
+                                        &context.current_context_qualified_name(),
+                                        location,
+                                        &true_effect,
+                                        &false_effect,
+                                    ));
+                                }
+                                None => None,
+                            }
+                        }
+                        _ => None,
+                    }
+                }
+                Node::Repeat { times, body } => {
+                    self.infer_stack_effect(body, context, strict)?.map(|body_effect| {
+                        (0..*times)
+                            .fold(StackEffect::empty(), |effect, _| effect.then(&body_effect))
+                    })
+                }
+                Node::While { body } => match self.infer_stack_effect(body, context, strict)? {
+                    // the body of the loop must push the condition for the next iteration
+                    Some(body_effect) if body_effect.depth_change() != 1 && strict => {
+                        return Err(AssemblyError::unbalanced_loop(
+                            &context.current_context_qualified_name(),
+                            location,
+                            &body_effect,
+                        ));
+                    }
+                    Some(body_effect) if body_effect.depth_change() == 1 => {
+                        Some(CONDITION.then(&body_effect.then(&CONDITION)))
+                    }
+                    _ => None,
+                },
+            };
+            effect = effect.zip(node_effect).map(|(effect, node_effect)| effect.then(&node_effect));
+        }
+
+        Ok(effect)
+    }
+
+    /// Returns the stack effect of the specified instruction, or None if it cannot be inferred.
+    fn infer_instruction_stack_effect(
+        &self,
+        instruction: &Instruction,
+        location: SourceLocation,
+        context: &AssemblyContext,
+        strict: bool,
+    ) -> Result<Option<StackEffect>, AssemblyError> {
+        let proc_cache = self.proc_cache.borrow();
+        let call_effect = |proc: Option<&Procedure>| match proc.and_then(Procedure::stack_effect) {
+            Some(effect) if effect.depth_change() > 0 && strict => {
+                Err(AssemblyError::invalid_stack_depth_on_return(
+                    &context.current_context_qualified_name(),
+                    location,
+                    effect.depth_change(),
+                ))
+            }
+            Some(effect) if effect.depth_change() > 0 => Ok(None),
+            Some(effect) if effect.depth_change() < 0 => Ok(Some(SHRINKING_CALL)),
+            effect => Ok(effect),
+        };
+
+        match instruction {
+            Instruction::ExecLocal(idx) => Ok(context.get_local_procedure(*idx)?.stack_effect()),
+            Instruction::ExecImported(id) => {
+                Ok(proc_cache.get_by_id(id).and_then(Procedure::stack_effect))
+            }
+            Instruction::CallLocal(idx) => call_effect(Some(context.get_local_procedure(*idx)?)),
+            Instruction::CallImported(id) | Instruction::SysCall(id) => {
+                call_effect(proc_cache.get_by_id(id))
+            }
+            Instruction::CallMastRoot(mast_root) => call_effect(proc_cache.get_by_hash(mast_root)),
+            instruction => Ok(instruction.stack_effect()),
+        }
+    }
+}
@@ -96,7 +96,11 @@ impl fmt::Display for FormattableProcedureAst<'_> {
         } else {
             write!(f, "proc.")?;
         }
-        writeln!(f, "{}.{}", self.proc.name, self.proc.num_locals)?;
+        write!(f, "{}.{}", self.proc.name, self.proc.num_locals)?;
+        if let Some(signature) = self.proc.signature {
+            write!(f, ".{signature}")?;
+        }
+        writeln!(f)?;
         // Body
         write!(
             f,
@@ -43,6 +43,9 @@ pub use procedure::{ProcReExport, ProcedureAst};
 mod program;
 pub use program::ProgramAst;
 
+mod stack_effect;
+pub use stack_effect::StackEffect;
+
 pub(crate) use parsers::{
     parse_param_with_constant_lookup, NAMESPACE_LABEL_PARSER, PROCEDURE_LABEL_PARSER,
 };
@@ -20,6 +20,7 @@ use vm_core::{AdviceInjector, Felt, SignatureKind, ZERO};
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum AdviceInjectorNode {
     PushU64Div,
+    PushU256Div,
     PushExt2intt,
     PushSmtGet,
     PushSmtSet,
@@ -41,6 +42,7 @@ impl From<&AdviceInjectorNode> for AdviceInjector {
         use AdviceInjectorNode::*;
         match value {
             PushU64Div => Self::U64Div,
+            PushU256Div => Self::U256Div,
             PushExt2intt => Self::Ext2Intt,
             PushSmtGet => Self::SmtGet,
             PushSmtSet => Self::SmtSet,
@@ -78,6 +80,7 @@ impl fmt::Display for AdviceInjectorNode {
         use AdviceInjectorNode::*;
         match self {
             PushU64Div => write!(f, "push_u64div"),
+            PushU256Div => write!(f, "push_u256div"),
             PushExt2intt => write!(f, "push_ext2intt"),
             PushSmtGet => write!(f, "push_smtget"),
             PushSmtSet => write!(f, "push_smtset"),
@@ -114,12 +117,14 @@ const INSERT_HDWORD: u8 = 11;
 const INSERT_HDWORD_IMM: u8 = 12;
 const INSERT_HPERM: u8 = 13;
 const PUSH_SIG: u8 = 14;
+const PUSH_U256DIV: u8 = 15;
 
 impl Serializable for AdviceInjectorNode {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         use AdviceInjectorNode::*;
         match self {
             PushU64Div => target.write_u8(PUSH_U64DIV),
+            PushU256Div => target.write_u8(PUSH_U256DIV),
             PushExt2intt => target.write_u8(PUSH_EXT2INTT),
             PushSmtGet => target.write_u8(PUSH_SMTGET),
             PushSmtSet => target.write_u8(PUSH_SMTSET),
@@ -154,6 +159,7 @@ impl Deserializable for AdviceInjectorNode {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         match source.read_u8()? {
             PUSH_U64DIV => Ok(AdviceInjectorNode::PushU64Div),
+            PUSH_U256DIV => Ok(AdviceInjectorNode::PushU256Div),
             PUSH_EXT2INTT => Ok(AdviceInjectorNode::PushExt2intt),
             PUSH_SMTGET => Ok(AdviceInjectorNode::PushSmtGet),
             PUSH_SMTSET => Ok(AdviceInjectorNode::PushSmtSet),
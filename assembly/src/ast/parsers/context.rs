@@ -248,11 +248,13 @@ impl ParserContext<'_> {
         while let Some(token) = tokens.read() {
             let is_reexport = match token.parts()[0] {
                 Token::EXPORT => {
+                    let Some(proc_name) = token.parts().get(1) else {
+                        return Err(ParsingError::missing_param(token, "export.<procedure_name>"));
+                    };
                     if !allow_export {
-                        let proc_name = token.parts()[1];
                         return Err(ParsingError::proc_export_not_allowed(token, proc_name));
                     }
-                    token.parts()[1].contains(LibraryPath::PATH_DELIM)
+                    proc_name.contains(LibraryPath::PATH_DELIM)
                 }
                 Token::PROC => {
                     // no validation needed, parse the procedure below
@@ -290,7 +292,7 @@ impl ParserContext<'_> {
         // parse procedure declaration, make sure the procedure with the same name hasn't been
         // declared previously, and consume the `proc` or `export` token.
         let header = tokens.read().expect("missing procedure header");
-        let (name, num_locals, is_export) = header.parse_proc()?;
+        let (name, num_locals, signature, is_export) = header.parse_proc()?;
         if self.contains_proc_name(&name) {
             return Err(ParsingError::duplicate_proc_name(header, name.as_str()));
         }
@@ -338,6 +340,7 @@ impl ParserContext<'_> {
         // build and return the procedure
         let (nodes, locations) = body.into_parts();
         Ok(ProcedureAst::new(name, num_locals, nodes, is_export, docs)
+            .with_signature(signature)
             .with_source_locations(locations, start))
     }
 
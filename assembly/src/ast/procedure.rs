@@ -8,6 +8,7 @@ use crate::ast::{MAX_BODY_LEN, MAX_DOCS_LEN};
 use super::{
     super::tokens::SourceLocation, code_body::CodeBody, nodes::Node, ByteReader, ByteWriter,
     Deserializable, DeserializationError, LibraryPath, ProcedureId, ProcedureName, Serializable,
+    StackEffect,
 };
 use core::{iter, str::from_utf8};
 
@@ -27,6 +28,8 @@ pub struct ProcedureAst {
     pub body: CodeBody,
     pub start: SourceLocation,
     pub is_export: bool,
+    /// Stack effect declared in the procedure signature, if any.
+    pub signature: Option<StackEffect>,
 }
 
 impl ProcedureAst {
@@ -52,9 +55,16 @@ impl ProcedureAst {
             body,
             is_export,
             start,
+            signature: None,
         }
     }
 
+    /// Sets the stack effect declared in the signature of this procedure.
+    pub fn with_signature(mut self, signature: Option<StackEffect>) -> Self {
+        self.signature = signature;
+        self
+    }
+
     /// Binds the provided `locations` into the ast nodes.
     ///
     /// The `start` location points to the first node of this block.
@@ -129,6 +139,10 @@ impl Serializable for ProcedureAst {
 
         target.write_bool(self.is_export);
         target.write_u16(self.num_locals);
+        target.write_bool(self.signature.is_some());
+        if let Some(signature) = self.signature {
+            signature.write_into(target);
+        }
         assert!(self.body.nodes().len() <= MAX_BODY_LEN, "too many body instructions");
         target.write_u16(self.body.nodes().len() as u16);
         target.write_many(self.body.nodes());
@@ -150,6 +164,11 @@ impl Deserializable for ProcedureAst {
 
         let is_export = source.read_bool()?;
         let num_locals = source.read_u16()?;
+        let signature = if source.read_bool()? {
+            Some(StackEffect::read_from(source)?)
+        } else {
+            None
+        };
         let body_len = source.read_u16()? as usize;
         let nodes = source.read_many::<Node>(body_len)?;
         let body = CodeBody::new(nodes);
@@ -161,6 +180,7 @@ impl Deserializable for ProcedureAst {
             start,
             is_export,
             docs,
+            signature,
         })
     }
 }
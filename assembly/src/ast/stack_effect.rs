@@ -0,0 +1,274 @@
+use super::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Instruction, Serializable,
+};
+use core::fmt;
+
+// STACK EFFECT
+// ================================================================================================
+
+/// Describes how a sequence of instructions changes the operand stack.
+///
+/// A stack effect consists of:
+/// - The number of inputs, i.e., the number of elements at the top of the stack which are read or
+///   modified by the instructions.
+/// - The number of outputs, i.e., the number of elements at the top of the stack which replace the
+///   inputs once the instructions are executed.
+/// - The maximum depth, i.e., the maximum number of elements above the deepest input which are on
+///   the stack at any point during the execution of the instructions.
+///
+/// Elements below the inputs are not touched by the instructions. For example, the stack effect of
+/// `push.1 add` consists of 1 input and 1 output, and its maximum depth is 2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StackEffect {
+    inputs: u16,
+    outputs: u16,
+    max_depth: u16,
+}
+
+impl StackEffect {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [StackEffect] with the specified number of inputs and outputs, whose maximum
+    /// depth is the larger of the two.
+    pub const fn new(inputs: u16, outputs: u16) -> Self {
+        let max_depth = if inputs > outputs { inputs } else { outputs };
+        Self {
+            inputs,
+            outputs,
+            max_depth,
+        }
+    }
+
+    /// Returns the stack effect of a sequence of instructions which does not touch the stack.
+    pub const fn empty() -> Self {
+        Self::new(0, 0)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of elements at the top of the stack which are read or modified.
+    pub const fn inputs(&self) -> u16 {
+        self.inputs
+    }
+
+    /// Returns the number of elements at the top of the stack which replace the inputs.
+    pub const fn outputs(&self) -> u16 {
+        self.outputs
+    }
+
+    /// Returns the maximum number of elements above the deepest input which are on the stack at
+    /// any point.
+    pub const fn max_depth(&self) -> u16 {
+        self.max_depth
+    }
+
+    /// Returns the change of the stack depth caused by the instructions.
+    pub fn depth_change(&self) -> i32 {
+        i32::from(self.outputs) - i32::from(self.inputs)
+    }
+
+    // COMBINATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the stack effect of executing instructions with this stack effect followed by
+    /// instructions with the `next` stack effect.
+    pub fn then(&self, next: &Self) -> Self {
+        // if the next instructions read deeper than the outputs of these instructions, they read
+        // elements which are below the inputs of these instructions
+        let extra = next.inputs.saturating_sub(self.outputs);
+        let next_base = self.outputs + extra - next.inputs;
+        Self {
+            inputs: self.inputs.saturating_add(extra),
+            outputs: next_base.saturating_add(next.outputs),
+            max_depth: (self.max_depth.saturating_add(extra))
+                .max(next_base.saturating_add(next.max_depth)),
+        }
+    }
+
+    /// Returns the stack effect of executing instructions with either this stack effect or the
+    /// `other` stack effect, or None if the two change the stack depth differently.
+    pub fn join(&self, other: &Self) -> Option<Self> {
+        if self.depth_change() != other.depth_change() {
+            return None;
+        }
+        let inputs = self.inputs.max(other.inputs);
+        Some(Self {
+            inputs,
+            outputs: inputs + self.outputs - self.inputs,
+            max_depth: (self.max_depth + (inputs - self.inputs))
+                .max(other.max_depth + (inputs - other.inputs)),
+        })
+    }
+
+    /// Returns true if instructions with the specified stack effect can be used where instructions
+    /// with this stack effect are expected, i.e., they change the stack depth in the same way and
+    /// do not read deeper than this stack effect allows.
+    pub fn accepts(&self, other: &Self) -> bool {
+        self.depth_change() == other.depth_change() && other.inputs <= self.inputs
+    }
+}
+
+impl Default for StackEffect {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl fmt::Display for StackEffect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}->{}", self.inputs, self.outputs)
+    }
+}
+
+impl Serializable for StackEffect {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u16(self.inputs);
+        target.write_u16(self.outputs);
+    }
+}
+
+impl Deserializable for StackEffect {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let inputs = source.read_u16()?;
+        let outputs = source.read_u16()?;
+        Ok(Self::new(inputs, outputs))
+    }
+}
+
+// INSTRUCTION STACK EFFECTS
+// ================================================================================================
+
+impl Instruction {
+    /// Returns the stack effect of this instruction.
+    ///
+    /// Returns None for instructions which invoke procedures (i.e., `exec`, `call`, `syscall`,
+    /// `dynexec` and `dyncall`), as their stack effect depends on the invoked procedure.
+    pub fn stack_effect(&self) -> Option<StackEffect> {
+        use Instruction::*;
+        let (inputs, outputs) = match self {
+            Assert | AssertWithError(_) | Assertz | AssertzWithError(_) => (1, 0),
+            AssertEq | AssertEqWithError(_) => (2, 0),
+            AssertEqw | AssertEqwWithError(_) => (8, 0),
+            Add | Sub | Mul | Div | Exp | ExpBitLength(_) | And | Or | Xor | Eq | Neq | Lt
+            | Lte | Gt | Gte => (2, 1),
+            AddImm(_) | SubImm(_) | MulImm(_) | DivImm(_) | Neg | Inv | Incr | Pow2 | ExpImm(_)
+            | ILog2 | Not | EqImm(_) | NeqImm(_) | IsOdd => (1, 1),
+            Eqw => (8, 9),
+
+            // ----- ext2 operations ----------------------------------------------------------
+            Ext2Add | Ext2Sub | Ext2Mul | Ext2Div => (4, 2),
+            Ext2Neg | Ext2Inv => (2, 2),
+
+            // ----- u32 manipulation ---------------------------------------------------------
+            U32Test => (1, 2),
+            U32TestW => (4, 5),
+            U32Assert | U32AssertWithError(_) | U32Cast => (1, 1),
+            U32Assert2 | U32Assert2WithError(_) => (2, 2),
+            U32AssertW | U32AssertWWithError(_) => (4, 4),
+            U32Split => (1, 2),
+            U32WrappingAdd | U32WrappingSub | U32WrappingMul | U32Div | U32Mod | U32And | U32Or
+            | U32Xor | U32Shr | U32Shl | U32Rotr | U32Rotl | U32Lt | U32Lte | U32Gt | U32Gte
+            | U32Min | U32Max => (2, 1),
+            U32WrappingAddImm(_) | U32WrappingSubImm(_) | U32WrappingMulImm(_) | U32DivImm(_)
+            | U32ModImm(_) | U32Not | U32ShrImm(_) | U32ShlImm(_) | U32RotrImm(_)
+            | U32RotlImm(_) | U32Popcnt | U32Clz | U32Ctz | U32Clo | U32Cto => (1, 1),
+            U32OverflowingAdd | U32OverflowingSub | U32OverflowingMul | U32DivMod => (2, 2),
+            U32OverflowingAddImm(_)
+            | U32OverflowingSubImm(_)
+            | U32OverflowingMulImm(_)
+            | U32DivModImm(_) => (1, 2),
+            U32OverflowingAdd3 | U32OverflowingMadd => (3, 2),
+            U32WrappingAdd3 | U32WrappingMadd => (3, 1),
+
+            // ----- stack manipulation -------------------------------------------------------
+            Drop => (1, 0),
+            DropW => (4, 0),
+            PadW => (0, 4),
+            Dup0 => (1, 2),
+            Dup1 => (2, 3),
+            Dup2 => (3, 4),
+            Dup3 => (4, 5),
+            Dup4 => (5, 6),
+            Dup5 => (6, 7),
+            Dup6 => (7, 8),
+            Dup7 => (8, 9),
+            Dup8 => (9, 10),
+            Dup9 => (10, 11),
+            Dup10 => (11, 12),
+            Dup11 => (12, 13),
+            Dup12 => (13, 14),
+            Dup13 => (14, 15),
+            Dup14 => (15, 16),
+            Dup15 => (16, 17),
+            DupW0 => (4, 8),
+            DupW1 => (8, 12),
+            DupW2 => (12, 16),
+            DupW3 => (16, 20),
+            Swap1 => (2, 2),
+            Swap2 | MovUp2 | MovDn2 => (3, 3),
+            Swap3 | MovUp3 | MovDn3 => (4, 4),
+            Swap4 | MovUp4 | MovDn4 => (5, 5),
+            Swap5 | MovUp5 | MovDn5 => (6, 6),
+            Swap6 | MovUp6 | MovDn6 => (7, 7),
+            Swap7 | MovUp7 | MovDn7 => (8, 8),
+            Swap8 | MovUp8 | MovDn8 => (9, 9),
+            Swap9 | MovUp9 | MovDn9 => (10, 10),
+            Swap10 | MovUp10 | MovDn10 => (11, 11),
+            Swap11 | MovUp11 | MovDn11 => (12, 12),
+            Swap12 | MovUp12 | MovDn12 => (13, 13),
+            Swap13 | MovUp13 | MovDn13 => (14, 14),
+            Swap14 | MovUp14 | MovDn14 => (15, 15),
+            Swap15 | MovUp15 | MovDn15 => (16, 16),
+            SwapW1 => (8, 8),
+            SwapW2 | MovUpW2 | MovDnW2 => (12, 12),
+            SwapW3 | SwapDw | MovUpW3 | MovDnW3 => (16, 16),
+            CSwap => (3, 2),
+            CSwapW => (9, 8),
+            CDrop => (3, 1),
+            CDropW => (9, 4),
+
+            // ----- input / output operations ------------------------------------------------
+            PushU8(_) | PushU16(_) | PushU32(_) | PushFelt(_) => (0, 1),
+            PushWord(_) => (0, 4),
+            PushU8List(values) => (0, values.len()),
+            PushU16List(values) => (0, values.len()),
+            PushU32List(values) => (0, values.len()),
+            PushFeltList(values) => (0, values.len()),
+            Locaddr(_) | Sdepth | Clk | MemLoadImm(_) | LocLoad(_) => (0, 1),
+            Caller | MemLoadWImm(_) | LocLoadW(_) | MemStoreWImm(_) | LocStoreW(_) | AdvLoadW => {
+                (4, 4)
+            }
+            MemLoad => (1, 1),
+            MemLoadW | MemStoreW => (5, 4),
+            MemStore => (2, 0),
+            MemStoreImm(_) | LocStore(_) => (1, 0),
+            MemStream | AdvPipe => (13, 13),
+            AdvPush(n) => (0, *n as usize),
+            // advice injectors read values from the stack, but never change it
+            AdvInject(_) => (0, 0),
+
+            // ----- cryptographic operations -------------------------------------------------
+            Hash => (4, 4),
+            HMerge | MTreeMerge => (8, 4),
+            HPerm => (12, 12),
+            MTreeGet => (6, 8),
+            MTreeSet => (10, 8),
+            MTreeVerify => (10, 10),
+
+            // ----- STARK proof verification -------------------------------------------------
+            FriExt2Fold4 => (16, 15),
+            RCombBase => (16, 16),
+
+            // ----- exec / call --------------------------------------------------------------
+            ExecLocal(_) | ExecImported(_) | CallLocal(_) | CallMastRoot(_) | CallImported(_)
+            | SysCall(_) | DynExec | DynCall => return None,
+            ProcRefLocal(_) | ProcRefImported(_) => (0, 4),
+
+            // ----- debug and event decorators -----------------------------------------------
+            Breakpoint | Debug(_) | Emit(_) | Trace(_) => (0, 0),
+        };
+        Some(StackEffect::new(inputs as u16, outputs as u16))
+    }
+}
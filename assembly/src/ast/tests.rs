@@ -1,6 +1,6 @@
 use super::{
     AstSerdeOptions, CodeBody, Felt, Instruction, LocalProcMap, ModuleAst, Node, ParsingError,
-    ProcedureAst, ProcedureId, ProcedureName, ProgramAst, SourceLocation, Token,
+    ProcedureAst, ProcedureId, ProcedureName, ProgramAst, SourceLocation, StackEffect, Token,
 };
 use alloc::{
     collections::BTreeMap,
@@ -228,9 +228,11 @@ fn test_ast_parsing_adv_injection() {
     use super::AdviceInjectorNode::*;
     use Instruction::AdvInject;
 
-    let source = "begin adv.push_u64div adv.push_mapval adv.push_smtget adv.insert_mem end";
+    let source =
+        "begin adv.push_u64div adv.push_u256div adv.push_mapval adv.push_smtget adv.insert_mem end";
     let nodes: Vec<Node> = vec![
         Node::Instruction(AdvInject(PushU64Div)),
+        Node::Instruction(AdvInject(PushU256Div)),
         Node::Instruction(AdvInject(PushMapVal)),
         Node::Instruction(AdvInject(PushSmtGet)),
         Node::Instruction(AdvInject(InsertMem)),
@@ -539,6 +541,18 @@ fn test_unterminated_proc() {
     }
 }
 
+#[test]
+fn test_export_without_name() {
+    let result = ModuleAst::parse("export");
+    match result {
+        Ok(_) => panic!("should have panicked"),
+        Err(err) => assert!(err.to_string().contains("expected format `export.<procedure_name>`")),
+    }
+
+    let result = ProgramAst::parse("export begin push.1 end");
+    assert!(result.is_err());
+}
+
 #[test]
 fn test_unterminated_if() {
     let source = "proc.foo add mul if.true add.2 begin push.1 end";
@@ -812,6 +826,18 @@ fn test_ast_program_serde_exported_procs() {
     assert_correct_module_serialization(source, true);
 }
 
+#[test]
+fn test_ast_program_serde_proc_signatures() {
+    let source = "\
+    export.foo.1.2->1
+        loc_store.0
+    end
+    export.bar.4->0
+        dropw
+    end";
+    assert_correct_module_serialization(source, true);
+}
+
 #[test]
 fn test_ast_program_serde_control_flow() {
     let source = "\
@@ -852,6 +878,25 @@ fn assert_parsing_line_unmatched_begin() {
     assert_eq!(err, ParsingError::unmatched_begin(&Token::new("begin", location)));
 }
 
+#[test]
+fn assert_parsing_proc_signature() {
+    let module = ModuleAst::parse("export.foo.2->1\nadd\nend").unwrap();
+    assert_eq!(Some(StackEffect::new(2, 1)), module.procs()[0].signature);
+    assert_eq!(0, module.procs()[0].num_locals);
+
+    let module = ModuleAst::parse("proc.foo.3.2->1\nadd\nend export.bar\nexec.foo\nend").unwrap();
+    assert_eq!(Some(StackEffect::new(2, 1)), module.procs()[0].signature);
+    assert_eq!(3, module.procs()[0].num_locals);
+    assert_eq!(None, module.procs()[1].signature);
+
+    let err = ModuleAst::parse("export.foo.2->x\nadd\nend").err().unwrap();
+    let location = SourceLocation::new(1, 1);
+    assert_eq!(
+        err,
+        ParsingError::invalid_proc_signature(&Token::new("export.foo.2->x", location), "2->x")
+    );
+}
+
 #[test]
 fn assert_parsing_line_extra_param() {
     let source = "begin add.1.2\nend".to_string();
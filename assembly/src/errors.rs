@@ -1,6 +1,8 @@
 use super::{
-    ast::ProcReExport, crypto::hash::RpoDigest, tokens::SourceLocation, KernelError,
-    LibraryNamespace, ProcedureId, ProcedureName, Token,
+    ast::{ProcReExport, StackEffect},
+    crypto::hash::RpoDigest,
+    tokens::SourceLocation,
+    KernelError, LibraryNamespace, ProcedureId, ProcedureName, Token,
 };
 use alloc::{
     string::{String, ToString},
@@ -27,6 +29,7 @@ pub enum AssemblyError {
     ImportedProcNotFoundInModule(ProcedureId, String),
     InvalidCacheLock,
     InvalidProgramAssemblyContext,
+    InvalidStackDepthOnReturn(String, SourceLocation, i32),
     Io(String),
     KernelError(KernelError),
     KernelProcNotFound(ProcedureId),
@@ -36,8 +39,11 @@ pub enum AssemblyError {
     ParsingError(String),
     PhantomCallsNotAllowed(RpoDigest),
     ProcedureNameError(String),
+    ProcSignatureMismatch(String, StackEffect, StackEffect),
     ReExportedProcModuleNotFound(ProcReExport),
     SysCallInKernel(String),
+    UnbalancedBranches(String, SourceLocation, i32, i32),
+    UnbalancedLoop(String, SourceLocation, i32),
 }
 
 impl AssemblyError {
@@ -76,6 +82,14 @@ impl AssemblyError {
         Self::ExportedProcInProgram(proc_name.to_string())
     }
 
+    pub fn invalid_stack_depth_on_return(
+        proc_name: &str,
+        location: SourceLocation,
+        depth_change: i32,
+    ) -> Self {
+        Self::InvalidStackDepthOnReturn(proc_name.to_string(), location, depth_change)
+    }
+
     pub fn imported_proc_module_not_found(
         proc_id: &ProcedureId,
         proc_name: Option<ProcedureName>,
@@ -107,10 +121,40 @@ impl AssemblyError {
         Self::PhantomCallsNotAllowed(mast_root)
     }
 
+    pub fn proc_signature_mismatch(
+        proc_name: &str,
+        declared: StackEffect,
+        inferred: StackEffect,
+    ) -> Self {
+        Self::ProcSignatureMismatch(proc_name.to_string(), declared, inferred)
+    }
+
     pub fn syscall_in_kernel(kernel_proc_name: &str) -> Self {
         Self::SysCallInKernel(kernel_proc_name.to_string())
     }
 
+    pub fn unbalanced_branches(
+        proc_name: &str,
+        location: SourceLocation,
+        true_effect: &StackEffect,
+        false_effect: &StackEffect,
+    ) -> Self {
+        Self::UnbalancedBranches(
+            proc_name.to_string(),
+            location,
+            true_effect.depth_change(),
+            false_effect.depth_change(),
+        )
+    }
+
+    pub fn unbalanced_loop(
+        proc_name: &str,
+        location: SourceLocation,
+        body_effect: &StackEffect,
+    ) -> Self {
+        Self::UnbalancedLoop(proc_name.to_string(), location, body_effect.depth_change() - 1)
+    }
+
     pub fn invalid_cache_lock() -> Self {
         Self::InvalidCacheLock
     }
@@ -151,6 +195,7 @@ impl fmt::Display for AssemblyError {
             ImportedProcNotFoundInModule(proc_id, module_path) => write!(f, "imported procedure {proc_id} not found in module {module_path}"),
             InvalidCacheLock => write!(f, "an attempt was made to lock a borrowed procedures cache"),
             InvalidProgramAssemblyContext => write!(f, "assembly context improperly initialized for program compilation"),
+            InvalidStackDepthOnReturn(proc_name, location, depth_change) => write!(f, "procedure invoked via call at {location} in '{proc_name}' changes the stack depth by {depth_change:+}, but the stack depth must not grow across a call"),
             Io(description) => write!(f, "I/O error: {description}"),
             KernelError(error) => write!(f, "{}", error),
             KernelProcNotFound(proc_id) => write!(f, "procedure {proc_id} not found in kernel"),
@@ -158,8 +203,11 @@ impl fmt::Display for AssemblyError {
             LocalProcNotFound(proc_idx, module_path) => write!(f, "procedure at index {proc_idx} not found in module {module_path}"),
             ParamOutOfBounds(value, min, max) => write!(f, "parameter value must be greater than or equal to {min} and less than or equal to {max}, but was {value}"),
             PhantomCallsNotAllowed(mast_root) => write!(f, "cannot call phantom procedure with MAST root {mast_root}: phantom calls not allowed"),
+            ProcSignatureMismatch(proc_name, declared, inferred) => write!(f, "procedure '{proc_name}' is declared with stack effect {declared}, but its body has stack effect {inferred}"),
             ReExportedProcModuleNotFound(reexport) => write!(f, "re-exported proc {} with id {} not found", reexport.name(), reexport.proc_id()),
             SysCallInKernel(proc_name) => write!(f, "syscall instruction used in kernel procedure '{proc_name}'"),
+            UnbalancedBranches(proc_name, location, true_change, false_change) => write!(f, "branches of if.true at {location} in '{proc_name}' change the stack depth differently: by {true_change:+} and by {false_change:+}"),
+            UnbalancedLoop(proc_name, location, depth_change) => write!(f, "body of while.true at {location} in '{proc_name}' changes the stack depth by {depth_change:+} in every iteration"),
         }
     }
 }
@@ -495,6 +543,14 @@ impl ParsingError {
         }
     }
 
+    pub fn invalid_proc_signature(token: &Token, signature: &str) -> Self {
+        ParsingError {
+            message: format!("invalid procedure signature: {signature}"),
+            location: *token.location(),
+            op: token.to_string(),
+        }
+    }
+
     pub fn too_many_proc_locals(token: &Token, num_locals: u64, max_locals: u64) -> Self {
         ParsingError {
             message: format!("number of procedure locals cannot be greater than {max_locals} characters, but was {num_locals}"),
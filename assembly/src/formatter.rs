@@ -0,0 +1,427 @@
+use super::{
+    ast::{ModuleAst, ProgramAst},
+    tokenize, LibraryPath, ParsingError, Token,
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// String used to indent a single nesting level of the formatted source.
+const INDENT_STRING: &str = "    ";
+
+// SOURCE FORMATTER
+// ================================================================================================
+
+/// Returns the specified Miden assembly source formatted in the canonical style.
+///
+/// The source is parsed as an executable program if it contains a `begin` token, and as a library
+/// module otherwise. Unlike the [core::fmt::Display] implementations of the ASTs, the formatter
+/// works on the source itself and thus preserves comments, doc comments and constants. The
+/// formatting consists of:
+/// - placing `begin`, `proc`, `export`, `if.true`, `else`, `while.true`, `repeat` and `end` on
+///   separate lines, and indenting the bodies of blocks by four spaces per nesting level;
+/// - separating consecutive tokens by a single space, while preserving the alignment of trailing
+///   comments;
+/// - separating procedures and the program body by a single blank line, collapsing consecutive
+///   blank lines and removing blank lines at the start and the end of blocks;
+/// - writing numeric immediate values without leading zeros, and hexadecimal immediate values in
+///   lowercase.
+///
+/// Instructions which are on the same line in the source are kept on the same line.
+///
+/// # Errors
+/// Returns an error if the source cannot be parsed.
+pub fn format_source(source: &str) -> Result<String, ParsingError> {
+    if tokenize(source).any(|(token, _)| token == Token::BEGIN) {
+        ProgramAst::parse(source)?;
+    } else {
+        ModuleAst::parse(source)?;
+    }
+
+    let mut formatter = SourceFormatter::default();
+    for line in source.lines() {
+        formatter.format_line(line);
+    }
+    Ok(formatter.into_source())
+}
+
+/// Kinds of the lines of a formatted source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LineKind {
+    Blank,
+    Comment,
+    Code,
+    /// A line which starts a block, i.e., `begin`, `proc`, `if.true`, `else` etc.
+    BlockStart,
+}
+
+/// A line of a formatted source.
+struct Line {
+    kind: LineKind,
+    indent: usize,
+    text: String,
+}
+
+/// Accumulates the formatted lines of a source.
+#[derive(Default)]
+struct SourceFormatter {
+    lines: Vec<Line>,
+    indent: usize,
+}
+
+impl SourceFormatter {
+    /// Formats the specified line of the source and appends the result to the formatted lines.
+    fn format_line(&mut self, line: &str) {
+        let line = line.trim();
+        if line.is_empty() {
+            return self.push_blank();
+        }
+
+        // comments start at the first token which starts with the comment prefix
+        let (code, comment) = match line
+            .match_indices(Token::COMMENT_PREFIX)
+            .find(|(pos, _)| *pos == 0 || line[..*pos].ends_with(char::is_whitespace))
+        {
+            Some((pos, _)) => (&line[..pos], Some(format_comment(&line[pos..]))),
+            None => (line, None),
+        };
+
+        let num_lines = self.lines.len();
+        let mut instructions = Vec::new();
+        for token in code.split_whitespace() {
+            let token = format_token(token);
+            match block_delimiter(&token) {
+                Some(Delimiter::Start) => {
+                    self.push_code(&mut instructions);
+                    if self.indent == 0 {
+                        self.separate_definition();
+                    }
+                    self.push(LineKind::BlockStart, token);
+                    self.indent += 1;
+                }
+                Some(Delimiter::Else) => {
+                    self.push_code(&mut instructions);
+                    self.indent = self.indent.saturating_sub(1);
+                    self.trim_trailing_blanks();
+                    self.push(LineKind::BlockStart, token);
+                    self.indent += 1;
+                }
+                Some(Delimiter::End) => {
+                    self.push_code(&mut instructions);
+                    self.indent = self.indent.saturating_sub(1);
+                    self.trim_trailing_blanks();
+                    self.push(LineKind::Code, token);
+                }
+                None => instructions.push(token),
+            }
+        }
+        self.push_code(&mut instructions);
+
+        // trailing comments are attached to the last line formatted from the same source line
+        if let Some(comment) = comment {
+            if self.lines.len() > num_lines {
+                // the whitespace preceding the comment is preserved to keep comments aligned
+                let padding = code.len() - code.trim_end().len();
+                let line = self.lines.last_mut().expect("no formatted lines");
+                line.text.push_str(&" ".repeat(padding));
+                line.text.push_str(&comment);
+            } else {
+                self.push(LineKind::Comment, comment);
+            }
+        }
+    }
+
+    /// Returns the formatted source.
+    fn into_source(mut self) -> String {
+        self.trim_trailing_blanks();
+        let mut source = String::new();
+        for line in self.lines.iter() {
+            if line.kind != LineKind::Blank {
+                source.push_str(&INDENT_STRING.repeat(line.indent));
+                source.push_str(&line.text);
+            }
+            source.push('\n');
+        }
+        source
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Appends a line with the specified contents at the current indentation level.
+    fn push(&mut self, kind: LineKind, text: String) {
+        self.lines.push(Line {
+            kind,
+            indent: self.indent,
+            text,
+        });
+    }
+
+    /// Appends a line with the specified instructions, if any, and clears the instructions.
+    fn push_code(&mut self, instructions: &mut Vec<String>) {
+        if !instructions.is_empty() {
+            self.push(LineKind::Code, instructions.join(" "));
+            instructions.clear();
+        }
+    }
+
+    /// Appends a blank line, unless it would be at the start of the source or a block, or follow
+    /// another blank line.
+    fn push_blank(&mut self) {
+        if self
+            .lines
+            .last()
+            .is_some_and(|line| !matches!(line.kind, LineKind::Blank | LineKind::BlockStart))
+        {
+            self.push(LineKind::Blank, String::new());
+        }
+    }
+
+    /// Removes the blank lines at the end of the formatted lines.
+    fn trim_trailing_blanks(&mut self) {
+        while self.lines.last().is_some_and(|line| line.kind == LineKind::Blank) {
+            self.lines.pop();
+        }
+    }
+
+    /// Makes sure that a top-level definition which is about to be appended is separated from the
+    /// preceding definitions by a blank line.
+    ///
+    /// The comments immediately preceding the definition (e.g., its doc comment) are considered to
+    /// be part of the definition.
+    fn separate_definition(&mut self) {
+        let start = self
+            .lines
+            .iter()
+            .rposition(|line| line.kind != LineKind::Comment)
+            .map_or(0, |pos| pos + 1);
+        if start > 0 && self.lines[start - 1].kind != LineKind::Blank {
+            self.lines.insert(
+                start,
+                Line {
+                    kind: LineKind::Blank,
+                    indent: 0,
+                    text: String::new(),
+                },
+            );
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Tokens which delimit the blocks of a source.
+enum Delimiter {
+    Start,
+    Else,
+    End,
+}
+
+/// Returns the kind of the block delimiter represented by the specified token, if any.
+fn block_delimiter(token: &str) -> Option<Delimiter> {
+    let (name, label) = token.split_once('.').unwrap_or((token, ""));
+    match name {
+        Token::BEGIN | Token::PROC | Token::IF | Token::WHILE | Token::REPEAT => {
+            Some(Delimiter::Start)
+        }
+        // re-exported procedures are referenced by their module and do not have a body
+        Token::EXPORT if !label.contains(LibraryPath::PATH_DELIM) => Some(Delimiter::Start),
+        Token::ELSE => Some(Delimiter::Else),
+        Token::END => Some(Delimiter::End),
+        _ => None,
+    }
+}
+
+/// Returns the specified token with its numeric immediate values in the canonical form.
+fn format_token(token: &str) -> String {
+    let mut parts = token.split('.');
+    let mut formatted = parts.next().unwrap_or_default().to_string();
+    for part in parts {
+        formatted.push('.');
+        match part.split_once('=') {
+            // values of constants and error codes of assertions, e.g., `const.A=0x10`
+            Some((name, value)) => {
+                formatted.push_str(name);
+                formatted.push('=');
+                formatted.push_str(&format_number(value));
+            }
+            None => formatted.push_str(&format_number(part)),
+        }
+    }
+    formatted
+}
+
+/// Returns the specified value without leading zeros if it is a decimal number, or in lowercase if
+/// it is a hexadecimal number; other values are returned unchanged.
+fn format_number(value: &str) -> String {
+    if let Some(digits) = value.strip_prefix("0x").or_else(|| value.strip_prefix("0X")) {
+        if !digits.is_empty() && digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return format!("0x{}", digits.to_ascii_lowercase());
+        }
+    } else if value.len() > 1 && value.chars().all(|c| c.is_ascii_digit()) {
+        let digits = value.trim_start_matches('0');
+        return if digits.is_empty() { "0" } else { digits }.to_string();
+    }
+    value.to_string()
+}
+
+/// Returns the specified comment with trailing whitespace removed and with doc comments separated
+/// from their prefix by a space.
+fn format_comment(comment: &str) -> String {
+    let comment = comment.trim_end();
+    match comment.strip_prefix(Token::DOC_COMMENT_PREFIX) {
+        Some(docs) if !docs.is_empty() && !docs.starts_with(char::is_whitespace) => {
+            format!("{} {docs}", Token::DOC_COMMENT_PREFIX)
+        }
+        _ => comment.to_string(),
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{format_source, ModuleAst, ProgramAst};
+    use alloc::string::ToString;
+
+    #[test]
+    fn format_program() {
+        let source = "\
+use.std::math::u64
+
+
+const.LIMIT=0x1F
+#! Adds the limit.
+proc.add_limit   # trailing comment
+push.LIMIT add end
+  # comment before foo
+proc.foo.2
+  push.007 if.true
+      # comment in block
+
+      loc_store.0 else
+    push.0xABcd.1 drop
+  end
+
+  assert.err=010
+
+end
+begin push.1   push.2
+   exec.foo repeat.3 add end # loop
+   while.true
+       dup
+   end
+end
+
+
+";
+        let expected = "\
+use.std::math::u64
+
+const.LIMIT=0x1f
+
+#! Adds the limit.
+proc.add_limit   # trailing comment
+    push.LIMIT add
+end
+
+# comment before foo
+proc.foo.2
+    push.7
+    if.true
+        # comment in block
+
+        loc_store.0
+    else
+        push.0xabcd.1 drop
+    end
+
+    assert.err=10
+end
+
+begin
+    push.1 push.2
+    exec.foo
+    repeat.3
+        add
+    end # loop
+    while.true
+        dup
+    end
+end
+";
+        let formatted = format_source(source).unwrap();
+        assert_eq!(formatted, expected);
+
+        // formatting is idempotent and does not change the program
+        assert_eq!(format_source(&formatted).unwrap(), formatted);
+        let original = ProgramAst::parse(source).unwrap().to_string();
+        assert_eq!(ProgramAst::parse(&formatted).unwrap().to_string(), original);
+    }
+
+    #[test]
+    fn format_module() {
+        let source = "\
+#!Module docs.
+#!
+#!    Indented docs.
+
+use.std::math::u64
+export.u64::checked_add->add
+export.helper
+    push.1 end
+proc.other
+end";
+        let expected = "\
+#! Module docs.
+#!
+#!    Indented docs.
+
+use.std::math::u64
+export.u64::checked_add->add
+
+export.helper
+    push.1
+end
+
+proc.other
+end
+";
+        let formatted = format_source(source).unwrap();
+        assert_eq!(formatted, expected);
+        assert_eq!(format_source(&formatted).unwrap(), formatted);
+        assert_eq!(
+            ModuleAst::parse(&formatted).unwrap().docs().map(|docs| docs.to_string()),
+            Some("Module docs.\nIndented docs.".to_string())
+        );
+    }
+
+    #[test]
+    fn format_procedure_signatures() {
+        let source = "\
+export.helper.1->2 dup end
+proc.other.2.2->0
+    drop drop end";
+        let expected = "\
+export.helper.1->2
+    dup
+end
+
+proc.other.2.2->0
+    drop drop
+end
+";
+        let formatted = format_source(source).unwrap();
+        assert_eq!(formatted, expected);
+        assert_eq!(format_source(&formatted).unwrap(), formatted);
+    }
+
+    #[test]
+    fn format_invalid_source() {
+        assert!(format_source("begin push.1 addd end").is_err());
+        assert!(format_source("proc.foo push.1").is_err());
+    }
+}
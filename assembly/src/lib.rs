@@ -27,13 +27,20 @@ pub mod ast;
 use ast::{NAMESPACE_LABEL_PARSER, PROCEDURE_LABEL_PARSER};
 
 mod tokens;
+pub use tokens::tokenize;
 use tokens::{Token, TokenStream};
 
+mod formatter;
+pub use formatter::format_source;
+
+mod linter;
+pub use linter::{Lint, LintKind, Linter};
+
 mod errors;
 pub use errors::{AssemblyError, LabelError, LibraryError, ParsingError, PathError};
 
 mod assembler;
-pub use assembler::{Assembler, AssemblyContext};
+pub use assembler::{Assembler, AssemblyContext, OptimizationLevel, OptimizationStats};
 
 #[cfg(test)]
 mod tests;
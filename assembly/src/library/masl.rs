@@ -3,13 +3,23 @@ use super::{
     LibraryError, LibraryNamespace, LibraryPath, Module, ModuleAst, Serializable, Version,
     MAX_DEPENDENCIES, MAX_MODULES,
 };
-use alloc::{collections::BTreeSet, vec::Vec};
+use alloc::{collections::BTreeSet, string::ToString, vec::Vec};
 use core::slice::Iter;
 
 // CONSTANT DEFINITIONS
 // ================================================================================================
 //
 
+/// Magic bytes identifying a serialized library.
+pub const MAGIC: &[u8; 4] = b"MASL";
+
+/// Current version of the library serialization format.
+///
+/// Version history:
+/// - 1: procedures include their declared signatures. Libraries serialized before the format was
+///   versioned (i.e., without the magic bytes) are not supported.
+pub const VERSION: u8 = 1;
+
 /// Serialization options for [ModuleAst]. Imports and information about imported procedures are
 /// part of the ModuleAst serialization by default.
 const AST_DEFAULT_SERDE_OPTIONS: AstSerdeOptions = AstSerdeOptions {
@@ -303,6 +313,8 @@ mod use_std {
 
 impl Serializable for MaslLibrary {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_bytes(MAGIC);
+        target.write_u8(VERSION);
         self.namespace.write_into(target);
         self.version.write_into(target);
 
@@ -334,6 +346,20 @@ impl Serializable for MaslLibrary {
 
 impl Deserializable for MaslLibrary {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let magic: [u8; 4] = source.read_array()?;
+        if &magic != MAGIC {
+            return Err(DeserializationError::InvalidValue(
+                "invalid library magic bytes".to_string(),
+            ));
+        }
+
+        let version = source.read_u8()?;
+        if version != VERSION {
+            return Err(DeserializationError::InvalidValue(format!(
+                "unsupported library format version {version}; expected version {VERSION}"
+            )));
+        }
+
         let namespace = LibraryNamespace::read_from(source)?;
         let version = Version::read_from(source)?;
 
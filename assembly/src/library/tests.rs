@@ -1,4 +1,6 @@
-use super::{Library, LibraryNamespace, LibraryPath, MaslLibrary, Module, ModuleAst, Version};
+use super::{
+    masl::VERSION, Library, LibraryNamespace, LibraryPath, MaslLibrary, Module, ModuleAst, Version,
+};
 use alloc::vec::Vec;
 use vm_core::utils::{Deserializable, Serializable, SliceReader};
 
@@ -58,6 +60,33 @@ fn masl_locations_serialization() {
     assert_eq!(bundle, deserialized);
 }
 
+#[test]
+fn masl_deserialization_rejects_other_versions() {
+    let source = r#"
+        export.foo.1.2->1
+            loc_store.0
+            push.1 add
+        end
+    "#;
+    let path = LibraryPath::new("test::foo").unwrap();
+    let ast = ModuleAst::parse(source).unwrap();
+    let modules = [Module::new(path, ast)].to_vec();
+
+    let namespace = LibraryNamespace::new("test").unwrap();
+    let bundle = MaslLibrary::new(namespace, Version::MIN, true, modules, Vec::new()).unwrap();
+    let mut bytes = bundle.to_bytes();
+    assert_eq!(bundle, MaslLibrary::read_from_bytes(&bytes).unwrap());
+
+    // libraries serialized with an older or a newer version of the format are not supported
+    bytes[4] = VERSION - 1;
+    assert!(MaslLibrary::read_from_bytes(&bytes).is_err());
+    bytes[4] = VERSION + 1;
+    assert!(MaslLibrary::read_from_bytes(&bytes).is_err());
+
+    // libraries serialized before the format was versioned do not start with the magic bytes
+    assert!(MaslLibrary::read_from_bytes(&bytes[5..]).is_err());
+}
+
 #[test]
 fn get_module_by_path() {
     // declare foo module
@@ -0,0 +1,157 @@
+use crate::{Felt, StarkField};
+use alloc::{collections::BTreeMap, vec::Vec};
+
+// CONSTANT VALUE
+// ================================================================================================
+
+/// The value of a constant expression evaluated over the integers.
+///
+/// The parser evaluates constant expressions over the field; thus, if the integer value of a
+/// constant exceeds the field modulus (e.g., for `const.A=18446744069414584321*2`), the value of the
+/// constant is silently reduced modulo the field modulus. Negative values are not considered to be
+/// out of range, as they are commonly used to denote their field equivalents (e.g., `0-1`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ConstValue {
+    /// The integer value of the expression, which may be outside of the range of field elements.
+    Exact(i128),
+    /// The value of the expression exceeded the field modulus at some point during the evaluation.
+    Overflow,
+    /// The value of the expression has no integer equivalent, e.g., because it contains a field
+    /// division which is not an exact integer division.
+    Unknown,
+}
+
+impl ConstValue {
+    /// Returns true if the integer value of the expression exceeds the field modulus.
+    pub fn exceeds_modulus(&self) -> bool {
+        match self {
+            Self::Exact(value) => *value >= Felt::MODULUS as i128,
+            Self::Overflow => true,
+            Self::Unknown => false,
+        }
+    }
+}
+
+/// Operators of constant expressions; a left parenthesis is treated as an operator with the lowest
+/// precedence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    LPar,
+    Add,
+    Sub,
+    Mul,
+    FeltDiv,
+    IntDiv,
+}
+
+impl Operator {
+    fn precedence(&self) -> u8 {
+        match self {
+            Self::LPar => 0,
+            Self::Add | Self::Sub => 1,
+            Self::Mul | Self::FeltDiv | Self::IntDiv => 2,
+        }
+    }
+
+    /// Applies this operator to the specified operands.
+    fn apply(&self, left: ConstValue, right: ConstValue) -> ConstValue {
+        use ConstValue::*;
+        let (left, right) = match (left, right) {
+            (Unknown, _) | (_, Unknown) => return Unknown,
+            (Overflow, _) | (_, Overflow) => return Overflow,
+            (Exact(left), Exact(right)) => (left, right),
+        };
+        let result = match self {
+            Self::Add => left.checked_add(right),
+            Self::Sub => left.checked_sub(right),
+            Self::Mul => left.checked_mul(right),
+            // integer division is applied to the field representations of the operands
+            Self::IntDiv if Exact(left).exceeds_modulus() || Exact(right).exceeds_modulus() => None,
+            Self::IntDiv if left < 0 || right < 0 => return Unknown,
+            Self::IntDiv => left.checked_div(right),
+            Self::FeltDiv if right != 0 && left % right == 0 => left.checked_div(right),
+            // unmatched parentheses are rejected by the parser
+            Self::FeltDiv | Self::LPar => return Unknown,
+        };
+        result.map_or(Overflow, Exact)
+    }
+}
+
+// EVALUATION
+// ================================================================================================
+
+/// Evaluates the specified constant expression over the integers, looking up the values of the
+/// referenced constants in `constants`.
+///
+/// The expression is expected to be valid (i.e., to be accepted by the parser); otherwise,
+/// [ConstValue::Unknown] is returned.
+pub(super) fn evaluate_const_expression(
+    expression: &str,
+    constants: &BTreeMap<&str, ConstValue>,
+) -> ConstValue {
+    if let Some(hex_value) = expression.strip_prefix("0x") {
+        return u64::from_str_radix(hex_value, 16)
+            .map_or(ConstValue::Unknown, |value| ConstValue::Exact(value as i128));
+    }
+
+    let mut values = Vec::new();
+    let mut operators: Vec<Operator> = Vec::new();
+    let mut remainder = expression;
+    while let Some(c) = remainder.chars().next() {
+        let operator = match c {
+            '(' => {
+                operators.push(Operator::LPar);
+                remainder = &remainder[1..];
+                continue;
+            }
+            ')' => {
+                while let Some(operator) = operators.pop().filter(|op| *op != Operator::LPar) {
+                    reduce(&mut values, operator);
+                }
+                remainder = &remainder[1..];
+                continue;
+            }
+            '+' => Operator::Add,
+            '-' => Operator::Sub,
+            '*' => Operator::Mul,
+            '/' if remainder.starts_with("//") => Operator::IntDiv,
+            '/' => Operator::FeltDiv,
+            _ => {
+                let len = remainder.find(|c| "+-*/()".contains(c)).unwrap_or(remainder.len());
+                let operand = &remainder[..len];
+                values.push(match operand.parse::<u64>() {
+                    Ok(value) => ConstValue::Exact(value as i128),
+                    Err(_) => constants.get(operand).copied().unwrap_or(ConstValue::Unknown),
+                });
+                remainder = &remainder[len..];
+                continue;
+            }
+        };
+
+        // operators with the same or higher precedence on the left are applied first
+        while let Some(&top) = operators.last() {
+            if top.precedence() < operator.precedence() {
+                break;
+            }
+            operators.pop();
+            reduce(&mut values, top);
+        }
+        operators.push(operator);
+        remainder = &remainder[if operator == Operator::IntDiv { 2 } else { 1 }..];
+    }
+
+    while let Some(operator) = operators.pop() {
+        reduce(&mut values, operator);
+    }
+    match values.as_slice() {
+        [value] => *value,
+        _ => ConstValue::Unknown,
+    }
+}
+
+/// Replaces the two topmost values with the result of applying the specified operator to them.
+fn reduce(values: &mut Vec<ConstValue>, operator: Operator) {
+    let right = values.pop().unwrap_or(ConstValue::Unknown);
+    let left = values.pop().unwrap_or(ConstValue::Unknown);
+    values.push(operator.apply(left, right));
+}
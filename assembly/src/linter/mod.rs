@@ -0,0 +1,611 @@
+use super::{
+    assembler::{collect_local_callees, CallGraph},
+    ast::{CodeBody, Instruction, ModuleAst, Node, ProcedureAst, ProgramAst, SourceLocation},
+    tokenize, ParsingError, ProcedureName, Token,
+};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+mod constants;
+use constants::{evaluate_const_expression, ConstValue};
+
+mod u32_values;
+use u32_values::U32ValueTracker;
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+/// The lowest memory address used for procedure locals (`FMP_MIN` of the processor); memory above
+/// this address is managed by the VM and should not be written to directly.
+const FMP_MIN: u64 = 2_u64.pow(30);
+
+/// Prefix of comments which suppress lints, e.g. `# lint: allow(unused_constant)`.
+const ALLOW_DIRECTIVE: &str = "lint: allow(";
+
+// LINT KIND
+// ================================================================================================
+
+/// Kinds of warnings reported by the [Linter].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LintKind {
+    /// A module is imported via `use`, but none of its procedures are invoked or re-exported.
+    UnusedImport,
+    /// A constant is declared, but never referenced.
+    UnusedConstant,
+    /// A local procedure is not reachable from the program body or the exported procedures.
+    UnreachableProc,
+    /// A u32 instruction is applied to a value produced by a field operation, which was not
+    /// checked to be a u32 value.
+    UncheckedU32Operand,
+    /// A `loc_*` instruction accesses a local which exceeds the number of locals declared by the
+    /// procedure.
+    LocalOutOfBounds,
+    /// A `push` instruction pushes a constant whose value exceeds the field modulus, and thus is
+    /// silently reduced.
+    ImmediateExceedsModulus,
+    /// A memory write to an address reserved for procedure locals.
+    WriteAboveFmpMin,
+    /// An instruction reads from the advice stack, but no advice injector precedes it.
+    MissingAdviceInjector,
+}
+
+impl LintKind {
+    /// All kinds of lints.
+    pub const ALL: [Self; 8] = [
+        Self::UnusedImport,
+        Self::UnusedConstant,
+        Self::UnreachableProc,
+        Self::UncheckedU32Operand,
+        Self::LocalOutOfBounds,
+        Self::ImmediateExceedsModulus,
+        Self::WriteAboveFmpMin,
+        Self::MissingAdviceInjector,
+    ];
+
+    /// Returns the name of this lint kind used to suppress the lint.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::UnusedImport => "unused_import",
+            Self::UnusedConstant => "unused_constant",
+            Self::UnreachableProc => "unreachable_proc",
+            Self::UncheckedU32Operand => "unchecked_u32_operand",
+            Self::LocalOutOfBounds => "local_out_of_bounds",
+            Self::ImmediateExceedsModulus => "immediate_exceeds_modulus",
+            Self::WriteAboveFmpMin => "write_above_fmp_min",
+            Self::MissingAdviceInjector => "missing_advice_injector",
+        }
+    }
+
+    /// Returns the lint kind with the specified name, if any.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.name() == name)
+    }
+}
+
+impl fmt::Display for LintKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+// LINT
+// ================================================================================================
+
+/// A warning about a potential problem in Miden assembly code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lint {
+    kind: LintKind,
+    location: SourceLocation,
+    message: String,
+}
+
+impl Lint {
+    /// Returns the kind of this lint.
+    pub fn kind(&self) -> LintKind {
+        self.kind
+    }
+
+    /// Returns the location of the code this lint refers to.
+    pub fn location(&self) -> SourceLocation {
+        self.location
+    }
+
+    /// Returns the description of the problem.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} [{}]", self.location, self.message, self.kind)
+    }
+}
+
+// LINTER
+// ================================================================================================
+
+/// Reports potential problems in Miden assembly code which are not compilation errors.
+///
+/// Lints can be disabled altogether via [Linter::with_disabled_lint()], or within a single
+/// procedure via [Linter::with_allowed_lint()]. When linting a source via [Linter::lint_source()],
+/// lints can also be suppressed via comments of the form `# lint: allow(<lint>, ...)`. Such a
+/// comment suppresses the lints in the procedure (or the program body) which contains it or whose
+/// declaration directly follows it, and in the entire source otherwise.
+#[derive(Debug, Clone, Default)]
+pub struct Linter {
+    /// Kinds of lints which are not reported at all.
+    disabled: BTreeSet<LintKind>,
+    /// Kinds of lints which are not reported within the procedures with the specified names.
+    allowed_in_procs: BTreeMap<String, BTreeSet<LintKind>>,
+}
+
+impl Linter {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [Linter] which reports all kinds of lints.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Disables lints of the specified kind.
+    pub fn with_disabled_lint(mut self, kind: LintKind) -> Self {
+        self.disabled.insert(kind);
+        self
+    }
+
+    /// Disables lints of the specified kind within the procedure with the specified name.
+    ///
+    /// The body of a program can be referred to as `#main`.
+    pub fn with_allowed_lint(mut self, proc_name: &str, kind: LintKind) -> Self {
+        self.allowed_in_procs.entry(proc_name.to_string()).or_default().insert(kind);
+        self
+    }
+
+    // LINTING
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the lints found in the specified program, ordered by their locations.
+    ///
+    /// Unused imports and constants, as well as constants exceeding the field modulus, are not
+    /// reported, as constants are resolved during parsing; use [Linter::lint_source()] to check
+    /// for them.
+    pub fn lint_program(&self, program: &ProgramAst) -> Vec<Lint> {
+        let mut roots = BTreeSet::new();
+        collect_local_callees(program.body(), &mut roots);
+        let mut lints = self.lint_procs(program.procedures(), roots);
+        lints.extend(BodyLinter::new(self, ProcedureName::MAIN_PROC_NAME, 0).lint(program.body()));
+        lints.sort_by_key(|lint| lint.location);
+        lints
+    }
+
+    /// Returns the lints found in the specified module, ordered by their locations.
+    ///
+    /// Unused imports and constants, as well as constants exceeding the field modulus, are not
+    /// reported, as constants are resolved during parsing; use [Linter::lint_source()] to check
+    /// for them.
+    pub fn lint_module(&self, module: &ModuleAst) -> Vec<Lint> {
+        let procs = module.procs();
+        let roots = (0..procs.len() as u16).filter(|&idx| procs[idx as usize].is_export);
+        let mut lints = self.lint_procs(procs, roots);
+        lints.sort_by_key(|lint| lint.location);
+        lints
+    }
+
+    /// Returns the lints found in the specified Miden assembly source, ordered by their locations.
+    ///
+    /// The source is parsed as an executable program if it contains a `begin` token, and as a
+    /// library module otherwise.
+    ///
+    /// # Errors
+    /// Returns an error if the source cannot be parsed.
+    pub fn lint_source(&self, source: &str) -> Result<Vec<Lint>, ParsingError> {
+        let tokens = tokenize(source).collect::<Vec<_>>();
+
+        // the names and line ranges of the procedures and the program body are needed to resolve
+        // the scopes of lints and `# lint: allow(...)` comments
+        if tokens.iter().any(|(token, _)| *token == Token::BEGIN) {
+            let program = ProgramAst::parse(source)?;
+            let mut scopes = program
+                .procedures()
+                .iter()
+                .map(|proc| proc_scope(proc, &tokens))
+                .collect::<Vec<_>>();
+            let start = program.source_locations().next().expect("no begin location");
+            let end = block_end(&tokens, start);
+            scopes.push((ProcedureName::MAIN_PROC_NAME.to_string(), start.line(), end));
+
+            let linter = self.with_directives(source, &scopes);
+            let lints = linter.lint_program(&program);
+            Ok(linter.lint_tokens(&tokens, &scopes, lints))
+        } else {
+            let module = ModuleAst::parse(source)?;
+            let scopes =
+                module.procs().iter().map(|proc| proc_scope(proc, &tokens)).collect::<Vec<_>>();
+
+            let linter = self.with_directives(source, &scopes);
+            let lints = linter.lint_module(&module);
+            Ok(linter.lint_tokens(&tokens, &scopes, lints))
+        }
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the lints found in the specified local procedures, where the procedures which are
+    /// not reachable from the specified roots are reported as unreachable.
+    fn lint_procs<I>(&self, procs: &[ProcedureAst], roots: I) -> Vec<Lint>
+    where
+        I: IntoIterator<Item = u16>,
+    {
+        let reachable = CallGraph::new(procs).reachable_from(roots);
+        let mut lints = Vec::new();
+        for (proc, is_reachable) in procs.iter().zip(reachable) {
+            let name = proc.name.as_ref();
+            if !is_reachable {
+                let message = format!("procedure '{name}' is never invoked");
+                self.report(&mut lints, Some(name), LintKind::UnreachableProc, proc.start, message);
+            }
+            lints.extend(BodyLinter::new(self, name, proc.num_locals).lint(&proc.body));
+        }
+        lints
+    }
+
+    /// Appends the lints which require the tokens of the source to `lints`, and returns all lints
+    /// ordered by their locations.
+    fn lint_tokens(
+        &self,
+        tokens: &[(&str, SourceLocation)],
+        scopes: &[(String, u32, u32)],
+        mut lints: Vec<Lint>,
+    ) -> Vec<Lint> {
+        lints.extend(SourceLinter::new(self, tokens, scopes).lint());
+        lints.sort_by_key(|lint| lint.location);
+        lints
+    }
+
+    /// Appends the specified lint to `lints` unless it is suppressed in the specified procedure.
+    fn report(
+        &self,
+        lints: &mut Vec<Lint>,
+        proc_name: Option<&str>,
+        kind: LintKind,
+        location: SourceLocation,
+        message: String,
+    ) {
+        let is_allowed = proc_name
+            .and_then(|proc_name| self.allowed_in_procs.get(proc_name))
+            .is_some_and(|allowed| allowed.contains(&kind));
+        if !self.disabled.contains(&kind) && !is_allowed {
+            lints.push(Lint {
+                kind,
+                location,
+                message,
+            });
+        }
+    }
+
+    /// Returns a copy of this linter which additionally suppresses the lints specified by the
+    /// `# lint: allow(...)` comments of the source.
+    fn with_directives(&self, source: &str, scopes: &[(String, u32, u32)]) -> Self {
+        let lines = source.lines().collect::<Vec<_>>();
+        let mut linter = self.clone();
+        for (line_idx, line) in lines.iter().enumerate() {
+            let Some(kinds) = parse_allow_directive(line) else {
+                continue;
+            };
+
+            // the directive applies to the scope which contains it, or to the scope which starts
+            // on the first line following the directive which is not blank and not a comment
+            let line_num = line_idx as u32 + 1;
+            let next_code_line = lines[line_idx + 1..]
+                .iter()
+                .position(|line| !line.trim().is_empty() && !line.trim().starts_with('#'))
+                .map(|offset| line_num + 1 + offset as u32);
+            let scope = scopes.iter().find(|(_, start, end)| {
+                (*start..=*end).contains(&line_num) || Some(*start) == next_code_line
+            });
+
+            for kind in kinds {
+                linter = match scope {
+                    Some((name, ..)) => linter.with_allowed_lint(name, kind),
+                    None => linter.with_disabled_lint(kind),
+                };
+            }
+        }
+        linter
+    }
+}
+
+// BODY LINTER
+// ================================================================================================
+
+/// Checks the instructions of the body of a single procedure or program.
+struct BodyLinter<'a> {
+    linter: &'a Linter,
+    proc_name: &'a str,
+    num_locals: u16,
+    /// Values on the stack which are known to be u32 values.
+    u32_values: U32ValueTracker,
+    /// The value pushed by the previous instruction, if it was a `push` instruction.
+    pushed_value: Option<u64>,
+    /// Whether an advice injector was visited or a missing advice injector was already reported.
+    has_advice: bool,
+    lints: Vec<Lint>,
+}
+
+impl<'a> BodyLinter<'a> {
+    fn new(linter: &'a Linter, proc_name: &'a str, num_locals: u16) -> Self {
+        Self {
+            linter,
+            proc_name,
+            num_locals,
+            u32_values: U32ValueTracker::default(),
+            pushed_value: None,
+            has_advice: false,
+            lints: Vec::new(),
+        }
+    }
+
+    /// Returns the lints found in the specified body.
+    fn lint(mut self, body: &CodeBody) -> Vec<Lint> {
+        self.visit_body(body);
+        self.lints
+    }
+
+    fn visit_body(&mut self, body: &CodeBody) {
+        for (node_idx, node) in body.nodes().iter().enumerate() {
+            let location = body.source_locations().get(node_idx).copied().unwrap_or_default();
+            match node {
+                Node::Instruction(instruction) => self.visit_instruction(instruction, location),
+                // values are not tracked across control flow statements
+                Node::IfElse {
+                    true_case,
+                    false_case,
+                } => {
+                    self.reset_values();
+                    self.visit_body(true_case);
+                    self.reset_values();
+                    self.visit_body(false_case);
+                    self.reset_values();
+                }
+                Node::Repeat { body, .. } | Node::While { body } => {
+                    self.reset_values();
+                    self.visit_body(body);
+                    self.reset_values();
+                }
+            }
+        }
+    }
+
+    fn visit_instruction(&mut self, instruction: &Instruction, location: SourceLocation) {
+        use Instruction::*;
+
+        if self.u32_values.apply(instruction) {
+            let message = format!(
+                "'{instruction}' is applied to a value which is not known to be a u32 value"
+            );
+            self.report(LintKind::UncheckedU32Operand, location, message);
+        }
+
+        match instruction {
+            Locaddr(idx) | LocLoad(idx) | LocLoadW(idx) | LocStore(idx) | LocStoreW(idx)
+                if *idx >= self.num_locals =>
+            {
+                let message = format!(
+                    "'{instruction}' accesses local {idx}, but only {} locals are declared",
+                    self.num_locals
+                );
+                self.report(LintKind::LocalOutOfBounds, location, message);
+            }
+            MemStoreImm(addr) | MemStoreWImm(addr) => {
+                self.check_write_address(*addr as u64, location, instruction)
+            }
+            MemStore | MemStoreW => {
+                if let Some(addr) = self.pushed_value {
+                    self.check_write_address(addr, location, instruction);
+                }
+            }
+            AdvInject(_) => self.has_advice = true,
+            AdvPush(_) | AdvLoadW | AdvPipe if !self.has_advice => {
+                // reported only once per procedure, as advice is usually read in bulk
+                self.has_advice = true;
+                let message = format!(
+                    "'{instruction}' reads from the advice stack, but no advice injector precedes it"
+                );
+                self.report(LintKind::MissingAdviceInjector, location, message);
+            }
+            _ => (),
+        }
+
+        self.pushed_value = match instruction {
+            PushU8(value) => Some(*value as u64),
+            PushU16(value) => Some(*value as u64),
+            PushU32(value) => Some(*value as u64),
+            PushFelt(value) => Some(value.as_int()),
+            _ => None,
+        };
+    }
+
+    fn check_write_address(
+        &mut self,
+        addr: u64,
+        location: SourceLocation,
+        instruction: &Instruction,
+    ) {
+        if addr >= FMP_MIN {
+            let message = format!(
+                "'{instruction}' writes to address {addr}, which is reserved for procedure locals"
+            );
+            self.report(LintKind::WriteAboveFmpMin, location, message);
+        }
+    }
+
+    fn reset_values(&mut self) {
+        self.u32_values.clear();
+        self.pushed_value = None;
+    }
+
+    fn report(&mut self, kind: LintKind, location: SourceLocation, message: String) {
+        self.linter
+            .report(&mut self.lints, Some(self.proc_name), kind, location, message);
+    }
+}
+
+// SOURCE LINTER
+// ================================================================================================
+
+/// Checks the lints which require the source, as imports and constants are resolved during
+/// parsing.
+struct SourceLinter<'a> {
+    linter: &'a Linter,
+    tokens: &'a [(&'a str, SourceLocation)],
+    /// Names and line ranges of the procedures and the program body.
+    scopes: &'a [(String, u32, u32)],
+    lints: Vec<Lint>,
+}
+
+impl<'a> SourceLinter<'a> {
+    fn new(
+        linter: &'a Linter,
+        tokens: &'a [(&'a str, SourceLocation)],
+        scopes: &'a [(String, u32, u32)],
+    ) -> Self {
+        Self {
+            linter,
+            tokens,
+            scopes,
+            lints: Vec::new(),
+        }
+    }
+
+    fn lint(mut self) -> Vec<Lint> {
+        self.check_imports();
+        self.check_constants();
+        self.lints
+    }
+
+    fn check_imports(&mut self) {
+        for &(token, location) in self.tokens.iter() {
+            let Some(import) = token.strip_prefix("use.") else {
+                continue;
+            };
+            let module_name = match import.split_once(Token::ALIAS_DELIM) {
+                Some((_, alias)) => alias,
+                None => import.rsplit("::").next().unwrap_or(import),
+            };
+
+            let prefix = format!("{module_name}::");
+            let is_used = self.tokens.iter().any(|(token, _)| {
+                !token.starts_with("use.")
+                    && token.split('.').skip(1).any(|part| part.starts_with(&prefix))
+            });
+            if !is_used {
+                let message = format!("module '{module_name}' is imported, but never used");
+                self.report(LintKind::UnusedImport, location, message);
+            }
+        }
+    }
+
+    fn check_constants(&mut self) {
+        let mut values = BTreeMap::new();
+        for (token_idx, &(token, location)) in self.tokens.iter().enumerate() {
+            let Some((name, expression)) =
+                token.strip_prefix("const.").and_then(|constant| constant.split_once('='))
+            else {
+                continue;
+            };
+            values.insert(name, evaluate_const_expression(expression, &values));
+
+            let is_used = self.tokens.iter().enumerate().any(|(idx, (token, _))| {
+                idx != token_idx && identifiers(token).any(|identifier| identifier == name)
+            });
+            if !is_used {
+                let message = format!("constant '{name}' is declared, but never used");
+                self.report(LintKind::UnusedConstant, location, message);
+            }
+        }
+
+        for &(token, location) in self.tokens.iter() {
+            if !token.starts_with("push.") {
+                continue;
+            }
+            for param in token.split('.').skip(1) {
+                if values.get(param).is_some_and(ConstValue::exceeds_modulus) {
+                    let message = format!(
+                        "value of constant '{param}' exceeds the field modulus, so '{token}' \
+                         pushes the value reduced modulo the field modulus"
+                    );
+                    self.report(LintKind::ImmediateExceedsModulus, location, message);
+                }
+            }
+        }
+    }
+
+    fn report(&mut self, kind: LintKind, location: SourceLocation, message: String) {
+        let line = location.line();
+        let scope = self.scopes.iter().find(|(_, start, end)| (*start..=*end).contains(&line));
+        let proc_name = scope.map(|(name, ..)| name.as_str());
+        self.linter.report(&mut self.lints, proc_name, kind, location, message);
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the name and the line range of the specified procedure, which spans from its header to
+/// the `end` token closing it.
+fn proc_scope(proc: &ProcedureAst, tokens: &[(&str, SourceLocation)]) -> (String, u32, u32) {
+    (proc.name.to_string(), proc.start.line(), block_end(tokens, &proc.start))
+}
+
+/// Returns the line of the `end` token closing the block opened by the token at the specified
+/// location, skipping over the `end` tokens of the nested blocks.
+fn block_end(tokens: &[(&str, SourceLocation)], start: &SourceLocation) -> u32 {
+    let mut depth = 0_usize;
+    for (token, location) in tokens.iter().skip_while(|(_, location)| location != start) {
+        match token.split('.').next() {
+            Some(
+                Token::BEGIN
+                | Token::EXPORT
+                | Token::PROC
+                | Token::IF
+                | Token::WHILE
+                | Token::REPEAT,
+            ) => depth += 1,
+            Some(Token::END) => {
+                depth -= 1;
+                if depth == 0 {
+                    return location.line();
+                }
+            }
+            _ => (),
+        }
+    }
+    start.line()
+}
+
+/// Returns the kinds of lints listed in the `# lint: allow(...)` comment on the specified line, if
+/// any; unknown lint names are ignored.
+fn parse_allow_directive(line: &str) -> Option<Vec<LintKind>> {
+    let (_, comment) = line.split_once('#')?;
+    let directive = comment.trim_start_matches('!').trim().strip_prefix(ALLOW_DIRECTIVE)?;
+    let (names, _) = directive.split_once(')')?;
+    Some(names.split(',').filter_map(|name| LintKind::from_name(name.trim())).collect())
+}
+
+/// Returns the identifiers (i.e., words consisting of alphanumeric characters and underscores)
+/// contained in the specified token.
+fn identifiers(token: &str) -> impl Iterator<Item = &str> {
+    token
+        .split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        .filter(|word| !word.is_empty())
+}
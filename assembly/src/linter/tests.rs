@@ -0,0 +1,295 @@
+use super::{LintKind, Linter, SourceLocation};
+use crate::ast::{ModuleAst, ProgramAst};
+use alloc::vec::Vec;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the kinds and lines of the lints found in the specified source.
+fn lint(source: &str) -> Vec<(LintKind, u32)> {
+    lint_with(&Linter::new(), source)
+}
+
+fn lint_with(linter: &Linter, source: &str) -> Vec<(LintKind, u32)> {
+    linter
+        .lint_source(source)
+        .unwrap()
+        .iter()
+        .map(|lint| (lint.kind(), lint.location().line()))
+        .collect()
+}
+
+// IMPORTS AND CONSTANTS
+// ================================================================================================
+
+#[test]
+fn unused_imports() {
+    let source = "\
+use.std::math::u64
+use.std::math::u256
+use.std::crypto::hashes::blake3->b3
+use.std::sys
+
+export.u256::add_unsafe->add
+
+export.foo
+    exec.u64::wrapping_add
+    exec.b3::hash_1to1
+end";
+    assert_eq!(lint(source), [(LintKind::UnusedImport, 4)]);
+}
+
+#[test]
+fn unused_constants() {
+    let source = "\
+const.A=1
+const.B=A*2
+const.C=3
+const.D=4
+
+begin
+    push.B
+    repeat.D
+        add
+    end
+end";
+    assert_eq!(lint(source), [(LintKind::UnusedConstant, 3)]);
+}
+
+#[test]
+fn immediates_exceeding_modulus() {
+    let source = "\
+const.P=18446744069414584320
+const.A=P-1
+const.B=P+2
+const.C=0-1
+const.D=(P+1)//2
+const.E=P/P
+
+begin
+    push.A push.B
+    push.C push.D push.E
+end";
+    assert_eq!(
+        lint(source),
+        [(LintKind::ImmediateExceedsModulus, 9), (LintKind::ImmediateExceedsModulus, 10)]
+    );
+}
+
+// PROCEDURES
+// ================================================================================================
+
+#[test]
+fn unreachable_procs() {
+    let source = "\
+proc.foo
+    push.1
+end
+
+proc.bar
+    exec.foo
+end
+
+proc.baz
+    push.2
+end
+
+export.qux
+    call.bar
+end";
+    assert_eq!(lint(source), [(LintKind::UnreachableProc, 9)]);
+
+    let program = ProgramAst::parse("proc.foo push.1 end proc.bar push.2 end begin exec.bar end");
+    let lints = Linter::new().lint_program(&program.unwrap());
+    assert_eq!(lints.len(), 1);
+    assert_eq!(lints[0].kind(), LintKind::UnreachableProc);
+    assert_eq!(lints[0].location(), SourceLocation::new(1, 1));
+}
+
+#[test]
+fn unchecked_u32_operands() {
+    let source = "\
+export.foo
+    u32wrapping_add
+    push.1 add
+    u32wrapping_add
+    dup.1 dup.1 add
+    swap u32not drop
+    dup.0 u32assert
+    u32wrapping_mul
+    push.5 add
+    movdn.3 movup.3 u32shr.2
+end";
+    assert_eq!(
+        lint(source),
+        [
+            (LintKind::UncheckedU32Operand, 4),
+            (LintKind::UncheckedU32Operand, 8),
+            (LintKind::UncheckedU32Operand, 10)
+        ]
+    );
+
+    // values are not tracked into or across control flow statements
+    let source = "\
+export.foo
+    push.1 add
+    if.true
+        u32wrapping_add
+    end
+    u32wrapping_add
+end";
+    assert!(lint(source).is_empty());
+}
+
+#[test]
+fn locals_out_of_bounds() {
+    let source = "\
+export.foo.2
+    loc_load.1
+    loc_store.2
+    locaddr.3
+end
+
+export.bar
+    loc_loadw.0
+end";
+    assert_eq!(
+        lint(source),
+        [
+            (LintKind::LocalOutOfBounds, 3),
+            (LintKind::LocalOutOfBounds, 4),
+            (LintKind::LocalOutOfBounds, 8)
+        ]
+    );
+}
+
+#[test]
+fn writes_above_fmp_min() {
+    let source = "\
+export.foo
+    mem_store.1073741823
+    mem_store.1073741824
+    mem_storew.2147483648
+    push.1073741824 mem_store
+    push.100 mem_storew
+    push.1073741824 mem_load
+end";
+    assert_eq!(
+        lint(source),
+        [
+            (LintKind::WriteAboveFmpMin, 3),
+            (LintKind::WriteAboveFmpMin, 4),
+            (LintKind::WriteAboveFmpMin, 5)
+        ]
+    );
+}
+
+#[test]
+fn missing_advice_injectors() {
+    let source = "\
+export.foo
+    adv_push.1
+    adv_loadw
+end
+
+export.bar
+    adv.push_mapval
+    adv_loadw
+end
+
+export.baz
+    if.true
+        adv.push_mtnode
+    end
+    adv_push.2
+end";
+    assert_eq!(lint(source), [(LintKind::MissingAdviceInjector, 2)]);
+}
+
+// SUPPRESSION
+// ================================================================================================
+
+#[test]
+fn lints_are_suppressed() {
+    let source = "\
+use.std::sys
+const.A=1
+
+# lint: allow(missing_advice_injector)
+#! Docs of foo.
+export.foo
+    adv_push.1
+end
+
+export.bar
+    # lint: allow(unchecked_u32_operand, unknown_lint)
+    push.1 add u32not
+    adv_push.1
+end
+
+export.baz
+    push.1 add u32not
+end";
+    assert_eq!(
+        lint(source),
+        [
+            (LintKind::UnusedImport, 1),
+            (LintKind::UnusedConstant, 2),
+            (LintKind::MissingAdviceInjector, 13),
+            (LintKind::UncheckedU32Operand, 17)
+        ]
+    );
+
+    // directives outside of procedures apply to the entire source
+    let source = format!("# lint: allow(unused_import, unused_constant)\n{source}");
+    assert_eq!(
+        lint(&source),
+        [(LintKind::MissingAdviceInjector, 14), (LintKind::UncheckedU32Operand, 18)]
+    );
+
+    // lints can also be suppressed when the linter is constructed
+    let linter = Linter::new()
+        .with_disabled_lint(LintKind::MissingAdviceInjector)
+        .with_allowed_lint("baz", LintKind::UncheckedU32Operand);
+    assert!(lint_with(&linter, &source).is_empty());
+
+    // comments are not available when linting ASTs
+    let module = ModuleAst::parse(&source).unwrap();
+    let lints = linter.lint_module(&module);
+    assert_eq!(lints.len(), 1);
+    assert_eq!(lints[0].kind(), LintKind::UncheckedU32Operand);
+    assert_eq!(lints[0].location().line(), 13);
+}
+
+#[test]
+fn lints_in_nested_blocks_are_suppressed() {
+    // the directive in the trailing block applies to foo only
+    let source = "\
+export.foo
+    push.1
+    if.true
+        # lint: allow(missing_advice_injector)
+        adv_push.1
+    end
+end
+
+export.bar
+    adv_push.1
+end";
+    assert_eq!(lint(source), [(LintKind::MissingAdviceInjector, 10)]);
+}
+
+#[test]
+fn lints_in_program_body_are_suppressed() {
+    let source = "\
+# lint: allow(missing_advice_injector)
+begin
+    adv_push.1
+    # lint: allow(write_above_fmp_min)
+    mem_store.1073741824
+end";
+    assert!(lint(source).is_empty());
+
+    let program = ProgramAst::parse(source).unwrap();
+    assert_eq!(Linter::new().lint_program(&program).len(), 2);
+}
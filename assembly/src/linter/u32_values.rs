@@ -0,0 +1,305 @@
+use crate::ast::Instruction;
+use alloc::vec::Vec;
+
+// VALUE KIND
+// ================================================================================================
+
+/// What is statically known about a value on the operand stack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ValueKind {
+    /// The value is known to be a u32 value, e.g., because it was pushed as a u32 immediate, was
+    /// produced by a u32 instruction, or was checked via `u32assert`.
+    U32,
+    /// The value was produced by a field operation (e.g., `add`), and thus may not be a u32 value.
+    Felt,
+    /// Nothing is known about the value, e.g., because it was passed to the procedure, loaded from
+    /// memory or produced by an invoked procedure.
+    Unknown,
+}
+
+// U32 VALUE TRACKER
+// ================================================================================================
+
+/// Tracks which values on the operand stack are known to be u32 values while the instructions of a
+/// code body are visited in order.
+///
+/// Values which were on the stack before the code body started executing are assumed to be valid
+/// inputs, and thus only values produced by field operations within the body are reported as not
+/// being u32 values.
+#[derive(Debug, Clone, Default)]
+pub(super) struct U32ValueTracker {
+    /// Kinds of the tracked values, with the top of the stack at the end; values below the tracked
+    /// ones are unknown.
+    stack: Vec<ValueKind>,
+}
+
+impl U32ValueTracker {
+    /// Forgets everything known about the values on the stack.
+    pub fn clear(&mut self) {
+        self.stack.clear();
+    }
+
+    /// Updates the tracked values according to the specified instruction.
+    ///
+    /// Returns true if the instruction is a u32 instruction which is applied to a value which was
+    /// produced by a field operation and was not checked to be a u32 value since.
+    pub fn apply(&mut self, instruction: &Instruction) -> bool {
+        use Instruction::*;
+        use ValueKind::*;
+
+        if requires_u32_operands(instruction) {
+            let num_operands = instruction.stack_effect().map_or(0, |effect| effect.inputs());
+            let is_unchecked = (0..num_operands as usize).any(|idx| self.get(idx) == Felt);
+            self.apply_effect(instruction, U32);
+            return is_unchecked;
+        }
+
+        match instruction {
+            // ----- values known to be u32 values --------------------------------------------
+            PushU8(_) | PushU16(_) | PushU32(_) | Sdepth | Clk | Locaddr(_) => self.push(U32),
+            PushU8List(values) => values.iter().for_each(|_| self.push(U32)),
+            PushU16List(values) => values.iter().for_each(|_| self.push(U32)),
+            PushU32List(values) => values.iter().for_each(|_| self.push(U32)),
+            PushFelt(value) => self.push(felt_kind(value.as_int())),
+            PushWord(values) => {
+                values.iter().for_each(|value| self.push(felt_kind(value.as_int())))
+            }
+            PushFeltList(values) => {
+                values.iter().for_each(|value| self.push(felt_kind(value.as_int())))
+            }
+            PadW => (0..4).for_each(|_| self.push(U32)),
+            Eq | Neq | Lt | Lte | Gt | Gte | EqImm(_) | NeqImm(_) | And | Or | Xor | Not
+            | IsOdd | ILog2 | U32Cast | U32Split => self.apply_effect(instruction, U32),
+            Eqw => {
+                self.apply_effect(instruction, Unknown);
+                self.set(0, U32);
+            }
+            U32Test | U32TestW => self.push(U32),
+            U32Assert | U32AssertWithError(_) => self.set(0, U32),
+            U32Assert2 | U32Assert2WithError(_) => (0..2).for_each(|idx| self.set(idx, U32)),
+            U32AssertW | U32AssertWWithError(_) => (0..4).for_each(|idx| self.set(idx, U32)),
+
+            // ----- values produced by field operations --------------------------------------
+            Add | Sub | Mul | Div | Neg | Inv | Incr | Pow2 | Exp | ExpImm(_) | ExpBitLength(_)
+            | AddImm(_) | SubImm(_) | MulImm(_) | DivImm(_) | Ext2Add | Ext2Sub | Ext2Mul
+            | Ext2Div | Ext2Neg | Ext2Inv => self.apply_effect(instruction, Felt),
+
+            // ----- stack manipulation -------------------------------------------------------
+            Drop => self.pop(1),
+            DropW => self.pop(4),
+            Dup0 => self.dup(0),
+            Dup1 => self.dup(1),
+            Dup2 => self.dup(2),
+            Dup3 => self.dup(3),
+            Dup4 => self.dup(4),
+            Dup5 => self.dup(5),
+            Dup6 => self.dup(6),
+            Dup7 => self.dup(7),
+            Dup8 => self.dup(8),
+            Dup9 => self.dup(9),
+            Dup10 => self.dup(10),
+            Dup11 => self.dup(11),
+            Dup12 => self.dup(12),
+            Dup13 => self.dup(13),
+            Dup14 => self.dup(14),
+            Dup15 => self.dup(15),
+            DupW0 => self.dupw(0),
+            DupW1 => self.dupw(1),
+            DupW2 => self.dupw(2),
+            DupW3 => self.dupw(3),
+            Swap1 => self.swap(0, 1),
+            Swap2 => self.swap(0, 2),
+            Swap3 => self.swap(0, 3),
+            Swap4 => self.swap(0, 4),
+            Swap5 => self.swap(0, 5),
+            Swap6 => self.swap(0, 6),
+            Swap7 => self.swap(0, 7),
+            Swap8 => self.swap(0, 8),
+            Swap9 => self.swap(0, 9),
+            Swap10 => self.swap(0, 10),
+            Swap11 => self.swap(0, 11),
+            Swap12 => self.swap(0, 12),
+            Swap13 => self.swap(0, 13),
+            Swap14 => self.swap(0, 14),
+            Swap15 => self.swap(0, 15),
+            SwapW1 => self.swapw(0, 1),
+            SwapW2 => self.swapw(0, 2),
+            SwapW3 => self.swapw(0, 3),
+            SwapDw => (0..2).for_each(|idx| self.swapw(idx, idx + 2)),
+            MovUp2 => self.movup(2),
+            MovUp3 => self.movup(3),
+            MovUp4 => self.movup(4),
+            MovUp5 => self.movup(5),
+            MovUp6 => self.movup(6),
+            MovUp7 => self.movup(7),
+            MovUp8 => self.movup(8),
+            MovUp9 => self.movup(9),
+            MovUp10 => self.movup(10),
+            MovUp11 => self.movup(11),
+            MovUp12 => self.movup(12),
+            MovUp13 => self.movup(13),
+            MovUp14 => self.movup(14),
+            MovUp15 => self.movup(15),
+            MovDn2 => self.movdn(2),
+            MovDn3 => self.movdn(3),
+            MovDn4 => self.movdn(4),
+            MovDn5 => self.movdn(5),
+            MovDn6 => self.movdn(6),
+            MovDn7 => self.movdn(7),
+            MovDn8 => self.movdn(8),
+            MovDn9 => self.movdn(9),
+            MovDn10 => self.movdn(10),
+            MovDn11 => self.movdn(11),
+            MovDn12 => self.movdn(12),
+            MovDn13 => self.movdn(13),
+            MovDn14 => self.movdn(14),
+            MovDn15 => self.movdn(15),
+            MovUpW2 => (0..4).for_each(|_| self.movup(11)),
+            MovUpW3 => (0..4).for_each(|_| self.movup(15)),
+            MovDnW2 => (0..4).for_each(|_| self.movdn(11)),
+            MovDnW3 => (0..4).for_each(|_| self.movdn(15)),
+
+            // ----- everything else ----------------------------------------------------------
+            _ => match instruction.stack_effect() {
+                Some(_) => self.apply_effect(instruction, Unknown),
+                // the effect of invoked procedures is not tracked
+                None => self.clear(),
+            },
+        }
+        false
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the kind of the value at the specified depth (0 being the top of the stack).
+    fn get(&self, depth: usize) -> ValueKind {
+        self.stack.iter().rev().nth(depth).copied().unwrap_or(ValueKind::Unknown)
+    }
+
+    /// Sets the kind of the value at the specified depth.
+    fn set(&mut self, depth: usize, kind: ValueKind) {
+        self.reserve(depth + 1);
+        let idx = self.stack.len() - 1 - depth;
+        self.stack[idx] = kind;
+    }
+
+    /// Makes sure that at least `depth` values are tracked by inserting unknown values at the
+    /// bottom of the tracked values.
+    fn reserve(&mut self, depth: usize) {
+        if self.stack.len() < depth {
+            let missing = depth - self.stack.len();
+            self.stack.splice(0..0, (0..missing).map(|_| ValueKind::Unknown));
+        }
+    }
+
+    fn push(&mut self, kind: ValueKind) {
+        self.stack.push(kind);
+    }
+
+    fn pop(&mut self, num_values: usize) {
+        self.stack.truncate(self.stack.len().saturating_sub(num_values));
+    }
+
+    fn dup(&mut self, depth: usize) {
+        self.push(self.get(depth));
+    }
+
+    fn dupw(&mut self, word_idx: usize) {
+        (0..4).for_each(|_| self.dup(word_idx * 4 + 3));
+    }
+
+    fn swap(&mut self, depth_a: usize, depth_b: usize) {
+        self.reserve(depth_b + 1);
+        let len = self.stack.len();
+        self.stack.swap(len - 1 - depth_a, len - 1 - depth_b);
+    }
+
+    fn swapw(&mut self, word_idx_a: usize, word_idx_b: usize) {
+        (0..4).for_each(|idx| self.swap(word_idx_a * 4 + idx, word_idx_b * 4 + idx));
+    }
+
+    fn movup(&mut self, depth: usize) {
+        self.reserve(depth + 1);
+        let kind = self.stack.remove(self.stack.len() - 1 - depth);
+        self.push(kind);
+    }
+
+    fn movdn(&mut self, depth: usize) {
+        self.reserve(depth + 1);
+        let kind = self.stack.pop().expect("stack is empty");
+        self.stack.insert(self.stack.len() - depth, kind);
+    }
+
+    /// Replaces the inputs of the specified instruction with its outputs of the specified kind.
+    fn apply_effect(&mut self, instruction: &Instruction, output_kind: ValueKind) {
+        let effect = instruction.stack_effect().expect("instruction must have a stack effect");
+        self.pop(effect.inputs() as usize);
+        (0..effect.outputs()).for_each(|_| self.push(output_kind));
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the kind of a field element immediate with the specified value.
+fn felt_kind(value: u64) -> ValueKind {
+    if value <= u32::MAX as u64 {
+        ValueKind::U32
+    } else {
+        ValueKind::Felt
+    }
+}
+
+/// Returns true if the specified instruction expects all of its operands to be u32 values.
+fn requires_u32_operands(instruction: &Instruction) -> bool {
+    use Instruction::*;
+    matches!(
+        instruction,
+        U32WrappingAdd
+            | U32WrappingAddImm(_)
+            | U32OverflowingAdd
+            | U32OverflowingAddImm(_)
+            | U32OverflowingAdd3
+            | U32WrappingAdd3
+            | U32WrappingSub
+            | U32WrappingSubImm(_)
+            | U32OverflowingSub
+            | U32OverflowingSubImm(_)
+            | U32WrappingMul
+            | U32WrappingMulImm(_)
+            | U32OverflowingMul
+            | U32OverflowingMulImm(_)
+            | U32OverflowingMadd
+            | U32WrappingMadd
+            | U32Div
+            | U32DivImm(_)
+            | U32Mod
+            | U32ModImm(_)
+            | U32DivMod
+            | U32DivModImm(_)
+            | U32And
+            | U32Or
+            | U32Xor
+            | U32Not
+            | U32Shr
+            | U32ShrImm(_)
+            | U32Shl
+            | U32ShlImm(_)
+            | U32Rotr
+            | U32RotrImm(_)
+            | U32Rotl
+            | U32RotlImm(_)
+            | U32Popcnt
+            | U32Clz
+            | U32Ctz
+            | U32Clo
+            | U32Cto
+            | U32Lt
+            | U32Lte
+            | U32Gt
+            | U32Gte
+            | U32Min
+            | U32Max
+    )
+}
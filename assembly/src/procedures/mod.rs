@@ -1,4 +1,5 @@
 use super::{
+    ast::StackEffect,
     crypto::hash::{Blake3_160, RpoDigest},
     ByteReader, ByteWriter, CodeBlock, Deserializable, DeserializationError, LabelError,
     LibraryPath, Serializable, PROCEDURE_LABEL_PARSER,
@@ -21,11 +22,13 @@ use core::{
 /// Procedure metadata includes:
 /// - Number of procedure locals available to the procedure.
 /// - A set of MAST roots of procedures which are invoked from this procedure.
+/// - The stack effect of the procedure, if it is known.
 #[derive(Clone, Debug)]
 pub struct Procedure {
     num_locals: u32,
     code: CodeBlock,
     callset: CallSet,
+    stack_effect: Option<StackEffect>,
 }
 
 impl Procedure {
@@ -49,6 +52,12 @@ impl Procedure {
     pub fn callset(&self) -> &CallSet {
         &self.callset
     }
+
+    /// Returns the stack effect of this procedure, or None if the stack effect could not be
+    /// inferred (e.g., because the procedure makes dynamic calls) and was not declared.
+    pub fn stack_effect(&self) -> Option<StackEffect> {
+        self.stack_effect
+    }
 }
 
 // NAMED PROCEDURE
@@ -77,6 +86,7 @@ impl NamedProcedure {
         num_locals: u32,
         code: CodeBlock,
         callset: CallSet,
+        stack_effect: Option<StackEffect>,
     ) -> Self {
         NamedProcedure {
             name,
@@ -85,6 +95,7 @@ impl NamedProcedure {
                 num_locals,
                 code,
                 callset,
+                stack_effect,
             },
         }
     }
@@ -1,5 +1,5 @@
 use crate::{
-    ast::{ModuleAst, ProgramAst},
+    ast::{ModuleAst, ProgramAst, StackEffect},
     Assembler, AssemblyContext, AssemblyError, Library, LibraryNamespace, LibraryPath, MaslLibrary,
     Module, ProcedureName, Version,
 };
@@ -350,6 +350,103 @@ fn get_proc_name_of_unknown_module() {
     assert_eq!(compilation_error, expected_error);
 }
 
+// DEAD PROCEDURE ELIMINATION
+// ================================================================================================
+
+/// A call to a procedure whose code is not available; compiling it results in an error since
+/// phantom calls are not allowed by default.
+const PHANTOM_CALL: &str =
+    "call.0x0000000000000000000000000000000000000000000000000000000000000001";
+
+#[test]
+fn unreachable_program_procs_are_dropped() {
+    let assembler = Assembler::default();
+    let source = format!(
+        "
+        proc.foo
+            push.5
+        end
+
+        proc.bar
+            call.foo
+        end
+
+        proc.baz
+            {PHANTOM_CALL}
+        end
+
+        begin
+            exec.foo
+        end"
+    );
+
+    // neither the phantom call nor the call to `foo` in unreachable procedures are compiled
+    let program = assembler.compile(&source).unwrap();
+    assert_eq!("begin span push(5) end end", format!("{program}"));
+    assert!(program.cb_table().is_empty());
+
+    let source = source.replace("exec.foo", "exec.bar");
+    let program = assembler.compile(&source).unwrap();
+    assert!(!program.cb_table().is_empty());
+
+    let source = source.replace("exec.bar", "exec.baz");
+    assert!(assembler.compile(source).is_err());
+}
+
+#[test]
+fn unreachable_module_procs_are_dropped() {
+    let module_source = format!(
+        "
+        proc.helper
+            push.5
+        end
+
+        proc.unused
+            {PHANTOM_CALL}
+        end
+
+        export.foo
+            exec.helper
+        end
+
+        export.bar
+            exec.helper push.2
+        end
+
+        export.baz
+            {PHANTOM_CALL}
+        end"
+    );
+    let module_ast = ModuleAst::parse(&module_source).unwrap();
+    let module_path = LibraryPath::new("dummy::math").unwrap();
+    let masl_lib = MaslLibrary::new(
+        LibraryNamespace::new("dummy").unwrap(),
+        Version::default(),
+        false,
+        vec![Module::new(module_path.clone(), module_ast.clone())],
+        vec![],
+    )
+    .unwrap();
+
+    // only the imported procedures are compiled; the module is compiled once for each of them
+    let assembler = Assembler::default().with_library(&masl_lib).unwrap();
+    let source = "use.dummy::math begin exec.math::foo exec.math::bar exec.math::foo end";
+    let program = assembler.compile(source).unwrap();
+    assert_eq!("begin span push(5) push(5) push(2) push(5) end end", format!("{program}"));
+    let source = "use.dummy::math begin exec.math::baz end";
+    assert!(assembler.compile(source).is_err());
+
+    // when the module itself is compiled, all exported procedures are compiled
+    let assembler = Assembler::default();
+    let mut context = AssemblyContext::for_module(false);
+    assert!(assembler.compile_module(&module_ast, Some(&module_path), &mut context).is_err());
+
+    let module_ast = ModuleAst::parse(&module_source.replace("export.baz", "proc.baz")).unwrap();
+    let mut context = AssemblyContext::for_module(false);
+    let roots = assembler.compile_module(&module_ast, Some(&module_path), &mut context).unwrap();
+    assert_eq!(roots.len(), 2);
+}
+
 // CONSTANTS
 // ================================================================================================
 
@@ -1479,6 +1576,159 @@ fn program_with_import_errors() {
     );
 }
 
+// STACK EFFECTS
+// ================================================================================================
+
+#[test]
+fn proc_signatures_are_checked() {
+    let assembler = Assembler::default();
+
+    // a signature may declare more inputs than the body actually reads
+    let source = "\
+        proc.foo.1.4->2
+            loc_store.0 add
+        end
+        proc.bar.5->3
+            exec.foo
+        end
+        begin
+            exec.bar
+        end";
+    assert!(assembler.compile(source).is_ok());
+
+    // but the body must change the stack depth as declared
+    let source = "\
+        proc.foo.2->2
+            add
+        end
+        begin
+            exec.foo
+        end";
+    assert!(matches!(
+        assembler.compile(source),
+        Err(AssemblyError::ProcSignatureMismatch(name, declared, inferred))
+            if name == "foo" && declared == StackEffect::new(2, 2)
+                && inferred == StackEffect::new(2, 1)
+    ));
+
+    // and must not read deeper than declared
+    let source = "\
+        proc.foo.1->1
+            swap drop
+        end
+        begin
+            exec.foo
+        end";
+    assert!(matches!(
+        assembler.compile(source),
+        Err(AssemblyError::ProcSignatureMismatch(_, _, inferred))
+            if inferred == StackEffect::new(2, 1)
+    ));
+}
+
+#[test]
+fn proc_signatures_propagate_through_control_flow() {
+    let assembler = Assembler::default();
+
+    // the effect of `bar` is inferred from the effects of `foo`, the branches and the loop
+    let source = "\
+        proc.foo
+            dup mul
+        end
+        proc.bar.2->1
+            if.true
+                exec.foo
+            else
+                push.5 add
+            end
+            push.1
+            while.true
+                exec.foo push.0
+            end
+            repeat.2
+                exec.foo
+            end
+        end
+        begin
+            exec.bar
+        end";
+    assert!(assembler.compile(source).is_ok());
+
+    // dynamic calls have unknown stack effects, so the declared signature is trusted
+    let source = "\
+        proc.foo.4->7
+            dynexec
+        end
+        begin
+            exec.foo
+        end";
+    assert!(assembler.compile(source).is_ok());
+}
+
+#[test]
+fn unbalanced_control_flow_in_procs_with_signatures() {
+    let assembler = Assembler::default();
+
+    let source = "\
+        proc.foo.3->1
+            if.true
+                add
+            end
+        end
+        begin
+            exec.foo
+        end";
+    assert!(matches!(
+        assembler.compile(source),
+        Err(AssemblyError::UnbalancedBranches(name, _, -1, 0)) if name == "foo"
+    ));
+
+    let source = "\
+        proc.foo.2->0
+            while.true
+                drop push.0
+            end
+        end
+        begin
+            exec.foo
+        end";
+    assert!(matches!(
+        assembler.compile(source),
+        Err(AssemblyError::UnbalancedLoop(name, _, -1)) if name == "foo"
+    ));
+
+    let source = "\
+        proc.foo
+            push.5
+        end
+        proc.bar.0->0
+            call.foo
+        end
+        begin
+            exec.bar
+        end";
+    assert!(matches!(
+        assembler.compile(source),
+        Err(AssemblyError::InvalidStackDepthOnReturn(name, _, 1)) if name == "bar"
+    ));
+
+    // the same control flow is accepted in procedures without signatures, as their stack effects
+    // are inferred on a best-effort basis
+    let source = "\
+        proc.foo
+            if.true
+                add
+            end
+            while.true
+                drop push.0
+            end
+        end
+        begin
+            exec.foo
+        end";
+    assert!(assembler.compile(source).is_ok());
+}
+
 // COMMENTS
 // ================================================================================================
 
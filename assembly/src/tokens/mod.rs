@@ -1,7 +1,6 @@
 use super::{
-    ast::{parse_param_with_constant_lookup, InvocationTarget},
-    ByteReader, ByteWriter, Deserializable, DeserializationError, LibraryPath, ParsingError,
-    ProcedureName, Serializable,
+    ast::{parse_param_with_constant_lookup, InvocationTarget, StackEffect},
+    LibraryPath, ParsingError, ProcedureName,
 };
 use alloc::{
     collections::BTreeMap,
@@ -13,8 +12,7 @@ use core::fmt;
 mod lines;
 pub use lines::{LineInfo, LinesStream};
 
-mod location;
-pub use location::SourceLocation;
+pub use vm_core::SourceLocation;
 
 mod stream;
 pub use stream::TokenStream;
@@ -22,6 +20,19 @@ pub use stream::TokenStream;
 mod tokenizer;
 pub use tokenizer::LineTokenizer;
 
+// TOKENIZER
+// ================================================================================================
+
+/// Returns an iterator over the tokens of the specified Miden assembly source together with their
+/// source locations.
+///
+/// Comments are skipped and the tokens are not validated. Thus, unlike [TokenStream], this can be
+/// used to inspect sources which cannot be parsed (e.g., by editor tooling).
+pub fn tokenize(source: &str) -> impl Iterator<Item = (&str, SourceLocation)> {
+    LinesStream::from(source)
+        .flat_map(|line_info| LineTokenizer::new(&line_info).into_iter().flatten())
+}
+
 // TOKEN
 // ================================================================================================
 /// Token type used to represent a token in the Miden assembly source.
@@ -148,25 +159,40 @@ impl<'a> Token<'a> {
         }
     }
 
-    pub fn parse_proc(&self) -> Result<(ProcedureName, u16, bool), ParsingError> {
+    /// Parses a procedure declaration of the form
+    /// `[proc|export].<procedure_name>[.<num_locals>][.<inputs>-><outputs>]` and returns the
+    /// procedure name, the number of its locals, its declared stack effect (if any) and whether the
+    /// procedure is exported.
+    pub fn parse_proc(
+        &self,
+    ) -> Result<(ProcedureName, u16, Option<StackEffect>, bool), ParsingError> {
         assert!(
             self.parts[0] == Self::PROC || self.parts[0] == Self::EXPORT,
             "invalid procedure declaration"
         );
         let is_export = self.parts[0] == Self::EXPORT;
-        let (name_str, num_locals) = match self.num_parts() {
+        let (name_str, num_locals, signature) = match self.num_parts() {
             0 => unreachable!(),
             1 => return Err(ParsingError::missing_param(self, "[proc|export].<procedure_name>")),
-            2 => (self.parts[1], 0),
+            2 => (self.parts[1], 0, None),
+            3 if self.parts[2].contains(Self::ALIAS_DELIM) => {
+                let signature = validate_proc_signature(self.parts[2], self)?;
+                (self.parts[1], 0, Some(signature))
+            }
             3 => {
                 let num_locals = validate_proc_locals(self.parts[2], self)?;
-                (self.parts[1], num_locals)
+                (self.parts[1], num_locals, None)
+            }
+            4 => {
+                let num_locals = validate_proc_locals(self.parts[2], self)?;
+                let signature = validate_proc_signature(self.parts[3], self)?;
+                (self.parts[1], num_locals, Some(signature))
             }
             _ => return Err(ParsingError::extra_param(self)),
         };
 
         ProcedureName::try_from(name_str.to_string())
-            .map(|proc_name| (proc_name, num_locals, is_export))
+            .map(|proc_name| (proc_name, num_locals, signature, is_export))
             .map_err(|err| ParsingError::invalid_proc_name(self, err))
     }
 
@@ -310,6 +336,18 @@ fn validate_proc_locals(locals: &str, token: &Token) -> Result<u16, ParsingError
     }
 }
 
+/// Procedure signature must consist of the number of inputs and the number of outputs of the
+/// procedure, each a 16-bit integer, separated by `->` (e.g., `4->2`).
+fn validate_proc_signature(signature: &str, token: &Token) -> Result<StackEffect, ParsingError> {
+    let (inputs, outputs) = signature
+        .split_once(Token::ALIAS_DELIM)
+        .ok_or_else(|| ParsingError::invalid_proc_signature(token, signature))?;
+    match (inputs.parse::<u16>(), outputs.parse::<u16>()) {
+        (Ok(inputs), Ok(outputs)) => Ok(StackEffect::new(inputs, outputs)),
+        _ => Err(ParsingError::invalid_proc_signature(token, signature)),
+    }
+}
+
 /// A module name must comply with the following rules:
 /// - The name must be between 1 and 255 characters long.
 /// - The name must start with an ASCII letter.
@@ -81,6 +81,14 @@ mod tests {
     // UNIT TESTS
     // ============================================================================================
 
+    #[test]
+    fn tokenize_source() {
+        let source = "#! docs\nproc.foo # comment\n  add\n\n  mul.2 end\n# trailing";
+        let tokens: alloc::vec::Vec<_> = crate::tokenize(source).collect();
+        let expected = [l("proc.foo", 2, 1), l("add", 3, 3), l("mul.2", 5, 3), l("end", 5, 9)];
+        assert_eq!(tokens, expected.map(Option::unwrap));
+    }
+
     #[test]
     fn empty_line() {
         let info = LineInfo::new(1, 0).with_contents("");
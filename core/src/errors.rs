@@ -44,6 +44,8 @@ pub enum OutputError {
     InvalidOverflowAddressLength(usize, usize),
     InvalidStackElement(String),
     OutputSizeTooBig(usize),
+    InvalidOutputRegionLength(usize, usize),
+    OutputRegionOutOfBounds(u32, usize),
 }
 
 impl fmt::Display for OutputError {
@@ -62,6 +64,12 @@ impl fmt::Display for OutputError {
             OutputSizeTooBig(size) => {
                 write!(f, "too many elements for output stack, {size} elements")
             }
+            InvalidOutputRegionLength(len, max_len) => {
+                write!(f, "output region must contain between 1 and {max_len} words, but {len} were given")
+            }
+            OutputRegionOutOfBounds(start_addr, len) => {
+                write!(f, "output region of {len} words starting at address {start_addr} exceeds the memory address space")
+            }
         }
     }
 }
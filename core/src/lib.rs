@@ -14,8 +14,9 @@ pub mod crypto {
     pub mod merkle {
         pub use miden_crypto::merkle::{
             DefaultMerkleStore, EmptySubtreeRoots, InnerNodeInfo, LeafIndex, MerkleError,
-            MerklePath, MerkleStore, MerkleTree, Mmr, MmrPeaks, NodeIndex, PartialMerkleTree,
-            RecordingMerkleStore, SimpleSmt, Smt, SmtProof, SmtProofError, StoreNode, SMT_DEPTH,
+            MerklePath, MerkleStore, MerkleTree, Mmr, MmrPeaks, MmrProof, NodeIndex,
+            PartialMerkleTree, PartialMmr, RecordingMerkleStore, SimpleSmt, Smt, SmtProof,
+            SmtProofError, StoreNode, SMT_DEPTH,
         };
     }
 
@@ -44,16 +45,19 @@ pub use math::{
 };
 
 mod program;
-pub use program::{blocks as code_blocks, CodeBlockTable, Kernel, Program, ProgramInfo};
+pub use program::{
+    blocks as code_blocks, CodeBlockTable, Kernel, MastStats, Program, ProgramDisassembly,
+    ProgramInfo, ProgramSerdeOptions,
+};
 
 mod operations;
 pub use operations::{
     AdviceInjector, AssemblyOp, DebugOptions, Decorator, DecoratorIterator, DecoratorList,
-    Operation, SignatureKind,
+    Operation, SignatureKind, SourceLocation,
 };
 
 pub mod stack;
-pub use stack::{StackInputs, StackOutputs};
+pub use stack::{OutputRegion, StackInputs, StackOutputs};
 
 pub mod utils;
 
@@ -1,5 +1,8 @@
 use super::SignatureKind;
-use crate::Felt;
+use crate::{
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    Felt,
+};
 use core::fmt;
 
 // ADVICE INJECTORS
@@ -105,6 +108,23 @@ pub enum AdviceInjector {
     /// the remainder respectively.
     U64Div,
 
+    /// Pushes the result of 256-bit unsigned integer division (both the quotient and the
+    /// remainder) onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [b7, b6, b5, b4, b3, b2, b1, b0, a7, a6, a5, a4, a3, a2, a1, a0, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [b7, b6, b5, b4, b3, b2, b1, b0, a7, a6, a5, a4, a3, a2, a1, a0, ...]
+    ///   Advice stack: [q0, q1, q2, q3, q4, q5, q6, q7, r0, r1, r2, r3, r4, r5, r6, r7, ...]
+    ///
+    /// Where (a0, ..., a7) and (b0, ..., b7) are the 32-bit limbs of the dividend and the divisor
+    /// respectively (with a0 representing the 32 least significant bits and a7 representing the
+    /// 32 most significant bits). Similarly, (q0, ..., q7) and (r0, ..., r7) represent the
+    /// quotient and the remainder respectively.
+    U256Div,
+
     /// Given an element in a quadratic extension field on the top of the stack (i.e., a0, b1),
     /// computes its multiplicative inverse and push the result onto the advice stack.
     ///
@@ -144,10 +164,38 @@ pub enum AdviceInjector {
     ///   degree coefficients are located at the top of the advice stack.
     Ext2Intt,
 
-    /// Currently unimplemented
+    /// Pushes onto the advice stack the pre-image of the leaf which the specified key maps to in a
+    /// Sparse Merkle Tree defined by the specified root, preceded by the number of elements in the
+    /// pre-image.
+    ///
+    /// If the leaf is empty, only the number of elements (i.e., 0) is pushed onto the advice
+    /// stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [KEY, ROOT, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [KEY, ROOT, ...]
+    ///   Advice stack: [leaf_size, KEY_0, VALUE_0, ..., KEY_{n-1}, VALUE_{n-1}, ...]
+    ///
+    /// Where leaf_size = 8 * n.
     SmtGet,
 
-    /// Currently unimplemented
+    /// Inserts into the advice map the pre-image of the leaf which the specified key maps to, as
+    /// the leaf would be after the specified value is set under the key in a Sparse Merkle Tree
+    /// defined by the specified root.
+    ///
+    /// The pre-image is inserted under the hash of the updated leaf. If the leaf becomes empty,
+    /// the advice map is left unchanged.
+    ///
+    /// Inputs:
+    ///   Operand stack: [VALUE, KEY, ROOT, ...]
+    ///   Advice map: {...}
+    ///
+    /// Outputs:
+    ///   Operand stack: [VALUE, KEY, ROOT, ...]
+    ///   Advice map: {NEW_LEAF_HASH: [KEY_0, VALUE_0, ..., KEY_{n-1}, VALUE_{n-1}]}
     SmtSet,
 
     /// Pushes onto the advice stack the value associated with the specified key in a Sparse
@@ -300,6 +348,7 @@ impl fmt::Display for AdviceInjector {
                 }
             }
             Self::U64Div => write!(f, "div_u64"),
+            Self::U256Div => write!(f, "div_u256"),
             Self::Ext2Inv => write!(f, "ext2_inv"),
             Self::Ext2Intt => write!(f, "ext2_intt"),
             Self::SmtGet => write!(f, "smt_get"),
@@ -317,3 +366,91 @@ impl fmt::Display for AdviceInjector {
         }
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for AdviceInjector {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Self::MerkleNodeMerge => target.write_u8(0),
+            Self::MerkleNodeToStack => target.write_u8(1),
+            Self::UpdateMerkleNode => target.write_u8(2),
+            Self::MapValueToStack {
+                include_len,
+                key_offset,
+            } => {
+                target.write_u8(3);
+                target.write_bool(*include_len);
+                target.write_usize(*key_offset);
+            }
+            Self::U64Div => target.write_u8(4),
+            Self::Ext2Inv => target.write_u8(5),
+            Self::Ext2Intt => target.write_u8(6),
+            Self::SmtGet => target.write_u8(7),
+            Self::SmtSet => target.write_u8(8),
+            Self::SmtPeek => target.write_u8(9),
+            Self::U32Clz => target.write_u8(10),
+            Self::U32Ctz => target.write_u8(11),
+            Self::U32Clo => target.write_u8(12),
+            Self::U32Cto => target.write_u8(13),
+            Self::ILog2 => target.write_u8(14),
+            Self::MemToMap => target.write_u8(15),
+            Self::HdwordToMap { domain } => {
+                target.write_u8(16);
+                domain.write_into(target);
+            }
+            Self::HpermToMap => target.write_u8(17),
+            Self::SigToStack { kind } => {
+                target.write_u8(18);
+                kind.write_into(target);
+            }
+            Self::U256Div => target.write_u8(19),
+        }
+    }
+}
+
+impl Deserializable for AdviceInjector {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let injector = match source.read_u8()? {
+            0 => Self::MerkleNodeMerge,
+            1 => Self::MerkleNodeToStack,
+            2 => Self::UpdateMerkleNode,
+            3 => {
+                let include_len = source.read_bool()?;
+                let key_offset = source.read_usize()?;
+                Self::MapValueToStack {
+                    include_len,
+                    key_offset,
+                }
+            }
+            4 => Self::U64Div,
+            5 => Self::Ext2Inv,
+            6 => Self::Ext2Intt,
+            7 => Self::SmtGet,
+            8 => Self::SmtSet,
+            9 => Self::SmtPeek,
+            10 => Self::U32Clz,
+            11 => Self::U32Ctz,
+            12 => Self::U32Clo,
+            13 => Self::U32Cto,
+            14 => Self::ILog2,
+            15 => Self::MemToMap,
+            16 => Self::HdwordToMap {
+                domain: Felt::read_from(source)?,
+            },
+            17 => Self::HpermToMap,
+            18 => Self::SigToStack {
+                kind: SignatureKind::read_from(source)?,
+            },
+            19 => Self::U256Div,
+            tag => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "invalid advice injector tag: {tag}"
+                )))
+            }
+        };
+
+        Ok(injector)
+    }
+}
@@ -1,16 +1,20 @@
-use alloc::string::String;
-use core::fmt;
+use super::SourceLocation;
+use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+use alloc::string::{String, ToString};
+use core::{fmt, str::from_utf8};
 
 // ASSEMBLY OP
 // ================================================================================================
 
 /// Contains information corresponding to an assembly instruction (only applicable in debug mode).
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq)]
 pub struct AssemblyOp {
     context_name: String,
     num_cycles: u8,
     op: String,
     should_break: bool,
+    module_path: Option<String>,
+    location: Option<SourceLocation>,
 }
 
 impl AssemblyOp {
@@ -22,9 +26,25 @@ impl AssemblyOp {
             num_cycles,
             op,
             should_break,
+            module_path: None,
+            location: None,
         }
     }
 
+    /// Returns this [AssemblyOp] with the path of the module which defines the procedure of this
+    /// operation set to the specified path.
+    pub fn with_module_path(mut self, module_path: String) -> Self {
+        self.module_path = Some(module_path);
+        self
+    }
+
+    /// Returns this [AssemblyOp] with the location of the assembly instruction in its source set
+    /// to the specified location.
+    pub fn with_location(mut self, location: SourceLocation) -> Self {
+        self.location = Some(location);
+        self
+    }
+
     /// Returns the context name for this operation.
     pub fn context_name(&self) -> &str {
         &self.context_name
@@ -45,6 +65,17 @@ impl AssemblyOp {
         self.should_break
     }
 
+    /// Returns the path of the module which defines the procedure of this operation, or None if
+    /// the procedure is defined in the program itself.
+    pub fn module_path(&self) -> Option<&str> {
+        self.module_path.as_deref()
+    }
+
+    /// Returns the location of the assembly instruction in its source, if known.
+    pub const fn location(&self) -> Option<&SourceLocation> {
+        self.location.as_ref()
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -54,6 +85,22 @@ impl AssemblyOp {
     }
 }
 
+impl PartialEq for AssemblyOp {
+    fn eq(&self, other: &Self) -> bool {
+        // source locations are optional; they are compared only if both operations have one
+        let locations = match (self.location, other.location) {
+            (Some(left), Some(right)) => left == right,
+            _ => true,
+        };
+        self.context_name == other.context_name
+            && self.num_cycles == other.num_cycles
+            && self.op == other.op
+            && self.should_break == other.should_break
+            && self.module_path == other.module_path
+            && locations
+    }
+}
+
 impl fmt::Display for AssemblyOp {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -63,3 +110,51 @@ impl fmt::Display for AssemblyOp {
         )
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for AssemblyOp {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.context_name.len());
+        target.write_bytes(self.context_name.as_bytes());
+        target.write_u8(self.num_cycles);
+        target.write_usize(self.op.len());
+        target.write_bytes(self.op.as_bytes());
+        target.write_bool(self.should_break);
+        target.write_bool(self.module_path.is_some());
+        if let Some(module_path) = &self.module_path {
+            target.write_usize(module_path.len());
+            target.write_bytes(module_path.as_bytes());
+        }
+        target.write_bool(self.location.is_some());
+        if let Some(location) = self.location {
+            location.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for AssemblyOp {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let context_name = read_string(source)?;
+        let num_cycles = source.read_u8()?;
+        let op = read_string(source)?;
+        let should_break = source.read_bool()?;
+        let mut asmop = Self::new(context_name, num_cycles, op, should_break);
+        if source.read_bool()? {
+            asmop = asmop.with_module_path(read_string(source)?);
+        }
+        match source.read_bool()? {
+            true => Ok(asmop.with_location(SourceLocation::read_from(source)?)),
+            false => Ok(asmop),
+        }
+    }
+}
+
+/// Reads a length-prefixed UTF-8 string from the specified source.
+fn read_string<R: ByteReader>(source: &mut R) -> Result<String, DeserializationError> {
+    let len = source.read_usize()?;
+    let bytes = source.read_slice(len)?;
+    let value = from_utf8(bytes).map_err(|e| DeserializationError::InvalidValue(e.to_string()))?;
+    Ok(value.to_string())
+}
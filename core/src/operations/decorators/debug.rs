@@ -1,3 +1,4 @@
+use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 use core::fmt;
 
 // DEBUG OPTIONS
@@ -41,3 +42,49 @@ impl fmt::Display for DebugOptions {
         }
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for DebugOptions {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Self::StackAll => target.write_u8(0),
+            Self::StackTop(n) => {
+                target.write_u8(1);
+                target.write_u16(*n);
+            }
+            Self::MemAll => target.write_u8(2),
+            Self::MemInterval(start, end) => {
+                target.write_u8(3);
+                target.write_u32(*start);
+                target.write_u32(*end);
+            }
+            Self::LocalInterval(start, end, num_locals) => {
+                target.write_u8(4);
+                target.write_u16(*start);
+                target.write_u16(*end);
+                target.write_u16(*num_locals);
+            }
+        }
+    }
+}
+
+impl Deserializable for DebugOptions {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let options = match source.read_u8()? {
+            0 => Self::StackAll,
+            1 => Self::StackTop(source.read_u16()?),
+            2 => Self::MemAll,
+            3 => Self::MemInterval(source.read_u32()?, source.read_u32()?),
+            4 => Self::LocalInterval(source.read_u16()?, source.read_u16()?, source.read_u16()?),
+            tag => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "invalid debug options tag: {tag}"
+                )))
+            }
+        };
+
+        Ok(options)
+    }
+}
@@ -1,4 +1,4 @@
-use super::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 use core::fmt;
 
 // SOURCE LOCATION
@@ -35,6 +35,11 @@ impl SourceLocation {
         self.line
     }
 
+    /// Returns the column of the location.
+    pub const fn column(&self) -> u32 {
+        self.column
+    }
+
     // STATE MUTATORS
     // -------------------------------------------------------------------------------------------------
 
@@ -1,9 +1,10 @@
 use super::Felt;
+use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 use core::fmt;
 mod decorators;
 pub use decorators::{
     AdviceInjector, AssemblyOp, DebugOptions, Decorator, DecoratorIterator, DecoratorList,
-    SignatureKind,
+    SignatureKind, SourceLocation,
 };
 
 // OPERATIONS
@@ -711,3 +712,129 @@ impl fmt::Display for Operation {
         }
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for Operation {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.op_code());
+        match self {
+            Self::Assert(err_code) => target.write_u32(*err_code),
+            Self::U32assert2(err_code) => err_code.write_into(target),
+            Self::Push(value) => value.write_into(target),
+            _ => (),
+        }
+    }
+}
+
+impl Deserializable for Operation {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let op_code = source.read_u8()?;
+        let op = match op_code {
+            0b0000_0000 => Self::Noop,
+            0b0000_0001 => Self::Eqz,
+            0b0000_0010 => Self::Neg,
+            0b0000_0011 => Self::Inv,
+            0b0000_0100 => Self::Incr,
+            0b0000_0101 => Self::Not,
+            0b0000_0110 => Self::FmpAdd,
+            0b0000_0111 => Self::MLoad,
+            0b0000_1000 => Self::Swap,
+            0b0000_1001 => Self::Caller,
+            0b0000_1010 => Self::MovUp2,
+            0b0000_1011 => Self::MovDn2,
+            0b0000_1100 => Self::MovUp3,
+            0b0000_1101 => Self::MovDn3,
+            0b0000_1110 => Self::AdvPopW,
+            0b0000_1111 => Self::Expacc,
+
+            0b0001_0000 => Self::MovUp4,
+            0b0001_0001 => Self::MovDn4,
+            0b0001_0010 => Self::MovUp5,
+            0b0001_0011 => Self::MovDn5,
+            0b0001_0100 => Self::MovUp6,
+            0b0001_0101 => Self::MovDn6,
+            0b0001_0110 => Self::MovUp7,
+            0b0001_0111 => Self::MovDn7,
+            0b0001_1000 => Self::SwapW,
+            0b0001_1001 => Self::Ext2Mul,
+            0b0001_1010 => Self::MovUp8,
+            0b0001_1011 => Self::MovDn8,
+            0b0001_1100 => Self::SwapW2,
+            0b0001_1101 => Self::SwapW3,
+            0b0001_1110 => Self::SwapDW,
+
+            0b0010_0000 => Self::Assert(source.read_u32()?),
+            0b0010_0001 => Self::Eq,
+            0b0010_0010 => Self::Add,
+            0b0010_0011 => Self::Mul,
+            0b0010_0100 => Self::And,
+            0b0010_0101 => Self::Or,
+            0b0010_0110 => Self::U32and,
+            0b0010_0111 => Self::U32xor,
+            0b0010_1000 => Self::FriE2F4,
+            0b0010_1001 => Self::Drop,
+            0b0010_1010 => Self::CSwap,
+            0b0010_1011 => Self::CSwapW,
+            0b0010_1100 => Self::MLoadW,
+            0b0010_1101 => Self::MStore,
+            0b0010_1110 => Self::MStoreW,
+            0b0010_1111 => Self::FmpUpdate,
+
+            0b0011_0000 => Self::Pad,
+            0b0011_0001 => Self::Dup0,
+            0b0011_0010 => Self::Dup1,
+            0b0011_0011 => Self::Dup2,
+            0b0011_0100 => Self::Dup3,
+            0b0011_0101 => Self::Dup4,
+            0b0011_0110 => Self::Dup5,
+            0b0011_0111 => Self::Dup6,
+            0b0011_1000 => Self::Dup7,
+            0b0011_1001 => Self::Dup9,
+            0b0011_1010 => Self::Dup11,
+            0b0011_1011 => Self::Dup13,
+            0b0011_1100 => Self::Dup15,
+            0b0011_1101 => Self::AdvPop,
+            0b0011_1110 => Self::SDepth,
+            0b0011_1111 => Self::Clk,
+
+            0b0100_0000 => Self::U32add,
+            0b0100_0010 => Self::U32sub,
+            0b0100_0100 => Self::U32mul,
+            0b0100_0110 => Self::U32div,
+            0b0100_1000 => Self::U32split,
+            0b0100_1010 => Self::U32assert2(Felt::read_from(source)?),
+            0b0100_1100 => Self::U32add3,
+            0b0100_1110 => Self::U32madd,
+
+            0b0101_0000 => Self::HPerm,
+            0b0101_0001 => Self::MpVerify,
+            0b0101_0010 => Self::Pipe,
+            0b0101_0011 => Self::MStream,
+            0b0101_0100 => Self::Split,
+            0b0101_0101 => Self::Loop,
+            0b0101_0110 => Self::Span,
+            0b0101_0111 => Self::Join,
+            0b0101_1000 => Self::Dyn,
+            0b0101_1001 => Self::RCombBase,
+
+            0b0110_0000 => Self::MrUpdate,
+            0b0110_0100 => Self::Push(Felt::read_from(source)?),
+            0b0110_1000 => Self::SysCall,
+            0b0110_1100 => Self::Call,
+            0b0111_0000 => Self::End,
+            0b0111_0100 => Self::Repeat,
+            0b0111_1000 => Self::Respan,
+            0b0111_1100 => Self::Halt,
+
+            _ => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "invalid operation code: {op_code:#09b}"
+                )))
+            }
+        };
+
+        Ok(op)
+    }
+}
@@ -0,0 +1,213 @@
+use super::{
+    blocks::{Call, CodeBlock, Dyn, Span},
+    Digest, Program,
+};
+use crate::utils::write_hex_bytes;
+use alloc::vec::Vec;
+use core::fmt;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of spaces used to indent each level of the MAST.
+const INDENT: usize = 4;
+
+// PROGRAM DISASSEMBLY
+// ================================================================================================
+
+/// A structured, human-readable rendering of a program's MAST.
+///
+/// Unlike the [Display](fmt::Display) implementation of [Program], which prints the whole MAST on
+/// a single line, the disassembly:
+/// - prints each code block on a separate line together with its digest;
+/// - splits span blocks into operation batches and prints decorators attached to each operation;
+/// - resolves targets of `call` and `syscall` blocks against the code block table and the kernel
+///   of the program, and prints bodies of the called procedures after the program body;
+/// - flags proxy blocks (i.e., blocks whose code is not available).
+pub struct ProgramDisassembly<'a> {
+    program: &'a Program,
+}
+
+impl<'a> ProgramDisassembly<'a> {
+    /// Returns a new disassembly of the specified program.
+    pub fn new(program: &'a Program) -> Self {
+        Self { program }
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Writes the specified code block indented to the specified depth. Hashes of procedures
+    /// called from the block which are present in the code block table are appended to `callees`.
+    fn fmt_block(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        block: &CodeBlock,
+        depth: usize,
+        callees: &mut Vec<Digest>,
+    ) -> fmt::Result {
+        match block {
+            CodeBlock::Span(span) => self.fmt_span(f, span, depth),
+            CodeBlock::Join(join) => {
+                write_line(f, depth, format_args!("join"), Some(join.hash()))?;
+                self.fmt_block(f, join.first(), depth + 1, callees)?;
+                self.fmt_block(f, join.second(), depth + 1, callees)?;
+                write_line(f, depth, format_args!("end"), None)
+            }
+            CodeBlock::Split(split) => {
+                write_line(f, depth, format_args!("split"), Some(split.hash()))?;
+                self.fmt_block(f, split.on_true(), depth + 1, callees)?;
+                write_line(f, depth, format_args!("else"), None)?;
+                self.fmt_block(f, split.on_false(), depth + 1, callees)?;
+                write_line(f, depth, format_args!("end"), None)
+            }
+            CodeBlock::Loop(loop_block) => {
+                write_line(f, depth, format_args!("loop"), Some(loop_block.hash()))?;
+                self.fmt_block(f, loop_block.body(), depth + 1, callees)?;
+                write_line(f, depth, format_args!("end"), None)
+            }
+            CodeBlock::Call(call) => self.fmt_call(f, call, depth, callees),
+            CodeBlock::Dyn(block) => write_line(f, depth, format_args!("dyn"), Some(block.hash())),
+            CodeBlock::Proxy(proxy) => {
+                write_indent(f, depth)?;
+                write!(f, "proxy.")?;
+                write_hex_bytes(f, &proxy.hash().as_bytes())?;
+                writeln!(f, " # code not available")
+            }
+        }
+    }
+
+    /// Writes the specified span block splitting its operations into batches. Decorators are
+    /// written right before the operation to which they are attached.
+    fn fmt_span(&self, f: &mut fmt::Formatter<'_>, span: &Span, depth: usize) -> fmt::Result {
+        write_line(f, depth, format_args!("span"), Some(span.hash()))?;
+
+        let mut decorators = span.decorators().iter().peekable();
+        let mut op_idx = 0;
+        for (batch_idx, batch) in span.op_batches().iter().enumerate() {
+            write_line(
+                f,
+                depth + 1,
+                format_args!("# batch {batch_idx} ({} groups)", batch.num_groups()),
+                None,
+            )?;
+            for op in batch.ops() {
+                while let Some((_, decorator)) = decorators.next_if(|(idx, _)| *idx == op_idx) {
+                    write_line(f, depth + 1, format_args!("decorator {decorator}"), None)?;
+                }
+                write_line(f, depth + 1, format_args!("{op}"), None)?;
+                op_idx += 1;
+            }
+        }
+
+        // decorators may also be attached to the position right after the last operation
+        for (_, decorator) in decorators {
+            write_line(f, depth + 1, format_args!("decorator {decorator}"), None)?;
+        }
+
+        write_line(f, depth, format_args!("end"), None)
+    }
+
+    /// Writes the specified call block together with the description of its target.
+    fn fmt_call(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        call: &Call,
+        depth: usize,
+        callees: &mut Vec<Digest>,
+    ) -> fmt::Result {
+        let fn_hash = call.fn_hash();
+        write_indent(f, depth)?;
+
+        if !call.is_syscall() && fn_hash == Dyn::dyn_hash() {
+            write!(f, "dyncall")?;
+            return write_digest_comment(f, call.hash());
+        }
+
+        write!(f, "{}.", if call.is_syscall() { "syscall" } else { "call" })?;
+        write_hex_bytes(f, &fn_hash.as_bytes())?;
+        write!(f, " # ")?;
+        write_hex_bytes(f, &call.hash().as_bytes())?;
+
+        if call.is_syscall() {
+            let kernel_idx = self.program.kernel().proc_hashes().iter().position(|&h| h == fn_hash);
+            match kernel_idx {
+                Some(idx) => writeln!(f, ", kernel procedure {idx}"),
+                None => writeln!(f, ", NOT IN KERNEL"),
+            }
+        } else if self.program.cb_table().has(fn_hash) {
+            if !callees.contains(&fn_hash) {
+                callees.push(fn_hash);
+            }
+            writeln!(f)
+        } else {
+            writeln!(f, ", target not in code block table")
+        }
+    }
+}
+
+impl fmt::Display for ProgramDisassembly<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "# program ")?;
+        write_hex_bytes(f, &self.program.hash().as_bytes())?;
+        writeln!(f)?;
+
+        for (idx, proc_hash) in self.program.kernel().proc_hashes().iter().enumerate() {
+            write!(f, "# kernel procedure {idx}: ")?;
+            write_hex_bytes(f, &proc_hash.as_bytes())?;
+            writeln!(f)?;
+        }
+
+        let mut callees = Vec::new();
+        writeln!(f, "begin")?;
+        self.fmt_block(f, self.program.root(), 1, &mut callees)?;
+        writeln!(f, "end")?;
+
+        // write out all procedures reachable via calls; the list of callees may grow as we go
+        let mut idx = 0;
+        while idx < callees.len() {
+            let proc_hash = callees[idx];
+            idx += 1;
+            let block = self.program.cb_table().get(proc_hash).expect("callee not in cb table");
+
+            writeln!(f)?;
+            write!(f, "proc.")?;
+            write_hex_bytes(f, &proc_hash.as_bytes())?;
+            writeln!(f)?;
+            self.fmt_block(f, block, 1, &mut callees)?;
+            writeln!(f, "end")?;
+        }
+
+        Ok(())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Writes indentation for the specified depth.
+fn write_indent(f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+    write!(f, "{:width$}", "", width = depth * INDENT)
+}
+
+/// Writes a comment containing the specified digest and terminates the line.
+fn write_digest_comment(f: &mut fmt::Formatter<'_>, digest: Digest) -> fmt::Result {
+    write!(f, " # ")?;
+    write_hex_bytes(f, &digest.as_bytes())?;
+    writeln!(f)
+}
+
+/// Writes a single line at the specified depth, optionally followed by a digest comment.
+fn write_line(
+    f: &mut fmt::Formatter<'_>,
+    depth: usize,
+    content: fmt::Arguments<'_>,
+    digest: Option<Digest>,
+) -> fmt::Result {
+    write_indent(f, depth)?;
+    f.write_fmt(content)?;
+    match digest {
+        Some(digest) => write_digest_comment(f, digest),
+        None => writeln!(f),
+    }
+}
@@ -9,9 +9,18 @@ use core::fmt;
 pub mod blocks;
 use blocks::CodeBlock;
 
+mod disasm;
+pub use disasm::ProgramDisassembly;
+
 mod info;
 pub use info::ProgramInfo;
 
+mod serde;
+pub use serde::ProgramSerdeOptions;
+
+mod stats;
+pub use stats::MastStats;
+
 #[cfg(test)]
 mod tests;
 
@@ -68,6 +77,16 @@ impl Program {
     pub fn cb_table(&self) -> &CodeBlockTable {
         &self.cb_table
     }
+
+    /// Returns a structured, human-readable rendering of this program's MAST.
+    pub fn disassemble(&self) -> ProgramDisassembly<'_> {
+        ProgramDisassembly::new(self)
+    }
+
+    /// Returns statistics about the size of this program's MAST.
+    pub fn mast_stats(&self) -> MastStats {
+        MastStats::new(self)
+    }
 }
 
 impl fmt::Display for Program {
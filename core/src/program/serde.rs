@@ -0,0 +1,384 @@
+//! Serialization and deserialization of compiled programs.
+//!
+//! A [Program] is serialized as a flat table of MAST nodes followed by references into this
+//! table. Nodes are written in post-order, so a node only ever references nodes which precede it
+//! in the table, and structurally identical subtrees (i.e., subtrees with the same digest and the
+//! same decorators) are written only once. Decorators can optionally be left out of the output;
+//! since decorators do not affect code block hashes, a program deserialized without decorators
+//! has the same hash as the original one.
+//!
+//! The layout of a serialized program is as follows:
+//!
+//! - magic bytes `MASB` followed by the format version (1 byte);
+//! - [ProgramSerdeOptions] (so that the format is self-contained);
+//! - the kernel of the program;
+//! - the node table: number of nodes followed by the nodes themselves;
+//! - index of the root node in the node table;
+//! - the code block table: number of entries followed by the node table index of each entry.
+
+use super::{
+    blocks::CodeBlock, ByteReader, ByteWriter, CodeBlockTable, Deserializable,
+    DeserializationError, Digest, Kernel, Operation, Program, Serializable,
+};
+use crate::DecoratorList;
+use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Magic bytes identifying a serialized program.
+pub const MAGIC: &[u8; 4] = b"MASB";
+
+/// Current version of the program serialization format.
+///
+/// Version history:
+/// - 1: initial format.
+/// - 2: `AsmOp` decorators include the module paths of library procedures and the source
+///   locations of assembly instructions.
+pub const VERSION: u8 = 2;
+
+// Tags of the serialized MAST nodes.
+const SPAN: u8 = 0;
+const JOIN: u8 = 1;
+const SPLIT: u8 = 2;
+const LOOP: u8 = 3;
+const CALL: u8 = 4;
+const SYSCALL: u8 = 5;
+const DYN: u8 = 6;
+const PROXY: u8 = 7;
+
+// SERDE OPTIONS
+// ================================================================================================
+
+/// Serialization options for compiled programs.
+///
+/// The options are serialized along with the program to make the serialization format
+/// self-contained.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ProgramSerdeOptions {
+    pub serialize_decorators: bool,
+}
+
+impl ProgramSerdeOptions {
+    pub const fn new(serialize_decorators: bool) -> Self {
+        Self {
+            serialize_decorators,
+        }
+    }
+}
+
+impl Default for ProgramSerdeOptions {
+    fn default() -> Self {
+        Self::new(true)
+    }
+}
+
+impl Serializable for ProgramSerdeOptions {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_bool(self.serialize_decorators);
+    }
+}
+
+impl Deserializable for ProgramSerdeOptions {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let serialize_decorators = source.read_bool()?;
+        Ok(Self::new(serialize_decorators))
+    }
+}
+
+// PROGRAM SERIALIZATION
+// ================================================================================================
+
+impl Program {
+    /// Writes this program into the specified target using the provided serialization options.
+    pub fn write_with_options<W: ByteWriter>(&self, target: &mut W, options: ProgramSerdeOptions) {
+        target.write_bytes(MAGIC);
+        target.write_u8(VERSION);
+        options.write_into(target);
+        self.kernel.write_into(target);
+
+        let mut nodes = MastNodeTable::new(options);
+        let root = nodes.add_block(&self.root);
+        let cb_table = nodes.add_cb_table(&self.cb_table);
+
+        nodes.write_into(target);
+        target.write_u32(root);
+        target.write_usize(cb_table.len());
+        cb_table.into_iter().for_each(|idx| target.write_u32(idx));
+    }
+
+    /// Returns byte representation of this program serialized using the provided options.
+    pub fn to_bytes_with_options(&self, options: ProgramSerdeOptions) -> Vec<u8> {
+        let mut target = Vec::<u8>::default();
+        self.write_with_options(&mut target, options);
+        target
+    }
+}
+
+impl Serializable for Program {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.write_with_options(target, ProgramSerdeOptions::default());
+    }
+}
+
+impl Deserializable for Program {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let magic: [u8; 4] = source.read_array()?;
+        if &magic != MAGIC {
+            return Err(DeserializationError::InvalidValue(
+                "invalid program magic bytes".to_string(),
+            ));
+        }
+
+        let version = source.read_u8()?;
+        if version != VERSION {
+            return Err(DeserializationError::InvalidValue(format!(
+                "unsupported program format version {version}; expected version {VERSION}"
+            )));
+        }
+
+        let options = ProgramSerdeOptions::read_from(source)?;
+        let kernel = Kernel::read_from(source)?;
+        let nodes = read_nodes(source, options)?;
+
+        let root = get_node(&nodes, source.read_u32()?)?.clone();
+
+        let mut cb_table = CodeBlockTable::default();
+        let num_cb_table_entries = source.read_usize()?;
+        for _ in 0..num_cb_table_entries {
+            cb_table.insert(get_node(&nodes, source.read_u32()?)?.clone());
+        }
+
+        Ok(Self::with_kernel(root, kernel, cb_table))
+    }
+}
+
+// CODE BLOCK TABLE SERIALIZATION
+// ================================================================================================
+
+impl Serializable for CodeBlockTable {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        let options = ProgramSerdeOptions::default();
+        options.write_into(target);
+
+        let mut nodes = MastNodeTable::new(options);
+        let entries = nodes.add_cb_table(self);
+
+        nodes.write_into(target);
+        target.write_usize(entries.len());
+        entries.into_iter().for_each(|idx| target.write_u32(idx));
+    }
+}
+
+impl Deserializable for CodeBlockTable {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let options = ProgramSerdeOptions::read_from(source)?;
+        let nodes = read_nodes(source, options)?;
+
+        let mut cb_table = CodeBlockTable::default();
+        let num_entries = source.read_usize()?;
+        for _ in 0..num_entries {
+            cb_table.insert(get_node(&nodes, source.read_u32()?)?.clone());
+        }
+
+        Ok(cb_table)
+    }
+}
+
+// MAST NODE TABLE
+// ================================================================================================
+
+/// A table of serialized MAST nodes used during program serialization.
+///
+/// Each node is encoded independently with references to its children expressed as indexes into
+/// this table. The encoding of a node is used to detect duplicates, and thus, identical subtrees
+/// are added to the table only once.
+struct MastNodeTable {
+    options: ProgramSerdeOptions,
+    nodes: Vec<Vec<u8>>,
+    node_indexes: BTreeMap<Vec<u8>, u32>,
+}
+
+impl MastNodeTable {
+    /// Returns a new empty table which encodes nodes according to the specified options.
+    pub fn new(options: ProgramSerdeOptions) -> Self {
+        Self {
+            options,
+            nodes: Vec::new(),
+            node_indexes: BTreeMap::new(),
+        }
+    }
+
+    /// Adds the specified code block and all of its descendants to this table, and returns the
+    /// index of the block in the table.
+    pub fn add_block(&mut self, block: &CodeBlock) -> u32 {
+        let mut node = Vec::<u8>::new();
+        match block {
+            CodeBlock::Span(span) => {
+                node.write_u8(SPAN);
+                let ops =
+                    span.op_batches().iter().flat_map(|batch| batch.ops()).collect::<Vec<_>>();
+                node.write_usize(ops.len());
+                ops.into_iter().for_each(|op| op.write_into(&mut node));
+                if self.options.serialize_decorators {
+                    write_decorators(span.decorators(), &mut node);
+                }
+            }
+            CodeBlock::Join(join) => {
+                let first = self.add_block(join.first());
+                let second = self.add_block(join.second());
+                node.write_u8(JOIN);
+                node.write_u32(first);
+                node.write_u32(second);
+            }
+            CodeBlock::Split(split) => {
+                let on_true = self.add_block(split.on_true());
+                let on_false = self.add_block(split.on_false());
+                node.write_u8(SPLIT);
+                node.write_u32(on_true);
+                node.write_u32(on_false);
+            }
+            CodeBlock::Loop(loop_block) => {
+                let body = self.add_block(loop_block.body());
+                node.write_u8(LOOP);
+                node.write_u32(body);
+            }
+            CodeBlock::Call(call) => {
+                node.write_u8(if call.is_syscall() { SYSCALL } else { CALL });
+                call.fn_hash().write_into(&mut node);
+            }
+            CodeBlock::Dyn(_) => node.write_u8(DYN),
+            CodeBlock::Proxy(proxy) => {
+                node.write_u8(PROXY);
+                proxy.hash().write_into(&mut node);
+            }
+        }
+
+        if let Some(&idx) = self.node_indexes.get(&node) {
+            return idx;
+        }
+        let idx = self.nodes.len() as u32;
+        self.nodes.push(node.clone());
+        self.node_indexes.insert(node, idx);
+        idx
+    }
+
+    /// Adds all code blocks from the specified code block table to this table, and returns the
+    /// indexes of these blocks in the table.
+    pub fn add_cb_table(&mut self, cb_table: &CodeBlockTable) -> Vec<u32> {
+        cb_table.0.values().map(|block| self.add_block(block)).collect()
+    }
+
+    /// Writes all nodes of this table into the specified target.
+    pub fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.nodes.len());
+        self.nodes.iter().for_each(|node| target.write_bytes(node));
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Reads a table of MAST nodes from the specified source and returns the code blocks defined by
+/// these nodes in the order in which they were serialized.
+fn read_nodes<R: ByteReader>(
+    source: &mut R,
+    options: ProgramSerdeOptions,
+) -> Result<Vec<CodeBlock>, DeserializationError> {
+    let num_nodes = source.read_usize()?;
+    let mut nodes = Vec::<CodeBlock>::new();
+    for _ in 0..num_nodes {
+        let block = match source.read_u8()? {
+            SPAN => {
+                let num_ops = source.read_usize()?;
+                if num_ops == 0 {
+                    return Err(DeserializationError::InvalidValue(
+                        "span block must contain at least one operation".to_string(),
+                    ));
+                }
+                let ops = (0..num_ops)
+                    .map(|_| Operation::read_from(source))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let decorators = if options.serialize_decorators {
+                    read_decorators(source, num_ops)?
+                } else {
+                    DecoratorList::new()
+                };
+                CodeBlock::new_span_with_decorators(ops, decorators)
+            }
+            JOIN => {
+                let first = get_node(&nodes, source.read_u32()?)?.clone();
+                let second = get_node(&nodes, source.read_u32()?)?.clone();
+                CodeBlock::new_join([first, second])
+            }
+            SPLIT => {
+                let on_true = get_node(&nodes, source.read_u32()?)?.clone();
+                let on_false = get_node(&nodes, source.read_u32()?)?.clone();
+                CodeBlock::new_split(on_true, on_false)
+            }
+            LOOP => CodeBlock::new_loop(get_node(&nodes, source.read_u32()?)?.clone()),
+            CALL => CodeBlock::new_call(Digest::read_from(source)?),
+            SYSCALL => CodeBlock::new_syscall(Digest::read_from(source)?),
+            DYN => CodeBlock::new_dyn(),
+            PROXY => CodeBlock::new_proxy(Digest::read_from(source)?),
+            tag => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "invalid code block tag: {tag}"
+                )))
+            }
+        };
+        nodes.push(block);
+    }
+
+    Ok(nodes)
+}
+
+/// Returns a node at the specified index, or an error if the index is out of bounds.
+///
+/// Since nodes reference only previously deserialized nodes, this also guarantees that the MAST
+/// does not contain any cycles.
+fn get_node(nodes: &[CodeBlock], idx: u32) -> Result<&CodeBlock, DeserializationError> {
+    nodes.get(idx as usize).ok_or_else(|| {
+        DeserializationError::InvalidValue(format!(
+            "node index {idx} is out of bounds; only {} nodes have been read",
+            nodes.len()
+        ))
+    })
+}
+
+/// Writes the decorators of a span block into the specified target.
+fn write_decorators<W: ByteWriter>(decorators: &DecoratorList, target: &mut W) {
+    target.write_usize(decorators.len());
+    for (op_idx, decorator) in decorators {
+        target.write_usize(*op_idx);
+        decorator.write_into(target);
+    }
+}
+
+/// Reads the decorators of a span block containing the specified number of operations.
+///
+/// Returns an error if the decorators are not sorted by operation index or if they refer to an
+/// operation index past the end of the span.
+fn read_decorators<R: ByteReader>(
+    source: &mut R,
+    num_ops: usize,
+) -> Result<DecoratorList, DeserializationError> {
+    let num_decorators = source.read_usize()?;
+    let mut decorators = DecoratorList::new();
+    for _ in 0..num_decorators {
+        let op_idx = source.read_usize()?;
+        if op_idx > num_ops {
+            return Err(DeserializationError::InvalidValue(format!(
+                "decorator operation index {op_idx} exceeds the number of operations {num_ops}"
+            )));
+        }
+        if decorators.last().is_some_and(|(prev_idx, _)| *prev_idx > op_idx) {
+            return Err(DeserializationError::InvalidValue(
+                "decorators are not sorted by operation index".to_string(),
+            ));
+        }
+        decorators.push((op_idx, Deserializable::read_from(source)?));
+    }
+
+    Ok(decorators)
+}
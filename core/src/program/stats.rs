@@ -0,0 +1,105 @@
+use super::{blocks::CodeBlock, Program};
+use alloc::{collections::BTreeSet, vec::Vec};
+
+// MAST STATS
+// ================================================================================================
+
+/// Statistics about the size of a program's MAST.
+///
+/// The statistics cover the program body as well as the bodies of all procedures invoked via
+/// `call` instructions which are present in the code block table of the program (i.e., the code
+/// which needs to be deployed together with the program). Blocks are counted as they appear in
+/// the MAST; thus, for example, a block which is repeated several times is counted several times,
+/// but only once towards the number of distinct blocks.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MastStats {
+    num_blocks: usize,
+    num_distinct_blocks: usize,
+    num_span_blocks: usize,
+    num_distinct_span_blocks: usize,
+    num_operations: usize,
+    num_procedures: usize,
+}
+
+impl MastStats {
+    /// Returns the statistics of the MAST of the specified program.
+    pub fn new(program: &Program) -> Self {
+        let mut stats = Self::default();
+        let mut distinct_blocks = BTreeSet::new();
+        let mut distinct_spans = BTreeSet::new();
+
+        // the list of called procedures may grow as we go
+        let mut callees = Vec::new();
+        let mut blocks = vec![program.root()];
+        let mut idx = 0;
+        loop {
+            while let Some(block) = blocks.pop() {
+                stats.num_blocks += 1;
+                distinct_blocks.insert(block.hash().as_bytes());
+                match block {
+                    CodeBlock::Span(span) => {
+                        stats.num_span_blocks += 1;
+                        distinct_spans.insert(span.hash().as_bytes());
+                        stats.num_operations +=
+                            span.op_batches().iter().map(|batch| batch.ops().len()).sum::<usize>();
+                    }
+                    CodeBlock::Join(join) => blocks.extend([join.second(), join.first()]),
+                    CodeBlock::Split(split) => blocks.extend([split.on_false(), split.on_true()]),
+                    CodeBlock::Loop(loop_block) => blocks.push(loop_block.body()),
+                    CodeBlock::Call(call) => {
+                        let fn_hash = call.fn_hash();
+                        if program.cb_table().has(fn_hash) && !callees.contains(&fn_hash) {
+                            callees.push(fn_hash);
+                        }
+                    }
+                    CodeBlock::Dyn(_) | CodeBlock::Proxy(_) => (),
+                }
+            }
+
+            let Some(&proc_hash) = callees.get(idx) else {
+                break;
+            };
+            idx += 1;
+            blocks.push(program.cb_table().get(proc_hash).expect("callee not in cb table"));
+        }
+
+        stats.num_distinct_blocks = distinct_blocks.len();
+        stats.num_distinct_span_blocks = distinct_spans.len();
+        stats.num_procedures = callees.len();
+        stats
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of code blocks in the MAST.
+    pub fn num_blocks(&self) -> usize {
+        self.num_blocks
+    }
+
+    /// Returns the number of code blocks in the MAST with distinct hashes.
+    pub fn num_distinct_blocks(&self) -> usize {
+        self.num_distinct_blocks
+    }
+
+    /// Returns the number of SPAN blocks in the MAST.
+    pub fn num_span_blocks(&self) -> usize {
+        self.num_span_blocks
+    }
+
+    /// Returns the number of SPAN blocks in the MAST with distinct hashes.
+    pub fn num_distinct_span_blocks(&self) -> usize {
+        self.num_distinct_span_blocks
+    }
+
+    /// Returns the total number of operations in the SPAN blocks of the MAST.
+    pub fn num_operations(&self) -> usize {
+        self.num_operations
+    }
+
+    /// Returns the number of procedures invoked via `call` instructions whose bodies are included
+    /// in the statistics.
+    pub fn num_procedures(&self) -> usize {
+        self.num_procedures
+    }
+}
@@ -1,6 +1,14 @@
-use super::{blocks::Dyn, Deserializable, Digest, Felt, Kernel, ProgramInfo, Serializable};
-use crate::{chiplets::hasher, Word};
-use alloc::vec::Vec;
+use super::{
+    blocks::{CodeBlock, Dyn},
+    serde::VERSION,
+    CodeBlockTable, Deserializable, Digest, Felt, Kernel, Program, ProgramInfo,
+    ProgramSerdeOptions, Serializable,
+};
+use crate::{
+    chiplets::hasher, utils::to_hex, AssemblyOp, DebugOptions, Decorator, Operation,
+    SourceLocation, Word,
+};
+use alloc::{string::ToString, vec::Vec};
 use proptest::prelude::*;
 use rand_utils::prng_array;
 
@@ -11,6 +19,144 @@ fn dyn_hash_is_correct() {
     assert_eq!(expected_constant, Dyn::new().hash());
 }
 
+#[test]
+fn program_serialization_round_trip() {
+    let program = build_test_program();
+    let bytes = program.to_bytes();
+    let deser = Program::read_from_bytes(&bytes).unwrap();
+
+    assert_eq!(program.hash(), deser.hash());
+    assert_eq!(program.root(), deser.root());
+    assert_eq!(program.kernel(), deser.kernel());
+    let callee = CodeBlock::new_span(vec![Operation::Mul]);
+    assert_eq!(program.cb_table().get(callee.hash()), deser.cb_table().get(callee.hash()));
+}
+
+#[test]
+fn program_serialization_strips_decorators() {
+    let program = build_test_program();
+    let stripped = program.to_bytes_with_options(ProgramSerdeOptions::new(false));
+    assert!(stripped.len() < program.to_bytes().len());
+
+    let deser = Program::read_from_bytes(&stripped).unwrap();
+    assert_eq!(program.hash(), deser.hash());
+    match deser.root() {
+        CodeBlock::Join(join) => match join.first() {
+            CodeBlock::Span(span) => assert!(span.decorators().is_empty()),
+            _ => panic!("expected a span block"),
+        },
+        _ => panic!("expected a join block"),
+    }
+}
+
+#[test]
+fn program_serialization_deduplicates_subtrees() {
+    let span = CodeBlock::new_span(vec![Operation::Push(Felt::new(7)); 100]);
+    let join = CodeBlock::new_join([span.clone(), span.clone()]);
+    let shared = Program::new(CodeBlock::new_join([join.clone(), join]));
+    let single = Program::new(span);
+
+    // the shared program contains 4 copies of the span, but it should be serialized only once
+    let shared_bytes = shared.to_bytes();
+    assert!(shared_bytes.len() < 2 * single.to_bytes().len());
+    assert_eq!(shared.hash(), Program::read_from_bytes(&shared_bytes).unwrap().hash());
+}
+
+#[test]
+fn program_deserialization_rejects_invalid_input() {
+    let mut bytes = build_test_program().to_bytes();
+
+    // invalid version
+    bytes[4] = u8::MAX;
+    assert!(Program::read_from_bytes(&bytes).is_err());
+
+    // programs serialized with the previous version of the format are not supported
+    bytes[4] = VERSION - 1;
+    assert!(Program::read_from_bytes(&bytes).is_err());
+
+    // invalid magic
+    bytes[4] = VERSION;
+    bytes[0] = b'X';
+    assert!(Program::read_from_bytes(&bytes).is_err());
+
+    // truncated input
+    bytes[0] = b'M';
+    assert!(Program::read_from_bytes(&bytes[..bytes.len() - 1]).is_err());
+}
+
+#[test]
+fn cb_table_serialization_round_trip() {
+    let mut cb_table = CodeBlockTable::default();
+    cb_table.insert(CodeBlock::new_span(vec![Operation::Mul]));
+    cb_table.insert(CodeBlock::new_loop(CodeBlock::new_span(vec![Operation::Pad])));
+
+    let deser = CodeBlockTable::read_from_bytes(&cb_table.to_bytes()).unwrap();
+    for block in cb_table.0.values() {
+        assert_eq!(Some(block), deser.get(block.hash()));
+    }
+}
+
+#[test]
+fn program_disassembly() {
+    let program = build_test_program();
+    let disasm = program.disassemble().to_string();
+
+    let callee = CodeBlock::new_span(vec![Operation::Mul]);
+    let kernel_proc = CodeBlock::new_span(vec![Operation::Add]);
+    let callee_hex = to_hex(&callee.hash().as_bytes()).unwrap();
+    let kernel_proc_hex = to_hex(&kernel_proc.hash().as_bytes()).unwrap();
+    let program_hex = to_hex(&program.hash().as_bytes()).unwrap();
+
+    assert!(disasm.starts_with(&format!("# program 0x{program_hex}\n")));
+    assert!(disasm.contains(&format!("# kernel procedure 0: 0x{kernel_proc_hex}\n")));
+
+    // decorators are printed right before the operations they are attached to
+    let expected_span = [
+        "            # batch 0 (2 groups)",
+        "            decorator asmOp(push.1, 2)",
+        "            push(1)",
+        "            decorator debug(stack.4)",
+        "            assert(3)",
+        "            decorator event(17)",
+        "            u32assert2(5)",
+    ]
+    .join("\n");
+    assert!(disasm.contains(&expected_span));
+
+    // call targets are resolved against the code block table and the kernel
+    assert!(disasm.contains(&format!("call.0x{callee_hex} # 0x")));
+    assert!(disasm.contains(&format!("\nproc.0x{callee_hex}\n    span # 0x{callee_hex}\n")));
+    assert!(disasm.contains(&format!("syscall.0x{kernel_proc_hex} # 0x")));
+    assert!(disasm.contains(", kernel procedure 0\n"));
+    assert!(disasm.contains("dyncall # 0x"));
+    assert!(disasm.contains(&format!("proxy.0x{callee_hex} # code not available\n")));
+}
+
+#[test]
+fn program_mast_stats() {
+    let program = build_test_program();
+    let stats = program.mast_stats();
+
+    // the body of the called procedure is included, and the proxy block has the same hash as it
+    assert_eq!(stats.num_blocks(), 13);
+    assert_eq!(stats.num_distinct_blocks(), 12);
+    assert_eq!(stats.num_span_blocks(), 2);
+    assert_eq!(stats.num_distinct_span_blocks(), 2);
+    assert_eq!(stats.num_operations(), 4);
+    assert_eq!(stats.num_procedures(), 1);
+
+    // repeated blocks are counted towards the number of distinct blocks only once
+    let span = CodeBlock::new_span(vec![Operation::Add]);
+    let program = Program::new(CodeBlock::new_join([span.clone(), CodeBlock::new_loop(span)]));
+    let stats = program.mast_stats();
+    assert_eq!(stats.num_blocks(), 4);
+    assert_eq!(stats.num_distinct_blocks(), 3);
+    assert_eq!(stats.num_span_blocks(), 2);
+    assert_eq!(stats.num_distinct_span_blocks(), 1);
+    assert_eq!(stats.num_operations(), 2);
+    assert_eq!(stats.num_procedures(), 0);
+}
+
 proptest! {
     #[test]
     fn arbitrary_program_info_serialization_works(
@@ -35,6 +181,54 @@ proptest! {
 // HELPER FUNCTIONS
 // --------------------------------------------------------------------------------------------
 
+/// Builds a program which contains every kind of code block.
+fn build_test_program() -> Program {
+    let decorators = vec![
+        (
+            0,
+            Decorator::AsmOp(
+                AssemblyOp::new("#main".to_string(), 2, "push.1".to_string(), false)
+                    .with_location(SourceLocation::new(3, 5)),
+            ),
+        ),
+        (1, Decorator::Debug(DebugOptions::StackTop(4))),
+        (2, Decorator::Event(17)),
+    ];
+    let span = CodeBlock::new_span_with_decorators(
+        vec![
+            Operation::Push(Felt::new(1)),
+            Operation::Assert(3),
+            Operation::U32assert2(Felt::new(5)),
+        ],
+        decorators,
+    );
+
+    let callee = CodeBlock::new_span_with_decorators(
+        vec![Operation::Mul],
+        vec![(
+            0,
+            Decorator::AsmOp(
+                AssemblyOp::new("mul".to_string(), 1, "mul".to_string(), false)
+                    .with_module_path("std::math::ext2".to_string()),
+            ),
+        )],
+    );
+    let kernel_proc = CodeBlock::new_span(vec![Operation::Add]);
+    let mut cb_table = CodeBlockTable::default();
+    cb_table.insert(callee.clone());
+
+    let body = CodeBlock::new_join([
+        CodeBlock::new_split(CodeBlock::new_call(callee.hash()), CodeBlock::new_dyncall()),
+        CodeBlock::new_join([
+            CodeBlock::new_loop(CodeBlock::new_syscall(kernel_proc.hash())),
+            CodeBlock::new_join([CodeBlock::new_dyn(), CodeBlock::new_proxy(callee.hash())]),
+        ]),
+    ]);
+
+    let kernel = Kernel::new(&[kernel_proc.hash()]).unwrap();
+    Program::with_kernel(CodeBlock::new_join([span, body]), kernel, cb_table)
+}
+
 fn digest_from_seed(seed: [u8; 32]) -> Digest {
     let mut digest = Word::default();
     digest.iter_mut().enumerate().for_each(|(i, d)| {
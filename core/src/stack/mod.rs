@@ -10,6 +10,9 @@ pub use inputs::StackInputs;
 mod outputs;
 pub use outputs::StackOutputs;
 
+mod output_region;
+pub use output_region::OutputRegion;
+
 // CONSTANTS
 // ================================================================================================
 
@@ -0,0 +1,168 @@
+use crate::{
+    chiplets::hasher::{hash_elements, Digest},
+    utils::{ByteReader, Deserializable, DeserializationError},
+};
+use alloc::{string::ToString, vec::Vec};
+use miden_crypto::Word;
+
+use super::{ByteWriter, Felt, OutputError, Serializable, StackOutputs, ToElements};
+
+// OUTPUT REGION
+// ================================================================================================
+
+/// A range of memory whose contents are a part of the public outputs of a program.
+///
+/// A program designates an output region by invoking `std::sys::commit_output_region` at the end
+/// of its execution. This procedure hashes the words stored in memory at addresses
+/// `[start_addr, start_addr + num_words)` of the root context, and leaves the stack in the
+/// following state:
+///
+/// [COM, start_addr, num_words, ...]
+///
+/// where `COM` is the commitment to the region computed as described in [OutputRegion::commitment].
+/// Since the commitment is a part of the stack outputs, it is enforced by the VM constraints, and
+/// thus the contents of the region can be provided to the verifier directly instead of being
+/// placed on the stack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputRegion {
+    start_addr: u32,
+    words: Vec<Word>,
+}
+
+impl OutputRegion {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    pub const MAX_LEN: usize = u16::MAX as usize;
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [OutputRegion] consisting of the specified words stored in memory starting at
+    /// `start_addr`.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The region is empty or contains more than [OutputRegion::MAX_LEN] words.
+    /// - The region extends beyond the end of the memory address space.
+    pub fn new(start_addr: u32, words: Vec<Word>) -> Result<Self, OutputError> {
+        if words.is_empty() || words.len() > Self::MAX_LEN {
+            return Err(OutputError::InvalidOutputRegionLength(words.len(), Self::MAX_LEN));
+        }
+        if start_addr.checked_add(words.len() as u32).is_none() {
+            return Err(OutputError::OutputRegionOutOfBounds(start_addr, words.len()));
+        }
+
+        Ok(Self { start_addr, words })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the memory address of the first word of this region.
+    pub fn start_addr(&self) -> u32 {
+        self.start_addr
+    }
+
+    /// Returns the number of words in this region.
+    pub fn num_words(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Returns the words of this region, starting with the word at the lowest address.
+    pub fn words(&self) -> &[Word] {
+        &self.words
+    }
+
+    /// Returns the commitment to the contents of this region.
+    ///
+    /// The commitment is computed as a sequential RPO hash of the elements of all words in the
+    /// region (i.e., the same way as `std::crypto::hashes::native::hash_memory` computes it).
+    pub fn commitment(&self) -> Digest {
+        let elements = self.words.iter().flatten().copied().collect::<Vec<_>>();
+        hash_elements(&elements)
+    }
+
+    /// Returns true if the specified stack outputs start with the commitment to this region,
+    /// followed by the start address and the length of this region.
+    pub fn is_committed_to(&self, stack_outputs: &StackOutputs) -> bool {
+        let commitment: Word = self.commitment().into();
+        stack_outputs.get_stack_word(0) == Some(commitment)
+            && stack_outputs.get_stack_item(4) == Some(Felt::from(self.start_addr))
+            && stack_outputs.get_stack_item(5) == Some(Felt::from(self.words.len() as u32))
+    }
+}
+
+impl ToElements<Felt> for OutputRegion {
+    fn to_elements(&self) -> Vec<Felt> {
+        let mut result = Vec::with_capacity(2 + self.words.len() * 4);
+        result.push(Felt::from(self.start_addr));
+        result.push(Felt::from(self.words.len() as u32));
+        result.extend(self.words.iter().flatten());
+        result
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for OutputRegion {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        debug_assert!(self.words.len() <= Self::MAX_LEN);
+        target.write_u32(self.start_addr);
+        target.write_usize(self.words.len());
+        target.write_many(&self.words);
+    }
+}
+
+impl Deserializable for OutputRegion {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let start_addr = source.read_u32()?;
+        let num_words = source.read_usize()?;
+        if num_words > Self::MAX_LEN {
+            return Err(DeserializationError::InvalidValue(format!(
+                "Number of words in an output region can not be more than {}, but {} was found",
+                Self::MAX_LEN,
+                num_words
+            )));
+        }
+        let words = source.read_many::<Word>(num_words)?;
+
+        Self::new(start_addr, words)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Deserializable, OutputRegion, Serializable, StackOutputs};
+    use crate::{Felt, ONE, ZERO};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn output_region_commitment() {
+        let words = vec![[ONE, ZERO, ONE, ZERO], [Felt::new(5), ZERO, ZERO, Felt::new(7)]];
+        let region = OutputRegion::new(100, words).unwrap();
+
+        let commitment: [Felt; 4] = region.commitment().into();
+        let mut stack = commitment.iter().rev().copied().collect::<Vec<_>>();
+        stack.extend([Felt::new(100), Felt::new(2)]);
+        assert!(region.is_committed_to(&StackOutputs::new(stack.clone(), vec![]).unwrap()));
+
+        stack[4] = Felt::new(101);
+        assert!(!region.is_committed_to(&StackOutputs::new(stack, vec![]).unwrap()));
+
+        let bytes = region.to_bytes();
+        assert_eq!(region, OutputRegion::read_from_bytes(&bytes).unwrap());
+    }
+
+    #[test]
+    fn output_region_bounds() {
+        assert!(OutputRegion::new(0, vec![]).is_err());
+        assert!(OutputRegion::new(u32::MAX, vec![[ZERO; 4]]).is_err());
+        assert!(OutputRegion::new(u32::MAX - 1, vec![[ZERO; 4]]).is_ok());
+    }
+}
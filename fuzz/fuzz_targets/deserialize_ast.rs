@@ -0,0 +1,9 @@
+#![no_main]
+
+use assembly::ast::{ModuleAst, ProgramAst};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|bytes: &[u8]| {
+    let _ = ProgramAst::from_bytes(bytes);
+    let _ = ModuleAst::from_bytes(bytes);
+});
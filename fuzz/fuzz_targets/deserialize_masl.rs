@@ -0,0 +1,8 @@
+#![no_main]
+
+use assembly::{utils::Deserializable, MaslLibrary};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|bytes: &[u8]| {
+    let _ = MaslLibrary::read_from_bytes(bytes);
+});
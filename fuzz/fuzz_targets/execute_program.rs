@@ -0,0 +1,12 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use miden_fuzz::{check_execution, check_proof, RandomProgram, MAX_PROVE_CYCLES};
+
+fuzz_target!(|program: RandomProgram| {
+    if let Some(num_cycles) = check_execution(&program) {
+        if num_cycles <= MAX_PROVE_CYCLES {
+            check_proof(&program);
+        }
+    }
+});
@@ -0,0 +1,453 @@
+//! Helpers shared by the fuzz targets of the Miden assembler and processor.
+//!
+//! Besides round-trip checks for the AST serialization, this crate contains a generator of random
+//! Miden assembly programs. The generated programs are well-typed by construction: all operands
+//! of u32 operations are valid u32 values, all conditions are binary, all loops terminate, and
+//! all procedures return with a stack of depth 16. Thus, a generated program is expected to
+//! execute successfully unless it exceeds the cycle limit.
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+use assembly::{
+    ast::{AstSerdeOptions, ModuleAst, ProgramAst},
+    utils::SliceReader,
+};
+use core::fmt::Write;
+use miden_vm::{
+    execute, execute_fast, math::Felt, prove, verify, Assembler, DefaultHost, ProgramInfo,
+    ProvingOptions, StackInputs,
+};
+use processor::{ExecutionOptions, Program};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Maximum number of cycles a generated program is allowed to run for.
+pub const MAX_CYCLES: u32 = 1 << 16;
+
+/// Programs executing in at most this many cycles are also proven and verified.
+pub const MAX_PROVE_CYCLES: u32 = 1 << 10;
+
+/// Maximum nesting depth of control flow blocks.
+const MAX_NESTING_DEPTH: usize = 3;
+
+/// Maximum number of statements in a single block.
+const MAX_BLOCK_LEN: usize = 24;
+
+/// Maximum number of local procedures and kernel procedures.
+const MAX_PROCS: usize = 4;
+
+/// Maximum number of iterations of `repeat` and `while` loops.
+const MAX_ITERATIONS: u32 = 4;
+
+/// Memory addresses accessed by the generated memory instructions are below this value.
+const NUM_MEM_ADDRS: u32 = 64;
+
+/// Loop counters are kept in memory starting at this address, so that they cannot be overwritten
+/// by the generated memory instructions.
+const LOOP_COUNTER_ADDR: u32 = 1 << 16;
+
+/// Instructions which can be applied to any stack.
+const FIELD_OPS: &[&str] = &[
+    "add", "sub", "mul", "neg", "eq", "neq", "lt", "lte", "gt", "gte", "drop", "dropw", "padw",
+    "swapw", "swapdw", "hperm", "hmerge", "ext2add", "ext2mul", "ext2neg", "sdepth", "clk",
+];
+
+/// u32 instructions which take a single operand.
+const U32_UNARY_OPS: &[&str] = &["u32assert", "u32not", "u32popcnt", "u32clz"];
+
+/// u32 instructions which take two operands; the second operand of the last three instructions
+/// must not be zero.
+const U32_BINARY_OPS: &[&str] = &[
+    "u32wrapping_add",
+    "u32overflowing_add",
+    "u32wrapping_sub",
+    "u32overflowing_sub",
+    "u32wrapping_mul",
+    "u32overflowing_mul",
+    "u32and",
+    "u32or",
+    "u32xor",
+    "u32lt",
+    "u32gt",
+    "u32min",
+    "u32max",
+    "u32div",
+    "u32mod",
+    "u32divmod",
+];
+
+/// u32 instructions which take a shift (or rotation) amount as an immediate value.
+const U32_SHIFT_OPS: &[&str] = &["u32shl", "u32shr", "u32rotl", "u32rotr"];
+
+/// Drops items from the stack until its depth is 16; this is appended to every procedure so that
+/// procedures can be invoked via `call`, `dyncall` and `syscall`.
+const TRUNCATE_STACK: &str = "sdepth neq.16 while.true drop sdepth neq.16 end";
+
+// RANDOM PROGRAM
+// ================================================================================================
+
+/// A random Miden assembly program together with its kernel and stack inputs.
+#[derive(Debug, Clone)]
+pub struct RandomProgram {
+    kernel: Option<String>,
+    source: String,
+    stack_inputs: Vec<u64>,
+}
+
+impl RandomProgram {
+    /// Returns the source code of the program.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the source code of the kernel, if the program has one.
+    pub fn kernel(&self) -> Option<&str> {
+        self.kernel.as_deref()
+    }
+
+    /// Returns the stack inputs of the program.
+    pub fn stack_inputs(&self) -> StackInputs {
+        StackInputs::try_from_ints(self.stack_inputs.iter().copied())
+            .expect("stack inputs are valid field elements")
+    }
+
+    /// Compiles this program.
+    ///
+    /// # Panics
+    /// Panics if the program fails to compile; every generated program is expected to compile.
+    pub fn compile(&self) -> Program {
+        let assembler = match &self.kernel {
+            Some(kernel) => Assembler::default()
+                .with_kernel(kernel)
+                .unwrap_or_else(|err| panic!("failed to compile kernel:\n{kernel}\n{err}")),
+            None => Assembler::default(),
+        };
+        assembler
+            .compile(&self.source)
+            .unwrap_or_else(|err| panic!("failed to compile program:\n{}\n{err}", self.source))
+    }
+}
+
+impl<'a> Arbitrary<'a> for RandomProgram {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut generator = ProgramGenerator {
+            u,
+            num_loops: 0,
+            num_procs: 0,
+        };
+
+        // kernel procedures do not invoke any other procedures
+        let num_kernel_procs = generator.u.int_in_range(0..=MAX_PROCS)?;
+        let mut kernel = String::new();
+        let mut syscalls = Vec::new();
+        for i in 0..num_kernel_procs {
+            let name = format!("kernel_proc_{i}");
+            generator.procedure(&mut kernel, "export", &name, &[], &[])?;
+            syscalls.push(name);
+        }
+
+        // each local procedure can invoke the procedures defined before it
+        let num_procs = generator.u.int_in_range(0..=MAX_PROCS)?;
+        let mut source = String::new();
+        let mut procs = Vec::new();
+        for i in 0..num_procs {
+            let name = format!("proc_{i}");
+            generator.procedure(&mut source, "proc", &name, &procs, &syscalls)?;
+            procs.push(name);
+        }
+
+        source.push_str("begin\n");
+        generator.block(&mut source, &procs, &syscalls, 0, 0)?;
+        source.push_str("end\n");
+
+        let num_inputs = generator.u.int_in_range(0..=20)?;
+        let stack_inputs = (0..num_inputs)
+            .map(|_| Ok(Felt::new(generator.u.arbitrary()?).as_int()))
+            .collect::<Result<Vec<_>>>()?;
+
+        let kernel = if num_kernel_procs > 0 { Some(kernel) } else { None };
+        Ok(Self {
+            kernel,
+            source,
+            stack_inputs,
+        })
+    }
+}
+
+// PROGRAM GENERATOR
+// ================================================================================================
+
+/// Writes random, well-typed Miden assembly code using the bytes of the [Unstructured] input.
+struct ProgramGenerator<'a, 'b> {
+    u: &'b mut Unstructured<'a>,
+    num_loops: u32,
+    num_procs: u32,
+}
+
+impl ProgramGenerator<'_, '_> {
+    /// Writes a procedure which truncates the stack to 16 elements before returning.
+    fn procedure(
+        &mut self,
+        out: &mut String,
+        keyword: &str,
+        name: &str,
+        procs: &[String],
+        syscalls: &[String],
+    ) -> Result<()> {
+        let num_locals = self.u.int_in_range(0..=4)?;
+        writeln!(out, "{keyword}.{name}.{num_locals}").unwrap();
+
+        // the tag makes the MAST roots of procedures distinct, as kernels cannot contain
+        // duplicated procedures
+        let tag = self.num_procs;
+        self.num_procs += 1;
+        writeln!(out, "push.{tag} drop").unwrap();
+
+        self.block(out, procs, syscalls, num_locals, 1)?;
+        writeln!(out, "{TRUNCATE_STACK}\nend\n").unwrap();
+        Ok(())
+    }
+
+    /// Writes a non-empty sequence of statements.
+    fn block(
+        &mut self,
+        out: &mut String,
+        procs: &[String],
+        syscalls: &[String],
+        num_locals: u16,
+        depth: usize,
+    ) -> Result<()> {
+        let len = self.u.int_in_range(1..=MAX_BLOCK_LEN)?;
+        for i in 0..len {
+            // always write at least one statement, as empty blocks are not allowed
+            if i > 0 && self.u.is_empty() {
+                break;
+            }
+            self.statement(out, procs, syscalls, num_locals, depth)?;
+            out.push('\n');
+        }
+        Ok(())
+    }
+
+    /// Writes a single statement; a statement consists of one or more instructions, where the
+    /// leading instructions prepare valid operands for the last one.
+    fn statement(
+        &mut self,
+        out: &mut String,
+        procs: &[String],
+        syscalls: &[String],
+        num_locals: u16,
+        depth: usize,
+    ) -> Result<()> {
+        match self.u.int_in_range(0..=17)? {
+            0 => write!(out, "{}", self.u.choose(FIELD_OPS)?),
+            1 => write!(out, "push.{}", self.felt()?),
+            2 => write!(out, "dup.{}", self.u.int_in_range(0..=15)?),
+            3 => write!(out, "swap.{}", self.u.int_in_range(1..=15)?),
+            4 => write!(out, "movup.{}", self.u.int_in_range(2..=15)?),
+            5 => write!(out, "movdn.{}", self.u.int_in_range(2..=15)?),
+            6 => write!(out, "dupw.{}", self.u.int_in_range(0..=3)?),
+            7 => write!(out, "push.{} inv", self.felt()?.max(1)),
+            8 => write!(out, "push.{} pow2", self.u.int_in_range(0..=63)?),
+            9 => write!(out, "push.{} push.{} exp", self.felt()?, self.felt()?),
+            10 => write!(out, "push.{} u32split", self.felt()?),
+            11 => write!(out, "push.{} {}", self.u32()?, self.u.choose(U32_UNARY_OPS)?),
+            12 => {
+                let op = self.u.choose(U32_BINARY_OPS)?;
+                let a = self.u32()?;
+                let b = if op.ends_with("div") || op.ends_with("mod") {
+                    self.u32()?.max(1)
+                } else {
+                    self.u32()?
+                };
+                write!(out, "push.{a} push.{b} {op}")
+            }
+            13 => {
+                let op = self.u.choose(U32_SHIFT_OPS)?;
+                write!(out, "push.{} {op}.{}", self.u32()?, self.u.int_in_range(0..=31)?)
+            }
+            14 => return self.memory_access(out, num_locals),
+            15 if depth < MAX_NESTING_DEPTH => {
+                return self.control_block(out, procs, syscalls, num_locals, depth);
+            }
+            16 if !procs.is_empty() => {
+                let target = self.u.choose(procs)?;
+                match self.u.int_in_range(0..=3)? {
+                    0 => write!(out, "exec.{target}"),
+                    1 => write!(out, "call.{target}"),
+                    2 => write!(out, "procref.{target} dynexec"),
+                    _ => write!(out, "procref.{target} dyncall"),
+                }
+            }
+            17 if !syscalls.is_empty() => write!(out, "syscall.{}", self.u.choose(syscalls)?),
+            _ => write!(out, "push.{}", self.u32()?),
+        }
+        .unwrap();
+        Ok(())
+    }
+
+    /// Writes an instruction accessing global memory or procedure locals.
+    fn memory_access(&mut self, out: &mut String, num_locals: u16) -> Result<()> {
+        let op = *self.u.choose(&["store", "load", "storew", "loadw"])?;
+        if num_locals > 0 && self.u.arbitrary()? {
+            let index = self.u.int_in_range(0..=num_locals - 1)?;
+            write!(out, "loc_{op}.{index}").unwrap();
+        } else {
+            let addr = self.u.int_in_range(0..=NUM_MEM_ADDRS - 1)?;
+            write!(out, "mem_{op}.{addr}").unwrap();
+        }
+        Ok(())
+    }
+
+    /// Writes an `if`, `repeat` or `while` block; `while` loops keep their counter in memory so
+    /// that the loop body can modify the stack arbitrarily.
+    fn control_block(
+        &mut self,
+        out: &mut String,
+        procs: &[String],
+        syscalls: &[String],
+        num_locals: u16,
+        depth: usize,
+    ) -> Result<()> {
+        match self.u.int_in_range(0..=2)? {
+            0 => {
+                writeln!(out, "push.{} if.true", u8::from(self.u.arbitrary::<bool>()?)).unwrap();
+                self.block(out, procs, syscalls, num_locals, depth + 1)?;
+                out.push_str("else\n");
+                self.block(out, procs, syscalls, num_locals, depth + 1)?;
+            }
+            1 => {
+                writeln!(out, "repeat.{}", self.u.int_in_range(1..=MAX_ITERATIONS)?).unwrap();
+                self.block(out, procs, syscalls, num_locals, depth + 1)?;
+            }
+            _ => {
+                let addr = LOOP_COUNTER_ADDR + self.num_loops;
+                self.num_loops += 1;
+                let num_iterations = self.u.int_in_range(1..=MAX_ITERATIONS)?;
+                writeln!(out, "push.{num_iterations} mem_store.{addr} push.1 while.true").unwrap();
+                self.block(out, procs, syscalls, num_locals, depth + 1)?;
+                writeln!(out, "mem_load.{addr} sub.1 dup mem_store.{addr} neq.0").unwrap();
+            }
+        }
+        out.push_str("end");
+        Ok(())
+    }
+
+    fn felt(&mut self) -> Result<u64> {
+        Ok(Felt::new(self.u.arbitrary()?).as_int())
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        self.u.arbitrary()
+    }
+}
+
+// CHECKS
+// ================================================================================================
+
+/// Parses the source as a program and makes sure the parsed AST survives a serialization round
+/// trip.
+pub fn check_program_roundtrip(source: &str) {
+    let Ok(program) = ProgramAst::parse(source) else {
+        return;
+    };
+
+    let bytes = program.to_bytes(AstSerdeOptions::new(true));
+    let mut deserialized = ProgramAst::from_bytes(&bytes).expect("failed to deserialize program");
+
+    let mut locations = Vec::new();
+    program.write_source_locations(&mut locations);
+    deserialized
+        .load_source_locations(&mut SliceReader::new(&locations))
+        .expect("failed to load source locations");
+    assert_eq!(program, deserialized);
+}
+
+/// Parses the source as a module and makes sure the parsed AST survives a serialization round
+/// trip.
+pub fn check_module_roundtrip(source: &str) {
+    let Ok(module) = ModuleAst::parse(source) else {
+        return;
+    };
+
+    let bytes = module.to_bytes(AstSerdeOptions::new(true));
+    let mut deserialized = ModuleAst::from_bytes(&bytes).expect("failed to deserialize module");
+
+    let mut locations = Vec::new();
+    module.write_source_locations(&mut locations);
+    deserialized
+        .load_source_locations(&mut SliceReader::new(&locations))
+        .expect("failed to load source locations");
+    assert_eq!(module, deserialized);
+}
+
+/// Executes the program using both the regular and the fast processor, and makes sure that both
+/// produce the same stack outputs and cycle counts, or fail with the same error.
+///
+/// Returns the number of cycles executed by the program, if the execution succeeded.
+pub fn check_execution(program: &RandomProgram) -> Option<u32> {
+    let compiled = program.compile();
+    let options =
+        ExecutionOptions::new(Some(MAX_CYCLES), 64, false).expect("execution options are valid");
+
+    let traced = execute(&compiled, program.stack_inputs(), DefaultHost::default(), options);
+    let fast = execute_fast(&compiled, program.stack_inputs(), DefaultHost::default(), options);
+    match (traced, fast) {
+        (Ok(trace), Ok(outputs)) => {
+            assert_eq!(trace.stack_outputs(), outputs.stack_outputs(), "{}", program.source);
+            let num_cycles = trace.trace_len_summary().main_trace_len() as u32;
+            assert_eq!(num_cycles, outputs.num_cycles(), "{}", program.source);
+            Some(num_cycles)
+        }
+        (Err(expected), Err(actual)) => {
+            assert_eq!(expected, actual, "{}", program.source);
+            None
+        }
+        (Ok(_), Err(err)) => panic!("fast execution failed with {err}:\n{}", program.source),
+        (Err(err), Ok(_)) => panic!("traced execution failed with {err}:\n{}", program.source),
+    }
+}
+
+/// Generates a proof of the program execution and verifies it.
+pub fn check_proof(program: &RandomProgram) {
+    let compiled = program.compile();
+    let (stack_outputs, proof) = prove(
+        &compiled,
+        program.stack_inputs(),
+        DefaultHost::default(),
+        ProvingOptions::default(),
+    )
+    .unwrap_or_else(|err| panic!("failed to prove program with {err}:\n{}", program.source));
+
+    let program_info = ProgramInfo::from(compiled);
+    verify(program_info, program.stack_inputs(), stack_outputs, proof)
+        .unwrap_or_else(|err| panic!("failed to verify program with {err}:\n{}", program.source));
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{check_execution, RandomProgram};
+    use arbitrary::{Arbitrary, Unstructured};
+
+    #[test]
+    fn random_programs_execute() {
+        // a fixed xorshift sequence keeps the generated programs the same across runs
+        let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+        for _ in 0..16 {
+            let bytes = (0..2048)
+                .map(|_| {
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+                    state as u8
+                })
+                .collect::<Vec<_>>();
+
+            let program = RandomProgram::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+            assert!(check_execution(&program).is_some(), "{}", program.source());
+        }
+    }
+}
@@ -0,0 +1,502 @@
+use super::{
+    instructions::{INSTRUCTIONS, KEYWORDS},
+    library::LibraryIndex,
+};
+use assembly::{
+    ast::{ModuleAst, ProcedureAst, ProgramAst, SourceLocation},
+    LibraryPath, ParsingError,
+};
+use lsp_types::{
+    CompletionItem, CompletionItemKind, Diagnostic, DiagnosticSeverity, Position, Range,
+};
+use std::{collections::BTreeMap, path::PathBuf};
+
+/// Instructions which invoke procedures or reference them by name.
+const INVOCATIONS: [&str; 4] = ["exec", "call", "syscall", "procref"];
+
+// DOCUMENT
+// ================================================================================================
+
+/// An analyzed Miden assembly source file opened in the editor.
+///
+/// Symbols (imports, procedures and constants) are collected from the tokens of the source, so
+/// that navigation and completion keep working while the source cannot be parsed. The source is
+/// also parsed into an AST, which provides parsing errors and procedure doc comments.
+pub struct Document {
+    /// Source of the document split into lines.
+    lines: Vec<String>,
+    /// Tokens of the source.
+    tokens: Vec<SourceToken>,
+    /// Paths of the imported modules keyed by their alias.
+    imports: BTreeMap<String, String>,
+    /// Locations of the definitions of procedures declared in this document.
+    procedures: BTreeMap<String, SourceLocation>,
+    /// Constants declared in this document.
+    constants: BTreeMap<String, Constant>,
+    /// Procedures of the parsed AST, or the error which occurred while parsing the source.
+    ast: Result<Vec<ProcedureAst>, ParsingError>,
+}
+
+impl Document {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns an analyzed document with the specified source.
+    pub fn new(source: &str) -> Self {
+        let tokens: Vec<_> = assembly::tokenize(source)
+            .map(|(text, location)| SourceToken {
+                text: text.to_string(),
+                location,
+            })
+            .collect();
+
+        let mut imports = BTreeMap::new();
+        let mut procedures = BTreeMap::new();
+        let mut constants = BTreeMap::new();
+        for token in tokens.iter() {
+            if let Some(import) = token.text.strip_prefix("use.") {
+                let (path, alias) = match import.split_once("->") {
+                    Some((path, alias)) => (path, alias),
+                    None => (import, import.rsplit(LibraryPath::PATH_DELIM).next().unwrap_or("")),
+                };
+                imports.insert(alias.to_string(), path.to_string());
+            } else if let Some(name) = definition_name(&token.text) {
+                procedures.insert(name.to_string(), token.location);
+            } else if let Some((name, value)) =
+                token.text.strip_prefix("const.").and_then(|constant| constant.split_once('='))
+            {
+                let constant = Constant {
+                    value: value.to_string(),
+                    location: token.location,
+                };
+                constants.insert(name.to_string(), constant);
+            }
+        }
+
+        // a source containing a `begin` token is an executable program, and a library module
+        // otherwise
+        let ast = if tokens.iter().any(|token| token.text == "begin") {
+            ProgramAst::parse(source).map(|ast| ast.procedures().to_vec())
+        } else {
+            ModuleAst::parse(source).map(|ast| ast.procs().to_vec())
+        };
+
+        Self {
+            lines: source.lines().map(|line| line.to_string()).collect(),
+            tokens,
+            imports,
+            procedures,
+            constants,
+            ast,
+        }
+    }
+
+    // DIAGNOSTICS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the errors and warnings of this document.
+    ///
+    /// If the document cannot be parsed, the parsing error is returned. Otherwise, the imported
+    /// modules and procedures are checked against the specified libraries.
+    pub fn diagnostics(&self, libraries: &LibraryIndex) -> Vec<Diagnostic> {
+        if let Err(err) = &self.ast {
+            let range = match self.token_at_location(err.location()) {
+                Some(token) => token.range(),
+                None => location_range(err.location(), 0),
+            };
+            return vec![diagnostic(range, DiagnosticSeverity::ERROR, err.message().clone())];
+        }
+
+        let mut diagnostics = Vec::new();
+        for token in self.tokens.iter() {
+            if let Some(import) = token.text.strip_prefix("use.") {
+                let path = import.split_once("->").map_or(import, |(path, _)| path);
+                if libraries.get(path).is_none() {
+                    let message = format!("module `{path}` was not found in the libraries");
+                    diagnostics.push(diagnostic(
+                        token.range(),
+                        DiagnosticSeverity::WARNING,
+                        message,
+                    ));
+                }
+            } else if let Some(Target::Imported { module_path, name }) = self.invocation(token) {
+                let is_exported = libraries
+                    .get(module_path)
+                    .map(|module| module.export(name).is_some())
+                    .unwrap_or(true);
+                if !is_exported {
+                    let message = format!("procedure `{name}` is not exported by `{module_path}`");
+                    diagnostics.push(diagnostic(token.range(), DiagnosticSeverity::ERROR, message));
+                }
+            }
+        }
+        diagnostics
+    }
+
+    // NAVIGATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the location of the definition of the symbol at the specified position.
+    ///
+    /// The returned path is None if the symbol is defined in this document.
+    pub fn definition(
+        &self,
+        position: Position,
+        libraries: &LibraryIndex,
+    ) -> Option<(Option<PathBuf>, SourceLocation)> {
+        let token = self.token_at(position)?;
+        if let Some(target) = self.invocation(token) {
+            return match target {
+                Target::Local(name) => Some((None, *self.procedures.get(name)?)),
+                Target::Imported { module_path, name } => {
+                    let (path, location) = libraries.definition(module_path, name)?;
+                    Some((Some(path), location))
+                }
+            };
+        }
+        if let Some(constant) = self.referenced_constant(token) {
+            return Some((None, self.constants[constant].location));
+        }
+        None
+    }
+
+    /// Returns the hover text for the symbol at the specified position in markdown format.
+    pub fn hover(&self, position: Position, libraries: &LibraryIndex) -> Option<String> {
+        let token = self.token_at(position)?;
+        if let Some(target) = self.invocation(token) {
+            let (signature, docs) = match target {
+                Target::Local(name) => {
+                    let proc = self
+                        .ast
+                        .as_ref()
+                        .ok()
+                        .and_then(|procs| procs.iter().find(|proc| proc.name.as_ref() == name));
+                    // the parser retains doc comments of exported procedures only
+                    let docs = match proc.and_then(|proc| proc.docs.clone()) {
+                        Some(docs) => Some(docs),
+                        None => self.doc_comment_before(self.procedures.get(name)?),
+                    };
+                    (name.to_string(), docs)
+                }
+                Target::Imported { module_path, name } => {
+                    let export = libraries.get(module_path)?.export(name)?;
+                    let docs = export.docs().map(|docs| docs.to_string());
+                    (format!("{module_path}{}{name}", LibraryPath::PATH_DELIM), docs)
+                }
+            };
+            let mut hover = format!("```masm\n{signature}\n```");
+            if let Some(docs) = docs {
+                hover.push_str("\n\n");
+                hover.push_str(&docs);
+            }
+            return Some(hover);
+        }
+        if let Some(name) = self.referenced_constant(token) {
+            return Some(format!("```masm\nconst.{name}={}\n```", self.constants[name].value));
+        }
+        if let Some(import) = token.text.strip_prefix("use.") {
+            let path = import.split_once("->").map_or(import, |(path, _)| path);
+            let docs = libraries.get(path)?.ast.docs()?;
+            return Some(format!("```masm\n{path}\n```\n\n{docs}"));
+        }
+        None
+    }
+
+    /// Returns completion candidates for the token ending at the specified position.
+    ///
+    /// Procedures are completed after invocation instructions (e.g., `exec.`), module paths after
+    /// `use.`, and constants within the immediate values of other instructions; otherwise,
+    /// instruction mnemonics and keywords are completed.
+    pub fn completions(&self, position: Position, libraries: &LibraryIndex) -> Vec<CompletionItem> {
+        let line = self.lines.get(position.line as usize).map(|line| line.as_str()).unwrap_or("");
+        let prefix = line.get(..position.character as usize).unwrap_or(line);
+        let prefix = prefix.rsplit(char::is_whitespace).next().unwrap_or("");
+
+        let (instruction, label) = match prefix.split_once('.') {
+            Some(parts) => parts,
+            None => {
+                let instructions = INSTRUCTIONS.iter().chain(KEYWORDS.iter());
+                return instructions
+                    .map(|name| completion(name, CompletionItemKind::KEYWORD, None))
+                    .collect();
+            }
+        };
+
+        if instruction == "use" {
+            return libraries
+                .module_paths()
+                .map(|path| completion(path, CompletionItemKind::MODULE, None))
+                .collect();
+        }
+
+        if INVOCATIONS.contains(&instruction) {
+            return match label.split_once(LibraryPath::PATH_DELIM) {
+                Some((alias, _)) => {
+                    let module = self.imports.get(alias).and_then(|path| libraries.get(path));
+                    let exports = module.into_iter().flat_map(|module| module.export_names());
+                    exports
+                        .map(|name| completion(name, CompletionItemKind::FUNCTION, None))
+                        .collect()
+                }
+                None => {
+                    let procedures = self
+                        .procedures
+                        .keys()
+                        .map(|name| completion(name, CompletionItemKind::FUNCTION, None));
+                    let modules = self.imports.iter().map(|(alias, path)| {
+                        completion(alias, CompletionItemKind::MODULE, Some(path.clone()))
+                    });
+                    procedures.chain(modules).collect()
+                }
+            };
+        }
+
+        self.constants
+            .iter()
+            .map(|(name, constant)| {
+                completion(name, CompletionItemKind::CONSTANT, Some(constant.value.clone()))
+            })
+            .collect()
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the token located at the specified position, if any.
+    fn token_at(&self, position: Position) -> Option<&SourceToken> {
+        self.tokens.iter().find(|token| {
+            let range = token.range();
+            range.start.line == position.line
+                && range.start.character <= position.character
+                && position.character <= range.end.character
+        })
+    }
+
+    /// Returns the token starting at the specified location, if any.
+    fn token_at_location(&self, location: &SourceLocation) -> Option<&SourceToken> {
+        self.tokens.iter().find(|token| token.location == *location)
+    }
+
+    /// Returns the procedure invoked by the specified token, if the token is an invocation
+    /// instruction with a procedure name.
+    fn invocation<'a>(&'a self, token: &'a SourceToken) -> Option<Target<'a>> {
+        let (instruction, label) = token.text.split_once('.')?;
+        if !INVOCATIONS.contains(&instruction) {
+            return None;
+        }
+        match label.split_once(LibraryPath::PATH_DELIM) {
+            Some((alias, name)) => Some(Target::Imported {
+                module_path: self.imports.get(alias)?,
+                name,
+            }),
+            None if self.procedures.contains_key(label) => Some(Target::Local(label)),
+            None => None,
+        }
+    }
+
+    /// Returns the name of the constant referenced by the immediate values of the specified token.
+    fn referenced_constant(&self, token: &SourceToken) -> Option<&str> {
+        if token.text.starts_with("const.") {
+            let (name, _) = token.text["const.".len()..].split_once('=')?;
+            return self.constants.get_key_value(name).map(|(name, _)| name.as_str());
+        }
+        token
+            .text
+            .split('.')
+            .skip(1)
+            .find_map(|part| self.constants.get_key_value(part).map(|(name, _)| name.as_str()))
+    }
+
+    /// Returns the doc comment on the lines immediately preceding the specified location, if any.
+    fn doc_comment_before(&self, location: &SourceLocation) -> Option<String> {
+        let preceding = self.lines.get(..location.line().saturating_sub(1) as usize)?;
+        let mut docs = preceding
+            .iter()
+            .rev()
+            .map_while(|line| line.trim().strip_prefix("#!"))
+            .map(|line| line.trim())
+            .collect::<Vec<_>>();
+        if docs.is_empty() {
+            return None;
+        }
+        docs.reverse();
+        Some(docs.join("\n"))
+    }
+}
+
+// SOURCE TOKEN
+// ================================================================================================
+
+/// A token of a Miden assembly source together with its location.
+struct SourceToken {
+    text: String,
+    location: SourceLocation,
+}
+
+impl SourceToken {
+    /// Returns the range of this token in the document.
+    fn range(&self) -> Range {
+        location_range(&self.location, self.text.len() as u32)
+    }
+}
+
+/// A constant declared in a document.
+struct Constant {
+    value: String,
+    location: SourceLocation,
+}
+
+/// A procedure invoked by an invocation instruction.
+enum Target<'a> {
+    /// A procedure declared in the document.
+    Local(&'a str),
+    /// A procedure exported by an imported module.
+    Imported { module_path: &'a str, name: &'a str },
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the location of the definition of the procedure with the specified name in the
+/// specified source.
+pub fn find_definition(source: &str, name: &str) -> Option<SourceLocation> {
+    assembly::tokenize(source)
+        .find(|(token, _)| definition_name(token) == Some(name))
+        .map(|(_, location)| location)
+}
+
+/// Returns the name of the procedure defined by the specified token, if the token starts a
+/// procedure definition or a re-export (e.g., `export.foo.2` or `export.u64::add->add64`).
+fn definition_name(token: &str) -> Option<&str> {
+    let definition = token.strip_prefix("proc.").or_else(|| token.strip_prefix("export."))?;
+    match definition.rsplit_once("->") {
+        Some((_, alias)) => Some(alias),
+        None if definition.contains(LibraryPath::PATH_DELIM) => {
+            definition.rsplit(LibraryPath::PATH_DELIM).next()
+        }
+        None => definition.split('.').next(),
+    }
+}
+
+/// Converts the specified source location into a range of the specified length.
+///
+/// Source locations are 1-based while positions in documents are 0-based.
+pub fn location_range(location: &SourceLocation, len: u32) -> Range {
+    let start =
+        Position::new(location.line().saturating_sub(1), location.column().saturating_sub(1));
+    let end = Position::new(start.line, start.character + len);
+    Range::new(start, end)
+}
+
+/// Returns a new diagnostic with the specified range, severity and message.
+fn diagnostic(range: Range, severity: DiagnosticSeverity, message: String) -> Diagnostic {
+    Diagnostic {
+        range,
+        severity: Some(severity),
+        source: Some("miden".to_string()),
+        message,
+        ..Default::default()
+    }
+}
+
+/// Returns a new completion item with the specified label, kind and details.
+fn completion(label: &str, kind: CompletionItemKind, detail: Option<String>) -> CompletionItem {
+    CompletionItem {
+        label: label.to_string(),
+        kind: Some(kind),
+        detail,
+        ..Default::default()
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Document, LibraryIndex};
+    use lsp_types::{DiagnosticSeverity, Position};
+    use std::env;
+
+    const SOURCE: &str = "\
+use.std::math::u64
+const.LIMIT=16
+
+#! Adds two u64 values.
+proc.add
+    exec.u64::wrapping_add
+end
+
+begin
+    push.LIMIT
+    exec.add
+    exec.u64::missing
+end";
+
+    fn libraries() -> LibraryIndex {
+        LibraryIndex::new(env::temp_dir().join("miden-lsp-tests"))
+    }
+
+    #[test]
+    fn diagnostics() {
+        let libraries = libraries();
+        let diagnostics = Document::new(SOURCE).diagnostics(&libraries);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].range.start, Position::new(11, 4));
+        assert_eq!(diagnostics[0].severity, Some(DiagnosticSeverity::ERROR));
+
+        let diagnostics = Document::new("begin push.1 addd end").diagnostics(&libraries);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].range.start, Position::new(0, 13));
+        assert_eq!(diagnostics[0].range.end, Position::new(0, 17));
+    }
+
+    #[test]
+    fn definitions() {
+        let libraries = libraries();
+        let document = Document::new(SOURCE);
+
+        let (path, location) = document.definition(Position::new(10, 10), &libraries).unwrap();
+        assert_eq!((path, location.line(), location.column()), (None, 5, 1));
+
+        let (path, location) = document.definition(Position::new(9, 8), &libraries).unwrap();
+        assert_eq!((path, location.line(), location.column()), (None, 2, 1));
+
+        let (path, location) = document.definition(Position::new(5, 15), &libraries).unwrap();
+        let path = path.unwrap();
+        assert!(path.ends_with("std/math/u64.masm"));
+        let source = std::fs::read_to_string(path).unwrap();
+        let line = source.lines().nth(location.line() as usize - 1).unwrap();
+        assert!(line.trim_start().starts_with("export.wrapping_add"));
+    }
+
+    #[test]
+    fn hovers() {
+        let libraries = libraries();
+        let document = Document::new(SOURCE);
+
+        let hover = document.hover(Position::new(10, 8), &libraries).unwrap();
+        assert_eq!(hover, "```masm\nadd\n```\n\nAdds two u64 values.");
+
+        let hover = document.hover(Position::new(5, 10), &libraries).unwrap();
+        assert!(hover.starts_with("```masm\nstd::math::u64::wrapping_add\n```\n\n"));
+
+        let hover = document.hover(Position::new(9, 10), &libraries).unwrap();
+        assert_eq!(hover, "```masm\nconst.LIMIT=16\n```");
+    }
+
+    #[test]
+    fn completions() {
+        let libraries = libraries();
+        let document = Document::new(SOURCE);
+        let labels = |line, character| {
+            let completions = document.completions(Position::new(line, character), &libraries);
+            completions.into_iter().map(|item| item.label).collect::<Vec<_>>()
+        };
+
+        assert!(labels(10, 4).contains(&"u32wrapping_add".to_string()));
+        assert_eq!(labels(9, 9), ["LIMIT"]);
+        assert_eq!(labels(10, 9), ["add", "u64"]);
+        assert!(labels(11, 14).contains(&"wrapping_add".to_string()));
+        assert!(labels(0, 4).contains(&"std::math::u64".to_string()));
+    }
+}
@@ -0,0 +1,160 @@
+/// Mnemonics of all Miden assembly instructions.
+pub const INSTRUCTIONS: &[&str] = &[
+    "assert",
+    "assertz",
+    "assert_eq",
+    "assert_eqw",
+    "add",
+    "sub",
+    "mul",
+    "div",
+    "neg",
+    "inv",
+    "pow2",
+    "exp",
+    "ilog2",
+    "not",
+    "and",
+    "or",
+    "xor",
+    "eq",
+    "neq",
+    "lt",
+    "lte",
+    "gt",
+    "gte",
+    "is_odd",
+    "eqw",
+    "ext2add",
+    "ext2sub",
+    "ext2mul",
+    "ext2div",
+    "ext2neg",
+    "ext2inv",
+    "u32test",
+    "u32testw",
+    "u32assert",
+    "u32assert2",
+    "u32assertw",
+    "u32cast",
+    "u32split",
+    "u32wrapping_add",
+    "u32overflowing_add",
+    "u32overflowing_add3",
+    "u32wrapping_add3",
+    "u32wrapping_sub",
+    "u32overflowing_sub",
+    "u32wrapping_mul",
+    "u32overflowing_mul",
+    "u32overflowing_madd",
+    "u32wrapping_madd",
+    "u32div",
+    "u32mod",
+    "u32divmod",
+    "u32and",
+    "u32or",
+    "u32xor",
+    "u32not",
+    "u32shr",
+    "u32shl",
+    "u32rotr",
+    "u32rotl",
+    "u32popcnt",
+    "u32clz",
+    "u32ctz",
+    "u32clo",
+    "u32cto",
+    "u32lt",
+    "u32lte",
+    "u32gt",
+    "u32gte",
+    "u32min",
+    "u32max",
+    "drop",
+    "dropw",
+    "padw",
+    "dup",
+    "dupw",
+    "swap",
+    "swapw",
+    "swapdw",
+    "movup",
+    "movupw",
+    "movdn",
+    "movdnw",
+    "cswap",
+    "cswapw",
+    "cdrop",
+    "cdropw",
+    "push",
+    "sdepth",
+    "locaddr",
+    "caller",
+    "clk",
+    "mem_load",
+    "loc_load",
+    "mem_loadw",
+    "loc_loadw",
+    "mem_store",
+    "loc_store",
+    "mem_storew",
+    "loc_storew",
+    "mem_stream",
+    "adv_pipe",
+    "adv_push",
+    "adv_loadw",
+    "adv",
+    "hash",
+    "hmerge",
+    "hperm",
+    "mtree_get",
+    "mtree_set",
+    "mtree_merge",
+    "mtree_verify",
+    "fri_ext2fold4",
+    "rcomb_base",
+    "exec",
+    "call",
+    "syscall",
+    "dynexec",
+    "dyncall",
+    "procref",
+    "breakpoint",
+    "debug",
+    "emit",
+    "trace",
+];
+
+/// Keywords which start or end definitions and control flow blocks.
+pub const KEYWORDS: &[&str] = &[
+    "begin",
+    "end",
+    "proc",
+    "export",
+    "use",
+    "const",
+    "if.true",
+    "else",
+    "while.true",
+    "repeat",
+];
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::INSTRUCTIONS;
+    use assembly::ast::ProgramAst;
+
+    #[test]
+    fn instructions_are_valid() {
+        for instruction in INSTRUCTIONS {
+            // instructions with missing or invalid parameters fail with a different error
+            let source = format!("begin {instruction} end");
+            if let Err(err) = ProgramAst::parse(&source) {
+                assert!(!err.message().ends_with("is invalid"), "{instruction}: {err}");
+            }
+        }
+    }
+}
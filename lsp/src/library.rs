@@ -0,0 +1,202 @@
+use super::document::find_definition;
+use assembly::{
+    ast::{ModuleAst, ProcReExport, ProcedureAst, SourceLocation},
+    Library, LibraryNamespace, LibraryPath, MaslLibrary, Version,
+};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+// LIBRARY INDEX
+// ================================================================================================
+
+/// Contains the modules of all libraries which can be imported by the documents of the server.
+pub struct LibraryIndex {
+    /// Modules of the libraries keyed by their fully qualified path.
+    modules: BTreeMap<String, LibraryModule>,
+    /// Directory into which the sources of modules loaded from `.masl` files are rendered, so
+    /// that they can be navigated to.
+    render_dir: PathBuf,
+}
+
+impl LibraryIndex {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new index which contains only the modules of the standard library.
+    ///
+    /// The sources of modules loaded from `.masl` files are rendered into `render_dir`.
+    pub fn new(render_dir: PathBuf) -> Self {
+        let mut index = Self {
+            modules: BTreeMap::new(),
+            render_dir,
+        };
+        index.add_library(&stdlib::StdLibrary::default(), None);
+        index
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds the library located at the specified path to this index.
+    ///
+    /// The path must point either to a `.masl` file, or to a directory of `.masm` source files, in
+    /// which case the name of the directory is used as the namespace of the library.
+    ///
+    /// # Errors
+    /// Returns an error if the library cannot be loaded.
+    pub fn load(&mut self, path: &Path) -> Result<(), String> {
+        if path.is_dir() {
+            let namespace = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .ok_or(format!("invalid library directory {}", path.display()))?;
+            let namespace = LibraryNamespace::try_from(namespace.to_string())
+                .map_err(|err| format!("invalid library namespace `{namespace}`: {err}"))?;
+            let library = MaslLibrary::read_from_dir(path, namespace, true, Version::MIN)
+                .map_err(|err| format!("failed to load library {}: {err}", path.display()))?;
+            self.add_library(&library, Some(path));
+        } else {
+            let library = MaslLibrary::read_from_file(path)
+                .map_err(|err| format!("failed to load library {}: {err}", path.display()))?;
+            self.add_library(&library, None);
+        }
+        Ok(())
+    }
+
+    /// Adds the modules of the specified library to this index; `source_dir` is the directory
+    /// containing the source files of the library, if any.
+    fn add_library(&mut self, library: &impl Library, source_dir: Option<&Path>) {
+        for module in library.modules() {
+            let source_path = source_dir.map(|dir| {
+                // the first component of a module path is the namespace of the library
+                let mut path = dir.to_path_buf();
+                path.extend(module.path.as_ref().split(LibraryPath::PATH_DELIM).skip(1));
+                path.set_extension("masm");
+                path
+            });
+            self.modules.insert(
+                module.path.to_string(),
+                LibraryModule {
+                    ast: module.ast.clone(),
+                    source_path,
+                },
+            );
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the module with the specified fully qualified path.
+    pub fn get(&self, module_path: &str) -> Option<&LibraryModule> {
+        self.modules.get(module_path)
+    }
+
+    /// Returns the fully qualified paths of all modules in this index.
+    pub fn module_paths(&self) -> impl Iterator<Item = &str> {
+        self.modules.keys().map(|path| path.as_str())
+    }
+
+    /// Returns the source file and the location of the definition of the specified procedure.
+    ///
+    /// Sources of modules which were not loaded from source files are rendered from their ASTs.
+    pub fn definition(&self, module_path: &str, name: &str) -> Option<(PathBuf, SourceLocation)> {
+        let module = self.get(module_path)?;
+        let (path, source) = match &module.source_path {
+            Some(path) => (path.clone(), fs::read_to_string(path).ok()?),
+            None => {
+                let mut path = self.render_dir.clone();
+                path.extend(module_path.split(LibraryPath::PATH_DELIM));
+                path.set_extension("masm");
+                let source = module.render();
+                fs::create_dir_all(path.parent()?).ok()?;
+                fs::write(&path, &source).ok()?;
+                (path, source)
+            }
+        };
+
+        // default to the start of the module if the definition cannot be located
+        let location = find_definition(&source, name).unwrap_or(SourceLocation::new(1, 1));
+        Some((path, location))
+    }
+}
+
+// LIBRARY MODULE
+// ================================================================================================
+
+/// A module of a library.
+pub struct LibraryModule {
+    /// AST of the module.
+    pub ast: ModuleAst,
+    /// Path to the source file of the module, if the library was loaded from source files.
+    pub source_path: Option<PathBuf>,
+}
+
+impl LibraryModule {
+    /// Returns the procedure exported by this module under the specified name.
+    pub fn export(&self, name: &str) -> Option<Export<'_>> {
+        if let Some(proc) = self
+            .ast
+            .procs()
+            .iter()
+            .find(|proc| proc.is_export && proc.name.as_ref() == name)
+        {
+            return Some(Export::Procedure(proc));
+        }
+        self.ast
+            .reexported_procs()
+            .iter()
+            .find(|proc| proc.name().as_ref() == name)
+            .map(Export::ReExport)
+    }
+
+    /// Returns the names of all procedures exported by this module.
+    pub fn export_names(&self) -> impl Iterator<Item = &str> {
+        let procs = self.ast.procs().iter().filter(|proc| proc.is_export);
+        procs
+            .map(|proc| proc.name.as_ref())
+            .chain(self.ast.reexported_procs().iter().map(|proc| proc.name().as_ref()))
+    }
+
+    /// Renders the source of this module from its AST.
+    ///
+    /// Doc comments are omitted as they may span multiple lines, which cannot be rendered as valid
+    /// source by the AST formatter.
+    fn render(&self) -> String {
+        let procs = self
+            .ast
+            .procs()
+            .iter()
+            .cloned()
+            .map(|proc| ProcedureAst { docs: None, ..proc })
+            .collect();
+        let reexports = self
+            .ast
+            .reexported_procs()
+            .iter()
+            .map(|proc| ProcReExport::new(proc.proc_id(), proc.name().clone(), None))
+            .collect();
+        match ModuleAst::new(procs, reexports, None) {
+            Ok(ast) => ast.with_import_info(self.ast.import_info().clone()).to_string(),
+            Err(_) => self.ast.to_string(),
+        }
+    }
+}
+
+/// A procedure exported by a library module.
+pub enum Export<'a> {
+    Procedure(&'a ProcedureAst),
+    ReExport(&'a ProcReExport),
+}
+
+impl<'a> Export<'a> {
+    /// Returns the doc comment of the exported procedure, if any.
+    pub fn docs(&self) -> Option<&'a str> {
+        match self {
+            Self::Procedure(proc) => proc.docs.as_deref(),
+            Self::ReExport(proc) => proc.docs(),
+        }
+    }
+}
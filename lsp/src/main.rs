@@ -0,0 +1,44 @@
+use clap::Parser;
+use library::LibraryIndex;
+use lsp_server::Connection;
+use server::Server;
+use std::{env, error::Error, path::PathBuf};
+
+mod document;
+mod instructions;
+mod library;
+mod server;
+
+// ARGUMENTS
+// ================================================================================================
+
+/// Language server for Miden assembly which communicates with the editor over stdio.
+#[derive(Debug, Parser)]
+#[clap(name = "miden-lsp", version, about)]
+struct Args {
+    /// Paths to additional libraries which can be imported by the edited documents; each path
+    /// must point either to a `.masl` file or to a directory of `.masm` source files
+    #[clap(short = 'l', long = "library")]
+    libraries: Vec<PathBuf>,
+}
+
+// MAIN
+// ================================================================================================
+
+fn main() -> Result<(), Box<dyn Error + Sync + Send>> {
+    let args = Args::parse();
+
+    // the standard library is always available; sources of modules which are not located on disk
+    // are rendered into a temporary directory so that they can be navigated to
+    let mut libraries = LibraryIndex::new(env::temp_dir().join("miden-lsp"));
+    for path in args.libraries.iter() {
+        libraries.load(path)?;
+    }
+
+    let (connection, io_threads) = Connection::stdio();
+    connection.initialize(serde_json::to_value(Server::capabilities())?)?;
+    Server::new(libraries).run(&connection)?;
+    io_threads.join()?;
+
+    Ok(())
+}
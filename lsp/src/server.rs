@@ -0,0 +1,182 @@
+use super::{
+    document::{location_range, Document},
+    library::LibraryIndex,
+};
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::{
+    notification::{
+        DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument,
+        Notification as NotificationTrait, PublishDiagnostics,
+    },
+    request::{Completion, GotoDefinition, HoverRequest, Request as RequestTrait},
+    CompletionOptions, CompletionResponse, GotoDefinitionResponse, Hover, HoverContents,
+    HoverProviderCapability, Location, MarkupContent, MarkupKind, OneOf, PublishDiagnosticsParams,
+    ServerCapabilities, TextDocumentSyncCapability, TextDocumentSyncKind, Url,
+};
+use std::{collections::BTreeMap, error::Error};
+
+// SERVER
+// ================================================================================================
+
+/// Language server which handles requests for the Miden assembly documents opened in the editor.
+pub struct Server {
+    documents: BTreeMap<Url, Document>,
+    libraries: LibraryIndex,
+}
+
+impl Server {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new server which resolves imported modules using the specified libraries.
+    pub fn new(libraries: LibraryIndex) -> Self {
+        Self {
+            documents: BTreeMap::new(),
+            libraries,
+        }
+    }
+
+    /// Returns the capabilities supported by this server.
+    pub fn capabilities() -> ServerCapabilities {
+        ServerCapabilities {
+            text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+            definition_provider: Some(OneOf::Left(true)),
+            hover_provider: Some(HoverProviderCapability::Simple(true)),
+            completion_provider: Some(CompletionOptions {
+                trigger_characters: Some(vec![".".to_string(), ":".to_string()]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    // MESSAGE LOOP
+    // --------------------------------------------------------------------------------------------
+
+    /// Handles the messages received over the specified connection until the client requests the
+    /// server to shut down.
+    pub fn run(&mut self, connection: &Connection) -> Result<(), Box<dyn Error + Sync + Send>> {
+        for message in &connection.receiver {
+            match message {
+                Message::Request(request) => {
+                    if connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+                    let response = self.handle_request(request);
+                    connection.sender.send(Message::Response(response))?;
+                }
+                Message::Notification(notification) => {
+                    if let Some(notification) = self.handle_notification(notification) {
+                        connection.sender.send(Message::Notification(notification))?;
+                    }
+                }
+                Message::Response(_) => (),
+            }
+        }
+        Ok(())
+    }
+
+    // REQUEST HANDLERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Handles the specified request and returns the response to it.
+    fn handle_request(&self, request: Request) -> Response {
+        match request.method.as_str() {
+            GotoDefinition::METHOD => respond::<GotoDefinition>(request, |params| {
+                let position = params.text_document_position_params;
+                let uri = position.text_document.uri;
+                let document = self.documents.get(&uri)?;
+                let (path, location) = document.definition(position.position, &self.libraries)?;
+                let uri = match path {
+                    Some(path) => Url::from_file_path(path).ok()?,
+                    None => uri,
+                };
+                let range = location_range(&location, 0);
+                Some(GotoDefinitionResponse::Scalar(Location::new(uri, range)))
+            }),
+            HoverRequest::METHOD => respond::<HoverRequest>(request, |params| {
+                let position = params.text_document_position_params;
+                let document = self.documents.get(&position.text_document.uri)?;
+                let value = document.hover(position.position, &self.libraries)?;
+                Some(Hover {
+                    contents: HoverContents::Markup(MarkupContent {
+                        kind: MarkupKind::Markdown,
+                        value,
+                    }),
+                    range: None,
+                })
+            }),
+            Completion::METHOD => respond::<Completion>(request, |params| {
+                let position = params.text_document_position;
+                let document = self.documents.get(&position.text_document.uri)?;
+                let items = document.completions(position.position, &self.libraries);
+                Some(CompletionResponse::Array(items))
+            }),
+            method => Response::new_err(
+                request.id.clone(),
+                ErrorCode::MethodNotFound as i32,
+                format!("unsupported request `{method}`"),
+            ),
+        }
+    }
+
+    // NOTIFICATION HANDLERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Handles the specified notification and returns the diagnostics which should be published
+    /// as a result, if any.
+    fn handle_notification(&mut self, notification: Notification) -> Option<Notification> {
+        let (uri, source) = match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params = extract_notification::<DidOpenTextDocument>(notification)?;
+                (params.text_document.uri, Some(params.text_document.text))
+            }
+            DidChangeTextDocument::METHOD => {
+                let mut params = extract_notification::<DidChangeTextDocument>(notification)?;
+                // documents are synchronized in full, so the last change contains the full text
+                (params.text_document.uri, Some(params.content_changes.pop()?.text))
+            }
+            DidCloseTextDocument::METHOD => {
+                let params = extract_notification::<DidCloseTextDocument>(notification)?;
+                (params.text_document.uri, None)
+            }
+            _ => return None,
+        };
+
+        let diagnostics = match source {
+            Some(source) => {
+                let document = Document::new(&source);
+                let diagnostics = document.diagnostics(&self.libraries);
+                self.documents.insert(uri.clone(), document);
+                diagnostics
+            }
+            None => {
+                self.documents.remove(&uri);
+                Vec::new()
+            }
+        };
+
+        let params = PublishDiagnosticsParams::new(uri, diagnostics, None);
+        Some(Notification::new(PublishDiagnostics::METHOD.to_string(), params))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the response to the specified request of type `R` computed by the specified handler.
+fn respond<R: RequestTrait>(
+    request: Request,
+    handler: impl FnOnce(R::Params) -> R::Result,
+) -> Response {
+    let id = request.id.clone();
+    match request.extract::<R::Params>(R::METHOD) {
+        Ok((id, params)) => Response::new_ok(id, handler(params)),
+        Err(err) => Response::new_err(id, ErrorCode::InvalidParams as i32, err.to_string()),
+    }
+}
+
+/// Extracts the parameters of the specified notification, or returns None if the parameters are
+/// malformed.
+fn extract_notification<N: NotificationTrait>(notification: Notification) -> Option<N::Params> {
+    notification.extract(N::METHOD).ok()
+}
@@ -1,5 +1,5 @@
 use criterion::{criterion_group, criterion_main, Criterion};
-use miden_vm::{execute, Assembler, DefaultHost, StackInputs};
+use miden_vm::{execute, execute_fast, Assembler, DefaultHost, StackInputs};
 use processor::ExecutionOptions;
 use std::time::Duration;
 use stdlib::StdLibrary;
@@ -8,17 +8,18 @@ fn program_execution(c: &mut Criterion) {
     let mut group = c.benchmark_group("program_execution");
     group.measurement_time(Duration::from_secs(10));
 
-    group.bench_function("sha256", |bench| {
-        let source = "
-            use.std::crypto::hashes::sha256
+    let source = "
+        use.std::crypto::hashes::sha256
+
+        begin
+            exec.sha256::hash_2to1
+        end";
+    let assembler = Assembler::default()
+        .with_library(&StdLibrary::default())
+        .expect("failed to load stdlib");
+    let program = assembler.compile(source).expect("Failed to compile test source.");
 
-            begin
-                exec.sha256::hash_2to1
-            end";
-        let assembler = Assembler::default()
-            .with_library(&StdLibrary::default())
-            .expect("failed to load stdlib");
-        let program = assembler.compile(source).expect("Failed to compile test source.");
+    group.bench_function("sha256", |bench| {
         bench.iter(|| {
             execute(
                 &program,
@@ -29,6 +30,17 @@ fn program_execution(c: &mut Criterion) {
         });
     });
 
+    group.bench_function("sha256_fast", |bench| {
+        bench.iter(|| {
+            execute_fast(
+                &program,
+                StackInputs::default(),
+                DefaultHost::default(),
+                ExecutionOptions::default(),
+            )
+        });
+    });
+
     group.finish();
 }
 
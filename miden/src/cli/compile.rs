@@ -1,6 +1,7 @@
 use clap::Parser;
 
 use super::data::{Debug, Libraries, ProgramFile};
+use miden_vm::{OptimizationLevel, ProgramSerdeOptions};
 use std::path::PathBuf;
 
 #[derive(Debug, Clone, Parser)]
@@ -15,6 +16,14 @@ pub struct CompileCmd {
     /// Path to output file
     #[clap(short = 'o', long = "output", value_parser)]
     output_file: Option<PathBuf>,
+    /// Omit decorators (advice injectors, events, debug info) from the output file. A program
+    /// compiled this way has the same hash, but may fail to execute if it relies on decorators
+    #[clap(long = "strip-decorators")]
+    strip_decorators: bool,
+    /// Apply peephole optimizations to the compiled program. The optimized program has a different
+    /// hash than the program compiled without optimizations
+    #[clap(long = "optimize")]
+    optimize: bool,
 }
 
 impl CompileCmd {
@@ -30,13 +39,43 @@ impl CompileCmd {
         let libraries = Libraries::new(&self.library_paths)?;
 
         // compile the program
-        let compiled_program = program.compile(&Debug::Off, libraries.libraries)?;
+        let optimization_level = if self.optimize {
+            OptimizationLevel::Peephole
+        } else {
+            OptimizationLevel::None
+        };
+        let (compiled_program, optimization_stats) = program.compile_with_optimization(
+            &Debug::Off,
+            optimization_level,
+            libraries.libraries,
+        )?;
+        if self.optimize {
+            println!(
+                "peephole optimizer saved {} cycles in {} rewrites",
+                optimization_stats.cycles_saved(),
+                optimization_stats.num_rewrites()
+            );
+        }
 
         // report program hash to user
         let program_hash: [u8; 32] = compiled_program.hash().into();
         println!("program hash is {}", hex::encode(program_hash));
 
+        // report the size of the program's MAST
+        let stats = compiled_program.mast_stats();
+        println!(
+            "MAST contains {} blocks ({} distinct), including {} span blocks ({} distinct) with {} \
+             operations, and {} called procedures",
+            stats.num_blocks(),
+            stats.num_distinct_blocks(),
+            stats.num_span_blocks(),
+            stats.num_distinct_span_blocks(),
+            stats.num_operations(),
+            stats.num_procedures()
+        );
+
         // write the compiled file
-        program.write(self.output_file.clone())
+        let options = ProgramSerdeOptions::new(!self.strip_decorators);
+        program.write(&compiled_program, self.output_file.clone(), options)
     }
 }
@@ -3,8 +3,8 @@ use miden_vm::{
     crypto::{MerkleStore, MerkleTree, NodeIndex, PartialMerkleTree, RpoDigest, SimpleSmt},
     math::Felt,
     utils::{Deserializable, SliceReader},
-    AdviceInputs, Assembler, Digest, ExecutionProof, MemAdviceProvider, Program, ProgramAst,
-    StackInputs, StackOutputs, Word,
+    AdviceInputs, Assembler, Digest, ExecutionProof, MemAdviceProvider, OptimizationLevel,
+    OptimizationStats, Program, ProgramAst, ProgramSerdeOptions, StackInputs, StackOutputs, Word,
 };
 use serde_derive::{Deserialize, Serialize};
 use std::{
@@ -376,42 +376,102 @@ impl OutputFile {
 // PROGRAM FILE
 // ================================================================================================
 
+/// Extension of files containing compiled programs.
+pub const COMPILED_PROGRAM_EXTENSION: &str = "masb";
+
+/// Contents of a program file.
+enum ProgramSource {
+    /// Miden assembly source parsed into a [ProgramAst].
+    Ast(ProgramAst),
+    /// A [Program] compiled ahead of time and loaded from a `.masb` file.
+    Compiled(Program),
+}
+
 pub struct ProgramFile {
-    ast: ProgramAst,
+    source: ProgramSource,
     path: PathBuf,
 }
 
 /// Helper methods to interact with masm program file.
 impl ProgramFile {
-    /// Reads the masm file at the specified path and parses it into a [ProgramAst].
+    /// Reads the program file at the specified path.
+    ///
+    /// Files with `.masb` extension are expected to contain a serialized [Program]; all other files
+    /// are parsed as masm source into a [ProgramAst].
     #[instrument(name = "read_program_file", fields(path = %path.display()))]
     pub fn read(path: &PathBuf) -> Result<Self, String> {
-        // read program file to string
-        let source = fs::read_to_string(path).map_err(|err| {
-            format!("Failed to open program file `{}` - {}\n", path.display(), err)
-        })?;
+        let is_compiled = path.extension().is_some_and(|ext| ext == COMPILED_PROGRAM_EXTENSION);
+        let source = if is_compiled {
+            // read the compiled program from file
+            let bytes = fs::read(path).map_err(|err| {
+                format!("Failed to open program file `{}` - {}\n", path.display(), err)
+            })?;
 
-        // parse the program into an AST
-        let ast = ProgramAst::parse(&source).map_err(|err| {
-            format!("Failed to parse program file `{}` - {}\n", path.display(), err)
-        })?;
+            let program = Program::read_from_bytes(&bytes).map_err(|err| {
+                format!("Failed to decode program file `{}` - {}\n", path.display(), err)
+            })?;
+
+            ProgramSource::Compiled(program)
+        } else {
+            // read program file to string
+            let source = fs::read_to_string(path).map_err(|err| {
+                format!("Failed to open program file `{}` - {}\n", path.display(), err)
+            })?;
+
+            // parse the program into an AST
+            let ast = ProgramAst::parse(&source).map_err(|err| {
+                format!("Failed to parse program file `{}` - {}\n", path.display(), err)
+            })?;
+
+            ProgramSource::Ast(ast)
+        };
 
         Ok(Self {
-            ast,
+            source,
             path: path.clone(),
         })
     }
 
     /// Compiles this program file into a [Program].
-    #[instrument(name = "compile_program", skip_all)]
+    ///
+    /// If the file contains an already compiled program, the program is returned as is, and the
+    /// debug mode and the libraries are ignored.
     pub fn compile<I, L>(&self, debug: &Debug, libraries: I) -> Result<Program, String>
     where
         I: IntoIterator<Item = L>,
         L: Library,
     {
+        self.compile_with_optimization(debug, OptimizationLevel::None, libraries)
+            .map(|(program, _)| program)
+    }
+
+    /// Compiles this program file into a [Program] applying the specified optimizations, and
+    /// returns the program together with the statistics of the applied optimizations.
+    ///
+    /// If the file contains an already compiled program, the program is returned as is, and the
+    /// debug mode, the optimization level and the libraries are ignored.
+    #[instrument(name = "compile_program", skip_all)]
+    pub fn compile_with_optimization<I, L>(
+        &self,
+        debug: &Debug,
+        optimization_level: OptimizationLevel,
+        libraries: I,
+    ) -> Result<(Program, OptimizationStats), String>
+    where
+        I: IntoIterator<Item = L>,
+        L: Library,
+    {
+        let ast = match &self.source {
+            ProgramSource::Ast(ast) => ast,
+            ProgramSource::Compiled(program) => {
+                return Ok((program.clone(), OptimizationStats::default()))
+            }
+        };
+
         // compile program
         let mut assembler = Assembler::default()
             .with_debug_mode(debug.is_on())
+            .with_optimization_level(optimization_level)
             .with_library(&StdLibrary::default())
             .map_err(|err| format!("Failed to load stdlib - {}", err))?;
 
@@ -420,23 +480,33 @@ impl ProgramFile {
             .map_err(|err| format!("Failed to load libraries `{}`", err))?;
 
         let program = assembler
-            .compile_ast(&self.ast)
+            .compile_ast(ast)
             .map_err(|err| format!("Failed to compile program - {}", err))?;
 
-        Ok(program)
+        Ok((program, assembler.optimization_stats()))
     }
 
-    /// Writes this file into the specified path, if one is provided. If the path is not provided,
-    /// writes the file into the same directory as the source file, but with `.masb` extension.
-    pub fn write(&self, out_path: Option<PathBuf>) -> Result<(), String> {
+    /// Writes the specified program compiled from this file into the specified path, if one is
+    /// provided. If the path is not provided, writes the file into the same directory as the source
+    /// file, but with `.masb` extension.
+    pub fn write(
+        &self,
+        program: &Program,
+        out_path: Option<PathBuf>,
+        options: ProgramSerdeOptions,
+    ) -> Result<(), String> {
         let out_path = out_path.unwrap_or_else(|| {
             let mut out_file = self.path.clone();
-            out_file.set_extension("masb");
+            out_file.set_extension(COMPILED_PROGRAM_EXTENSION);
             out_file
         });
 
-        self.ast
-            .write_to_file(out_path)
+        if let Some(dir) = out_path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|err| format!("Failed to write the compiled file: {err}"))?;
+        }
+
+        fs::write(&out_path, program.to_bytes_with_options(options))
             .map_err(|err| format!("Failed to write the compiled file: {err}"))
     }
 }
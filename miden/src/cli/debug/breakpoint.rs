@@ -0,0 +1,366 @@
+use crate::tools::procedure_name;
+use core::fmt;
+use miden_vm::{SourceLocation, VmState};
+use std::{
+    borrow::Cow,
+    path::{Path, PathBuf},
+};
+
+/// Extension of Miden assembly source files.
+const MASM_EXTENSION: &str = "masm";
+
+/// A condition on which execution of the program is interrupted by the debugger.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Breakpoint {
+    /// Breaks when execution reaches an instruction located at the specified line of the
+    /// specified source file.
+    Line { file: PathBuf, line: u32 },
+    /// Breaks when execution enters the procedure with the specified name.
+    Procedure(String),
+    /// Breaks when the word at the specified memory address changes.
+    WatchMemory(u64),
+    /// Breaks when the stack item at the specified index changes.
+    WatchStack(usize),
+    /// Breaks when execution reaches a `breakpoint` instruction.
+    Instruction,
+}
+
+impl Breakpoint {
+    /// Returns `true` if this breakpoint is hit when moving from the `prev` state to the `current`
+    /// state.
+    ///
+    /// `prev_context` is the name of the procedure which was executing prior to the current state,
+    /// and `program_path` is the path to the source file of the program being debugged.
+    pub fn is_hit(
+        &self,
+        prev: &VmState,
+        current: &VmState,
+        prev_context: Option<&str>,
+        program_path: &Path,
+    ) -> bool {
+        match self {
+            Self::Line { file, line } => current.asmop.as_ref().is_some_and(|asmop| {
+                // only break on the first cycle of the first instruction executed at the line;
+                // execution of the line starts over if the previous instruction is located at a
+                // different line or does not precede the current instruction (e.g., in a loop)
+                let context = procedure_name(asmop);
+                let is_line_start = match (asmop.location(), prev_location(prev)) {
+                    (Some(location), Some((prev_context, prev_location))) => {
+                        prev_context != context
+                            || prev_location.line() != location.line()
+                            || prev_location.column() >= location.column()
+                    }
+                    (Some(_), None) => true,
+                    (None, _) => false,
+                };
+                asmop.cycle_idx() == 1
+                    && is_line_start
+                    && asmop.location().is_some_and(|location| location.line() == *line)
+                    && is_in_file(&context, file, program_path)
+            }),
+            Self::Procedure(name) => current.asmop.as_ref().is_some_and(|asmop| {
+                let context = procedure_name(asmop);
+                is_procedure(&context, name) && prev_context != Some(&context)
+            }),
+            Self::WatchMemory(addr) => read_memory(prev, *addr) != read_memory(current, *addr),
+            Self::WatchStack(idx) => prev.stack.get(*idx) != current.stack.get(*idx),
+            Self::Instruction => current
+                .asmop
+                .as_ref()
+                .is_some_and(|asmop| asmop.should_break() && asmop.cycle_idx() == 1),
+        }
+    }
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Line { file, line } => write!(f, "break {}:{line}", file.display()),
+            Self::Procedure(name) => write!(f, "break {name}"),
+            Self::WatchMemory(addr) => write!(f, "watch mem {addr}"),
+            Self::WatchStack(idx) => write!(f, "watch stack {idx}"),
+            Self::Instruction => write!(f, "breakpoint instructions"),
+        }
+    }
+}
+
+// BREAK CONDITION
+// ================================================================================================
+
+/// A condition which must hold at the state at which a breakpoint is hit for the execution to be
+/// interrupted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BreakCondition {
+    /// Holds when the stack item at the specified index is equal to the specified value.
+    StackEquals { index: usize, value: u64 },
+}
+
+impl BreakCondition {
+    /// Returns `true` if this condition holds at the specified state.
+    pub fn holds(&self, state: &VmState) -> bool {
+        match self {
+            Self::StackEquals { index, value } => {
+                state.stack.get(*index).is_some_and(|item| item.as_int() == *value)
+            }
+        }
+    }
+}
+
+impl fmt::Display for BreakCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StackEquals { index, value } => write!(f, "stack[{index}] == {value}"),
+        }
+    }
+}
+
+// BREAKPOINT ENTRY
+// ================================================================================================
+
+/// A breakpoint set in the debugger together with the conditions under which it interrupts the
+/// execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BreakpointEntry {
+    id: usize,
+    breakpoint: Breakpoint,
+    condition: Option<BreakCondition>,
+    ignore_count: usize,
+    hit_count: usize,
+}
+
+impl BreakpointEntry {
+    /// Returns a new unconditional entry for the specified breakpoint.
+    pub fn new(id: usize, breakpoint: Breakpoint) -> Self {
+        Self {
+            id,
+            breakpoint,
+            condition: None,
+            ignore_count: 0,
+            hit_count: 0,
+        }
+    }
+
+    /// Returns the id of this breakpoint.
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Returns the number of times this breakpoint has been hit, including the ignored hits.
+    pub fn hit_count(&self) -> usize {
+        self.hit_count
+    }
+
+    /// Sets the condition under which this breakpoint interrupts the execution; if `None`, the
+    /// breakpoint interrupts the execution whenever it is hit.
+    pub fn set_condition(&mut self, condition: Option<BreakCondition>) {
+        self.condition = condition;
+    }
+
+    /// Sets the number of upcoming hits of this breakpoint which do not interrupt the execution.
+    pub fn set_ignore_count(&mut self, count: usize) {
+        self.ignore_count = count;
+    }
+
+    /// Returns `true` if this breakpoint interrupts the execution when moving from the `prev`
+    /// state to the `current` state.
+    ///
+    /// The breakpoint is hit if its condition holds at the `current` state; a hit is counted
+    /// towards the hit count of this breakpoint, but does not interrupt the execution while the
+    /// ignore count of the breakpoint is not exhausted.
+    pub fn check(
+        &mut self,
+        prev: &VmState,
+        current: &VmState,
+        prev_context: Option<&str>,
+        program_path: &Path,
+    ) -> bool {
+        if !self.is_hit(prev, current, prev_context, program_path) {
+            return false;
+        }
+
+        self.hit_count += 1;
+        if self.ignore_count > 0 {
+            self.ignore_count -= 1;
+            return false;
+        }
+        true
+    }
+
+    /// Returns `true` if this breakpoint interrupts the execution when moving backward from the
+    /// `prev` state to the `current` state.
+    ///
+    /// Unlike [BreakpointEntry::check()], hits are neither counted towards the hit count nor
+    /// consume the ignore count of this breakpoint; the breakpoint interrupts the execution only
+    /// if its ignore count is exhausted.
+    pub fn check_backward(
+        &self,
+        prev: &VmState,
+        current: &VmState,
+        prev_context: Option<&str>,
+        program_path: &Path,
+    ) -> bool {
+        self.ignore_count == 0 && self.is_hit(prev, current, prev_context, program_path)
+    }
+
+    /// Returns `true` if this breakpoint is hit and its condition holds when moving from the
+    /// `prev` state to the `current` state.
+    fn is_hit(
+        &self,
+        prev: &VmState,
+        current: &VmState,
+        prev_context: Option<&str>,
+        program_path: &Path,
+    ) -> bool {
+        self.breakpoint.is_hit(prev, current, prev_context, program_path)
+            && self.condition.as_ref().map_or(true, |condition| condition.holds(current))
+    }
+}
+
+impl fmt::Display for BreakpointEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.breakpoint)?;
+        if let Some(condition) = self.condition {
+            write!(f, " if {condition}")?;
+        }
+        if self.ignore_count > 0 {
+            write!(f, " (ignoring next {} hits)", self.ignore_count)?;
+        }
+        Ok(())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns `true` if the procedure with the specified context name is named `name`. The name may
+/// be either fully qualified (e.g., `std::math::u64::checked_add`) or contain only a suffix of
+/// the fully qualified path (e.g., `u64::checked_add` or `checked_add`).
+fn is_procedure(context_name: &str, name: &str) -> bool {
+    context_name == name
+        || context_name.strip_suffix(name).is_some_and(|prefix| prefix.ends_with("::"))
+}
+
+/// Returns `true` if the procedure with the specified context name is defined in the specified
+/// source file.
+///
+/// Procedures of library modules have context names qualified with their module path; such a
+/// procedure is defined in `file` if the path of its module (e.g., `std/math/u64.masm`) ends with
+/// `file`. Procedures without a module path are defined in the program being debugged.
+fn is_in_file(context_name: &str, file: &Path, program_path: &Path) -> bool {
+    let file = file.with_extension(MASM_EXTENSION);
+    match context_name.rsplit_once("::") {
+        Some((module_path, _)) => {
+            let module_file: PathBuf = module_path.split("::").collect();
+            module_file.with_extension(MASM_EXTENSION).ends_with(file)
+        }
+        None => program_path.with_extension(MASM_EXTENSION).ends_with(file),
+    }
+}
+
+/// Returns the procedure name and source location of the instruction executing in the specified
+/// state, if any.
+fn prev_location(state: &VmState) -> Option<(Cow<'_, str>, &SourceLocation)> {
+    let asmop = state.asmop.as_ref()?;
+    asmop.location().map(|location| (procedure_name(asmop), location))
+}
+
+/// Returns the word stored at the specified memory address, or None if the address has not been
+/// initialized.
+fn read_memory(state: &VmState, addr: u64) -> Option<&[miden_vm::math::Felt; 4]> {
+    state.memory.iter().find(|(a, _)| *a == addr).map(|(_, word)| word)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{is_in_file, is_procedure, BreakCondition, Breakpoint, BreakpointEntry};
+    use miden_vm::{math::Felt, VmState};
+    use processor::ContextId;
+    use std::path::Path;
+
+    #[test]
+    fn procedure_names() {
+        assert!(is_procedure("foo", "foo"));
+        assert!(is_procedure("std::math::u64::checked_add", "checked_add"));
+        assert!(is_procedure("std::math::u64::checked_add", "u64::checked_add"));
+        assert!(is_procedure("std::math::u64::checked_add", "std::math::u64::checked_add"));
+        assert!(!is_procedure("std::math::u64::checked_add", "add"));
+        assert!(!is_procedure("foo", "bar"));
+    }
+
+    #[test]
+    fn source_files() {
+        let program = Path::new("examples/fib/fib.masm");
+        assert!(is_in_file("#main", Path::new("fib.masm"), program));
+        assert!(is_in_file("foo", Path::new("fib/fib.masm"), program));
+        assert!(is_in_file("foo", Path::new("fib"), program));
+        assert!(!is_in_file("foo", Path::new("u64.masm"), program));
+
+        let context = "std::math::u64::checked_add";
+        assert!(is_in_file(context, Path::new("u64.masm"), program));
+        assert!(is_in_file(context, Path::new("math/u64.masm"), program));
+        assert!(!is_in_file(context, Path::new("fib.masm"), program));
+        assert!(!is_in_file(context, Path::new("math/u32.masm"), program));
+    }
+
+    #[test]
+    fn conditional_breakpoints() {
+        let program = Path::new("program.masm");
+        let mut entry = BreakpointEntry::new(1, Breakpoint::WatchStack(0));
+        entry.set_condition(Some(BreakCondition::StackEquals { index: 0, value: 3 }));
+        entry.set_ignore_count(1);
+
+        // the condition does not hold after the first change of the watched stack item
+        assert!(!entry.check(&state(0, 1), &state(1, 2), None, program));
+        assert_eq!(entry.hit_count(), 0);
+
+        // the first hit for which the condition holds is ignored
+        assert!(!entry.check(&state(1, 2), &state(2, 3), None, program));
+        assert_eq!(entry.hit_count(), 1);
+
+        // the watched stack item does not change
+        assert!(!entry.check(&state(2, 3), &state(3, 3), None, program));
+        assert_eq!(entry.hit_count(), 1);
+
+        assert!(entry.check(&state(3, 4), &state(4, 3), None, program));
+        assert_eq!(entry.hit_count(), 2);
+
+        entry.set_condition(None);
+        assert!(entry.check(&state(4, 3), &state(5, 7), None, program));
+        assert_eq!(entry.hit_count(), 3);
+    }
+
+    #[test]
+    fn backward_checks_keep_counts() {
+        let program = Path::new("program.masm");
+        let mut entry = BreakpointEntry::new(1, Breakpoint::WatchStack(0));
+        entry.set_ignore_count(1);
+
+        // hits are not counted and the ignore count is not consumed when moving backward
+        assert!(!entry.check_backward(&state(2, 3), &state(1, 2), None, program));
+        assert!(!entry.check_backward(&state(1, 2), &state(0, 1), None, program));
+        assert_eq!(entry.hit_count(), 0);
+
+        // the first hit when moving forward is still ignored
+        assert!(!entry.check(&state(0, 1), &state(1, 2), None, program));
+        assert_eq!(entry.hit_count(), 1);
+
+        assert!(entry.check_backward(&state(1, 2), &state(0, 1), None, program));
+        assert!(!entry.check_backward(&state(1, 2), &state(0, 2), None, program));
+        assert_eq!(entry.hit_count(), 1);
+    }
+
+    fn state(clk: u32, top: u64) -> VmState {
+        VmState {
+            clk,
+            ctx: ContextId::root(),
+            op: None,
+            asmop: None,
+            fmp: Felt::new(0),
+            stack: vec![Felt::new(top)],
+            memory: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use crate::tools::{procedure_name, CallResolver, CallTracker};
+use miden_vm::VmState;
+use std::collections::HashMap;
+
+/// Tracks the depth of the procedure call stack at each clock cycle of program execution.
+///
+/// Procedure calls are inferred by a [CallTracker] as the program is executed. Since the program
+/// being debugged may have been loaded in compiled form, its call graph is not available, and a
+/// newly executed procedure is always assumed to be invoked by the procedure at the top of the
+/// call stack.
+#[derive(Debug)]
+pub struct CallStack {
+    tracker: CallTracker,
+    resolver: CallResolver,
+    /// Indexes of the contexts executed so far, keyed by procedure names.
+    contexts: HashMap<String, usize>,
+    /// Depth of the call stack at each recorded clock cycle.
+    depths: Vec<usize>,
+}
+
+impl CallStack {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new call stack which contains only the specified entry point.
+    pub fn new(entry_point: &str) -> Self {
+        Self {
+            tracker: CallTracker::new(0),
+            resolver: CallResolver::default(),
+            contexts: HashMap::from([(entry_point.to_string(), 0)]),
+            depths: Vec::new(),
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the depth of the call stack at the specified clock cycle, or None if the clock
+    /// cycle has not been recorded yet.
+    pub fn depth_at(&self, clk: u32) -> Option<usize> {
+        self.depths.get(clk as usize).copied()
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Updates the call stack with the specified state.
+    ///
+    /// States must be recorded in the order of their clock cycles; states which have already
+    /// been recorded (e.g., when stepping backward) are ignored.
+    pub fn record(&mut self, state: &VmState) {
+        if state.clk as usize != self.depths.len() {
+            return;
+        }
+
+        if let Some(asmop) = state.asmop.as_ref() {
+            let name = procedure_name(asmop);
+            let num_contexts = self.contexts.len();
+            let context = match self.contexts.get(name.as_ref()) {
+                Some(&context) => context,
+                None => {
+                    self.contexts.insert(name.into_owned(), num_contexts);
+                    num_contexts
+                }
+            };
+            self.tracker.enter_context(context, None, &self.resolver);
+        }
+        if let Some(op) = state.op {
+            self.tracker.record_op(op);
+        }
+
+        self.depths.push(self.tracker.stack().len());
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::CallStack;
+    use miden_vm::{Assembler, DefaultHost, StackInputs};
+
+    #[test]
+    fn call_stack_depths() {
+        let source = "
+            proc.bar
+                push.1 drop
+            end
+
+            proc.foo
+                push.2 exec.bar push.3 drop drop
+            end
+
+            proc.baz
+                push.1 if.true exec.bar else exec.foo end
+            end
+
+            begin
+                exec.foo push.5 call.baz drop
+            end";
+        let program = Assembler::default().with_debug_mode(true).compile(source).unwrap();
+
+        let mut call_stack = CallStack::new("#main");
+        let mut contexts = Vec::new();
+        for state in
+            processor::execute_iter(&program, StackInputs::default(), DefaultHost::default())
+        {
+            let state = state.unwrap();
+            call_stack.record(&state);
+            if let Some(asmop) = state.asmop.filter(|asmop| asmop.cycle_idx() == 1) {
+                let depth = call_stack.depth_at(state.clk).unwrap();
+                contexts.push((asmop.op().to_string(), asmop.context_name().to_string(), depth));
+            }
+        }
+
+        let expected = [
+            ("push.2", "foo", 2),
+            ("push.1", "bar", 3),
+            ("drop", "bar", 3),
+            ("push.3", "foo", 2),
+            ("drop", "foo", 2),
+            ("drop", "foo", 2),
+            ("push.5", "#main", 1),
+            ("push.1", "baz", 2),
+            ("push.1", "bar", 3),
+            ("drop", "bar", 3),
+            ("drop", "#main", 1),
+        ];
+        let expected = expected
+            .iter()
+            .map(|(op, context, depth)| (op.to_string(), context.to_string(), *depth))
+            .collect::<Vec<_>>();
+        assert_eq!(contexts, expected);
+    }
+}
@@ -1,20 +1,43 @@
+use super::{BreakCondition, Breakpoint};
+use std::path::PathBuf;
+
 /// debug commands supported by the debugger
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum DebugCommand {
     Continue,
     Next(usize),
     Rewind,
     Back(usize),
+    Step(StepMode),
+    StepBack(StepMode),
     PrintState,
     PrintStack,
     PrintStackItem(usize),
     PrintMem,
     PrintMemAddress(u64),
     Clock,
+    AddBreakpoint(Breakpoint),
+    PrintBreakpoints,
+    DeleteBreakpoint(usize),
+    SetCondition(usize, Option<BreakCondition>),
+    IgnoreBreakpoint(usize, usize),
     Quit,
     Help,
 }
 
+/// Granularity of stepping through the source instructions of a program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StepMode {
+    /// Steps to the next instruction, entering procedure calls.
+    Into,
+    /// Steps to the next instruction of the current procedure, skipping over procedure calls.
+    Over,
+    /// Steps to the next instruction of the caller of the current procedure.
+    Out,
+    /// Steps to the end of the current procedure.
+    Finish,
+}
+
 impl DebugCommand {
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
@@ -37,8 +60,17 @@ impl DebugCommand {
             "c" | "continue" => Self::Continue,
             "b" | "back" => Self::parse_back(tokens.by_ref())?,
             "r" | "rewind" => Self::Rewind,
+            "st" | "step" => Self::parse_step(tokens.by_ref(), StepMode::Into)?,
+            "o" | "over" => Self::parse_step(tokens.by_ref(), StepMode::Over)?,
+            "u" | "out" => Self::parse_step(tokens.by_ref(), StepMode::Out)?,
+            "f" | "finish" => Self::parse_step(tokens.by_ref(), StepMode::Finish)?,
             "p" | "print" => Self::parse_print(tokens.by_ref())?,
             "l" | "clock" => Self::Clock,
+            "br" | "break" => Self::parse_break(tokens.by_ref())?,
+            "w" | "watch" => Self::parse_watch(tokens.by_ref())?,
+            "d" | "delete" => Self::parse_delete(tokens.by_ref())?,
+            "cond" | "condition" => Self::parse_condition(tokens.by_ref())?,
+            "i" | "ignore" => Self::parse_ignore(tokens.by_ref())?,
             "h" | "?" | "help" => Self::Help,
             "q" | "quit" => Self::Quit,
             _ => {
@@ -94,6 +126,18 @@ impl DebugCommand {
         Ok(Self::Back(num_cycles))
     }
 
+    /// parse step commands - [step|over|out|finish] [back]
+    fn parse_step<'a, I>(mut tokens: I, mode: StepMode) -> Result<Self, String>
+    where
+        I: Iterator<Item = &'a str>,
+    {
+        match tokens.next() {
+            Some("b" | "back") => Ok(Self::StepBack(mode)),
+            Some(t) => Err(format!("malformed step command - unexpected argument: `{t}`")),
+            None => Ok(Self::Step(mode)),
+        }
+    }
+
     /// parse print command - p [m|s] [addr]
     fn parse_print<'a, I>(mut tokens: I) -> Result<Self, String>
     where
@@ -121,11 +165,220 @@ impl DebugCommand {
                 format!("malformed command - failed to parse print argument: {err}")
             })?;
 
-        match (command, argument) {
+        match (&command, argument) {
             (Self::PrintMem, Some(arg)) => Ok(Self::PrintMemAddress(arg)),
             (Self::PrintStack, Some(arg)) => Ok(Self::PrintStackItem(arg as usize)),
             (_, Some(_)) => unreachable!("the command was previously parsed within this block"),
             (_, None) => Ok(command),
         }
     }
+
+    /// parse break command - break [<file>:<line> | <proc_name>]
+    fn parse_break<'a, I>(mut tokens: I) -> Result<Self, String>
+    where
+        I: Iterator<Item = &'a str>,
+    {
+        let target = match tokens.next() {
+            Some(t) => t,
+            None => return Ok(Self::PrintBreakpoints),
+        };
+
+        // a target of the form `<file>:<line>` is a line breakpoint; anything else (including
+        // fully qualified procedure names such as `std::math::u64::checked_add`) is treated as a
+        // procedure name
+        let line_target = target
+            .rsplit_once(':')
+            .filter(|(file, _)| !file.is_empty() && !file.ends_with(':'))
+            .and_then(|(file, line)| line.parse::<u32>().ok().map(|line| (file, line)));
+
+        let breakpoint = match line_target {
+            Some((file, line)) => Breakpoint::Line {
+                file: PathBuf::from(file),
+                line,
+            },
+            None => Breakpoint::Procedure(target.to_string()),
+        };
+        Ok(Self::AddBreakpoint(breakpoint))
+    }
+
+    /// parse watch command - watch [m|s] <addr>
+    fn parse_watch<'a, I>(mut tokens: I) -> Result<Self, String>
+    where
+        I: Iterator<Item = &'a str>,
+    {
+        let command = match tokens.next() {
+            Some(c) => c,
+            None => return Ok(Self::PrintBreakpoints),
+        };
+
+        let argument = tokens
+            .next()
+            .ok_or(format!("malformed `watch` command - missing argument for `{command}`"))?
+            .parse::<u64>()
+            .map_err(|err| format!("malformed command - failed to parse watch argument: {err}"))?;
+
+        let breakpoint = match command {
+            "m" | "mem" => Breakpoint::WatchMemory(argument),
+            "s" | "stack" => Breakpoint::WatchStack(argument as usize),
+            _ => {
+                return Err(format!(
+                    "malformed `watch` command - unexpected subcommand: `{command}`"
+                ))
+            }
+        };
+        Ok(Self::AddBreakpoint(breakpoint))
+    }
+
+    /// parse delete command - delete <id>
+    fn parse_delete<'a, I>(mut tokens: I) -> Result<Self, String>
+    where
+        I: Iterator<Item = &'a str>,
+    {
+        let id = tokens
+            .next()
+            .ok_or("malformed `delete` command - missing breakpoint id".to_string())?
+            .parse::<usize>()
+            .map_err(|err| {
+                format!("malformed `delete` command - failed to parse breakpoint id: {err}")
+            })?;
+        Ok(Self::DeleteBreakpoint(id))
+    }
+
+    /// parse condition command - condition <id> [stack <index> <value>]
+    fn parse_condition<'a, I>(mut tokens: I) -> Result<Self, String>
+    where
+        I: Iterator<Item = &'a str>,
+    {
+        let id = parse_breakpoint_id(tokens.next(), "condition")?;
+
+        let condition = match tokens.next() {
+            Some("s" | "stack") => {
+                let mut parse_argument = |name: &str| {
+                    tokens
+                        .next()
+                        .ok_or(format!("malformed `condition` command - missing stack {name}"))?
+                        .parse::<u64>()
+                        .map_err(|err| {
+                            format!("malformed `condition` command - failed to parse {name}: {err}")
+                        })
+                };
+                let index = parse_argument("index")? as usize;
+                let value = parse_argument("value")?;
+                Some(BreakCondition::StackEquals { index, value })
+            }
+            Some(t) => {
+                return Err(format!("malformed `condition` command - unexpected condition: `{t}`"))
+            }
+            None => None,
+        };
+        Ok(Self::SetCondition(id, condition))
+    }
+
+    /// parse ignore command - ignore <id> <count>
+    fn parse_ignore<'a, I>(mut tokens: I) -> Result<Self, String>
+    where
+        I: Iterator<Item = &'a str>,
+    {
+        let id = parse_breakpoint_id(tokens.next(), "ignore")?;
+        let count = tokens
+            .next()
+            .ok_or("malformed `ignore` command - missing ignore count".to_string())?
+            .parse::<usize>()
+            .map_err(|err| {
+                format!("malformed `ignore` command - failed to parse ignore count: {err}")
+            })?;
+        Ok(Self::IgnoreBreakpoint(id, count))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Parses the id of a breakpoint passed to the specified command.
+fn parse_breakpoint_id(token: Option<&str>, command: &str) -> Result<usize, String> {
+    token
+        .ok_or(format!("malformed `{command}` command - missing breakpoint id"))?
+        .parse::<usize>()
+        .map_err(|err| {
+            format!("malformed `{command}` command - failed to parse breakpoint id: {err}")
+        })
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{BreakCondition, Breakpoint, DebugCommand, StepMode};
+    use std::path::PathBuf;
+
+    #[test]
+    fn parse_steps() {
+        let parse = |command: &str| DebugCommand::parse(command).unwrap().unwrap();
+
+        assert_eq!(DebugCommand::Step(StepMode::Into), parse("step"));
+        assert_eq!(DebugCommand::Step(StepMode::Over), parse("o"));
+        assert_eq!(DebugCommand::Step(StepMode::Out), parse("out"));
+        assert_eq!(DebugCommand::Step(StepMode::Finish), parse("f"));
+        assert_eq!(DebugCommand::StepBack(StepMode::Into), parse("st b"));
+        assert_eq!(DebugCommand::StepBack(StepMode::Over), parse("over back"));
+        assert_eq!(DebugCommand::StepBack(StepMode::Out), parse("u back"));
+        assert_eq!(DebugCommand::StepBack(StepMode::Finish), parse("finish b"));
+
+        assert!(DebugCommand::parse("step 2").is_err());
+        assert!(DebugCommand::parse("over back 2").is_err());
+    }
+
+    #[test]
+    fn parse_breakpoints() {
+        let parse = |command: &str| DebugCommand::parse(command).unwrap().unwrap();
+
+        assert_eq!(DebugCommand::PrintBreakpoints, parse("break"));
+        assert_eq!(
+            DebugCommand::AddBreakpoint(Breakpoint::Line {
+                file: PathBuf::from("examples/fib/fib.masm"),
+                line: 12
+            }),
+            parse("break examples/fib/fib.masm:12")
+        );
+        assert_eq!(
+            DebugCommand::AddBreakpoint(Breakpoint::Procedure("foo".to_string())),
+            parse("br foo")
+        );
+        assert_eq!(
+            DebugCommand::AddBreakpoint(Breakpoint::Procedure("std::math::u64::add".to_string())),
+            parse("break std::math::u64::add")
+        );
+        assert_eq!(DebugCommand::AddBreakpoint(Breakpoint::WatchMemory(4)), parse("watch mem 4"));
+        assert_eq!(DebugCommand::AddBreakpoint(Breakpoint::WatchStack(2)), parse("w s 2"));
+        assert_eq!(DebugCommand::DeleteBreakpoint(1), parse("delete 1"));
+
+        assert!(DebugCommand::parse("watch mem").is_err());
+        assert!(DebugCommand::parse("watch foo 1").is_err());
+        assert!(DebugCommand::parse("delete").is_err());
+        assert!(DebugCommand::parse("break foo bar").is_err());
+    }
+
+    #[test]
+    fn parse_break_conditions() {
+        let parse = |command: &str| DebugCommand::parse(command).unwrap().unwrap();
+
+        assert_eq!(
+            DebugCommand::SetCondition(2, Some(BreakCondition::StackEquals { index: 0, value: 5 })),
+            parse("condition 2 stack 0 5")
+        );
+        assert_eq!(
+            DebugCommand::SetCondition(0, Some(BreakCondition::StackEquals { index: 3, value: 1 })),
+            parse("cond 0 s 3 1")
+        );
+        assert_eq!(DebugCommand::SetCondition(1, None), parse("condition 1"));
+        assert_eq!(DebugCommand::IgnoreBreakpoint(1, 10), parse("ignore 1 10"));
+        assert_eq!(DebugCommand::IgnoreBreakpoint(0, 2), parse("i 0 2"));
+
+        assert!(DebugCommand::parse("condition").is_err());
+        assert!(DebugCommand::parse("condition 1 stack 0").is_err());
+        assert!(DebugCommand::parse("condition 1 mem 0 1").is_err());
+        assert!(DebugCommand::parse("ignore 1").is_err());
+        assert!(DebugCommand::parse("ignore foo 1").is_err());
+    }
 }
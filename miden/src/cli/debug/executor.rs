@@ -1,12 +1,20 @@
-use super::DebugCommand;
+use super::{BreakCondition, Breakpoint, BreakpointEntry, CallStack, DebugCommand, StepMode};
+use crate::tools::procedure_name;
+use assembly::ProcedureName;
 use miden_vm::{
     math::Felt, DefaultHost, MemAdviceProvider, Program, StackInputs, VmState, VmStateIterator,
 };
+use std::{mem, path::PathBuf};
 
 /// Holds debugger state and iterator used for debugging.
 pub struct DebugExecutor {
     vm_state_iter: VmStateIterator,
     vm_state: VmState,
+    program_path: PathBuf,
+    breakpoints: Vec<BreakpointEntry>,
+    next_breakpoint_id: usize,
+    last_context: Option<String>,
+    call_stack: CallStack,
 }
 
 impl DebugExecutor {
@@ -14,12 +22,17 @@ impl DebugExecutor {
     // --------------------------------------------------------------------------------------------
     /// Returns a new DebugExecutor for the specified program, inputs and advice provider.
     ///
+    /// `program_path` is the path to the source file of the program and is used to resolve line
+    /// breakpoints set in the program's own source. Breaking at `breakpoint` instructions is
+    /// registered as the breakpoint with id `0`.
+    ///
     /// # Errors
     /// Returns an error if the command cannot be parsed.
     pub fn new(
         program: Program,
         stack_inputs: StackInputs,
         advice_provider: MemAdviceProvider,
+        program_path: PathBuf,
     ) -> Result<Self, String> {
         let mut vm_state_iter =
             processor::execute_iter(&program, stack_inputs, DefaultHost::new(advice_provider));
@@ -30,9 +43,18 @@ impl DebugExecutor {
             ))?
             .expect("initial state of vm must be healthy!");
 
+        let last_context = vm_state.asmop.as_ref().map(|asmop| procedure_name(asmop).into_owned());
+        let mut call_stack = CallStack::new(ProcedureName::MAIN_PROC_NAME);
+        call_stack.record(&vm_state);
+
         Ok(Self {
             vm_state_iter,
             vm_state,
+            program_path,
+            breakpoints: vec![BreakpointEntry::new(0, Breakpoint::Instruction)],
+            next_breakpoint_id: 1,
+            last_context,
+            call_stack,
         })
     }
 
@@ -44,8 +66,7 @@ impl DebugExecutor {
         match command {
             DebugCommand::Continue => {
                 while let Some(new_vm_state) = self.next_vm_state() {
-                    self.vm_state = new_vm_state;
-                    if self.should_break() {
+                    if self.update_vm_state(new_vm_state, false) {
                         break;
                     }
                 }
@@ -55,8 +76,7 @@ impl DebugExecutor {
                 for _cycle in 0..cycles {
                     match self.next_vm_state() {
                         Some(next_vm_state) => {
-                            self.vm_state = next_vm_state;
-                            if self.should_break() {
+                            if self.update_vm_state(next_vm_state, false) {
                                 break;
                             }
                         }
@@ -69,14 +89,14 @@ impl DebugExecutor {
                 while let Some(new_vm_state) = self.vm_state_iter.back() {
                     self.vm_state = new_vm_state;
                 }
+                self.update_last_context();
                 self.print_vm_state();
             }
             DebugCommand::Back(cycles) => {
                 for _cycle in 0..cycles {
                     match self.vm_state_iter.back() {
                         Some(new_vm_state) => {
-                            self.vm_state = new_vm_state;
-                            if self.should_break() {
+                            if self.update_vm_state(new_vm_state, true) {
                                 break;
                             }
                         }
@@ -85,18 +105,134 @@ impl DebugExecutor {
                 }
                 self.print_vm_state()
             }
+            DebugCommand::Step(mode) => {
+                self.step(mode, false);
+                self.print_vm_state();
+            }
+            DebugCommand::StepBack(mode) => {
+                self.step(mode, true);
+                self.print_vm_state();
+            }
             DebugCommand::PrintState => self.print_vm_state(),
             DebugCommand::PrintStack => self.print_stack(),
             DebugCommand::PrintStackItem(index) => self.print_stack_item(index),
             DebugCommand::PrintMem => self.print_memory(),
             DebugCommand::PrintMemAddress(address) => self.print_memory_entry(address),
             DebugCommand::Clock => println!("{}", self.vm_state.clk),
+            DebugCommand::AddBreakpoint(breakpoint) => self.add_breakpoint(breakpoint),
+            DebugCommand::PrintBreakpoints => self.print_breakpoints(),
+            DebugCommand::DeleteBreakpoint(id) => self.delete_breakpoint(id),
+            DebugCommand::SetCondition(id, condition) => self.set_condition(id, condition),
+            DebugCommand::IgnoreBreakpoint(id, count) => self.ignore_breakpoint(id, count),
             DebugCommand::Help => Self::print_help(),
             DebugCommand::Quit => return false,
         }
         true
     }
 
+    /// replaces the current state with the specified state and returns `true` if execution should
+    /// break at the new state; `backward` specifies whether the new state precedes the current
+    /// state.
+    fn update_vm_state(&mut self, vm_state: VmState, backward: bool) -> bool {
+        self.call_stack.record(&vm_state);
+        let prev_vm_state = mem::replace(&mut self.vm_state, vm_state);
+        let should_break = self.should_break(&prev_vm_state, backward);
+        self.update_last_context();
+        should_break
+    }
+
+    /// steps forward or backward through the source instructions of the program until the
+    /// condition of the specified step mode is met, a breakpoint is hit, or execution is complete.
+    ///
+    /// When stepping backward, the step modes are mirrored: `out` stops at the start of the
+    /// caller's instruction preceding the call of the current procedure, and `finish` stops at
+    /// the last cycle before the current procedure was entered.
+    fn step(&mut self, mode: StepMode, backward: bool) {
+        let depth = self.call_depth();
+        loop {
+            let vm_state = match backward {
+                true => self.vm_state_iter.back(),
+                false => self.next_vm_state(),
+            };
+            let vm_state = match vm_state {
+                Some(vm_state) => vm_state,
+                None => break,
+            };
+            // the iterator yields the current state again when the direction of iteration changes
+            if vm_state.clk == self.vm_state.clk {
+                continue;
+            }
+            if self.update_vm_state(vm_state, backward) {
+                break;
+            }
+
+            let is_instruction_start =
+                self.vm_state.asmop.as_ref().is_some_and(|asmop| asmop.cycle_idx() == 1);
+            let is_done = match mode {
+                StepMode::Into => is_instruction_start,
+                StepMode::Over => is_instruction_start && self.call_depth() <= depth,
+                StepMode::Out => is_instruction_start && self.call_depth() < depth,
+                StepMode::Finish => self.call_depth() < depth,
+            };
+            if is_done {
+                break;
+            }
+        }
+    }
+
+    /// updates the name of the last executed procedure from the current state.
+    fn update_last_context(&mut self) {
+        if let Some(asmop) = self.vm_state.asmop.as_ref() {
+            let context = procedure_name(asmop);
+            if self.last_context.as_deref() != Some(&context) {
+                self.last_context = Some(context.into_owned());
+            }
+        }
+    }
+
+    /// adds the specified breakpoint.
+    fn add_breakpoint(&mut self, breakpoint: Breakpoint) {
+        let id = self.next_breakpoint_id;
+        self.next_breakpoint_id += 1;
+        let entry = BreakpointEntry::new(id, breakpoint);
+        println!("Breakpoint {id}: {entry}");
+        self.breakpoints.push(entry);
+    }
+
+    /// deletes the breakpoint with the specified id.
+    fn delete_breakpoint(&mut self, id: usize) {
+        match self.breakpoints.iter().position(|entry| entry.id() == id) {
+            Some(index) => {
+                let entry = self.breakpoints.remove(index);
+                println!("Deleted breakpoint {id}: {entry}");
+            }
+            None => println!("breakpoint '{id}' not found"),
+        }
+    }
+
+    /// sets the condition of the breakpoint with the specified id, or removes the condition if
+    /// `condition` is `None`.
+    fn set_condition(&mut self, id: usize, condition: Option<BreakCondition>) {
+        match self.breakpoints.iter_mut().find(|entry| entry.id() == id) {
+            Some(entry) => {
+                entry.set_condition(condition);
+                println!("Breakpoint {id}: {entry}");
+            }
+            None => println!("breakpoint '{id}' not found"),
+        }
+    }
+
+    /// makes the breakpoint with the specified id ignore the next `count` hits.
+    fn ignore_breakpoint(&mut self, id: usize, count: usize) {
+        match self.breakpoints.iter_mut().find(|entry| entry.id() == id) {
+            Some(entry) => {
+                entry.set_ignore_count(count);
+                println!("Breakpoint {id}: {entry}");
+            }
+            None => println!("breakpoint '{id}' not found"),
+        }
+    }
+
     /// iterates to the next clock cycle.
     fn next_vm_state(&mut self) -> Option<VmState> {
         match self.vm_state_iter.next() {
@@ -117,6 +253,13 @@ impl DebugExecutor {
     // ACCESSORS
     // --------------------------------------------------------------------------------------------
 
+    /// returns the depth of the procedure call stack at the current state.
+    fn call_depth(&self) -> usize {
+        self.call_stack
+            .depth_at(self.vm_state.clk)
+            .expect("call stack must be recorded for all visited states")
+    }
+
     /// print general VM state information.
     fn print_vm_state(&self) {
         println!("{}", self.vm_state)
@@ -167,6 +310,16 @@ impl DebugExecutor {
         }
     }
 
+    /// print all breakpoints.
+    pub fn print_breakpoints(&self) {
+        if self.breakpoints.is_empty() {
+            println!("no breakpoints set");
+        }
+        for entry in self.breakpoints.iter() {
+            println!("{} {entry} (hit {} times)", entry.id(), entry.hit_count());
+        }
+    }
+
     // HELPERS
     // --------------------------------------------------------------------------------------------
 
@@ -187,24 +340,52 @@ impl DebugExecutor {
             back               rewinds `1` clock cycles\n\
             back <c>           rewinds `c` clock cycles\n\
             rewind             rewinds program until beginning\n\
+            step               steps to the next instruction, entering procedures\n\
+            over               steps to the next instruction of the current procedure\n\
+            out                steps to the next instruction of the calling procedure\n\
+            finish             steps to the end of the current procedure\n\
+            <s> back           performs step command `s` backward\n\
             print              displays the complete state of the virtual machine\n\
             print mem          displays the complete state of memory\n\
             print mem <i>      displays memory at address `i`\n\
             print stack        displays the complete state of the stack\n\
             print stack <i>    displays the stack element at index `i`\n\
             clock              displays the current clock cycle\n\
+            break              displays all breakpoints and watchpoints\n\
+            break <f>:<l>      breaks at line `l` of source file `f`\n\
+            break <p>          breaks upon entering procedure `p`\n\
+            watch mem <i>      breaks when memory at address `i` changes\n\
+            watch stack <i>    breaks when the stack element at index `i` changes\n\
+            delete <id>        deletes the breakpoint or watchpoint `id`\n\
+            condition <id> <c> breaks at `id` only if the condition `c` holds\n\
+            condition <id>     removes the condition of the breakpoint `id`\n\
+            ignore <id> <n>    ignores the next `n` hits of the breakpoint `id`\n\
             quit               quits the debugger\n\
             help               displays this message\n\
             \n\
+            Breakpoint conditions:\n\
+            stack <i> <v>      stack element at index `i` is equal to `v`\n\
+            \n\
+            Breakpoint `0` breaks at the `breakpoint` instructions of the program.\n\
+            \n\
             The following mappings are also available:\n\
             n -> next\n\
             c -> continue\n\
             b -> back\n\
             r -> rewind\n\
+            st -> step\n\
+            o -> over\n\
+            u -> out\n\
+            f -> finish\n\
             p -> print\n\
             m -> mem\n\
             s -> stack\n\
             l -> clock\n\
+            br -> break\n\
+            w -> watch\n\
+            d -> delete\n\
+            cond -> condition\n\
+            i -> ignore\n\
             q -> quit\n\
             h -> help\n\
             ? -> help";
@@ -212,8 +393,32 @@ impl DebugExecutor {
         println!("{}", message);
     }
 
-    /// Returns `true` if the current state should break.
-    fn should_break(&self) -> bool {
-        self.vm_state.asmop.as_ref().map(|asm| asm.should_break()).unwrap_or(false)
+    /// Returns `true` if the current state should break due to one of the breakpoints set in the
+    /// debugger (including the `breakpoint` instructions) being hit when moving from the
+    /// specified previous state to the current state.
+    ///
+    /// Hits are recorded for all breakpoints whose conditions hold at the current state, unless the
+    /// current state was reached by moving backward, in which case the hit and ignore counts of
+    /// the breakpoints are left unchanged.
+    fn should_break(&mut self, prev_vm_state: &VmState, backward: bool) -> bool {
+        let mut should_break = false;
+        for entry in self.breakpoints.iter_mut() {
+            let prev_context = self.last_context.as_deref();
+            let is_hit = if backward {
+                entry.check_backward(
+                    prev_vm_state,
+                    &self.vm_state,
+                    prev_context,
+                    &self.program_path,
+                )
+            } else {
+                entry.check(prev_vm_state, &self.vm_state, prev_context, &self.program_path)
+            };
+            if is_hit {
+                println!("Breakpoint {} hit: {entry}", entry.id());
+                should_break = true;
+            }
+        }
+        should_break
     }
 }
@@ -3,8 +3,14 @@ use clap::Parser;
 use rustyline::{error::ReadlineError, Config, DefaultEditor, EditMode};
 use std::path::PathBuf;
 
+mod breakpoint;
+use breakpoint::{BreakCondition, Breakpoint, BreakpointEntry};
+
+mod call_stack;
+use call_stack::CallStack;
+
 mod command;
-use command::DebugCommand;
+use command::{DebugCommand, StepMode};
 
 mod executor;
 use executor::DebugExecutor;
@@ -12,7 +18,7 @@ use executor::DebugExecutor;
 #[derive(Debug, Clone, Parser)]
 #[clap(about = "Debug a miden program")]
 pub struct DebugCmd {
-    /// Path to .masm assembly file
+    /// Path to .masm assembly file or .masb compiled program file
     #[clap(short = 'a', long = "assembly", value_parser)]
     assembly_file: PathBuf,
     /// Path to input file
@@ -50,7 +56,8 @@ impl DebugCmd {
         let advice_provider = input_data.parse_advice_provider()?;
 
         // Instantiate DebugExecutor
-        let mut debug_executor = DebugExecutor::new(program, stack_inputs, advice_provider)?;
+        let mut debug_executor =
+            DebugExecutor::new(program, stack_inputs, advice_provider, self.assembly_file.clone())?;
 
         // build readline config
         let mut rl_config = Config::builder().auto_add_history(true);
@@ -0,0 +1,40 @@
+use super::data::{Debug, Libraries, ProgramFile};
+use clap::Parser;
+use std::{fs, path::PathBuf};
+
+#[derive(Debug, Clone, Parser)]
+#[clap(about = "Print the MAST of a miden program in a human-readable form")]
+pub struct DisasmCmd {
+    /// Path to .masm assembly file or .masb compiled program file
+    #[clap(short = 'a', long = "assembly", value_parser)]
+    assembly_file: PathBuf,
+    /// Paths to .masl library files
+    #[clap(short = 'l', long = "libraries", value_parser)]
+    library_paths: Vec<PathBuf>,
+    /// Path to output file; if not specified, the disassembly is printed to stdout
+    #[clap(short = 'o', long = "output", value_parser)]
+    output_file: Option<PathBuf>,
+}
+
+impl DisasmCmd {
+    pub fn execute(&self) -> Result<(), String> {
+        // load libraries from files
+        let libraries = Libraries::new(&self.library_paths)?;
+
+        // load the program from file and compile it; debug mode is used so that the disassembly
+        // includes information about assembly instructions
+        let program =
+            ProgramFile::read(&self.assembly_file)?.compile(&Debug::On, libraries.libraries)?;
+
+        let disassembly = program.disassemble().to_string();
+        match &self.output_file {
+            Some(path) => fs::write(path, disassembly).map_err(|err| {
+                format!("Failed to write disassembly to `{}` - {}", path.display(), err)
+            }),
+            None => {
+                print!("{disassembly}");
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use clap::Parser;
+use miden_vm::format_source;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug, Clone, Parser)]
+#[clap(about = "Format Miden assembly source files")]
+pub struct FmtCmd {
+    /// Paths to .masm files, or to directories which are searched for .masm files recursively
+    #[clap(value_parser, required = true)]
+    paths: Vec<PathBuf>,
+    /// Do not write the formatted sources; instead, fail if any of the files is not formatted
+    #[clap(long = "check")]
+    check: bool,
+}
+
+impl FmtCmd {
+    pub fn execute(&self) -> Result<(), String> {
+        let mut files = Vec::new();
+        for path in self.paths.iter() {
+            collect_source_files(path, &mut files)?;
+        }
+
+        let mut unformatted = Vec::new();
+        for file in files.iter() {
+            let source = fs::read_to_string(file)
+                .map_err(|err| format!("Failed to read `{}` - {}", file.display(), err))?;
+            let formatted = format_source(&source)
+                .map_err(|err| format!("Failed to parse `{}` - {}", file.display(), err))?;
+            if formatted == source {
+                continue;
+            }
+
+            if self.check {
+                println!("{} is not formatted", file.display());
+            } else {
+                fs::write(file, formatted)
+                    .map_err(|err| format!("Failed to write `{}` - {}", file.display(), err))?;
+                println!("Formatted {}", file.display());
+            }
+            unformatted.push(file);
+        }
+
+        if self.check && !unformatted.is_empty() {
+            return Err(format!(
+                "{} of {} files are not formatted",
+                unformatted.len(),
+                files.len()
+            ));
+        }
+        Ok(())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Appends the specified path to `files` if it is a file, or all .masm files within it (sorted by
+/// path) if it is a directory.
+pub(super) fn collect_source_files(path: &Path, files: &mut Vec<PathBuf>) -> Result<(), String> {
+    if !path.is_dir() {
+        files.push(path.to_path_buf());
+        return Ok(());
+    }
+
+    let entries = fs::read_dir(path)
+        .map_err(|err| format!("Failed to read directory `{}` - {}", path.display(), err))?;
+    let mut paths = entries
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| format!("Failed to read directory `{}` - {}", path.display(), err))?;
+    paths.sort();
+
+    for path in paths {
+        if path.is_dir() {
+            collect_source_files(&path, files)?;
+        } else if path.extension().is_some_and(|extension| extension == "masm") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
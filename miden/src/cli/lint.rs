@@ -0,0 +1,66 @@
+use clap::Parser;
+use miden_vm::{LintKind, Linter};
+use std::{fs, path::PathBuf};
+
+use super::fmt::collect_source_files;
+
+#[derive(Debug, Clone, Parser)]
+#[clap(about = "Report potential problems in Miden assembly source files")]
+pub struct LintCmd {
+    /// Paths to .masm files, or to directories which are searched for .masm files recursively
+    #[clap(value_parser, required = true)]
+    paths: Vec<PathBuf>,
+    /// Names of lints which should not be reported (e.g., `unused_constant`)
+    #[clap(short = 'A', long = "allow", value_parser)]
+    allowed: Vec<String>,
+    /// Fail if any lints are reported
+    #[clap(long = "deny-warnings")]
+    deny_warnings: bool,
+}
+
+impl LintCmd {
+    pub fn execute(&self) -> Result<(), String> {
+        let mut linter = Linter::new();
+        for name in self.allowed.iter() {
+            let kind = LintKind::from_name(name).ok_or_else(|| {
+                let names = LintKind::ALL.map(|kind| kind.name()).join(", ");
+                format!("Unknown lint `{name}` - expected one of: {names}")
+            })?;
+            linter = linter.with_disabled_lint(kind);
+        }
+
+        let mut files = Vec::new();
+        for path in self.paths.iter() {
+            collect_source_files(path, &mut files)?;
+        }
+
+        let mut num_lints = 0;
+        for file in files.iter() {
+            let source = fs::read_to_string(file)
+                .map_err(|err| format!("Failed to read `{}` - {}", file.display(), err))?;
+            let lints = linter
+                .lint_source(&source)
+                .map_err(|err| format!("Failed to parse `{}` - {}", file.display(), err))?;
+            for lint in lints.iter() {
+                let location = lint.location();
+                println!(
+                    "{}:{}:{}: warning: {} [{}]",
+                    file.display(),
+                    location.line(),
+                    location.column(),
+                    lint.message(),
+                    lint.kind()
+                );
+            }
+            num_lints += lints.len();
+        }
+
+        if num_lints > 0 {
+            println!("found {num_lints} lints in {} files", files.len());
+            if self.deny_warnings {
+                return Err(format!("{num_lints} lints were reported"));
+            }
+        }
+        Ok(())
+    }
+}
@@ -2,6 +2,9 @@ mod bundle;
 mod compile;
 mod data;
 mod debug;
+mod disasm;
+mod fmt;
+mod lint;
 mod prove;
 mod repl;
 mod run;
@@ -11,6 +14,9 @@ pub use bundle::BundleCmd;
 pub use compile::CompileCmd;
 pub use data::InputFile;
 pub use debug::DebugCmd;
+pub use disasm::DisasmCmd;
+pub use fmt::FmtCmd;
+pub use lint::LintCmd;
 pub use prove::ProveCmd;
 pub use repl::ReplCmd;
 pub use run::RunCmd;
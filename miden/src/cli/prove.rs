@@ -1,14 +1,16 @@
 use super::data::{instrument, Debug, InputFile, Libraries, OutputFile, ProgramFile, ProofFile};
 use clap::Parser;
-use miden_vm::ProvingOptions;
-use processor::{DefaultHost, ExecutionOptions, ExecutionOptionsError, Program};
+use miden_vm::{check_constraints, ProvingOptions};
+use processor::{
+    DefaultHost, ExecutionOptions, ExecutionOptionsError, ExecutionTrace, Program, StackInputs,
+};
 
 use std::{path::PathBuf, time::Instant};
 
 #[derive(Debug, Clone, Parser)]
 #[clap(about = "Prove a miden program")]
 pub struct ProveCmd {
-    /// Path to .masm assembly file
+    /// Path to .masm assembly file or .masb compiled program file
     #[clap(short = 'a', long = "assembly", value_parser)]
     assembly_file: PathBuf,
 
@@ -51,6 +53,11 @@ pub struct ProveCmd {
     /// Enable tracing to monitor execution of the VM
     #[clap(short = 't', long = "tracing")]
     tracing: bool,
+
+    /// Evaluate all AIR constraints against the execution trace before generating the proof and
+    /// report the first violated constraint
+    #[clap(long = "check-constraints")]
+    check_constraints: bool,
 }
 
 impl ProveCmd {
@@ -83,9 +90,21 @@ impl ProveCmd {
         let proving_options = self.get_proof_options().map_err(|err| format!("{err}"))?;
 
         // execute program and generate proof
-        let (stack_outputs, proof) =
-            prover::prove(&program, stack_inputs, host, proving_options)
-                .map_err(|err| format!("Failed to prove program - {:?}", err))?;
+        let mut trace = processor::execute(
+            &program,
+            stack_inputs.clone(),
+            host,
+            *proving_options.execution_options(),
+        )
+        .map_err(|err| format!("Failed to generate execution trace = {:?}", err))?;
+        let stack_outputs = trace.stack_outputs().clone();
+
+        if self.check_constraints {
+            check_trace_constraints(&mut trace, stack_inputs.clone())?;
+        }
+
+        let proof = prover::prove_trace(trace, stack_inputs, proving_options)
+            .map_err(|err| format!("Failed to prove program - {:?}", err))?;
 
         println!(
             "Program with hash {} proved in {} ms",
@@ -132,3 +151,16 @@ fn load_data(params: &ProveCmd) -> Result<(Program, InputFile), String> {
 
     Ok((program, input_data))
 }
+
+/// Evaluates all AIR constraints against the execution trace of the program.
+#[instrument(skip_all)]
+fn check_trace_constraints(
+    trace: &mut ExecutionTrace,
+    stack_inputs: StackInputs,
+) -> Result<(), String> {
+    check_constraints(trace, stack_inputs)
+        .map_err(|violation| format!("Execution trace violates AIR constraints - {violation}"))?;
+    println!("Execution trace satisfies all AIR constraints");
+
+    Ok(())
+}
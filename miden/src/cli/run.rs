@@ -1,12 +1,17 @@
 use super::data::{instrument, Debug, InputFile, Libraries, OutputFile, ProgramFile};
 use clap::Parser;
-use processor::{DefaultHost, ExecutionOptions, ExecutionTrace};
-use std::{path::PathBuf, time::Instant};
+use processor::{utils::ByteWriter, DefaultHost, ExecutionOptions, ExecutionTrace};
+use std::{
+    fs,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    time::Instant,
+};
 
 #[derive(Debug, Clone, Parser)]
 #[clap(about = "Run a miden program")]
 pub struct RunCmd {
-    /// Path to .masm assembly file
+    /// Path to .masm assembly file or .masb compiled program file
     #[clap(short = 'a', long = "assembly", value_parser)]
     assembly_file: PathBuf,
 
@@ -37,6 +42,11 @@ pub struct RunCmd {
     /// Enable tracing to monitor execution of the VM
     #[clap(short = 't', long = "tracing")]
     tracing: bool,
+
+    /// Path to a file into which the execution trace should be exported; the trace is written as
+    /// CSV if the file has .csv extension, and in binary trace format otherwise
+    #[clap(long = "trace-out", value_parser)]
+    trace_out: Option<PathBuf>,
 }
 
 impl RunCmd {
@@ -63,6 +73,11 @@ impl RunCmd {
             println!("Output: {:?}", trace.stack_outputs().stack_truncated(self.num_outputs));
         }
 
+        if let Some(trace_path) = &self.trace_out {
+            write_trace(&trace, trace_path)?;
+            println!("Execution trace written to {}", trace_path.display());
+        }
+
         // calculate the percentage of padded rows
         let padding_percentage = (trace.trace_len_summary().padded_trace_len()
             - trace.trace_len_summary().trace_len())
@@ -126,3 +141,67 @@ fn run_program(params: &RunCmd) -> Result<(ExecutionTrace, [u8; 32]), String> {
 
     Ok((trace, program_hash))
 }
+
+/// Writes the execution trace into the specified file. The trace is written as CSV if the file has
+/// `.csv` extension, and in binary trace export format otherwise.
+///
+/// In both cases the trace is streamed into the file row by row.
+fn write_trace(trace: &ExecutionTrace, path: &Path) -> Result<(), String> {
+    let is_csv = path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("csv"));
+    let file = fs::File::create(path)
+        .map_err(|err| format!("Failed to create trace file {} - {}", path.display(), err))?;
+    let mut writer = BufWriter::new(file);
+
+    let result = if is_csv {
+        trace.write_csv(&mut writer)
+    } else {
+        let mut target = IoByteWriter::new(&mut writer);
+        trace.write_binary(&mut target);
+        target.finish()
+    };
+
+    result
+        .and_then(|_| writer.flush())
+        .map_err(|err| format!("Failed to write trace file {} - {}", path.display(), err))
+}
+
+// IO BYTE WRITER
+// ================================================================================================
+
+/// A [ByteWriter] which forwards all bytes to the wrapped [Write] target.
+///
+/// Unlike the [ByteWriter] implementation for [Write] targets, an I/O error does not cause a
+/// panic; instead, the first error is recorded, all subsequent writes are skipped, and the error
+/// is returned from [IoByteWriter::finish()].
+struct IoByteWriter<W: Write> {
+    target: W,
+    error: Option<io::Error>,
+}
+
+impl<W: Write> IoByteWriter<W> {
+    fn new(target: W) -> Self {
+        Self {
+            target,
+            error: None,
+        }
+    }
+
+    /// Returns the first I/O error encountered while writing, if any.
+    fn finish(self) -> io::Result<()> {
+        self.error.map_or(Ok(()), Err)
+    }
+}
+
+impl<W: Write> ByteWriter for IoByteWriter<W> {
+    fn write_u8(&mut self, value: u8) {
+        self.write_bytes(&[value]);
+    }
+
+    fn write_bytes(&mut self, values: &[u8]) {
+        if self.error.is_none() {
+            if let Err(err) = self.target.write_all(values) {
+                self.error = Some(err);
+            }
+        }
+    }
+}
@@ -6,15 +6,19 @@
 
 pub use assembly::{
     ast::{ModuleAst, ProgramAst},
-    Assembler, AssemblyError, ParsingError,
+    format_source, Assembler, AssemblyError, Lint, LintKind, Linter, OptimizationLevel,
+    OptimizationStats, ParsingError,
 };
 pub use processor::{
-    crypto, execute, execute_iter, utils, AdviceInputs, AdviceProvider, AsmOpInfo, DefaultHost,
-    ExecutionError, ExecutionTrace, Host, Kernel, MemAdviceProvider, Operation, Program,
-    ProgramInfo, StackInputs, VmState, VmStateIterator, ZERO,
+    crypto, execute, execute_fast, execute_iter, execute_until, resume, resume_until, utils,
+    AdviceInputs, AdviceProvider, AsmOpInfo, DefaultHost, ExecutionError, ExecutionOutputs,
+    ExecutionStatus, ExecutionTrace, Host, Kernel, MastStats, MemAdviceProvider, Operation,
+    ProcessSnapshot, ProcessState, Program, ProgramInfo, ProgramSerdeOptions, SourceLocation,
+    StackInputs, VmState, VmStateIterator, ZERO,
 };
 pub use prover::{
-    math, prove, Digest, ExecutionProof, FieldExtension, HashFunction, InputError, ProvingOptions,
+    check_constraints, math, prove, prove_trace, prove_with_output_region, ConstraintViolation,
+    Digest, ExecutionProof, FieldExtension, HashFunction, InputError, OutputRegion, ProvingOptions,
     StackOutputs, StarkProof, Word,
 };
-pub use verifier::{verify, VerificationError};
+pub use verifier::{verify, verify_with_output_region, VerificationError};
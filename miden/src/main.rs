@@ -27,7 +27,11 @@ pub enum Actions {
     Compile(cli::CompileCmd),
     Bundle(cli::BundleCmd),
     Debug(cli::DebugCmd),
+    Disasm(cli::DisasmCmd),
     Example(examples::ExampleOptions),
+    Fmt(cli::FmtCmd),
+    Lint(cli::LintCmd),
+    Profile(tools::Profile),
     Prove(cli::ProveCmd),
     Run(cli::RunCmd),
     Verify(cli::VerifyCmd),
@@ -40,10 +44,14 @@ impl Cli {
     pub fn execute(&self) -> Result<(), String> {
         match &self.action {
             Actions::Analyze(analyze) => analyze.execute(),
+            Actions::Profile(profile) => profile.execute(),
             Actions::Compile(compile) => compile.execute(),
             Actions::Bundle(compile) => compile.execute(),
             Actions::Debug(debug) => debug.execute(),
+            Actions::Disasm(disasm) => disasm.execute(),
             Actions::Example(example) => example.execute(),
+            Actions::Fmt(fmt) => fmt.execute(),
+            Actions::Lint(lint) => lint.execute(),
             Actions::Prove(prove) => prove.execute(),
             Actions::Run(run) => run.execute(),
             Actions::Verify(verify) => verify.execute(),
@@ -82,9 +90,11 @@ pub fn main() {
         registry.with(format).init();
     }
 
-    // execute cli action
+    // execute cli action; exit with a non-zero status on failure so that the CLI can be used in
+    // scripts (e.g., `miden fmt --check` in CI)
     if let Err(error) = cli.execute() {
         println!("{}", error);
+        std::process::exit(1);
     }
 }
 
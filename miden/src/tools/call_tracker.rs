@@ -0,0 +1,385 @@
+use assembly::{
+    ast::{CodeBody, Instruction, Node, ProgramAst},
+    Library, ProcedureId, ProcedureName,
+};
+use miden_vm::Operation;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+// CALL TRACKER
+// ================================================================================================
+
+/// Tracks the procedure call stack of a program from its executed operations and the contexts
+/// of its executed assembly instructions.
+///
+/// Procedures invoked via `exec` are inlined into their callers and their code is often merged
+/// into the code blocks of the callers. Thus, procedure calls cannot be observed directly, and
+/// are instead inferred from the changes of the context of executed assembly instructions: a
+/// procedure is entered when an instruction of a procedure which is not on the call stack is
+/// executed, and it returns either when an instruction of one of its callers is executed or when
+/// the code block containing its body ends. Procedures invoked via `call`, `syscall` and `dyncall`
+/// always return at the end of their `CALL`, `SYSCALL` or `DYN` block. Changes of the context
+/// which are ambiguous are resolved by a [CallResolver].
+///
+/// Since the compiled program does not preserve procedure boundaries, some call stacks cannot be
+/// determined exactly (e.g., when a procedure ends by invoking a procedure which its caller
+/// invokes next, or when consecutive procedures are not separated by any instructions of the
+/// caller).
+///
+/// Contexts are identified by indexes, and the context of the program's entry point is specified
+/// when the tracker is created.
+#[derive(Debug)]
+pub struct CallTracker {
+    /// Contexts of the procedures currently on the call stack, starting with the entry point.
+    stack: Vec<usize>,
+    /// Block depth at which each procedure on the call stack must have returned; the depth is a
+    /// lower bound on the depth of the procedure's body block.
+    return_depths: Vec<usize>,
+    /// Blocks currently on the decoder's block stack; blocks started by `CALL`, `SYSCALL` and
+    /// `DYN` operations contain the depth of the call stack prior to the call.
+    blocks: Vec<Option<usize>>,
+    /// Minimum block depth reached since the last executed assembly instruction.
+    min_depth: usize,
+    /// Set if a procedure was invoked via a call since the last executed assembly instruction.
+    pending_call: bool,
+}
+
+impl CallTracker {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new tracker whose call stack contains only the specified entry point.
+    pub fn new(entry_point: usize) -> Self {
+        Self {
+            stack: vec![entry_point],
+            return_depths: vec![0],
+            blocks: Vec::new(),
+            min_depth: 0,
+            pending_call: false,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the contexts of the procedures on the call stack, starting with the entry point.
+    pub fn stack(&self) -> &[usize] {
+        &self.stack
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Updates the call stack upon executing an assembly instruction of the specified context.
+    ///
+    /// `next_context` is the context of the next executed instruction of a different context, if
+    /// known. This must be invoked before the operation executed in the same cycle is recorded.
+    ///
+    /// Returns the number of procedures entered, i.e., pushed onto the call stack.
+    pub fn enter_context(
+        &mut self,
+        context: usize,
+        next_context: Option<usize>,
+        resolver: &CallResolver,
+    ) -> usize {
+        let mut num_entered = 0;
+        if Some(&context) != self.stack.last() {
+            num_entered = resolver.enter(&mut self.stack, context, next_context, self.pending_call);
+            self.pending_call = false;
+
+            // if new blocks were started since the last executed instruction, the body of the
+            // entered procedure starts at a depth greater than the minimum depth reached since
+            // then; otherwise, the procedure was merged into the current block
+            let return_depth = self.blocks.len().min(self.min_depth + 1);
+            self.return_depths.truncate(self.stack.len() - num_entered);
+            self.return_depths.resize(self.stack.len(), return_depth);
+        }
+        self.min_depth = self.blocks.len();
+        num_entered
+    }
+
+    /// Updates the call stack upon executing the specified operation.
+    pub fn record_op(&mut self, op: Operation) {
+        match op {
+            Operation::Join | Operation::Split | Operation::Loop | Operation::Span => {
+                self.start_block(false)
+            }
+            Operation::Call | Operation::SysCall | Operation::Dyn => self.start_block(true),
+            Operation::End => self.end_block(),
+            _ => (),
+        }
+    }
+
+    /// Updates the call stack upon entering a code block; `is_call` specifies whether the block
+    /// was started by a `CALL`, `SYSCALL` or `DYN` operation.
+    pub fn start_block(&mut self, is_call: bool) {
+        // remember the depth of the call stack prior to the call so that the call stack can be
+        // restored once the called procedure returns
+        self.blocks.push(is_call.then_some(self.stack.len()));
+        self.pending_call |= is_call;
+    }
+
+    /// Updates the call stack upon exiting the most recently started code block.
+    pub fn end_block(&mut self) {
+        if let Some(Some(depth)) = self.blocks.pop() {
+            self.stack.truncate(depth);
+            self.pending_call = false;
+        }
+        while self.return_depths.last().is_some_and(|&depth| depth > self.blocks.len()) {
+            self.return_depths.pop();
+        }
+        self.stack.truncate(self.return_depths.len());
+        self.return_depths.truncate(self.stack.len());
+        self.min_depth = self.min_depth.min(self.blocks.len());
+    }
+}
+
+// CALL RESOLVER
+// ================================================================================================
+
+/// Infers procedure calls from changes of the executed context using the static call graph.
+///
+/// A resolver without a call graph (e.g., when only the compiled program is available) assumes
+/// that each newly executed context is invoked by the procedure at the top of the call stack.
+#[derive(Debug, Default)]
+pub struct CallResolver {
+    /// Procedures invoked by each procedure.
+    callees: Vec<BTreeSet<usize>>,
+    /// Procedures which each procedure may invoke before executing any instructions of its own.
+    entry_callees: Vec<BTreeSet<usize>>,
+}
+
+impl CallResolver {
+    /// Returns a new resolver for the specified call graph; `context_id` maps procedure names to
+    /// context indexes.
+    pub fn new(call_graph: &CallGraph, mut context_id: impl FnMut(&str) -> usize) -> Self {
+        let mut callees: Vec<BTreeSet<usize>> = Vec::new();
+        let mut entry_callees: Vec<BTreeSet<usize>> = Vec::new();
+        for (caller, edges) in call_graph.edges.iter() {
+            let caller = context_id(caller);
+            for (callee, is_entry) in edges.iter() {
+                let callee = context_id(callee);
+                let len = caller.max(callee) + 1;
+                if callees.len() < len {
+                    callees.resize(len, BTreeSet::new());
+                    entry_callees.resize(len, BTreeSet::new());
+                }
+                callees[caller].insert(callee);
+                if *is_entry {
+                    entry_callees[caller].insert(callee);
+                }
+            }
+        }
+
+        Self {
+            callees,
+            entry_callees,
+        }
+    }
+
+    /// Updates the call stack upon a change of the executed context to `context`.
+    ///
+    /// If the context is already on the call stack, the procedures above it have returned.
+    /// Otherwise, the context is entered from the topmost procedure on the stack which may invoke
+    /// it, either directly or via procedures which invoke each other before executing any
+    /// instructions of their own. Among the possible call paths, a path via `next_context` is
+    /// preferred as the next context is likely to be its caller.
+    ///
+    /// `is_call` specifies that the context is being entered via a call from the procedure at the
+    /// top of the stack.
+    ///
+    /// Returns the number of procedures entered, i.e., pushed onto the call stack.
+    fn enter(
+        &self,
+        stack: &mut Vec<usize>,
+        context: usize,
+        next_context: Option<usize>,
+        is_call: bool,
+    ) -> usize {
+        let min_depth = match is_call {
+            true => stack.len(),
+            false => match stack.iter().position(|&frame| frame == context) {
+                Some(pos) => {
+                    stack.truncate(pos + 1);
+                    return 0;
+                }
+                None => 1,
+            },
+        };
+
+        for depth in (min_depth..=stack.len()).rev() {
+            let mut paths = self.call_paths(stack[depth - 1], context);
+            if paths.is_empty() {
+                continue;
+            }
+
+            paths.sort_by_key(|path| path.len());
+            let path = next_context
+                .filter(|next| !stack[..depth].contains(next))
+                .and_then(|next| paths.iter().find(|path| path.contains(&next)))
+                .unwrap_or(&paths[0]);
+
+            stack.truncate(depth);
+            stack.extend(path.iter().copied());
+            return path.len();
+        }
+
+        // the caller could not be determined from the call graph (e.g., the procedure was invoked
+        // dynamically)
+        stack.push(context);
+        1
+    }
+
+    /// Returns all call paths via which `caller` may enter `context`, i.e., a direct call and
+    /// paths via each of the callees of `caller` which may invoke `context` before executing any
+    /// instructions of their own. The paths exclude `caller` and include `context`.
+    fn call_paths(&self, caller: usize, context: usize) -> Vec<Vec<usize>> {
+        self.callees(caller)
+            .filter_map(|callee| match callee == context {
+                true => Some(vec![context]),
+                false => self.entry_path(callee, context).map(|path| {
+                    let mut full_path = vec![callee];
+                    full_path.extend(path);
+                    full_path
+                }),
+            })
+            .collect()
+    }
+
+    /// Returns the procedures invoked by the specified procedure.
+    fn callees(&self, caller: usize) -> impl Iterator<Item = usize> + '_ {
+        self.callees.get(caller).into_iter().flatten().copied()
+    }
+
+    /// Returns the shortest path from `from` to `to` over procedures invoked before executing any
+    /// instructions of their callers. The path excludes `from` and includes `to`.
+    fn entry_path(&self, from: usize, to: usize) -> Option<Vec<usize>> {
+        let mut visited = BTreeSet::from([from]);
+        let mut queue = VecDeque::from([(from, Vec::new())]);
+        while let Some((caller, path)) = queue.pop_front() {
+            for &callee in self.entry_callees.get(caller).into_iter().flatten() {
+                if visited.insert(callee) {
+                    let mut path = path.clone();
+                    path.push(callee);
+                    if callee == to {
+                        return Some(path);
+                    }
+                    queue.push_back((callee, path));
+                }
+            }
+        }
+        None
+    }
+}
+
+// CALL GRAPH
+// ================================================================================================
+
+/// Static call graph of a program and the libraries it uses, keyed by fully qualified procedure
+/// names (as returned by [procedure_name()](super::procedure_name)).
+#[derive(Debug, Default)]
+pub struct CallGraph {
+    /// Procedures invoked by each procedure; each callee is flagged if it may be invoked before
+    /// the caller executes any instructions of its own.
+    edges: BTreeMap<String, BTreeMap<String, bool>>,
+}
+
+impl CallGraph {
+    /// Builds the call graph of the specified program which uses procedures of the specified
+    /// library.
+    pub fn new(program: &ProgramAst, library: &impl Library) -> Self {
+        // map ids of library procedures to their fully qualified names
+        let mut proc_names = BTreeMap::new();
+        for module in library.modules() {
+            for proc in module.ast.procs() {
+                let id = ProcedureId::from_name(&proc.name, &module.path);
+                proc_names.insert(id, format!("{}::{}", module.path, proc.name));
+            }
+        }
+        for module in library.modules() {
+            for reexport in module.ast.reexported_procs() {
+                if let Some(name) = proc_names.get(&reexport.proc_id()).cloned() {
+                    proc_names.insert(reexport.get_alias_id(&module.path), name);
+                }
+            }
+        }
+
+        let mut graph = Self::default();
+        for module in library.modules() {
+            let locals: Vec<String> = module
+                .ast
+                .procs()
+                .iter()
+                .map(|proc| format!("{}::{}", module.path, proc.name))
+                .collect();
+            for (proc, name) in module.ast.procs().iter().zip(locals.iter()) {
+                graph.add_callees(name, &proc.body, &locals, &proc_names, true);
+            }
+        }
+
+        let locals: Vec<String> =
+            program.procedures().iter().map(|proc| proc.name.to_string()).collect();
+        for (proc, name) in program.procedures().iter().zip(locals.iter()) {
+            graph.add_callees(name, &proc.body, &locals, &proc_names, true);
+        }
+        graph.add_callees(
+            ProcedureName::MAIN_PROC_NAME,
+            program.body(),
+            &locals,
+            &proc_names,
+            true,
+        );
+
+        graph
+    }
+
+    /// Adds procedures invoked from the specified code body to the callees of `caller`.
+    ///
+    /// `is_entry` specifies whether the body is executed before the caller executes any
+    /// instructions of its own. Returns `true` if the body may complete without executing any
+    /// instructions of the caller.
+    fn add_callees(
+        &mut self,
+        caller: &str,
+        body: &CodeBody,
+        locals: &[String],
+        proc_names: &BTreeMap<ProcedureId, String>,
+        mut is_entry: bool,
+    ) -> bool {
+        for node in body.nodes() {
+            let callee = match node {
+                Node::Instruction(Instruction::ExecLocal(idx) | Instruction::CallLocal(idx)) => {
+                    locals.get(*idx as usize)
+                }
+                Node::Instruction(
+                    Instruction::ExecImported(id)
+                    | Instruction::CallImported(id)
+                    | Instruction::SysCall(id),
+                ) => proc_names.get(id),
+                Node::Instruction(_) => {
+                    is_entry = false;
+                    None
+                }
+                Node::IfElse {
+                    true_case,
+                    false_case,
+                } => {
+                    let true_case =
+                        self.add_callees(caller, true_case, locals, proc_names, is_entry);
+                    let false_case =
+                        self.add_callees(caller, false_case, locals, proc_names, is_entry);
+                    is_entry &= true_case || false_case;
+                    None
+                }
+                Node::Repeat { body, .. } | Node::While { body } => {
+                    is_entry &= self.add_callees(caller, body, locals, proc_names, is_entry);
+                    None
+                }
+            };
+
+            if let Some(callee) = callee {
+                let edges = self.edges.entry(caller.to_string()).or_default();
+                *edges.entry(callee.clone()).or_insert(false) |= is_entry;
+            }
+        }
+        is_entry
+    }
+}
@@ -1,11 +1,18 @@
 use super::{cli::InputFile, ProgramError};
+use assembly::ProcedureName;
 use clap::Parser;
-use core::fmt;
-use miden_vm::{Assembler, DefaultHost, Host, Operation, StackInputs};
+use core::{fmt, ops::AddAssign};
+use miden_vm::{math::Felt, Assembler, DefaultHost, Host, Operation, Program, StackInputs};
 use processor::{AsmOpInfo, TraceLenSummary};
-use std::{fs, path::PathBuf};
+use std::{borrow::Cow, collections::BTreeMap, fs, path::PathBuf};
 use stdlib::StdLibrary;
 
+mod call_tracker;
+pub use call_tracker::{CallResolver, CallTracker};
+
+mod profiler;
+pub use profiler::Profile;
+
 // CLI
 // ================================================================================================
 
@@ -72,6 +79,10 @@ pub struct ExecutionDetails {
     asm_op_stats: Vec<AsmOpStats>,
     /// Information about VM components trace lengths.
     trace_len_summary: TraceLenSummary,
+    /// Chiplet rows and range checks caused by each assembly instruction, see [ChipletsCost].
+    instruction_chiplets_costs: BTreeMap<String, ChipletsCost>,
+    /// Chiplet rows and range checks caused by the instructions of each procedure.
+    procedure_chiplets_costs: BTreeMap<String, ChipletsCost>,
 }
 
 impl ExecutionDetails {
@@ -91,6 +102,28 @@ impl ExecutionDetails {
         self.trace_len_summary
     }
 
+    /// Returns the chiplet rows and range checks caused by each assembly instruction.
+    ///
+    /// Operations which are not a part of any assembly instruction (i.e., control flow operations)
+    /// are keyed by the name of the operation.
+    pub fn instruction_chiplets_costs(&self) -> &BTreeMap<String, ChipletsCost> {
+        &self.instruction_chiplets_costs
+    }
+
+    /// Returns the chiplet rows and range checks caused by the instructions of each procedure.
+    pub fn procedure_chiplets_costs(&self) -> &BTreeMap<String, ChipletsCost> {
+        &self.procedure_chiplets_costs
+    }
+
+    /// Returns the sum of the chiplet rows and range checks attributed to all instructions.
+    pub fn total_chiplets_cost(&self) -> ChipletsCost {
+        let mut total = ChipletsCost::default();
+        for cost in self.instruction_chiplets_costs.values() {
+            total += *cost;
+        }
+        total
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -128,6 +161,17 @@ impl ExecutionDetails {
         }
     }
 
+    /// Attributes the specified chiplets cost to the specified instruction and procedure.
+    pub fn record_chiplets_cost(
+        &mut self,
+        instruction: String,
+        procedure: &str,
+        cost: ChipletsCost,
+    ) {
+        *self.instruction_chiplets_costs.entry(instruction).or_default() += cost;
+        *self.procedure_chiplets_costs.entry(procedure.to_string()).or_default() += cost;
+    }
+
     /// Sets the information about lengths of the trace parts.
     pub fn set_trace_len_summary(&mut self, extended_cycles_info: &TraceLenSummary) {
         self.trace_len_summary = *extended_cycles_info;
@@ -197,10 +241,72 @@ impl fmt::Display for ExecutionDetails {
 
         writeln!(f, "\nTotal number of NOOPs executed: {}", total_noops)?;
 
+        let total = self.total_chiplets_cost();
+        let chiplets_len = self.trace_len_summary().chiplets_trace_len();
+        writeln!(
+            f,
+            "\nAttributed chiplets rows and range checks:
+├── Hash chiplet rows: {} of {}
+├── Bitwise chiplet rows: {} of {}
+├── Memory chiplet rows: {} of {}
+├── Kernel ROM accesses: {} ({} rows)
+└── Range checks: {} by u32 operations, {} by memory accesses",
+            total.hasher_rows,
+            chiplets_len.hash_chiplet_len(),
+            total.bitwise_rows,
+            chiplets_len.bitwise_chiplet_len(),
+            total.memory_rows,
+            chiplets_len.memory_chiplet_len(),
+            total.kernel_rom_accesses,
+            chiplets_len.kernel_rom_len(),
+            total.u32_range_checks,
+            total.memory_range_checks,
+        )?;
+
+        writeln!(f)?;
+        write_chiplets_costs(f, "Assembly instruction", self.instruction_chiplets_costs())?;
+        writeln!(f)?;
+        write_chiplets_costs(f, "Procedure", self.procedure_chiplets_costs())?;
+
         Ok(())
     }
 }
 
+/// Writes a table of the specified chiplets costs into the provided formatter.
+fn write_chiplets_costs(
+    f: &mut fmt::Formatter<'_>,
+    title: &str,
+    costs: &BTreeMap<String, ChipletsCost>,
+) -> fmt::Result {
+    let padding = costs.keys().fold(20, |max, name| name.len().max(max));
+
+    writeln!(
+        f,
+        "{title: <padding$} | {0: <10} | {1: <10} | {2: <10} | {3: <10} | {4: <10} | Memory RC",
+        "Hasher", "Bitwise", "Memory", "Kernel ROM", "u32 RC",
+    )?;
+
+    let delimeter = "-".repeat(padding + 80);
+    writeln!(f, "{delimeter}")?;
+
+    for (name, cost) in costs {
+        writeln!(
+            f,
+            "{0: <width$} | {1: <10} | {2: <10} | {3: <10} | {4: <10} | {5: <10} | {6:}",
+            name,
+            cost.hasher_rows,
+            cost.bitwise_rows,
+            cost.memory_rows,
+            cost.kernel_rom_accesses,
+            cost.u32_range_checks,
+            cost.memory_range_checks,
+            width = padding,
+        )?;
+    }
+
+    Ok(())
+}
+
 /// Returns program analysis of a given program.
 pub fn analyze<H>(
     program: &str,
@@ -210,30 +316,66 @@ pub fn analyze<H>(
 where
     H: Host,
 {
-    let program = Assembler::default()
-        .with_debug_mode(true)
-        .with_library(&StdLibrary::default())
-        .map_err(ProgramError::AssemblyError)?
-        .compile(program)
-        .map_err(ProgramError::AssemblyError)?;
+    let program = assemble(program)?;
     let mut execution_details = ExecutionDetails::default();
 
     let vm_state_iterator = processor::execute_iter(&program, stack_inputs, host);
     execution_details.set_trace_len_summary(vm_state_iterator.trace_len_summary());
 
+    // chiplets costs of an operation depend on the state of the stack prior to its execution, and
+    // operations without an assembly instruction are attributed to the last executed procedure
+    let mut prev_stack = Vec::new();
+    let mut procedure = ProcedureName::MAIN_PROC_NAME.to_string();
+
     for state in vm_state_iterator {
         let vm_state = state.map_err(ProgramError::ExecutionError)?;
         if matches!(vm_state.op, Some(Operation::Noop)) {
             execution_details.incr_noop_count();
         }
+        if let Some(name) = vm_state.asmop.as_ref().map(procedure_name) {
+            if name != procedure {
+                procedure = name.into_owned();
+            }
+        }
+        if let Some(op) = vm_state.op {
+            let cost = ChipletsCost::of_operation(op, &prev_stack);
+            if cost != ChipletsCost::default() {
+                let instruction = match &vm_state.asmop {
+                    Some(asmop_info) => asmop_info.op_generalized(),
+                    None => op.to_string(),
+                };
+                execution_details.record_chiplets_cost(instruction, &procedure, cost);
+            }
+        }
         if let Some(asmop_info) = vm_state.asmop {
             execution_details.record_asmop(asmop_info);
         }
+        prev_stack = vm_state.stack;
     }
 
     Ok(execution_details)
 }
 
+/// Returns the name of the procedure which contains the specified assembly instruction; names of
+/// library procedures are qualified with the paths of their modules (e.g.,
+/// `std::math::u64::checked_add`), while names of the program's own procedures are kept as is.
+pub fn procedure_name(asmop: &AsmOpInfo) -> Cow<'_, str> {
+    match asmop.module_path() {
+        Some(module_path) => Cow::Owned(format!("{module_path}::{}", asmop.context_name())),
+        None => Cow::Borrowed(asmop.context_name()),
+    }
+}
+
+/// Compiles the given program in debug mode against the standard library.
+fn assemble(program: &str) -> Result<Program, ProgramError> {
+    Assembler::default()
+        .with_debug_mode(true)
+        .with_library(&StdLibrary::default())
+        .map_err(ProgramError::AssemblyError)?
+        .compile(program)
+        .map_err(ProgramError::AssemblyError)
+}
+
 // ASMOP STATS
 // ================================================================================================
 
@@ -285,13 +427,119 @@ impl AsmOpStats {
     }
 }
 
+// CHIPLETS COST
+// ================================================================================================
+
+/// Number of hasher chiplet rows needed to compute a single permutation of the hash function.
+const HASHER_ROWS_PER_PERMUTATION: usize = 8;
+
+/// Number of bitwise chiplet rows needed to compute a single bitwise operation.
+const BITWISE_ROWS_PER_OPERATION: usize = 8;
+
+/// Number of range checks performed by the range checker for each row of the memory chiplet.
+const RANGE_CHECKS_PER_MEMORY_ROW: usize = 2;
+
+/// Number of range checks performed by the range checker for each u32 operation which requires
+/// its results to be range-checked.
+const RANGE_CHECKS_PER_U32_OPERATION: usize = 4;
+
+/// Contains the number of chiplet rows and range checks which were caused by executing a set of
+/// operations.
+///
+/// The costs are attributed to operations statically, which introduces the following deviations
+/// from the actual chiplet traces:
+/// - A span block is hashed in full when the block is entered; here, each batch of the block is
+///   charged a single permutation by its `SPAN` or `RESPAN` operation instead.
+/// - The kernel ROM contains a row for each kernel procedure whether it was called or not, and
+///   the first call to a procedure does not add any rows. Thus, only the accesses to the kernel
+///   ROM by `SYSCALL` operations are counted.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct ChipletsCost {
+    /// Number of rows added to the hasher chiplet.
+    pub hasher_rows: usize,
+    /// Number of rows added to the bitwise chiplet.
+    pub bitwise_rows: usize,
+    /// Number of rows added to the memory chiplet.
+    pub memory_rows: usize,
+    /// Number of accesses to the kernel ROM chiplet.
+    pub kernel_rom_accesses: usize,
+    /// Number of range checks performed on the results of u32 operations.
+    pub u32_range_checks: usize,
+    /// Number of range checks performed on the address and clock deltas of memory accesses.
+    pub memory_range_checks: usize,
+}
+
+impl ChipletsCost {
+    /// Returns the cost of executing the specified operation.
+    ///
+    /// `stack` is the state of the operand stack prior to the execution of the operation (top
+    /// first), which is used to determine the depth of Merkle paths.
+    pub fn of_operation(op: Operation, stack: &[Felt]) -> Self {
+        let mut cost = Self::default();
+        match op {
+            Operation::Join
+            | Operation::Split
+            | Operation::Loop
+            | Operation::Call
+            | Operation::Dyn
+            | Operation::Span
+            | Operation::Respan
+            | Operation::HPerm => cost.hasher_rows = HASHER_ROWS_PER_PERMUTATION,
+            Operation::SysCall => {
+                cost.hasher_rows = HASHER_ROWS_PER_PERMUTATION;
+                cost.kernel_rom_accesses = 1;
+            }
+            Operation::MpVerify => {
+                cost.hasher_rows = HASHER_ROWS_PER_PERMUTATION * merkle_path_depth(stack);
+            }
+            Operation::MrUpdate => {
+                cost.hasher_rows = 2 * HASHER_ROWS_PER_PERMUTATION * merkle_path_depth(stack);
+            }
+            Operation::U32and | Operation::U32xor => cost.bitwise_rows = BITWISE_ROWS_PER_OPERATION,
+            Operation::MLoad | Operation::MLoadW | Operation::MStore | Operation::MStoreW => {
+                cost.memory_rows = 1;
+            }
+            Operation::MStream | Operation::Pipe | Operation::RCombBase => cost.memory_rows = 2,
+            Operation::U32split
+            | Operation::U32assert2(_)
+            | Operation::U32add
+            | Operation::U32add3
+            | Operation::U32sub
+            | Operation::U32mul
+            | Operation::U32madd
+            | Operation::U32div => cost.u32_range_checks = RANGE_CHECKS_PER_U32_OPERATION,
+            _ => (),
+        }
+        cost.memory_range_checks = cost.memory_rows * RANGE_CHECKS_PER_MEMORY_ROW;
+        cost
+    }
+}
+
+impl AddAssign for ChipletsCost {
+    fn add_assign(&mut self, rhs: Self) {
+        self.hasher_rows += rhs.hasher_rows;
+        self.bitwise_rows += rhs.bitwise_rows;
+        self.memory_rows += rhs.memory_rows;
+        self.kernel_rom_accesses += rhs.kernel_rom_accesses;
+        self.u32_range_checks += rhs.u32_range_checks;
+        self.memory_range_checks += rhs.memory_range_checks;
+    }
+}
+
+/// Returns the depth of the Merkle path used by `MPVERIFY` and `MRUPDATE` operations, which is
+/// located at the 5th position of the stack.
+fn merkle_path_depth(stack: &[Felt]) -> usize {
+    stack.get(4).map(|depth| depth.as_int() as usize).unwrap_or_default()
+}
+
 // TESTS
 // ================================================================================================
 
 #[cfg(test)]
 mod tests {
-    use super::{AsmOpStats, ExecutionDetails, StackInputs};
+    use super::{AsmOpStats, ChipletsCost, ExecutionDetails, StackInputs};
     use processor::{ChipletsLengths, DefaultHost, TraceLenSummary};
+    use std::collections::BTreeMap;
 
     #[test]
     fn analyze_test() {
@@ -315,10 +563,79 @@ mod tests {
                 39,
                 ChipletsLengths::from_parts(8, 0, 2, 0),
             ),
+            instruction_chiplets_costs: BTreeMap::from([
+                ("loc_store".to_string(), memory_cost(1)),
+                ("mem_storew".to_string(), memory_cost(1)),
+                ("span".to_string(), hasher_cost(8)),
+            ]),
+            procedure_chiplets_costs: BTreeMap::from([
+                (
+                    "#main".to_string(),
+                    ChipletsCost {
+                        hasher_rows: 8,
+                        ..memory_cost(1)
+                    },
+                ),
+                ("foo".to_string(), memory_cost(1)),
+            ]),
         };
         assert_eq!(execution_details, expected_details);
     }
 
+    #[test]
+    fn analyze_test_chiplets_costs() {
+        let source = "
+            use.std::math::u64
+
+            begin
+                push.1.2.3.4 mem_storew.8 dropw
+                push.5.6.7.8 mem_storew.9 dropw
+                padw padw padw push.8 mem_stream dropw dropw dropw drop
+                push.1.2 u32and push.3.4 u32xor push.7.8 u32wrapping_add
+                push.1.0.2.0 exec.u64::wrapping_add
+                push.1.0.2.0 exec.u64::and
+                push.1 if.true mem_load.8 else mem_load.9 end
+                push.3 push.1 while.true push.1 sub dup neq.0 end drop
+            end";
+        let stack_inputs = StackInputs::default();
+        let host = DefaultHost::default();
+        let execution_details = super::analyze(source, stack_inputs, host).unwrap();
+
+        // all hasher, bitwise and memory rows are attributed to the executed instructions
+        let total = execution_details.total_chiplets_cost();
+        let chiplets_len = execution_details.trace_len_summary().chiplets_trace_len();
+        assert_eq!(total.hasher_rows, chiplets_len.hash_chiplet_len());
+        assert_eq!(total.bitwise_rows, chiplets_len.bitwise_chiplet_len());
+        assert_eq!(total.memory_rows, chiplets_len.memory_chiplet_len());
+        assert_eq!(total.memory_range_checks, 2 * total.memory_rows);
+
+        let costs = execution_details.instruction_chiplets_costs();
+        assert_eq!(costs["u32and"].bitwise_rows, 24);
+        assert_eq!(costs["u32xor"].bitwise_rows, 8);
+        assert_eq!(costs["u32wrapping_add"].u32_range_checks, 4);
+        assert_eq!(costs["mem_load"].memory_rows, 1);
+        assert_eq!(costs["mem_stream"].memory_rows, 2);
+
+        let costs = execution_details.procedure_chiplets_costs();
+        assert_eq!(costs["std::math::u64::overflowing_add"].u32_range_checks, 8);
+        assert_eq!(costs["std::math::u64::and"].bitwise_rows, 16);
+    }
+
+    fn hasher_cost(hasher_rows: usize) -> ChipletsCost {
+        ChipletsCost {
+            hasher_rows,
+            ..Default::default()
+        }
+    }
+
+    fn memory_cost(memory_rows: usize) -> ChipletsCost {
+        ChipletsCost {
+            memory_rows,
+            memory_range_checks: 2 * memory_rows,
+            ..Default::default()
+        }
+    }
+
     #[test]
     fn analyze_test_execution_error() {
         let source = "begin div end";
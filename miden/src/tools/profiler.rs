@@ -0,0 +1,488 @@
+use super::{
+    assemble,
+    call_tracker::{CallGraph, CallResolver, CallTracker},
+    procedure_name, ProgramError,
+};
+use crate::cli::InputFile;
+use assembly::{ast::ProgramAst, ProcedureName};
+use clap::Parser;
+use core::fmt;
+use miden_vm::{DefaultHost, Host, Operation, StackInputs};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fs,
+    io::{BufWriter, Write},
+    path::PathBuf,
+};
+use stdlib::StdLibrary;
+
+// CLI
+// ================================================================================================
+
+/// Defines cli interface
+#[derive(Debug, Clone, Parser)]
+#[clap(about = "Profile cycles spent in procedures of a miden program")]
+pub struct Profile {
+    /// Path to .masm assembly file
+    #[clap(short = 'a', long = "assembly", value_parser)]
+    assembly_file: PathBuf,
+    /// Path to .inputs file
+    #[clap(short = 'i', long = "input", value_parser)]
+    input_file: Option<PathBuf>,
+    /// Path to a file into which call stacks should be written in folded stack format, which can
+    /// be rendered by flame graph tools (e.g., inferno or flamegraph.pl)
+    #[clap(short = 'f', long = "folded", value_parser)]
+    folded_file: Option<PathBuf>,
+}
+
+/// Implements CLI execution logic
+impl Profile {
+    pub fn execute(&self) -> Result<(), String> {
+        let program = fs::read_to_string(&self.assembly_file)
+            .map_err(|e| format!("could not read masm file: {e}"))?;
+
+        // load input data from file
+        let input_data = InputFile::read(&self.input_file, &self.assembly_file)?;
+
+        // fetch the stack and program inputs from the arguments
+        let stack_inputs = input_data.parse_stack_inputs()?;
+        let host = DefaultHost::new(input_data.parse_advice_provider()?);
+
+        let execution_profile =
+            profile(program.as_str(), stack_inputs, host).map_err(|err| err.to_string())?;
+
+        if let Some(folded_path) = &self.folded_file {
+            let file = fs::File::create(folded_path).map_err(|err| {
+                format!("Failed to create file {} - {err}", folded_path.display())
+            })?;
+            let mut writer = BufWriter::new(file);
+            execution_profile
+                .write_folded(&mut writer)
+                .and_then(|_| writer.flush())
+                .map_err(|err| format!("Failed to write file {} - {err}", folded_path.display()))?;
+        }
+
+        println!("============================================================");
+        println!("Profiled {} program", self.assembly_file.display());
+        println!("{execution_profile}");
+        if let Some(folded_path) = &self.folded_file {
+            println!("Folded call stacks written to {}", folded_path.display());
+        }
+
+        Ok(())
+    }
+}
+
+// EXECUTION PROFILE
+// ================================================================================================
+
+/// Contains the number of VM cycles spent in each procedure call stack of a program.
+///
+/// Every cycle of program execution is attributed to the stack of procedures which were being
+/// executed at that cycle. Procedures invoked via `exec`, `call`, `syscall` and `dyncall` are all
+/// treated as procedure calls.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct ExecutionProfile {
+    /// Total number of cycles executed by the program.
+    total_cycles: usize,
+    /// Number of cycles attributed to each call stack; call stack frames are separated by `;`.
+    call_stacks: BTreeMap<String, usize>,
+    /// Cycle statistics of individual procedures, see [ProcedureStats].
+    procedures: Vec<ProcedureStats>,
+}
+
+impl ExecutionProfile {
+    /// Returns the total number of cycles executed by the program.
+    pub fn total_cycles(&self) -> usize {
+        self.total_cycles
+    }
+
+    /// Returns an iterator over call stacks and the number of cycles attributed to each of them.
+    /// Call stack frames are separated by `;`, starting with the outermost frame.
+    pub fn call_stacks(&self) -> impl Iterator<Item = (&str, usize)> {
+        self.call_stacks.iter().map(|(stack, &cycles)| (stack.as_str(), cycles))
+    }
+
+    /// Returns [ProcedureStats] of all procedures executed by the program sorted by the number of
+    /// inclusive cycles in descending order.
+    pub fn procedures(&self) -> &[ProcedureStats] {
+        &self.procedures
+    }
+
+    /// Writes call stacks of this profile in folded stack format - a line per call stack which
+    /// contains `;`-separated stack frames followed by the number of cycles spent in the stack.
+    pub fn write_folded<W: Write>(&self, target: &mut W) -> std::io::Result<()> {
+        for (stack, cycles) in self.call_stacks() {
+            writeln!(target, "{stack} {cycles}")?;
+        }
+        Ok(())
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Builds a profile from the number of cycles spent in each call stack.
+    fn from_call_stacks(
+        call_stacks: BTreeMap<String, usize>,
+        calls: BTreeMap<String, usize>,
+    ) -> Self {
+        let mut total_cycles = 0;
+        let mut procedures = BTreeMap::<&str, ProcedureStats>::new();
+        for (stack, &cycles) in call_stacks.iter() {
+            total_cycles += cycles;
+            let frames: BTreeSet<&str> = stack.split(';').collect();
+            for frame in frames {
+                let stats = procedures.entry(frame).or_insert_with(|| {
+                    ProcedureStats::new(frame.to_string(), calls.get(frame).copied().unwrap_or(0))
+                });
+                stats.inclusive_cycles += cycles;
+            }
+
+            let leaf = stack.rsplit(';').next().expect("empty call stack");
+            procedures.get_mut(leaf).expect("no leaf procedure").exclusive_cycles += cycles;
+        }
+
+        let mut procedures: Vec<_> = procedures.into_values().collect();
+        procedures.sort_by(|a, b| {
+            b.inclusive_cycles.cmp(&a.inclusive_cycles).then_with(|| a.name.cmp(&b.name))
+        });
+
+        Self {
+            total_cycles,
+            call_stacks,
+            procedures,
+        }
+    }
+}
+
+impl fmt::Display for ExecutionProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "\nVM cycles: {}", self.total_cycles())?;
+
+        // calculate the total length of padding for the procedure column
+        let padding = self.procedures().iter().fold(20, |max, proc| proc.name().len().max(max));
+
+        writeln!(
+            f,
+            "{0: <width$} | {1: <10} | {2: <20} | {3: <20} | Inclusive %",
+            "Procedure",
+            "Calls",
+            "Inclusive Cycles",
+            "Exclusive Cycles",
+            width = padding,
+        )?;
+
+        let delimiter = "-".repeat(padding + 73);
+        writeln!(f, "{delimiter}")?;
+
+        for proc in self.procedures() {
+            writeln!(
+                f,
+                "{0: <width$} | {1: <10} | {2: <20} | {3: <20} | {4:.2}",
+                proc.name(),
+                proc.calls(),
+                proc.inclusive_cycles(),
+                proc.exclusive_cycles(),
+                proc.inclusive_cycles() as f64 * 100.0 / self.total_cycles().max(1) as f64,
+                width = padding,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the cycle profile of executing the given program.
+pub fn profile<H>(
+    program: &str,
+    stack_inputs: StackInputs,
+    host: H,
+) -> Result<ExecutionProfile, ProgramError>
+where
+    H: Host,
+{
+    let library = StdLibrary::default();
+    let ast = ProgramAst::parse(program).map_err(|err| ProgramError::AssemblyError(err.into()))?;
+    let call_graph = CallGraph::new(&ast, &library);
+    let program = assemble(program)?;
+
+    let mut events = ExecutionEvents::default();
+    for state in processor::execute_iter(&program, stack_inputs, host) {
+        let vm_state = state.map_err(ProgramError::ExecutionError)?;
+        if let Some(op) = vm_state.op {
+            let context = vm_state.asmop.as_ref().map(procedure_name);
+            events.record_cycle(op, context.as_deref());
+        }
+    }
+
+    Ok(events.into_profile(&call_graph))
+}
+
+// PROCEDURE STATS
+// ================================================================================================
+
+/// Cycle statistics of a single procedure.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ProcedureStats {
+    name: String,
+    calls: usize,
+    inclusive_cycles: usize,
+    exclusive_cycles: usize,
+}
+
+impl ProcedureStats {
+    /// Returns [ProcedureStats] for the procedure with the specified name which was called the
+    /// specified number of times.
+    fn new(name: String, calls: usize) -> Self {
+        Self {
+            name,
+            calls,
+            inclusive_cycles: 0,
+            exclusive_cycles: 0,
+        }
+    }
+
+    /// Returns the fully qualified name of this procedure.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the number of times this procedure was called.
+    ///
+    /// Consecutive invocations of a procedure which are not separated by any instructions of the
+    /// caller (e.g., `repeat.2 exec.foo end`) may be counted as a single call.
+    pub fn calls(&self) -> usize {
+        self.calls
+    }
+
+    /// Returns the number of cycles spent in this procedure, including the cycles spent in the
+    /// procedures it called.
+    pub fn inclusive_cycles(&self) -> usize {
+        self.inclusive_cycles
+    }
+
+    /// Returns the number of cycles spent in this procedure, excluding the cycles spent in the
+    /// procedures it called.
+    pub fn exclusive_cycles(&self) -> usize {
+        self.exclusive_cycles
+    }
+}
+
+// EXECUTION EVENTS
+// ================================================================================================
+
+/// An event of program execution relevant for attributing cycles to procedures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExecutionEvent {
+    /// Entry into a code block; `is_call` is set for blocks started by `CALL`, `SYSCALL` and
+    /// `DYN` operations, which always invoke a procedure.
+    BlockStart { is_call: bool },
+    /// Exit from the most recently started code block.
+    BlockEnd,
+    /// A number of consecutive cycles executing assembly instructions of the specified context,
+    /// or cycles which cannot be attributed to assembly instructions (e.g., control flow
+    /// operations).
+    Cycles {
+        context: Option<usize>,
+        num_cycles: usize,
+    },
+}
+
+/// Sequence of events which occurred during program execution.
+///
+/// Procedure calls are inferred from the events by a [CallTracker] once the execution is
+/// complete, so that ambiguous changes of the context can be resolved using the static call graph
+/// of the program and the context executed next. Cycles of call stacks which cannot be determined
+/// exactly may be attributed to a sibling procedure.
+#[derive(Debug)]
+struct ExecutionEvents {
+    events: Vec<ExecutionEvent>,
+    contexts: Vec<String>,
+    context_ids: HashMap<String, usize>,
+}
+
+impl Default for ExecutionEvents {
+    fn default() -> Self {
+        let main = ProcedureName::MAIN_PROC_NAME.to_string();
+        Self {
+            events: Vec::new(),
+            contexts: vec![main.clone()],
+            context_ids: HashMap::from([(main, MAIN_CONTEXT)]),
+        }
+    }
+}
+
+/// Index of the context of the program's main procedure.
+const MAIN_CONTEXT: usize = 0;
+
+impl ExecutionEvents {
+    /// Records a single cycle executing the specified operation within the specified context.
+    fn record_cycle(&mut self, op: Operation, context: Option<&str>) {
+        let context = context.map(|context| self.context_id(context));
+        match self.events.last_mut() {
+            Some(ExecutionEvent::Cycles {
+                context: last_context,
+                num_cycles,
+            }) if *last_context == context => *num_cycles += 1,
+            _ => self.events.push(ExecutionEvent::Cycles {
+                context,
+                num_cycles: 1,
+            }),
+        }
+
+        match op {
+            Operation::Join | Operation::Split | Operation::Loop | Operation::Span => {
+                self.events.push(ExecutionEvent::BlockStart { is_call: false })
+            }
+            Operation::Call | Operation::SysCall | Operation::Dyn => {
+                self.events.push(ExecutionEvent::BlockStart { is_call: true })
+            }
+            Operation::End => self.events.push(ExecutionEvent::BlockEnd),
+            _ => (),
+        }
+    }
+
+    /// Attributes the recorded cycles to procedure call stacks and returns the resulting profile.
+    fn into_profile(mut self, call_graph: &CallGraph) -> ExecutionProfile {
+        let resolver = CallResolver::new(call_graph, |name| self.context_id(name));
+
+        // the context executed after each event (excluding the context executed by the event)
+        let mut next_contexts = vec![None; self.events.len()];
+        for idx in (1..self.events.len()).rev() {
+            next_contexts[idx - 1] = match self.events[idx] {
+                ExecutionEvent::Cycles {
+                    context: Some(context),
+                    ..
+                } => Some(context),
+                _ => next_contexts[idx],
+            };
+        }
+
+        let mut tracker = CallTracker::new(MAIN_CONTEXT);
+        let mut calls = vec![0; self.contexts.len()];
+        calls[MAIN_CONTEXT] = 1;
+        let mut stack_cycles = BTreeMap::<Vec<usize>, usize>::new();
+        for (event, next_context) in self.events.iter().zip(next_contexts) {
+            match *event {
+                ExecutionEvent::BlockStart { is_call } => tracker.start_block(is_call),
+                ExecutionEvent::BlockEnd => tracker.end_block(),
+                ExecutionEvent::Cycles {
+                    context,
+                    num_cycles,
+                } => {
+                    if let Some(context) = context {
+                        let num_entered = tracker.enter_context(context, next_context, &resolver);
+                        for &callee in tracker.stack().iter().rev().take(num_entered) {
+                            calls[callee] += 1;
+                        }
+                    }
+                    *stack_cycles.entry(tracker.stack().to_vec()).or_insert(0) += num_cycles;
+                }
+            }
+        }
+
+        let mut call_stacks = BTreeMap::new();
+        for (stack, cycles) in stack_cycles {
+            let stack = stack
+                .iter()
+                .map(|&context| self.contexts[context].as_str())
+                .collect::<Vec<_>>()
+                .join(";");
+            *call_stacks.entry(stack).or_insert(0) += cycles;
+        }
+
+        let calls = calls
+            .into_iter()
+            .enumerate()
+            .map(|(context, calls)| (self.contexts[context].clone(), calls))
+            .collect();
+
+        ExecutionProfile::from_call_stacks(call_stacks, calls)
+    }
+
+    /// Returns the index of the specified context, adding it to the list of contexts if needed.
+    fn context_id(&mut self, context: &str) -> usize {
+        match self.context_ids.get(context) {
+            Some(&id) => id,
+            None => {
+                let id = self.contexts.len();
+                self.contexts.push(context.to_string());
+                self.context_ids.insert(context.to_string(), id);
+                id
+            }
+        }
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{profile, StackInputs};
+    use processor::DefaultHost;
+
+    #[test]
+    fn profile_nested_procedures() {
+        let source = "
+            use.std::math::u64
+            proc.bar push.1 add end
+            proc.foo exec.bar push.2 mul exec.u64::wrapping_add end
+            begin
+                push.1.2.3.4
+                exec.foo
+                repeat.2 push.3 exec.bar end
+                dropw drop
+            end";
+        let profile = profile(source, StackInputs::default(), DefaultHost::default())
+            .expect("profile_nested_procedures: unexpected error");
+
+        let stacks: Vec<_> = profile.call_stacks().map(|(stack, _)| stack).collect();
+        assert_eq!(
+            stacks,
+            vec![
+                "#main",
+                "#main;bar",
+                "#main;foo",
+                "#main;foo;bar",
+                "#main;foo;std::math::u64::wrapping_add",
+                "#main;foo;std::math::u64::wrapping_add;std::math::u64::overflowing_add",
+            ]
+        );
+
+        // all cycles are attributed to the main procedure
+        let main = &profile.procedures()[0];
+        assert_eq!(main.name(), "#main");
+        assert_eq!(main.inclusive_cycles(), profile.total_cycles());
+
+        // the inclusive cycles of a procedure are the sum of the cycles of its call stacks
+        let foo = profile.procedures().iter().find(|proc| proc.name() == "foo").unwrap();
+        let foo_cycles: usize = profile
+            .call_stacks()
+            .filter(|(stack, _)| stack.starts_with("#main;foo"))
+            .map(|(_, cycles)| cycles)
+            .sum();
+        assert_eq!(foo.calls(), 1);
+        assert_eq!(foo.inclusive_cycles(), foo_cycles);
+        assert!(foo.exclusive_cycles() < foo.inclusive_cycles());
+
+        let bar = profile.procedures().iter().find(|proc| proc.name() == "bar").unwrap();
+        assert_eq!(bar.calls(), 3);
+
+        let wrapping_add =
+            profile.procedures().iter().find(|proc| proc.name().ends_with("wrapping_add"));
+        assert_eq!(wrapping_add.unwrap().calls(), 1);
+        assert_eq!(bar.inclusive_cycles(), bar.exclusive_cycles());
+
+        let mut folded = Vec::new();
+        profile.write_folded(&mut folded).unwrap();
+        let folded = String::from_utf8(folded).unwrap();
+        assert_eq!(folded.lines().count(), stacks.len());
+        assert!(folded.lines().all(|line| line
+            .rsplit_once(' ')
+            .unwrap()
+            .1
+            .parse::<usize>()
+            .is_ok()));
+    }
+}
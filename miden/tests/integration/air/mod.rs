@@ -1,3 +1,4 @@
 mod chiplets;
+mod operations;
 mod range;
 mod stack;
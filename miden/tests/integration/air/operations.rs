@@ -0,0 +1,20 @@
+use test_utils::constraints::{all_operations, test_operation};
+
+/// Number of random test cases checked for every operation.
+const NUM_CASES: u32 = 16;
+
+/// Checks that the transitions produced by every operation satisfy the stack constraints, and
+/// that the constraints reject the transitions after a single value of the stack is changed.
+#[test]
+fn operations_satisfy_stack_constraints() {
+    let failures = all_operations()
+        .into_iter()
+        .filter_map(|op| test_operation(op, NUM_CASES).err())
+        .collect::<Vec<_>>();
+
+    assert!(
+        failures.is_empty(),
+        "operations failed constraint checks:\n{}",
+        failures.join("\n")
+    );
+}
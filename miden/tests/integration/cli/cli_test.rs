@@ -35,3 +35,47 @@ fn cli_run() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+// Compiles a program into a .masb file and checks that running the compiled file produces the
+// same program hash as running the source file.
+fn cli_compile_and_run() -> Result<(), Box<dyn std::error::Error>> {
+    let bin_under_test = escargot::CargoBuild::new()
+        .bin("miden")
+        .features("executable")
+        .current_release()
+        .current_target()
+        .run()
+        .unwrap();
+
+    let out_file = std::env::temp_dir().join("miden_cli_compile_and_run").join("fib.masb");
+
+    let output = bin_under_test
+        .command()
+        .arg("compile")
+        .arg("-a")
+        .arg("./examples/fib/fib.masm")
+        .arg("-o")
+        .arg(&out_file)
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout)?;
+    let program_hash = stdout
+        .lines()
+        .find_map(|line| line.strip_prefix("program hash is "))
+        .expect("program hash was not reported");
+
+    let mut cmd = bin_under_test.command();
+    cmd.arg("run")
+        .arg("-a")
+        .arg(&out_file)
+        .arg("-i")
+        .arg("./examples/fib/fib.inputs")
+        .arg("-n")
+        .arg("1");
+
+    cmd.unwrap().assert().stdout(predicate::str::contains(format!(
+        "Executed the program with hash {program_hash}"
+    )));
+
+    Ok(())
+}
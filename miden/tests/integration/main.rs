@@ -5,6 +5,8 @@ mod cli;
 mod exec_iters;
 mod flow_control;
 mod operations;
+mod optimizer;
+mod output_region;
 
 // TESTS
 // ================================================================================================
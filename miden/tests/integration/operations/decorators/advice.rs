@@ -2,7 +2,7 @@ use test_utils::{
     build_test,
     crypto::{MerkleStore, RpoDigest},
     rand::rand_value,
-    Felt,
+    ExecutionError, Felt, TestError,
 };
 
 // ADVICE INJECTION
@@ -122,6 +122,48 @@ fn advice_push_u64div_conditional_execution() {
     test.expect_stack(&[0, 0, 0, 0, 0, 4, 0, 8]);
 }
 
+#[test]
+fn advice_push_u256div() {
+    // push a/b onto the advice stack and then move these values onto the operand stack.
+    let source = "begin adv.push_u256div adv_push.16 end";
+
+    // get a random 128-bit dividend and a random 64-bit divisor, so that the quotient and the
+    // remainder can be computed natively; the upper limbs of both values are zero
+    let a = rand_value::<u64>() as u128 | ((rand_value::<u64>() as u128) << 64);
+    let b = rand_value::<u64>() as u128;
+    let q = a / b;
+    let r = a % b;
+
+    let to_limbs = |value: u128| -> [u64; 8] {
+        let mut limbs = [0; 8];
+        for (i, limb) in limbs.iter_mut().take(4).enumerate() {
+            *limb = (value >> (32 * i)) as u32 as u64;
+        }
+        limbs
+    };
+
+    let mut operands = to_limbs(a).to_vec();
+    operands.extend_from_slice(&to_limbs(b));
+
+    let mut expected = to_limbs(r).to_vec();
+    expected.reverse();
+    expected.extend(to_limbs(q).iter().rev());
+
+    let test = build_test!(source, &operands);
+    test.expect_stack(&expected);
+}
+
+#[test]
+fn advice_push_u256div_by_zero() {
+    let source = "begin adv.push_u256div adv_push.16 end";
+
+    let mut operands = vec![1; 8];
+    operands.extend_from_slice(&[0; 8]);
+
+    let test = build_test!(source, &operands);
+    test.expect_error(TestError::ExecutionError(ExecutionError::DivideByZero(1)));
+}
+
 #[test]
 fn advice_insert_mem() {
     let source = "begin
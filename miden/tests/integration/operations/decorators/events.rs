@@ -1,6 +1,6 @@
 use super::TestHost;
 use assembly::Assembler;
-use processor::ExecutionOptions;
+use processor::{ExecutionError, ExecutionOptions};
 
 #[test]
 fn test_event_handling() {
@@ -52,3 +52,69 @@ fn test_trace_handling() {
     let expected = vec![1, 2];
     assert_eq!(host.trace_handler, expected);
 }
+
+#[test]
+fn test_breakpoint_handling() {
+    let source = "\
+    begin
+        push.1
+        breakpoint
+        push.2
+        breakpoint
+    end";
+
+    // breakpoints are not compiled into the program outside of debug mode
+    let program = Assembler::default().compile(source).unwrap();
+    let mut host = TestHost::default();
+    processor::execute(&program, Default::default(), &mut host, Default::default()).unwrap();
+    assert!(host.breakpoint_handler.is_empty());
+
+    // the host receives the state of the process at each breakpoint
+    let program = Assembler::default().with_debug_mode(true).compile(source).unwrap();
+    processor::execute(&program, Default::default(), &mut host, Default::default()).unwrap();
+    assert_eq!(host.breakpoint_handler, vec![(3, 1), (5, 2)]);
+
+    // the fast processor invokes the host in the same way
+    host.breakpoint_handler.clear();
+    processor::execute_fast(&program, Default::default(), &mut host, Default::default()).unwrap();
+    assert_eq!(host.breakpoint_handler, vec![(3, 1), (5, 2)]);
+}
+
+#[test]
+fn test_breakpoint_error() {
+    struct FailingHost(TestHost<processor::MemAdviceProvider>);
+
+    impl processor::Host for FailingHost {
+        fn get_advice<S: processor::ProcessState>(
+            &mut self,
+            process: &S,
+            extractor: processor::AdviceExtractor,
+        ) -> Result<processor::HostResponse, ExecutionError> {
+            self.0.get_advice(process, extractor)
+        }
+
+        fn set_advice<S: processor::ProcessState>(
+            &mut self,
+            process: &S,
+            injector: vm_core::AdviceInjector,
+        ) -> Result<processor::HostResponse, ExecutionError> {
+            self.0.set_advice(process, injector)
+        }
+
+        fn on_breakpoint<S: processor::ProcessState>(
+            &mut self,
+            process: &S,
+        ) -> Result<processor::HostResponse, ExecutionError> {
+            match process.get_stack_item(0).as_int() {
+                2 => Err(ExecutionError::EventError("invariant violated".into())),
+                _ => Ok(processor::HostResponse::None),
+            }
+        }
+    }
+
+    let source = "begin push.1 breakpoint push.2 breakpoint push.3 end";
+    let program = Assembler::default().with_debug_mode(true).compile(source).unwrap();
+    let mut host = FailingHost(TestHost::default());
+    let result = processor::execute(&program, Default::default(), &mut host, Default::default());
+    assert!(matches!(result, Err(ExecutionError::EventError(_))));
+}
@@ -14,6 +14,7 @@ pub struct TestHost<A> {
     pub adv_provider: A,
     pub event_handler: Vec<u32>,
     pub trace_handler: Vec<u32>,
+    pub breakpoint_handler: Vec<(u32, u64)>,
 }
 
 impl Default for TestHost<MemAdviceProvider> {
@@ -22,6 +23,7 @@ impl Default for TestHost<MemAdviceProvider> {
             adv_provider: MemAdviceProvider::default(),
             event_handler: Vec::new(),
             trace_handler: Vec::new(),
+            breakpoint_handler: Vec::new(),
         }
     }
 }
@@ -60,4 +62,13 @@ impl<A: AdviceProvider> Host for TestHost<A> {
         self.trace_handler.push(trace_id);
         Ok(HostResponse::None)
     }
+
+    fn on_breakpoint<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.breakpoint_handler
+            .push((process.clk(), process.get_stack_item(0).as_int()));
+        Ok(HostResponse::None)
+    }
 }
@@ -0,0 +1,99 @@
+use miden_vm::{
+    execute, Assembler, DefaultHost, MemAdviceProvider, OptimizationLevel, OptimizationStats,
+    StackInputs,
+};
+use processor::ExecutionOptions;
+use stdlib::StdLibrary;
+
+// PEEPHOLE OPTIMIZER TESTS
+// ================================================================================================
+
+/// Executes the specified program compiled with and without the peephole optimizer, checks that
+/// both executions produce the same outputs and returns the number of cycles of each execution
+/// together with the statistics of the peephole optimizer.
+fn execute_optimized(source: &str, stack_inputs: &[u64]) -> (usize, usize, OptimizationStats) {
+    let compile = |optimization_level| {
+        let assembler = Assembler::default()
+            .with_library(&StdLibrary::default())
+            .unwrap()
+            .with_optimization_level(optimization_level);
+        let program = assembler.compile(source).unwrap();
+        (program, assembler.optimization_stats())
+    };
+    let (program, stats) = compile(OptimizationLevel::None);
+    let (optimized, optimized_stats) = compile(OptimizationLevel::Peephole);
+    assert_eq!(stats.cycles_saved(), 0);
+
+    let run = |program| {
+        let stack_inputs = StackInputs::try_from_ints(stack_inputs.iter().copied()).unwrap();
+        let host = DefaultHost::new(MemAdviceProvider::default());
+        execute(program, stack_inputs, host, ExecutionOptions::default()).unwrap()
+    };
+    let trace = run(&program);
+    let optimized_trace = run(&optimized);
+    // overflow addresses are clock cycles, which are expected to change
+    assert_eq!(optimized_trace.stack_outputs().stack(), trace.stack_outputs().stack());
+
+    (
+        trace.trace_len_summary().main_trace_len(),
+        optimized_trace.trace_len_summary().main_trace_len(),
+        optimized_stats,
+    )
+}
+
+#[test]
+fn peephole_optimizer_preserves_semantics() {
+    let source = "
+        use.std::math::u64
+
+        proc.foo.1
+            loc_store.0 push.0 add swap swap push.1 mul
+            push.3 push.4 add loc_load.0 add
+        end
+
+        begin
+            dup.0 drop movup.2 movdn.2 push.0 eq
+            exec.foo
+            repeat.4
+                push.1 add swap swap
+            end
+            push.7 push.9 exec.u64::wrapping_mul
+            push.0 push.1 exec.u64::overflowing_add
+        end";
+    let (cycles, optimized_cycles, stats) = execute_optimized(source, &[5, 4, 3, 2, 1]);
+    assert!(stats.cycles_saved() > 0);
+    assert!(optimized_cycles < cycles);
+}
+
+#[test]
+fn peephole_optimizer_preserves_stdlib_semantics() {
+    let source = "
+        use.std::crypto::hashes::blake3
+        use.std::math::u64
+
+        begin
+            push.1.2.3.4.5.6.7.8.9.10.11.12.13.14.15.16
+            exec.blake3::hash_2to1
+            exec.u64::div exec.u64::clz
+            exec.u64::ctz exec.u64::cto
+        end";
+    let (cycles, optimized_cycles, stats) = execute_optimized(source, &[]);
+    assert!(stats.cycles_saved() > 0);
+    assert!(optimized_cycles < cycles);
+}
+
+#[test]
+fn peephole_optimizer_preserves_u256_semantics() {
+    let source = "
+        use.std::crypto::hashes::blake3
+        use.std::math::u256
+
+        begin
+            push.1.2.3.4.5.6.7.8.9.10.11.12.13.14.15.16
+            exec.blake3::hash_2to1
+            exec.u256::mul_unsafe
+        end";
+    let (cycles, optimized_cycles, stats) = execute_optimized(source, &[]);
+    assert!(stats.cycles_saved() > 0);
+    assert!(optimized_cycles < cycles);
+}
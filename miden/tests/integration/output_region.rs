@@ -0,0 +1,52 @@
+use miden_vm::{execute, DefaultHost, MemAdviceProvider, OutputRegion, Word};
+use processor::ExecutionOptions;
+use stdlib::StdLibrary;
+use test_utils::{build_test, Felt, Test};
+
+// OUTPUT REGION TESTS
+// ================================================================================================
+
+/// Builds a test which copies `num_words` distinct words from the advice stack to memory starting
+/// at address 1000 and commits to them as the output region of the program; returns the test
+/// together with the copied words.
+fn build_output_test(num_words: usize) -> (Test, Vec<Word>) {
+    let source = format!(
+        "
+        use.std::mem
+        use.std::sys
+
+        begin
+            push.1000 push.{num_words} exec.mem::pipe_words_to_memory
+            dropw drop
+            push.{num_words} push.1000 exec.sys::commit_output_region
+        end"
+    );
+    let advice_stack = (0..num_words as u64 * 4).map(|i| i * 7 + 3).collect::<Vec<_>>();
+    let words = advice_stack
+        .chunks(4)
+        .map(|word| [word[0], word[1], word[2], word[3]].map(Felt::new))
+        .collect();
+
+    let mut test = build_test!(&source, &[], &advice_stack);
+    test.libraries = vec![StdLibrary::default().into()];
+    (test, words)
+}
+
+#[test]
+fn output_region_commitment_odd_words() {
+    for num_words in [1, 3] {
+        let (test, words) = build_output_test(num_words);
+        let program = test.compile().unwrap();
+        let host = DefaultHost::new(MemAdviceProvider::from(test.advice_inputs.clone()));
+        let options = ExecutionOptions::default().with_output_region();
+        let trace = execute(&program, test.stack_inputs.clone(), host, options).unwrap();
+
+        // the commitment computed by `std::sys::commit_output_region` matches the commitment
+        // computed on the host side
+        let expected_region = OutputRegion::new(1000, words).unwrap();
+        let commitment: Word = expected_region.commitment().into();
+        assert_eq!(trace.stack_outputs().get_stack_word(0), Some(commitment));
+        assert_eq!(trace.output_region(), Some(&expected_region));
+        assert!(expected_region.is_committed_to(trace.stack_outputs()));
+    }
+}
@@ -1,4 +1,5 @@
 use super::{utils::get_trace_len, ExecutionError, Felt, TraceFragment, ZERO};
+use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 use alloc::vec::Vec;
 use miden_air::trace::chiplets::bitwise::{
     A_COL_IDX, A_COL_RANGE, BITWISE_AND, BITWISE_XOR, B_COL_IDX, B_COL_RANGE, OUTPUT_COL_IDX,
@@ -195,6 +196,23 @@ impl Default for Bitwise {
     }
 }
 
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for Bitwise {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.trace.write_into(target);
+    }
+}
+
+impl Deserializable for Bitwise {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            trace: source.read()?,
+        })
+    }
+}
+
 // HELPER FUNCTIONS
 // --------------------------------------------------------------------------------------------
 
@@ -1,6 +1,7 @@
 use super::{
     Felt, HasherState, MerklePath, MerkleRootUpdate, OpBatch, TraceFragment, Word, ONE, ZERO,
 };
+use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 use alloc::collections::BTreeMap;
 use miden_air::trace::chiplets::hasher::{
     Digest, Selectors, DIGEST_LEN, DIGEST_RANGE, LINEAR_HASH, MP_VERIFY, MR_UPDATE_NEW,
@@ -366,6 +367,25 @@ impl Hasher {
     }
 }
 
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for Hasher {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.trace.write_into(target);
+        self.memoized_trace_map.write_into(target);
+    }
+}
+
+impl Deserializable for Hasher {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            trace: source.read()?,
+            memoized_trace_map: source.read()?,
+        })
+    }
+}
+
 // MERKLE PATH CONTEXT
 // ================================================================================================
 
@@ -1,4 +1,5 @@
 use super::{Felt, HasherState, Selectors, TraceFragment, STATE_WIDTH, TRACE_WIDTH, ZERO};
+use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 use alloc::vec::Vec;
 use core::ops::Range;
 use miden_air::trace::chiplets::hasher::NUM_ROUNDS;
@@ -145,3 +146,24 @@ impl HasherTrace {
         }
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for HasherTrace {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.selectors.write_into(target);
+        self.hasher_state.write_into(target);
+        self.node_index.write_into(target);
+    }
+}
+
+impl Deserializable for HasherTrace {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            selectors: source.read()?,
+            hasher_state: source.read()?,
+            node_index: source.read()?,
+        })
+    }
+}
@@ -1,4 +1,5 @@
 use super::{Digest, ExecutionError, Felt, Kernel, TraceFragment, Word, ONE, ZERO};
+use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 use alloc::collections::BTreeMap;
 use miden_air::trace::chiplets::kernel_rom::TRACE_WIDTH;
 
@@ -155,3 +156,40 @@ impl ProcAccessInfo {
         trace.set(row, 5, self.proc_hash[3]);
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for KernelRom {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.access_map.write_into(target);
+        self.kernel.write_into(target);
+        target.write_usize(self.trace_len);
+    }
+}
+
+impl Deserializable for KernelRom {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            access_map: source.read()?,
+            kernel: source.read()?,
+            trace_len: source.read_usize()?,
+        })
+    }
+}
+
+impl Serializable for ProcAccessInfo {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.proc_hash.write_into(target);
+        target.write_usize(self.num_accesses);
+    }
+}
+
+impl Deserializable for ProcAccessInfo {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            proc_hash: source.read()?,
+            num_accesses: source.read_usize()?,
+        })
+    }
+}
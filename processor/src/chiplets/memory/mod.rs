@@ -2,7 +2,10 @@ use super::{
     utils::{split_element_u32_into_u16, split_u32_into_u16},
     Felt, FieldElement, RangeChecker, TraceFragment, Word, EMPTY_WORD, ONE,
 };
-use crate::system::ContextId;
+use crate::{
+    system::ContextId,
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
 use alloc::{collections::BTreeMap, vec::Vec};
 use miden_air::trace::chiplets::memory::{
     ADDR_COL_IDX, CLK_COL_IDX, CTX_COL_IDX, D0_COL_IDX, D1_COL_IDX, D_INV_COL_IDX, V_COL_RANGE,
@@ -274,3 +277,22 @@ impl Memory {
         self.trace.iter().fold(0, |acc, (_, s)| acc + s.size())
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for Memory {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.trace.write_into(target);
+        target.write_usize(self.num_trace_rows);
+    }
+}
+
+impl Deserializable for Memory {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            trace: source.read()?,
+            num_trace_rows: source.read_usize()?,
+        })
+    }
+}
@@ -4,6 +4,7 @@ use miden_air::trace::chiplets::memory::{
 };
 
 use super::{Felt, Word, INIT_MEM_VALUE};
+use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 
 // MEMORY SEGMENT TRACE
 // ================================================================================================
@@ -169,3 +170,55 @@ impl MemorySegmentAccess {
         self.value
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for MemorySegmentTrace {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.0.write_into(target);
+    }
+}
+
+impl Deserializable for MemorySegmentTrace {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        source.read().map(Self)
+    }
+}
+
+impl Serializable for MemoryOperation {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(*self as u8);
+    }
+}
+
+impl Deserializable for MemoryOperation {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(Self::InitRead),
+            1 => Ok(Self::CopyRead),
+            2 => Ok(Self::Write),
+            tag => Err(DeserializationError::InvalidValue(format!(
+                "invalid memory operation tag {tag}"
+            ))),
+        }
+    }
+}
+
+impl Serializable for MemorySegmentAccess {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.clk.write_into(target);
+        self.op.write_into(target);
+        self.value.write_into(target);
+    }
+}
+
+impl Deserializable for MemorySegmentAccess {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            clk: source.read()?,
+            op: source.read()?,
+            value: source.read()?,
+        })
+    }
+}
@@ -1,4 +1,7 @@
-use crate::system::ContextId;
+use crate::{
+    system::ContextId,
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
 
 use super::{
     crypto::MerklePath, utils, ChipletsTrace, ExecutionError, Felt, FieldElement, RangeChecker,
@@ -9,6 +12,7 @@ use miden_air::trace::chiplets::hasher::{Digest, HasherState};
 use vm_core::{code_blocks::OpBatch, Kernel};
 
 mod bitwise;
+pub(crate) use bitwise::assert_u32;
 use bitwise::Bitwise;
 
 mod hasher;
@@ -481,6 +485,31 @@ impl Chiplets {
     }
 }
 
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for Chiplets {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u32(self.clk);
+        self.hasher.write_into(target);
+        self.bitwise.write_into(target);
+        self.memory.write_into(target);
+        self.kernel_rom.write_into(target);
+    }
+}
+
+impl Deserializable for Chiplets {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            clk: source.read_u32()?,
+            hasher: source.read()?,
+            bitwise: source.read()?,
+            memory: source.read()?,
+            kernel_rom: source.read()?,
+        })
+    }
+}
+
 // HELPER STRUCTS
 // ================================================================================================
 
@@ -495,6 +524,13 @@ pub struct MerkleRootUpdate {
 }
 
 impl MerkleRootUpdate {
+    pub fn new(address: Felt, old_root: Word, new_root: Word) -> Self {
+        Self {
+            address,
+            old_root,
+            new_root,
+        }
+    }
     pub fn get_address(&self) -> Felt {
         self.address
     }
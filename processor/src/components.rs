@@ -0,0 +1,321 @@
+use super::{
+    chiplets::MerkleRootUpdate, crypto::MerklePath, decoder::Decoder, range::RangeChecker,
+    stack::Stack, system::System, Chiplets, ContextId, ExecutionError, Felt, Operation, Word,
+};
+use alloc::vec::Vec;
+use miden_air::trace::chiplets::hasher::HasherState;
+
+// PROCESS COMPONENTS
+// ================================================================================================
+
+/// Defines the set of components which hold the state of a process.
+///
+/// VM operations are implemented once against the traits below. The components used by the
+/// regular processor record execution traces as the operations are executed, while the components
+/// used by the fast processor keep only the current state of the VM.
+pub trait ProcessComponents {
+    type System: SystemComponent;
+    type Decoder: DecoderComponent;
+    type Stack: StackComponent;
+    type RangeChecker: RangeCheckerComponent;
+    type Chiplets: ChipletsComponent;
+}
+
+/// System registers of a process.
+pub trait SystemComponent {
+    /// Returns the current clock cycle of the process.
+    fn clk(&self) -> u32;
+
+    /// Returns the current execution context ID.
+    fn ctx(&self) -> ContextId;
+
+    /// Returns the current value of the free memory pointer.
+    fn fmp(&self) -> Felt;
+
+    /// Returns true if the VM is currently executing a SYSCALL block.
+    fn in_syscall(&self) -> bool;
+
+    /// Returns the hash of the function which initiated the current execution context.
+    fn fn_hash(&self) -> Word;
+
+    /// Sets the value of free memory pointer for the next clock cycle.
+    fn set_fmp(&mut self, fmp: Felt);
+
+    /// Increments the clock cycle.
+    ///
+    /// # Errors
+    /// Returns an error if the clock cycle exceeds `max_cycles`.
+    fn advance_clock(&mut self, max_cycles: u32) -> Result<(), ExecutionError>;
+
+    /// Makes sure there is enough memory allocated for the trace to accommodate a new row.
+    fn ensure_trace_capacity(&mut self);
+}
+
+/// Program decoder of a process.
+pub trait DecoderComponent {
+    /// Sets the helper registers for the specified user operation.
+    fn set_user_op_helpers(&mut self, op: Operation, values: &[Felt]);
+}
+
+/// Operand stack of a process.
+///
+/// Operations read the state of the stack at the current clock cycle and describe the state of
+/// the stack at the next clock cycle; the new state becomes current when the clock advances.
+pub trait StackComponent {
+    /// Returns depth of the stack at the current clock cycle.
+    fn depth(&self) -> usize;
+
+    /// Returns the value located at the specified position on the stack at the current clock
+    /// cycle.
+    fn get(&self, pos: usize) -> Felt;
+
+    /// Returns a word located at the specified word index on the stack; the top element of the
+    /// stack is at the last position in the word.
+    fn get_word(&self, word_idx: usize) -> Word;
+
+    /// Returns stack state at the specified clock cycle, including the overflow entries.
+    fn get_state_at(&self, clk: u32) -> Vec<Felt>;
+
+    /// Sets the value at the specified position on the stack at the next clock cycle.
+    fn set(&mut self, pos: usize, value: Felt);
+
+    /// Copies stack values starting at the specified position at the current clock cycle to the
+    /// same position at the next clock cycle.
+    fn copy_state(&mut self, start_pos: usize);
+
+    /// Copies stack values starting at the specified position at the current clock cycle to
+    /// position - 1 at the next clock cycle, pulling a value from the overflow table if needed.
+    fn shift_left(&mut self, start_pos: usize);
+
+    /// Copies stack values starting at the specified position at the current clock cycle to
+    /// position + 1 at the next clock cycle, moving the last value into the overflow table.
+    fn shift_right(&mut self, start_pos: usize);
+
+    /// Increments the clock cycle.
+    fn advance_clock(&mut self);
+
+    /// Makes sure there is enough memory allocated for the trace to accommodate a new row.
+    fn ensure_trace_capacity(&mut self);
+}
+
+/// Range checker of a process.
+pub trait RangeCheckerComponent {
+    /// Adds range check lookups for the provided values at the specified clock cycle.
+    fn add_range_checks(&mut self, clk: u32, values: &[u16]);
+}
+
+/// Hasher, bitwise, memory, and kernel ROM chiplets of a process.
+pub trait ChipletsComponent {
+    /// Applies a single permutation of the hash function to the provided state and returns the
+    /// row address of the hasher trace together with the resulting state.
+    fn permute(&mut self, state: HasherState) -> (Felt, HasherState);
+
+    /// Computes the root of the Merkle path for the specified node value and index, and returns
+    /// it together with the row address of the hasher trace.
+    fn build_merkle_root(&mut self, value: Word, path: &MerklePath, index: Felt) -> (Felt, Word);
+
+    /// Computes the Merkle roots of the specified path for the old and the new node values.
+    fn update_merkle_root(
+        &mut self,
+        old_value: Word,
+        new_value: Word,
+        path: &MerklePath,
+        index: Felt,
+    ) -> MerkleRootUpdate;
+
+    /// Returns a bitwise AND of the provided 32-bit values.
+    fn u32and(&mut self, a: Felt, b: Felt) -> Result<Felt, ExecutionError>;
+
+    /// Returns a bitwise XOR of the provided 32-bit values.
+    fn u32xor(&mut self, a: Felt, b: Felt) -> Result<Felt, ExecutionError>;
+
+    /// Returns a word located in memory at the specified context/address.
+    fn read_mem(&mut self, ctx: ContextId, addr: u32) -> Word;
+
+    /// Returns two words located in memory at the specified context/address and the address
+    /// following it.
+    fn read_mem_double(&mut self, ctx: ContextId, addr: u32) -> [Word; 2];
+
+    /// Writes the provided word at the specified context/address.
+    fn write_mem(&mut self, ctx: ContextId, addr: u32, word: Word);
+
+    /// Writes the provided element into the first slot of the word at the specified
+    /// context/address, and returns the word which was previously stored at this address.
+    fn write_mem_element(&mut self, ctx: ContextId, addr: u32, value: Felt) -> Word;
+
+    /// Writes the provided words at the specified context/address and the address following it.
+    fn write_mem_double(&mut self, ctx: ContextId, addr: u32, words: [Word; 2]);
+
+    /// Returns a word located at the specified context/address, or None if the address hasn't
+    /// been accessed previously.
+    fn get_mem_value(&self, ctx: ContextId, addr: u32) -> Option<Word>;
+
+    /// Returns the memory state for the specified execution context at the specified clock
+    /// cycle.
+    fn get_mem_state_at(&self, ctx: ContextId, clk: u32) -> Vec<(u64, Word)>;
+
+    /// Increments the clock cycle.
+    fn advance_clock(&mut self);
+}
+
+// TRACE COMPONENTS
+// ================================================================================================
+
+/// Components of a process which record the execution trace of the VM.
+pub struct TraceComponents;
+
+impl ProcessComponents for TraceComponents {
+    type System = System;
+    type Decoder = Decoder;
+    type Stack = Stack;
+    type RangeChecker = RangeChecker;
+    type Chiplets = Chiplets;
+}
+
+impl SystemComponent for System {
+    fn clk(&self) -> u32 {
+        System::clk(self)
+    }
+
+    fn ctx(&self) -> ContextId {
+        System::ctx(self)
+    }
+
+    fn fmp(&self) -> Felt {
+        System::fmp(self)
+    }
+
+    fn in_syscall(&self) -> bool {
+        System::in_syscall(self)
+    }
+
+    fn fn_hash(&self) -> Word {
+        System::fn_hash(self)
+    }
+
+    fn set_fmp(&mut self, fmp: Felt) {
+        System::set_fmp(self, fmp)
+    }
+
+    fn advance_clock(&mut self, max_cycles: u32) -> Result<(), ExecutionError> {
+        System::advance_clock(self, max_cycles)
+    }
+
+    fn ensure_trace_capacity(&mut self) {
+        System::ensure_trace_capacity(self)
+    }
+}
+
+impl DecoderComponent for Decoder {
+    fn set_user_op_helpers(&mut self, op: Operation, values: &[Felt]) {
+        Decoder::set_user_op_helpers(self, op, values)
+    }
+}
+
+impl StackComponent for Stack {
+    fn depth(&self) -> usize {
+        Stack::depth(self)
+    }
+
+    fn get(&self, pos: usize) -> Felt {
+        Stack::get(self, pos)
+    }
+
+    fn get_word(&self, word_idx: usize) -> Word {
+        Stack::get_word(self, word_idx)
+    }
+
+    fn get_state_at(&self, clk: u32) -> Vec<Felt> {
+        Stack::get_state_at(self, clk)
+    }
+
+    fn set(&mut self, pos: usize, value: Felt) {
+        Stack::set(self, pos, value)
+    }
+
+    fn copy_state(&mut self, start_pos: usize) {
+        Stack::copy_state(self, start_pos)
+    }
+
+    fn shift_left(&mut self, start_pos: usize) {
+        Stack::shift_left(self, start_pos)
+    }
+
+    fn shift_right(&mut self, start_pos: usize) {
+        Stack::shift_right(self, start_pos)
+    }
+
+    fn advance_clock(&mut self) {
+        Stack::advance_clock(self)
+    }
+
+    fn ensure_trace_capacity(&mut self) {
+        Stack::ensure_trace_capacity(self)
+    }
+}
+
+impl RangeCheckerComponent for RangeChecker {
+    fn add_range_checks(&mut self, clk: u32, values: &[u16]) {
+        RangeChecker::add_range_checks(self, clk, values)
+    }
+}
+
+impl ChipletsComponent for Chiplets {
+    fn permute(&mut self, state: HasherState) -> (Felt, HasherState) {
+        Chiplets::permute(self, state)
+    }
+
+    fn build_merkle_root(&mut self, value: Word, path: &MerklePath, index: Felt) -> (Felt, Word) {
+        Chiplets::build_merkle_root(self, value, path, index)
+    }
+
+    fn update_merkle_root(
+        &mut self,
+        old_value: Word,
+        new_value: Word,
+        path: &MerklePath,
+        index: Felt,
+    ) -> MerkleRootUpdate {
+        Chiplets::update_merkle_root(self, old_value, new_value, path, index)
+    }
+
+    fn u32and(&mut self, a: Felt, b: Felt) -> Result<Felt, ExecutionError> {
+        Chiplets::u32and(self, a, b)
+    }
+
+    fn u32xor(&mut self, a: Felt, b: Felt) -> Result<Felt, ExecutionError> {
+        Chiplets::u32xor(self, a, b)
+    }
+
+    fn read_mem(&mut self, ctx: ContextId, addr: u32) -> Word {
+        Chiplets::read_mem(self, ctx, addr)
+    }
+
+    fn read_mem_double(&mut self, ctx: ContextId, addr: u32) -> [Word; 2] {
+        Chiplets::read_mem_double(self, ctx, addr)
+    }
+
+    fn write_mem(&mut self, ctx: ContextId, addr: u32, word: Word) {
+        Chiplets::write_mem(self, ctx, addr, word)
+    }
+
+    fn write_mem_element(&mut self, ctx: ContextId, addr: u32, value: Felt) -> Word {
+        Chiplets::write_mem_element(self, ctx, addr, value)
+    }
+
+    fn write_mem_double(&mut self, ctx: ContextId, addr: u32, words: [Word; 2]) {
+        Chiplets::write_mem_double(self, ctx, addr, words)
+    }
+
+    fn get_mem_value(&self, ctx: ContextId, addr: u32) -> Option<Word> {
+        Chiplets::get_mem_value(self, ctx, addr)
+    }
+
+    fn get_mem_state_at(&self, ctx: ContextId, clk: u32) -> Vec<(u64, Word)> {
+        Chiplets::get_mem_state_at(self, ctx, clk)
+    }
+
+    fn advance_clock(&mut self) {
+        Chiplets::advance_clock(self)
+    }
+}
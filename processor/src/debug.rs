@@ -5,7 +5,7 @@ use crate::{
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use core::fmt;
-use vm_core::{AssemblyOp, Operation, StackOutputs, Word};
+use vm_core::{AssemblyOp, Operation, SourceLocation, StackOutputs, Word};
 
 /// VmState holds a current process state information at a specific clock cycle.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -273,6 +273,12 @@ impl AsmOpInfo {
         self.asmop.context_name()
     }
 
+    /// Returns the path of the module which defines the procedure of this operation, or None if
+    /// the procedure is defined in the program itself.
+    pub fn module_path(&self) -> Option<&str> {
+        self.asmop.module_path()
+    }
+
     /// Returns the assembly instruction corresponding to this state.
     pub fn op(&self) -> &str {
         self.asmop.op()
@@ -304,6 +310,11 @@ impl AsmOpInfo {
     pub const fn should_break(&self) -> bool {
         self.asmop.should_break()
     }
+
+    /// Returns the location of the assembly instruction in its source, if known.
+    pub fn location(&self) -> Option<&SourceLocation> {
+        self.asmop.location()
+    }
 }
 
 impl fmt::Display for AsmOpInfo {
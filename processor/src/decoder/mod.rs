@@ -2,6 +2,7 @@ use super::{
     Call, Dyn, ExecutionError, Felt, Host, Join, Loop, OpBatch, Operation, Process, Span, Split,
     Word, EMPTY_WORD, MIN_TRACE_LEN, ONE, OP_BATCH_SIZE, ZERO,
 };
+use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 use alloc::vec::Vec;
 use miden_air::trace::{
     chiplets::hasher::DIGEST_LEN,
@@ -747,3 +748,60 @@ impl DebugInfo {
         self.assembly_ops.push((clk as usize, asmop));
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for Decoder {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.block_stack.write_into(target);
+        self.span_context.write_into(target);
+        self.trace.write_into(target);
+        self.debug_info.write_into(target);
+    }
+}
+
+impl Deserializable for Decoder {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            block_stack: source.read()?,
+            span_context: source.read()?,
+            trace: source.read()?,
+            debug_info: source.read()?,
+        })
+    }
+}
+
+impl Serializable for SpanContext {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.group_ops_left.write_into(target);
+        self.num_groups_left.write_into(target);
+    }
+}
+
+impl Deserializable for SpanContext {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            group_ops_left: source.read()?,
+            num_groups_left: source.read()?,
+        })
+    }
+}
+
+impl Serializable for DebugInfo {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_bool(self.in_debug_mode);
+        self.operations.write_into(target);
+        self.assembly_ops.write_into(target);
+    }
+}
+
+impl Deserializable for DebugInfo {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            in_debug_mode: source.read_bool()?,
+            operations: source.read()?,
+            assembly_ops: source.read()?,
+        })
+    }
+}
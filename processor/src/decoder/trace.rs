@@ -4,6 +4,7 @@ use super::{
     ONE, OP_BATCH_1_GROUPS, OP_BATCH_2_GROUPS, OP_BATCH_4_GROUPS, OP_BATCH_8_GROUPS, OP_BATCH_SIZE,
     ZERO,
 };
+use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 use alloc::vec::Vec;
 use core::ops::Range;
 use vm_core::utils::new_array_vec;
@@ -536,6 +537,37 @@ impl DecoderTrace {
     }
 }
 
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for DecoderTrace {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.addr_trace.write_into(target);
+        self.op_bits_trace.write_into(target);
+        self.hasher_trace.write_into(target);
+        self.in_span_trace.write_into(target);
+        self.group_count_trace.write_into(target);
+        self.op_idx_trace.write_into(target);
+        self.op_batch_flag_trace.write_into(target);
+        self.op_bit_extra_trace.write_into(target);
+    }
+}
+
+impl Deserializable for DecoderTrace {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            addr_trace: source.read()?,
+            op_bits_trace: source.read()?,
+            hasher_trace: source.read()?,
+            in_span_trace: source.read()?,
+            group_count_trace: source.read()?,
+            op_idx_trace: source.read()?,
+            op_batch_flag_trace: source.read()?,
+            op_bit_extra_trace: source.read()?,
+        })
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -38,6 +38,11 @@ pub enum ExecutionError {
         start_addr: u64,
         end_addr: u64,
     },
+    InvalidOutputRegion {
+        start_addr: Felt,
+        num_words: Felt,
+    },
+    InvalidProcessSnapshot(String),
     InvalidStackDepthOnReturn(usize),
     InvalidStackWordOffset(usize),
     InvalidTreeDepth {
@@ -60,6 +65,7 @@ pub enum ExecutionError {
     MerkleStoreUpdateFailed(MerkleError),
     NotBinaryValue(Felt),
     NotU32Value(Felt, Felt),
+    OutputRegionCommitmentMismatch(Digest),
     ProverError(ProverError),
     SmtNodeNotFound(Word),
     SmtNodePreImageNotValid(Word, usize),
@@ -132,6 +138,15 @@ impl Display for ExecutionError {
             } => {
                 write!(f, "Memory range start address cannot exceed end address, but was ({start_addr}, {end_addr})")
             }
+            InvalidOutputRegion {
+                start_addr,
+                num_words,
+            } => {
+                write!(f, "Output region of {num_words} words starting at address {start_addr} is invalid")
+            }
+            InvalidProcessSnapshot(reason) => {
+                write!(f, "Execution cannot be resumed from the process snapshot: {reason}")
+            }
             InvalidStackDepthOnReturn(depth) => {
                 write!(f, "When returning from a call, stack depth must be {STACK_TOP_SIZE}, but was {depth}")
             }
@@ -177,6 +192,10 @@ impl Display for ExecutionError {
                     "An operation expected a u32 value, but received {v} (error code: {err_code})"
                 )
             }
+            OutputRegionCommitmentMismatch(commitment) => {
+                let commitment_hex = to_hex(&commitment.as_bytes())?;
+                write!(f, "The stack outputs do not start with the commitment {commitment_hex} to the output region")
+            }
             SmtNodeNotFound(node) => {
                 let node_hex = to_hex(Felt::elements_as_bytes(node))?;
                 write!(f, "Smt node {node_hex} not found")
@@ -0,0 +1,165 @@
+use super::{
+    ChipletsComponent, ContextId, Digest, ExecutionError, Felt, Kernel, Word, EMPTY_WORD, ZERO,
+};
+use crate::{
+    chiplets::{assert_u32, MerkleRootUpdate},
+    crypto::MerklePath,
+};
+use alloc::{collections::BTreeMap, vec::Vec};
+use miden_air::trace::chiplets::hasher::HasherState;
+use vm_core::chiplets::hasher::{apply_permutation, merge};
+
+// FAST CHIPLETS
+// ================================================================================================
+
+/// Chiplets used by the fast processor.
+///
+/// Only the state of the memory is kept by these chiplets; the results of hasher and bitwise
+/// operations are computed directly without recording their execution traces, and ZERO is
+/// returned in place of hasher trace addresses.
+pub struct FastChiplets {
+    memory: BTreeMap<ContextId, BTreeMap<u32, Word>>,
+    kernel: Kernel,
+}
+
+impl FastChiplets {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns new [FastChiplets] instantiated with the provided kernel.
+    pub fn new(kernel: Kernel) -> Self {
+        Self {
+            memory: BTreeMap::new(),
+            kernel,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks that the kernel contains the procedure with the specified hash.
+    ///
+    /// # Errors
+    /// Returns an error if the procedure with the specified hash is not a part of the kernel.
+    pub fn access_kernel_proc(&self, proc_hash: Digest) -> Result<(), ExecutionError> {
+        if !self.kernel.contains_proc(proc_hash) {
+            return Err(ExecutionError::SyscallTargetNotInKernel(proc_hash));
+        }
+        Ok(())
+    }
+
+    /// Consumes these chiplets and returns the state of the memory.
+    pub fn into_memory(self) -> BTreeMap<ContextId, BTreeMap<u32, Word>> {
+        self.memory
+    }
+}
+
+impl ChipletsComponent for FastChiplets {
+    #[inline(always)]
+    fn permute(&mut self, mut state: HasherState) -> (Felt, HasherState) {
+        apply_permutation(&mut state);
+        (ZERO, state)
+    }
+
+    fn build_merkle_root(&mut self, value: Word, path: &MerklePath, index: Felt) -> (Felt, Word) {
+        (ZERO, compute_merkle_root(value, path, index.as_int()))
+    }
+
+    fn update_merkle_root(
+        &mut self,
+        old_value: Word,
+        new_value: Word,
+        path: &MerklePath,
+        index: Felt,
+    ) -> MerkleRootUpdate {
+        let old_root = compute_merkle_root(old_value, path, index.as_int());
+        let new_root = compute_merkle_root(new_value, path, index.as_int());
+        MerkleRootUpdate::new(ZERO, old_root, new_root)
+    }
+
+    fn u32and(&mut self, a: Felt, b: Felt) -> Result<Felt, ExecutionError> {
+        let a = assert_u32(a)?.as_int();
+        let b = assert_u32(b)?.as_int();
+        Ok(Felt::new(a & b))
+    }
+
+    fn u32xor(&mut self, a: Felt, b: Felt) -> Result<Felt, ExecutionError> {
+        let a = assert_u32(a)?.as_int();
+        let b = assert_u32(b)?.as_int();
+        Ok(Felt::new(a ^ b))
+    }
+
+    /// Returns a word located in memory at the specified context/address. If the address has
+    /// never been accessed, it is initialized to ZEROs, the same way as it is done by the memory
+    /// chiplet.
+    #[inline(always)]
+    fn read_mem(&mut self, ctx: ContextId, addr: u32) -> Word {
+        *self.memory.entry(ctx).or_default().entry(addr).or_insert(EMPTY_WORD)
+    }
+
+    #[inline(always)]
+    fn read_mem_double(&mut self, ctx: ContextId, addr: u32) -> [Word; 2] {
+        [self.read_mem(ctx, addr), self.read_mem(ctx, addr + 1)]
+    }
+
+    #[inline(always)]
+    fn write_mem(&mut self, ctx: ContextId, addr: u32, word: Word) {
+        self.memory.entry(ctx).or_default().insert(addr, word);
+    }
+
+    #[inline(always)]
+    fn write_mem_element(&mut self, ctx: ContextId, addr: u32, value: Felt) -> Word {
+        let segment = self.memory.entry(ctx).or_default();
+        let old_word = segment.get(&addr).copied().unwrap_or(EMPTY_WORD);
+        segment.insert(addr, [value, old_word[1], old_word[2], old_word[3]]);
+        old_word
+    }
+
+    #[inline(always)]
+    fn write_mem_double(&mut self, ctx: ContextId, addr: u32, words: [Word; 2]) {
+        self.write_mem(ctx, addr, words[0]);
+        self.write_mem(ctx, addr + 1, words[1]);
+    }
+
+    fn get_mem_value(&self, ctx: ContextId, addr: u32) -> Option<Word> {
+        self.memory.get(&ctx).and_then(|segment| segment.get(&addr)).copied()
+    }
+
+    /// Returns the current memory state for the specified execution context; only the current
+    /// state is kept by these chiplets, and thus, the specified clock cycle is ignored.
+    fn get_mem_state_at(&self, ctx: ContextId, _clk: u32) -> Vec<(u64, Word)> {
+        self.memory
+            .get(&ctx)
+            .map(|segment| segment.iter().map(|(&addr, &value)| (addr as u64, value)).collect())
+            .unwrap_or_default()
+    }
+
+    fn advance_clock(&mut self) {}
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Computes the root of a Merkle path for the specified node value and index.
+///
+/// # Panics
+/// Panics if the path is empty or if the index is out of range for the specified path, the same
+/// way as it is done by the hasher chiplet.
+fn compute_merkle_root(value: Word, path: &MerklePath, mut index: u64) -> Word {
+    assert!(!path.is_empty(), "path is empty");
+    assert!(
+        index.checked_shr(path.len() as u32).unwrap_or(0) == 0,
+        "invalid index for the path"
+    );
+
+    let mut root = Digest::from(value);
+    for &sibling in path.iter() {
+        root = if index & 1 == 0 {
+            merge(&[root, sibling])
+        } else {
+            merge(&[sibling, root])
+        };
+        index >>= 1;
+    }
+    root.into()
+}
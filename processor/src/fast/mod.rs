@@ -0,0 +1,448 @@
+use super::{
+    components::{
+        ChipletsComponent, DecoderComponent, ProcessComponents, RangeCheckerComponent,
+        StackComponent, SystemComponent,
+    },
+    read_output_region, Call, CodeBlock, CodeBlockTable, ContextId, Decorator, DecoratorIterator,
+    Digest, Dyn, ExecutionError, ExecutionOptions, Felt, Host, Join, Kernel, Loop, OpBatch,
+    Operation, OutputRegion, Process, ProcessState, Program, Span, Split, StackInputs,
+    StackOutputs, Word, EMPTY_WORD, FMP_MIN, ONE, OP_GROUP_SIZE, SYSCALL_FMP_MIN, ZERO,
+};
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::cell::RefCell;
+
+mod system;
+use system::FastSystem;
+
+mod stack;
+use stack::FastStack;
+
+mod chiplets;
+use chiplets::FastChiplets;
+
+#[cfg(test)]
+mod tests;
+
+// FAST EXECUTOR
+// ================================================================================================
+
+/// Executes the provided program against the provided inputs without building an execution
+/// trace, and returns the outputs of the execution.
+///
+/// The operations are executed with the same semantics as in [execute()](crate::execute), but no
+/// trace rows are recorded for any of the VM components. Thus, the stack outputs, the final
+/// state of the memory, and the number of executed cycles are identical to the ones of a traced
+/// execution, while the execution itself is considerably faster. This is useful when only the
+/// outputs of a program are needed (e.g., when simulating a program before proving it).
+///
+/// The `expected_cycles` and debugging options are ignored by this executor.
+#[tracing::instrument("execute_program_fast", skip_all)]
+pub fn execute_fast<H>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    host: H,
+    options: ExecutionOptions,
+) -> Result<ExecutionOutputs, ExecutionError>
+where
+    H: Host,
+{
+    let mut process = Process::new_fast(program.kernel().clone(), stack_inputs, host, options);
+    let stack_outputs = process.execute(program)?;
+
+    let output_region = if options.enable_output_region() {
+        Some(read_output_region(&stack_outputs, |addr| {
+            process.get_mem_value(ContextId::root(), addr)
+        })?)
+    } else {
+        None
+    };
+
+    Ok(ExecutionOutputs {
+        stack_outputs,
+        output_region,
+        num_cycles: process.system.clk(),
+        memory: process.chiplets.into_memory(),
+    })
+}
+
+// EXECUTION OUTPUTS
+// ================================================================================================
+
+/// Outputs of a program executed via [execute_fast()].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExecutionOutputs {
+    stack_outputs: StackOutputs,
+    output_region: Option<OutputRegion>,
+    memory: BTreeMap<ContextId, BTreeMap<u32, Word>>,
+    num_cycles: u32,
+}
+
+impl ExecutionOutputs {
+    /// Returns the stack outputs of the program.
+    pub fn stack_outputs(&self) -> &StackOutputs {
+        &self.stack_outputs
+    }
+
+    /// Returns the output region committed to by the program, if the output region was enabled
+    /// in the execution options.
+    pub fn output_region(&self) -> Option<&OutputRegion> {
+        self.output_region.as_ref()
+    }
+
+    /// Returns the number of clock cycles executed by the VM.
+    ///
+    /// This is equal to the length of the main trace of the VM before it is padded.
+    pub fn num_cycles(&self) -> u32 {
+        self.num_cycles
+    }
+
+    /// Returns a word located at the specified context/address at the end of the execution, or
+    /// None if the address has never been accessed.
+    pub fn get_mem_value(&self, ctx: ContextId, addr: u32) -> Option<Word> {
+        self.memory.get(&ctx).and_then(|segment| segment.get(&addr)).copied()
+    }
+
+    /// Returns the state of the memory for the specified execution context at the end of the
+    /// execution.
+    ///
+    /// The state is returned as a vector of (address, value) tuples, and includes addresses which
+    /// have been accessed at least once.
+    pub fn get_mem_state(&self, ctx: ContextId) -> Vec<(u64, Word)> {
+        self.memory
+            .get(&ctx)
+            .map(|segment| segment.iter().map(|(&addr, &value)| (addr as u64, value)).collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns IDs of all execution contexts in which memory has been accessed.
+    pub fn mem_contexts(&self) -> impl Iterator<Item = ContextId> + '_ {
+        self.memory.keys().copied()
+    }
+}
+
+// FAST PROCESS
+// ================================================================================================
+
+/// Components of a process which execute programs without recording an execution trace.
+///
+/// Only the current state of the system registers, the operand stack, and the memory is kept by
+/// these components. The decoder and the range checker are not needed to compute the outputs of
+/// a program, and thus, their state is not tracked at all.
+pub struct FastComponents;
+
+impl ProcessComponents for FastComponents {
+    type System = FastSystem;
+    type Decoder = ();
+    type Stack = FastStack;
+    type RangeChecker = ();
+    type Chiplets = FastChiplets;
+}
+
+impl DecoderComponent for () {
+    fn set_user_op_helpers(&mut self, _op: Operation, _values: &[Felt]) {}
+}
+
+impl RangeCheckerComponent for () {
+    fn add_range_checks(&mut self, _clk: u32, _values: &[u16]) {}
+}
+
+impl<H> Process<H, FastComponents>
+where
+    H: Host,
+{
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Creates a new fast process with the provided inputs.
+    fn new_fast(
+        kernel: Kernel,
+        stack_inputs: StackInputs,
+        host: H,
+        options: ExecutionOptions,
+    ) -> Self {
+        Self {
+            system: FastSystem::new(),
+            decoder: (),
+            stack: FastStack::new(&stack_inputs),
+            range: (),
+            chiplets: FastChiplets::new(kernel),
+            host: RefCell::new(host),
+            max_cycles: options.max_cycles(),
+            enable_tracing: options.enable_tracing(),
+            pause_at: None,
+            pause_path: None,
+        }
+    }
+
+    // PROGRAM EXECUTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Executes the provided [Program] in this process.
+    fn execute(&mut self, program: &Program) -> Result<StackOutputs, ExecutionError> {
+        self.execute_code_block(program.root(), program.cb_table())?;
+        Ok(self.stack.build_stack_outputs())
+    }
+
+    // CODE BLOCK EXECUTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Executes the specified [CodeBlock].
+    ///
+    /// The state of the VM at the start and at the end of each code block is updated by the same
+    /// operations as the ones executed by the decoder of the regular processor.
+    fn execute_code_block(
+        &mut self,
+        block: &CodeBlock,
+        cb_table: &CodeBlockTable,
+    ) -> Result<(), ExecutionError> {
+        match block {
+            CodeBlock::Join(block) => self.execute_join_block(block, cb_table),
+            CodeBlock::Split(block) => self.execute_split_block(block, cb_table),
+            CodeBlock::Loop(block) => self.execute_loop_block(block, cb_table),
+            CodeBlock::Call(block) => self.execute_call_block(block, cb_table),
+            CodeBlock::Dyn(block) => self.execute_dyn_block(block, cb_table),
+            CodeBlock::Span(block) => self.execute_span_block(block),
+            CodeBlock::Proxy(_) => Err(ExecutionError::UnexecutableCodeBlock(block.clone())),
+        }
+    }
+
+    /// Executes the specified [Join] block.
+    #[inline(always)]
+    fn execute_join_block(
+        &mut self,
+        block: &Join,
+        cb_table: &CodeBlockTable,
+    ) -> Result<(), ExecutionError> {
+        // JOIN and END operations do not change the state of the VM
+        self.execute_op(Operation::Noop)?;
+        self.execute_code_block(block.first(), cb_table)?;
+        self.execute_code_block(block.second(), cb_table)?;
+        self.execute_op(Operation::Noop)
+    }
+
+    /// Executes the specified [Split] block.
+    #[inline(always)]
+    fn execute_split_block(
+        &mut self,
+        block: &Split,
+        cb_table: &CodeBlockTable,
+    ) -> Result<(), ExecutionError> {
+        // SPLIT operation pops the condition off the stack
+        let condition = self.stack.get(0);
+        self.execute_op(Operation::Drop)?;
+
+        if condition == ONE {
+            self.execute_code_block(block.on_true(), cb_table)?;
+        } else if condition == ZERO {
+            self.execute_code_block(block.on_false(), cb_table)?;
+        } else {
+            return Err(ExecutionError::NotBinaryValue(condition));
+        }
+
+        self.execute_op(Operation::Noop)
+    }
+
+    /// Executes the specified [Loop] block.
+    #[inline(always)]
+    fn execute_loop_block(
+        &mut self,
+        block: &Loop,
+        cb_table: &CodeBlockTable,
+    ) -> Result<(), ExecutionError> {
+        // LOOP operation pops the condition off the stack
+        let condition = self.stack.get(0);
+        self.execute_op(Operation::Drop)?;
+
+        if condition == ONE {
+            // execute the loop body at least once; each subsequent iteration is preceded by
+            // REPEAT operation which drops the condition from the stack
+            self.execute_code_block(block.body(), cb_table)?;
+            while self.stack.get(0) == ONE {
+                self.execute_op(Operation::Drop)?;
+                self.execute_code_block(block.body(), cb_table)?;
+            }
+
+            // END operation drops the condition from the stack when exiting the loop
+            debug_assert_eq!(ZERO, self.stack.get(0));
+            self.execute_op(Operation::Drop)
+        } else if condition == ZERO {
+            self.execute_op(Operation::Noop)
+        } else {
+            Err(ExecutionError::NotBinaryValue(condition))
+        }
+    }
+
+    /// Executes the specified [Call] block.
+    #[inline(always)]
+    fn execute_call_block(
+        &mut self,
+        block: &Call,
+        cb_table: &CodeBlockTable,
+    ) -> Result<(), ExecutionError> {
+        // if this is a syscall, make sure the call target exists in the kernel
+        if block.is_syscall() {
+            self.chiplets.access_kernel_proc(block.fn_hash())?;
+        }
+
+        // save the current execution context and start a new one
+        let parent_ctx = self.system.ctx();
+        let parent_fmp = self.system.fmp();
+        let parent_fn_hash = self.system.fn_hash();
+        let parent_stack_context = self.stack.start_context();
+
+        if block.is_syscall() {
+            self.system.start_syscall();
+        } else {
+            self.system.start_call(block.fn_hash().into());
+        }
+        self.execute_op(Operation::Noop)?;
+
+        // if this is a dyncall, execute the dynamic code block
+        if block.fn_hash() == Dyn::dyn_hash() {
+            self.execute_dyn_block(&Dyn::new(), cb_table)?;
+        } else {
+            let fn_body = cb_table
+                .get(block.fn_hash())
+                .ok_or_else(|| ExecutionError::CodeBlockNotFound(block.fn_hash()))?;
+            self.execute_code_block(fn_body, cb_table)?;
+        }
+
+        // when a CALL block ends, stack depth must be exactly 16
+        let stack_depth = self.stack.depth();
+        if stack_depth > vm_core::stack::STACK_TOP_SIZE {
+            return Err(ExecutionError::InvalidStackDepthOnReturn(stack_depth));
+        }
+
+        // restore the context of the caller
+        self.system.restore_context(parent_ctx, parent_fmp, parent_fn_hash);
+        self.stack.restore_context(parent_stack_context);
+        self.execute_op(Operation::Noop)
+    }
+
+    /// Executes the specified [Dyn] block.
+    #[inline(always)]
+    fn execute_dyn_block(
+        &mut self,
+        _block: &Dyn,
+        cb_table: &CodeBlockTable,
+    ) -> Result<(), ExecutionError> {
+        // get target hash from the stack; DYN operation does not change the state of the stack
+        let dyn_digest = self.stack.get_word(0).into();
+        self.execute_op(Operation::Noop)?;
+
+        let dyn_code = cb_table
+            .get(dyn_digest)
+            .ok_or(ExecutionError::DynamicCodeBlockNotFound(dyn_digest))?;
+        self.execute_code_block(dyn_code, cb_table)?;
+
+        self.execute_op(Operation::Noop)
+    }
+
+    /// Executes the specified [Span] block.
+    #[inline(always)]
+    fn execute_span_block(&mut self, block: &Span) -> Result<(), ExecutionError> {
+        // SPAN operation does not change the state of the VM
+        self.execute_op(Operation::Noop)?;
+
+        let mut op_offset = 0;
+        let mut decorators = block.decorator_iter();
+
+        // each operation batch after the first one is preceded by a RESPAN operation
+        for (i, op_batch) in block.op_batches().iter().enumerate() {
+            if i > 0 {
+                self.execute_op(Operation::Noop)?;
+            }
+            self.execute_op_batch(op_batch, &mut decorators, op_offset)?;
+            op_offset += op_batch.ops().len();
+        }
+
+        // END operation does not change the state of the VM
+        self.execute_op(Operation::Noop)?;
+
+        // execute any decorators which have not been executed during span ops execution
+        for decorator in decorators {
+            self.execute_decorator(decorator)?;
+        }
+
+        Ok(())
+    }
+
+    /// Executes all operations in an [OpBatch]. This also executes the NOOPs required by the
+    /// alignment rules of operation groups in the same way as the regular processor does; this
+    /// keeps the clock cycles of all operations identical to the ones of a traced execution.
+    #[inline(always)]
+    fn execute_op_batch(
+        &mut self,
+        batch: &OpBatch,
+        decorators: &mut DecoratorIterator,
+        op_offset: usize,
+    ) -> Result<(), ExecutionError> {
+        let op_counts = batch.op_counts();
+        let mut op_idx = 0;
+        let mut group_idx = 0;
+        let mut next_group_idx = 1;
+        let num_batch_groups = batch.num_groups().next_power_of_two();
+
+        for (i, &op) in batch.ops().iter().enumerate() {
+            while let Some(decorator) = decorators.next_filtered(i + op_offset) {
+                self.execute_decorator(decorator)?;
+            }
+
+            self.execute_op(op)?;
+
+            let has_imm = op.imm_value().is_some();
+            if has_imm {
+                next_group_idx += 1;
+            }
+
+            if op_idx == op_counts[group_idx] - 1 {
+                // an operation with an immediate value cannot be the last operation in a group,
+                // and thus, a NOOP is executed after it
+                if has_imm {
+                    debug_assert!(op_idx < OP_GROUP_SIZE - 1, "invalid op index");
+                    self.execute_op(Operation::Noop)?;
+                }
+
+                group_idx = next_group_idx;
+                next_group_idx += 1;
+                op_idx = 0;
+            } else {
+                op_idx += 1;
+            }
+        }
+
+        // a NOOP is executed for each of the groups added to round up the number of groups in the
+        // batch to the next power of two
+        for _ in group_idx..num_batch_groups {
+            self.execute_op(Operation::Noop)?;
+        }
+
+        Ok(())
+    }
+
+    /// Executes the specified decorator.
+    fn execute_decorator(&mut self, decorator: &Decorator) -> Result<(), ExecutionError> {
+        match decorator {
+            Decorator::Advice(injector) => {
+                self.host.borrow_mut().set_advice(self, *injector)?;
+            }
+            Decorator::Debug(options) => {
+                self.host.borrow_mut().on_debug(self, options)?;
+            }
+            Decorator::AsmOp(assembly_op) => {
+                if assembly_op.should_break() {
+                    self.host.borrow_mut().on_breakpoint(self)?;
+                }
+            }
+            Decorator::Event(id) => {
+                self.host.borrow_mut().on_event(self, *id)?;
+            }
+            Decorator::Trace(id) => {
+                if self.enable_tracing {
+                    self.host.borrow_mut().on_trace(self, *id)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,167 @@
+use super::{Felt, StackComponent, StackInputs, StackOutputs, Word, ZERO};
+use alloc::vec::Vec;
+use vm_core::{stack::STACK_TOP_SIZE, StarkField, WORD_SIZE};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The last stack index accessible by the VM.
+const MAX_TOP_IDX: usize = STACK_TOP_SIZE - 1;
+
+// FAST STACK
+// ================================================================================================
+
+/// Operand stack used by the fast processor.
+///
+/// Unlike the stack of the regular processor, this stack keeps only the state of the top 16 slots
+/// at the current and at the next clock cycle. However, the values which do not fit into the top
+/// 16 slots are tagged with the same addresses as the rows of the overflow table would have been,
+/// so that [StackOutputs] built from this stack are identical to the ones built from a traced
+/// execution.
+pub struct FastStack {
+    clk: u32,
+    current: [Felt; STACK_TOP_SIZE],
+    next: [Felt; STACK_TOP_SIZE],
+    overflow: Vec<(Felt, Felt)>,
+    context_start: usize,
+}
+
+impl FastStack {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a [FastStack] initialized with the specified program inputs.
+    pub fn new(inputs: &StackInputs) -> Self {
+        let init_values = inputs.values();
+        let mut current = [ZERO; STACK_TOP_SIZE];
+        let num_top_values = init_values.len().min(STACK_TOP_SIZE);
+        current[..num_top_values].copy_from_slice(&init_values[..num_top_values]);
+
+        // values which do not fit into the top 16 slots are assigned addresses counting up to the
+        // field modulus, the same way as it is done for the initial rows of the overflow table
+        let mut overflow = Vec::new();
+        if init_values.len() > STACK_TOP_SIZE {
+            let overflow_values = &init_values[STACK_TOP_SIZE..];
+            let first_addr = Felt::MODULUS - overflow_values.len() as u64;
+            for (addr, &value) in (first_addr..).zip(overflow_values.iter().rev()) {
+                overflow.push((value, Felt::new(addr)));
+            }
+        }
+
+        Self {
+            clk: 0,
+            current,
+            next: current,
+            overflow,
+            context_start: 0,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns [StackOutputs] consisting of all values on the stack and all addresses of the
+    /// overflow table rows.
+    pub fn build_stack_outputs(&self) -> StackOutputs {
+        let stack_items = self.get_state_at(self.clk);
+        let overflow_addrs = if self.overflow.is_empty() {
+            Vec::new()
+        } else {
+            // the first row of the overflow table always points to an empty table
+            let mut addrs = Vec::with_capacity(self.overflow.len() + 1);
+            addrs.push(ZERO);
+            addrs.extend(self.overflow.iter().map(|&(_, addr)| addr));
+            addrs
+        };
+
+        StackOutputs::new(stack_items, overflow_addrs)
+            .expect("processor stack handling logic is valid")
+    }
+
+    // CONTEXT MANAGEMENT
+    // --------------------------------------------------------------------------------------------
+
+    /// Starts a new execution context for this stack and returns the state required to restore
+    /// the current context.
+    ///
+    /// This has the effect of hiding the contents of the overflow table such that it appears as
+    /// if the overflow table in the new context is empty.
+    pub fn start_context(&mut self) -> usize {
+        let context_start = self.context_start;
+        self.context_start = self.overflow.len();
+        context_start
+    }
+
+    /// Restores the prior context for this stack.
+    pub fn restore_context(&mut self, context_start: usize) {
+        debug_assert_eq!(self.context_start, self.overflow.len(), "overflow table not empty");
+        self.context_start = context_start;
+    }
+}
+
+impl StackComponent for FastStack {
+    fn depth(&self) -> usize {
+        STACK_TOP_SIZE + self.overflow.len() - self.context_start
+    }
+
+    #[inline(always)]
+    fn get(&self, pos: usize) -> Felt {
+        self.current[pos]
+    }
+
+    #[inline(always)]
+    fn get_word(&self, word_idx: usize) -> Word {
+        let offset = word_idx * WORD_SIZE;
+        [
+            self.current[offset + 3],
+            self.current[offset + 2],
+            self.current[offset + 1],
+            self.current[offset],
+        ]
+    }
+
+    /// Returns the current state of the stack; only the current state is kept by this stack, and
+    /// thus, the specified clock cycle is ignored.
+    fn get_state_at(&self, _clk: u32) -> Vec<Felt> {
+        let mut result = Vec::with_capacity(STACK_TOP_SIZE + self.overflow.len());
+        result.extend_from_slice(&self.current);
+        result.extend(self.overflow.iter().rev().map(|&(value, _)| value));
+        result
+    }
+
+    #[inline(always)]
+    fn set(&mut self, pos: usize, value: Felt) {
+        self.next[pos] = value;
+    }
+
+    #[inline(always)]
+    fn copy_state(&mut self, start_pos: usize) {
+        self.next[start_pos..].copy_from_slice(&self.current[start_pos..]);
+    }
+
+    #[inline(always)]
+    fn shift_left(&mut self, start_pos: usize) {
+        debug_assert!(start_pos > 0, "start position must be greater than 0");
+        self.next[start_pos - 1..MAX_TOP_IDX].copy_from_slice(&self.current[start_pos..]);
+        self.next[MAX_TOP_IDX] = if self.overflow.len() > self.context_start {
+            self.overflow.pop().expect("overflow table is empty").0
+        } else {
+            ZERO
+        };
+    }
+
+    #[inline(always)]
+    fn shift_right(&mut self, start_pos: usize) {
+        debug_assert_ne!(self.clk, 0, "cannot add value to overflow at clk=0");
+        self.next[start_pos + 1..].copy_from_slice(&self.current[start_pos..MAX_TOP_IDX]);
+        self.overflow.push((self.current[MAX_TOP_IDX], Felt::from(self.clk)));
+    }
+
+    #[inline(always)]
+    fn advance_clock(&mut self) {
+        self.clk += 1;
+        self.current = self.next;
+    }
+
+    fn ensure_trace_capacity(&mut self) {}
+}
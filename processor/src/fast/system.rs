@@ -0,0 +1,106 @@
+use super::{
+    ContextId, ExecutionError, Felt, SystemComponent, Word, EMPTY_WORD, FMP_MIN, SYSCALL_FMP_MIN,
+};
+
+// FAST SYSTEM
+// ================================================================================================
+
+/// System registers used by the fast processor.
+///
+/// Unlike the system info container of the regular processor, only the current values of the
+/// registers are kept.
+pub struct FastSystem {
+    clk: u32,
+    ctx: ContextId,
+    fmp: Felt,
+    in_syscall: bool,
+    fn_hash: Word,
+}
+
+impl FastSystem {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [FastSystem] with all registers set to their initial values.
+    pub fn new() -> Self {
+        Self {
+            clk: 0,
+            ctx: ContextId::root(),
+            fmp: Felt::new(FMP_MIN),
+            in_syscall: false,
+            fn_hash: EMPTY_WORD,
+        }
+    }
+
+    // CONTEXT MANAGEMENT
+    // --------------------------------------------------------------------------------------------
+
+    /// Updates system registers to mark a new function call. A CALL cannot be started when the
+    /// VM is executing a SYSCALL.
+    pub fn start_call(&mut self, fn_hash: Word) {
+        debug_assert!(!self.in_syscall, "call in syscall");
+        self.ctx = (self.clk + 1).into();
+        self.fmp = Felt::new(FMP_MIN);
+        self.fn_hash = fn_hash;
+    }
+
+    /// Updates system registers to mark a new syscall. A SYSCALL cannot be started when the VM
+    /// is executing a SYSCALL.
+    pub fn start_syscall(&mut self) {
+        debug_assert!(!self.in_syscall, "already in syscall");
+        self.ctx = ContextId::root();
+        self.fmp = Felt::from(SYSCALL_FMP_MIN);
+        self.in_syscall = true;
+    }
+
+    /// Updates system registers to the provided values at the end of a CALL or a SYSCALL block.
+    pub fn restore_context(&mut self, ctx: ContextId, fmp: Felt, fn_hash: Word) {
+        self.ctx = ctx;
+        self.fmp = fmp;
+        self.in_syscall = false;
+        self.fn_hash = fn_hash;
+    }
+}
+
+impl SystemComponent for FastSystem {
+    #[inline(always)]
+    fn clk(&self) -> u32 {
+        self.clk
+    }
+
+    #[inline(always)]
+    fn ctx(&self) -> ContextId {
+        self.ctx
+    }
+
+    #[inline(always)]
+    fn fmp(&self) -> Felt {
+        self.fmp
+    }
+
+    #[inline(always)]
+    fn in_syscall(&self) -> bool {
+        self.in_syscall
+    }
+
+    #[inline(always)]
+    fn fn_hash(&self) -> Word {
+        self.fn_hash
+    }
+
+    #[inline(always)]
+    fn set_fmp(&mut self, fmp: Felt) {
+        self.fmp = fmp;
+    }
+
+    #[inline(always)]
+    fn advance_clock(&mut self, max_cycles: u32) -> Result<(), ExecutionError> {
+        self.clk += 1;
+        if self.clk > max_cycles {
+            return Err(ExecutionError::CycleLimitExceeded(max_cycles));
+        }
+        Ok(())
+    }
+
+    fn ensure_trace_capacity(&mut self) {}
+}
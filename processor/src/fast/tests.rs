@@ -0,0 +1,217 @@
+use super::{execute_fast, ExecutionError, ExecutionOptions, Program, StackInputs};
+use crate::{
+    crypto::{MerkleStore, MerkleTree},
+    AdviceInputs, DefaultHost, MemAdviceProvider, Process, Word,
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use miden_assembly::Assembler;
+use test_utils::rand::rand_array;
+use vm_core::Felt;
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn fast_execution_field_and_u32_ops() {
+    let source = "
+        begin
+            push.5 push.7 add push.3 mul neg inv add.1
+            push.1 push.0 and push.1 or not
+            push.4 push.4 eq push.9 eq.0
+            push.3 push.2 exp drop
+            push.1 push.2 push.3 push.4 ext2mul
+            push.4294967297 u32split
+            push.10 push.20 u32overflowing_add push.1 push.2 push.3 u32overflowing_add3
+            push.30 push.40 u32overflowing_sub push.50 push.60 u32overflowing_mul
+            push.7 push.8 push.9 u32overflowing_madd push.100 push.7 u32divmod
+            push.12 push.10 u32and push.12 push.10 u32xor push.1 push.2 u32assert2
+            clk sdepth
+        end";
+
+    assert_outputs_match(&compile(source), stack_inputs(), AdviceInputs::default());
+}
+
+#[test]
+fn fast_execution_stack_ops() {
+    let source = "
+        begin
+            repeat.4 push.1 push.2 push.3 push.4 end
+            padw drop dup.3 dup.9 dup.15 swap swapw swapw.2 swapw.3 swapdw
+            movup.2 movup.5 movup.8 movdn.2 movdn.5 movdn.8
+            push.0 cswap push.1 cswap push.0 cswapw push.1 cswapw
+            dropw dropw dropw dropw dropw dropw dropw
+        end";
+
+    // stack inputs deeper than 16 elements make sure that the overflow table addresses match
+    let stack_inputs = StackInputs::try_from_ints(1..=20).unwrap();
+    assert_outputs_match(&compile(source), stack_inputs, AdviceInputs::default());
+}
+
+#[test]
+fn fast_execution_memory_and_advice_ops() {
+    let source = "
+        proc.foo.2
+            loc_store.0 loc_storew.1 dropw push.7 mem_store.3 loc_load.0 loc_loadw.1
+        end
+
+        begin
+            push.1.2.3.4 mem_storew.10 dropw push.10 mem_loadw
+            push.5 mem_store.11 mem_load.11 mem_load.12
+            adv_push.2 adv_loadw
+            push.20 padw padw padw adv_pipe hperm dropw dropw dropw drop
+            push.20 padw padw padw mem_stream dropw dropw dropw drop
+            exec.foo
+            push.30 push.31 push.0 padw padw padw rcomb_base
+        end";
+
+    let advice_inputs = AdviceInputs::default().with_stack_values(1..=14).unwrap();
+    assert_outputs_match(&compile(source), stack_inputs(), advice_inputs);
+}
+
+#[test]
+fn fast_execution_control_flow() {
+    let source = "
+        proc.foo.1
+            dup mem_store.1 loc_store.0 push.3 mem_store.2
+        end
+
+        proc.bar
+            push.1 push.2 push.3 dropw
+        end
+
+        begin
+            push.3 push.1
+            while.true
+                dup call.foo push.1 sub dup neq.0
+            end
+            push.1
+            if.true push.2 mul else push.3 mul end
+            push.0
+            if.true push.4 else push.5 end
+            push.0 while.true push.1 end
+            procref.bar mem_storew.20 dropw push.20 mem_loadw dynexec
+            procref.bar mem_storew.20 dropw push.20 mem_loadw dyncall
+            dropw
+        end";
+
+    assert_outputs_match(&compile(source), stack_inputs(), AdviceInputs::default());
+}
+
+#[test]
+fn fast_execution_syscall() {
+    let kernel = "
+        export.foo
+            caller push.1 mem_store.5 dropw
+        end";
+    let source = "
+        proc.bar
+            syscall.foo
+        end
+
+        begin
+            syscall.foo call.bar
+        end";
+
+    let assembler = Assembler::default().with_kernel(kernel).unwrap();
+    let program = assembler.compile(source).unwrap();
+    assert_outputs_match(&program, stack_inputs(), AdviceInputs::default());
+}
+
+#[test]
+fn fast_execution_merkle_ops() {
+    let leaves: [Word; 4] = [word(1), word(2), word(3), word(4)];
+    let tree = MerkleTree::new(leaves).unwrap();
+    let root = word_to_masm(tree.root().into());
+    let source = format!(
+        "
+        begin
+            push.{root} push.1 push.2 mtree_get
+            push.1 push.2 movdn.5 movdn.5 mtree_verify dropw drop drop
+            push.{new_leaf} swapw push.1 push.2 mtree_set
+        end",
+        new_leaf = word_to_masm(word(9)),
+    );
+
+    let advice_inputs = AdviceInputs::default().with_merkle_store(MerkleStore::from(&tree));
+    assert_outputs_match(&compile(&source), stack_inputs(), advice_inputs);
+}
+
+#[test]
+fn fast_execution_fri_ext2fold4() {
+    // the pointer to the last FRI layer is located in the first row of the overflow table
+    let mut inputs = rand_array::<Felt, 17>();
+    inputs[7] = Felt::new(2);
+    inputs[4] = inputs[13];
+    inputs[5] = inputs[14];
+
+    let stack_inputs = StackInputs::new(inputs.to_vec()).unwrap();
+    assert_outputs_match(
+        &compile("begin fri_ext2fold4 end"),
+        stack_inputs,
+        AdviceInputs::default(),
+    );
+}
+
+#[test]
+fn fast_execution_errors() {
+    let options = ExecutionOptions::new(Some(64), 64, false).unwrap();
+    let program = compile("begin repeat.100 push.1 drop end end");
+    let result = execute_fast(&program, stack_inputs(), DefaultHost::default(), options);
+    assert_eq!(result, Err(ExecutionError::CycleLimitExceeded(64)));
+
+    let program = compile("begin push.0 assert end");
+    let result = execute_fast(&program, stack_inputs(), DefaultHost::default(), options);
+    assert!(matches!(result, Err(ExecutionError::FailedAssertion { clk: 2, .. })));
+
+    let program = compile("begin push.0 inv end");
+    let result = execute_fast(&program, stack_inputs(), DefaultHost::default(), options);
+    assert_eq!(result, Err(ExecutionError::DivideByZero(2)));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Executes the program using both the fast and the regular processor, and makes sure that the
+/// stack outputs, the number of executed cycles, and the final state of the memory are the same.
+fn assert_outputs_match(program: &Program, stack_inputs: StackInputs, advice_inputs: AdviceInputs) {
+    let options = ExecutionOptions::default();
+
+    let host = DefaultHost::new(MemAdviceProvider::from(advice_inputs.clone()));
+    let mut process = Process::new(program.kernel().clone(), stack_inputs.clone(), host, options);
+    let expected_stack_outputs = process.execute(program).unwrap();
+
+    let host = DefaultHost::new(MemAdviceProvider::from(advice_inputs));
+    let outputs = execute_fast(program, stack_inputs, host, options).unwrap();
+
+    assert_eq!(&expected_stack_outputs, outputs.stack_outputs());
+    assert_eq!(process.system.clk(), outputs.num_cycles());
+
+    let clk = process.system.clk();
+    for ctx in outputs.mem_contexts() {
+        assert_eq!(process.chiplets.get_mem_state_at(ctx, clk), outputs.get_mem_state(ctx));
+    }
+}
+
+fn compile(source: &str) -> Program {
+    Assembler::default().compile(source).unwrap()
+}
+
+fn stack_inputs() -> StackInputs {
+    StackInputs::try_from_ints([1, 2, 3, 4]).unwrap()
+}
+
+fn word(value: u64) -> Word {
+    [
+        Felt::new(value),
+        Felt::new(value + 1),
+        Felt::new(value + 2),
+        Felt::new(value + 3),
+    ]
+}
+
+fn word_to_masm(word: Word) -> String {
+    word.iter().map(|value| value.to_string()).collect::<Vec<_>>().join(".")
+}
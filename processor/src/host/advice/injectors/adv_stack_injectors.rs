@@ -150,6 +150,52 @@ pub(crate) fn push_u64_div_result<S: ProcessState, A: AdviceProvider>(
     Ok(HostResponse::None)
 }
 
+/// Pushes the result of 256-bit unsigned integer division (both the quotient and the remainder)
+/// onto the advice stack.
+///
+/// Inputs:
+///   Operand stack: [b7, b6, b5, b4, b3, b2, b1, b0, a7, a6, a5, a4, a3, a2, a1, a0, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [b7, b6, b5, b4, b3, b2, b1, b0, a7, a6, a5, a4, a3, a2, a1, a0, ...]
+///   Advice stack: [q0, q1, q2, q3, q4, q5, q6, q7, r0, r1, r2, r3, r4, r5, r6, r7, ...]
+///
+/// Where (a0, ..., a7) and (b0, ..., b7) are the 32-bit limbs of the dividend and the divisor
+/// respectively (with a0 representing the 32 least significant bits and a7 representing the
+/// 32 most significant bits). Similarly, (q0, ..., q7) and (r0, ..., r7) represent the quotient
+/// and the remainder respectively.
+///
+/// # Errors
+/// Returns an error if:
+/// - The divisor is ZERO.
+/// - Any of the limbs of the dividend or the divisor is not a valid u32 value.
+pub(crate) fn push_u256_div_result<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+) -> Result<HostResponse, ExecutionError> {
+    // limbs are read from the stack with the most significant limb first, and stored with the
+    // least significant limb first
+    let mut divisor = [0_u32; 8];
+    let mut dividend = [0_u32; 8];
+    for i in 0..8 {
+        divisor[7 - i] = read_u32_limb(process, i)?;
+        dividend[7 - i] = read_u32_limb(process, i + 8)?;
+    }
+
+    if divisor == [0; 8] {
+        return Err(ExecutionError::DivideByZero(process.clk()));
+    }
+
+    let (quotient, remainder) = u256_divmod(&dividend, &divisor);
+
+    for &limb in remainder.iter().rev().chain(quotient.iter().rev()) {
+        advice_provider.push_stack(AdviceSource::Value(Felt::from(limb)))?;
+    }
+
+    Ok(HostResponse::None)
+}
+
 /// Given an element in a quadratic extension field on the top of the stack (i.e., a0, b1),
 /// computes its multiplicative inverse and push the result onto the advice stack.
 ///
@@ -418,3 +464,51 @@ fn push_transformed_stack_top<S: ProcessState, A: AdviceProvider>(
     advice_provider.push_stack(AdviceSource::Value(transformed_stack_top))?;
     Ok(HostResponse::None)
 }
+
+/// Reads the stack element at the specified position and returns it as a u32 value.
+///
+/// # Errors
+/// Returns an error if the element is not a valid u32 value.
+fn read_u32_limb<S: ProcessState>(process: &S, pos: usize) -> Result<u32, ExecutionError> {
+    let value = process.get_stack_item(pos);
+    value.as_int().try_into().map_err(|_| ExecutionError::NotU32Value(value, ZERO))
+}
+
+/// Computes the quotient and the remainder of dividing the 256-bit `dividend` by the 256-bit
+/// `divisor` using binary long division. Both values are represented by their 32-bit limbs, with
+/// the least significant limb first.
+///
+/// The divisor is assumed to be non-zero.
+fn u256_divmod(dividend: &[u32; 8], divisor: &[u32; 8]) -> ([u32; 8], [u32; 8]) {
+    let mut quotient = [0_u32; 8];
+    let mut remainder = [0_u32; 8];
+
+    for bit in (0..256).rev() {
+        // shift the remainder left by one bit, bringing in the next bit of the dividend
+        let mut carry = (dividend[bit / 32] >> (bit % 32)) & 1;
+        for limb in remainder.iter_mut() {
+            let next_carry = *limb >> 31;
+            *limb = (*limb << 1) | carry;
+            carry = next_carry;
+        }
+
+        // subtract the divisor if the (possibly 257-bit) remainder is not smaller than it
+        if carry == 1 || !u256_lt(&remainder, divisor) {
+            let mut borrow = false;
+            for (r, &d) in remainder.iter_mut().zip(divisor.iter()) {
+                let (diff, b1) = r.overflowing_sub(d);
+                let (diff, b2) = diff.overflowing_sub(borrow as u32);
+                *r = diff;
+                borrow = b1 || b2;
+            }
+            quotient[bit / 32] |= 1 << (bit % 32);
+        }
+    }
+
+    (quotient, remainder)
+}
+
+/// Returns true if the 256-bit value `a` is smaller than the 256-bit value `b`.
+fn u256_lt(a: &[u32; 8], b: &[u32; 8]) -> bool {
+    a.iter().rev().cmp(b.iter().rev()).is_lt()
+}
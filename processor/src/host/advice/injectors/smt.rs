@@ -1,12 +1,13 @@
 use super::super::{AdviceSource, ExecutionError, Felt, HostResponse, Word};
 use crate::{AdviceProvider, ProcessState};
 use alloc::vec::Vec;
+use core::cmp::Ordering;
 use vm_core::{
     crypto::{
-        hash::RpoDigest,
+        hash::{Rpo256, RpoDigest},
         merkle::{EmptySubtreeRoots, Smt, SMT_DEPTH},
     },
-    WORD_SIZE,
+    WORD_SIZE, ZERO,
 };
 
 // SMT INJECTORS
@@ -65,20 +66,108 @@ pub(crate) fn push_smtpeek_result<S: ProcessState, A: AdviceProvider>(
     Ok(HostResponse::None)
 }
 
-/// Currently unimplemented
+/// Pushes onto the advice stack the pre-image of the leaf which the specified key maps to in a
+/// Sparse Merkle Tree defined by the specified root, preceded by the number of elements in the
+/// pre-image.
+///
+/// If the leaf is empty, only the number of elements (i.e., 0) is pushed onto the advice
+/// stack.
+///
+/// Inputs:
+///   Operand stack: [KEY, ROOT, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [KEY, ROOT, ...]
+///   Advice stack: [leaf_size, KEY_0, VALUE_0, ..., KEY_{n-1}, VALUE_{n-1}, ...]
+///
+/// Where leaf_size = 8 * n.
+///
+/// # Errors
+/// Returns an error if:
+/// - The provided Merkle root doesn't exist on the advice provider.
+/// - The pre-image of a non-empty leaf is not present in the advice map or is malformed.
 pub(crate) fn push_smtget_inputs<S: ProcessState, A: AdviceProvider>(
-    _advice_provider: &mut A,
-    _process: &S,
+    advice_provider: &mut A,
+    process: &S,
 ) -> Result<HostResponse, ExecutionError> {
-    unimplemented!()
+    let empty_leaf = EmptySubtreeRoots::entry(SMT_DEPTH, SMT_DEPTH);
+    // fetch the arguments from the operand stack
+    let key = process.get_stack_word(0);
+    let root = process.get_stack_word(1);
+
+    let node = advice_provider.get_tree_node(root, &Felt::new(SMT_DEPTH as u64), &key[3])?;
+    if node == Word::from(empty_leaf) {
+        advice_provider.push_stack(AdviceSource::Value(ZERO))?;
+    } else {
+        // make sure that the pre-image is well-formed before pushing it onto the advice stack
+        get_smt_leaf_preimage(advice_provider, node)?;
+        advice_provider.push_stack(AdviceSource::Map {
+            key: node,
+            include_len: true,
+        })?;
+    }
+
+    Ok(HostResponse::None)
 }
 
-/// Currently unimplemented
+/// Inserts into the advice map the pre-image of the leaf which the specified key maps to, as
+/// the leaf would be after the specified value is set under the key in a Sparse Merkle Tree
+/// defined by the specified root.
+///
+/// The pre-image is inserted under the hash of the updated leaf. If the leaf becomes empty, the
+/// advice map is left unchanged.
+///
+/// Inputs:
+///   Operand stack: [VALUE, KEY, ROOT, ...]
+///   Advice map: {...}
+///
+/// Outputs:
+///   Operand stack: [VALUE, KEY, ROOT, ...]
+///   Advice map: {NEW_LEAF_HASH: [KEY_0, VALUE_0, ..., KEY_{n-1}, VALUE_{n-1}]}
+///
+/// # Errors
+/// Returns an error if:
+/// - The provided Merkle root doesn't exist on the advice provider.
+/// - The pre-image of a non-empty leaf is not present in the advice map or is malformed.
 pub(crate) fn push_smtset_inputs<S: ProcessState, A: AdviceProvider>(
-    _advice_provider: &mut A,
-    _process: &S,
+    advice_provider: &mut A,
+    process: &S,
 ) -> Result<HostResponse, ExecutionError> {
-    unimplemented!()
+    let empty_leaf = EmptySubtreeRoots::entry(SMT_DEPTH, SMT_DEPTH);
+    // fetch the arguments from the operand stack
+    let value = process.get_stack_word(0);
+    let key = process.get_stack_word(1);
+    let root = process.get_stack_word(2);
+
+    let node = advice_provider.get_tree_node(root, &Felt::new(SMT_DEPTH as u64), &key[3])?;
+    let mut kv_pairs = if node == Word::from(empty_leaf) {
+        Vec::new()
+    } else {
+        get_smt_leaf_preimage(advice_provider, node)?
+    };
+
+    // key-value pairs in a leaf are sorted by key; setting a value to [ZERO; 4] removes the pair
+    match kv_pairs.binary_search_by(|(key_in_leaf, _)| cmp_keys(key_in_leaf, &key)) {
+        Ok(pos) if value == Smt::EMPTY_VALUE => {
+            kv_pairs.remove(pos);
+        }
+        Ok(pos) => kv_pairs[pos].1 = value,
+        Err(_) if value == Smt::EMPTY_VALUE => (),
+        Err(pos) => kv_pairs.insert(pos, (key, value)),
+    }
+
+    if !kv_pairs.is_empty() {
+        let leaf_preimage: Vec<Felt> = kv_pairs
+            .iter()
+            .flat_map(|(key, value)| key.iter().chain(value.iter()))
+            .copied()
+            .collect();
+        let leaf_hash = Rpo256::hash_elements(&leaf_preimage);
+        advice_provider.insert_into_map(leaf_hash.into(), leaf_preimage)?;
+    }
+
+    Ok(HostResponse::None)
 }
 
 // HELPER METHODS
@@ -108,3 +197,8 @@ fn get_smt_leaf_preimage<A: AdviceProvider>(
         })
         .collect())
 }
+
+/// Compares two keys of a Sparse Merkle Tree leaf, starting with their most significant elements.
+fn cmp_keys(key_1: &Word, key_2: &Word) -> Ordering {
+    key_1.iter().rev().map(Felt::as_int).cmp(key_2.iter().rev().map(Felt::as_int))
+}
@@ -1,4 +1,5 @@
 use super::{AdviceMap, Felt, InnerNodeInfo, InputError, MerkleStore};
+use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 use alloc::vec::Vec;
 use vm_core::crypto::hash::RpoDigest;
 
@@ -130,6 +131,27 @@ impl AdviceInputs {
     }
 }
 
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for AdviceInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.stack.write_into(target);
+        self.map.write_into(target);
+        self.store.write_into(target);
+    }
+}
+
+impl Deserializable for AdviceInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            stack: source.read()?,
+            map: source.read()?,
+            store: source.read()?,
+        })
+    }
+}
+
 // INTERNALS
 // ================================================================================================
 
@@ -64,6 +64,7 @@ pub trait AdviceProvider: Sized {
             } => self.copy_map_value_to_adv_stack(process, *include_len, *key_offset),
             AdviceInjector::UpdateMerkleNode => self.update_operand_stack_merkle_node(process),
             AdviceInjector::U64Div => self.push_u64_div_result(process),
+            AdviceInjector::U256Div => self.push_u256_div_result(process),
             AdviceInjector::Ext2Inv => self.push_ext2_inv_result(process),
             AdviceInjector::Ext2Intt => self.push_ext2_intt_result(process),
             AdviceInjector::SmtGet => self.push_smtget_inputs(process),
@@ -283,6 +284,31 @@ pub trait AdviceProvider: Sized {
         injectors::adv_stack_injectors::push_u64_div_result(self, process)
     }
 
+    /// Pushes the result of 256-bit unsigned integer division (both the quotient and the
+    /// remainder) onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [b7, b6, b5, b4, b3, b2, b1, b0, a7, a6, a5, a4, a3, a2, a1, a0, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [b7, b6, b5, b4, b3, b2, b1, b0, a7, a6, a5, a4, a3, a2, a1, a0, ...]
+    ///   Advice stack: [q0, q1, q2, q3, q4, q5, q6, q7, r0, r1, r2, r3, r4, r5, r6, r7, ...]
+    ///
+    /// Where (a0, ..., a7) and (b0, ..., b7) are the 32-bit limbs of the dividend and the divisor
+    /// respectively (with a0 representing the 32 least significant bits and a7 representing the
+    /// 32 most significant bits). Similarly, (q0, ..., q7) and (r0, ..., r7) represent the
+    /// quotient and the remainder respectively.
+    ///
+    /// # Errors
+    /// Returns an error if the divisor is ZERO or if any of the limbs is not a valid u32 value.
+    fn push_u256_div_result<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_u256_div_result(self, process)
+    }
+
     /// Given an element in a quadratic extension field on the top of the stack (i.e., a0, b1),
     /// computes its multiplicative inverse and push the result onto the advice stack.
     ///
@@ -533,7 +559,26 @@ pub trait AdviceProvider: Sized {
         injectors::smt::push_smtpeek_result(self, process)
     }
 
-    /// Currently unimplemented
+    /// Pushes onto the advice stack the pre-image of the leaf which the specified key maps to in a
+    /// Sparse Merkle Tree defined by the specified root, preceded by the number of elements in the
+    /// pre-image.
+    ///
+    /// If the leaf is empty, only the number of elements (i.e., 0) is pushed onto the advice
+    /// stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [KEY, ROOT, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [KEY, ROOT, ...]
+    ///   Advice stack: [leaf_size, KEY_0, VALUE_0, ..., KEY_{n-1}, VALUE_{n-1}, ...]
+    ///
+    /// Where leaf_size = 8 * n.
+    ///
+    /// # Errors
+    /// Returns an error if the provided Merkle root doesn't exist on the advice provider, or if
+    /// the pre-image of a non-empty leaf cannot be found in the advice map.
     fn push_smtget_inputs<S: ProcessState>(
         &mut self,
         process: &S,
@@ -541,7 +586,21 @@ pub trait AdviceProvider: Sized {
         injectors::smt::push_smtget_inputs(self, process)
     }
 
-    /// Currently unimplemented
+    /// Inserts into the advice map the pre-image of the leaf which the specified key maps to, as
+    /// the leaf would be after the specified value is set under the key in a Sparse Merkle Tree
+    /// defined by the specified root.
+    ///
+    /// Inputs:
+    ///   Operand stack: [VALUE, KEY, ROOT, ...]
+    ///   Advice map: {...}
+    ///
+    /// Outputs:
+    ///   Operand stack: [VALUE, KEY, ROOT, ...]
+    ///   Advice map: {NEW_LEAF_HASH: [KEY_0, VALUE_0, ..., KEY_{n-1}, VALUE_{n-1}]}
+    ///
+    /// # Errors
+    /// Returns an error if the provided Merkle root doesn't exist on the advice provider, or if
+    /// the pre-image of the leaf cannot be found in the advice map.
     fn push_smtset_inputs<S: ProcessState>(
         &mut self,
         process: &S,
@@ -714,6 +773,19 @@ pub trait AdviceProvider: Sized {
     where
         I: Iterator<Item = R>,
         R: Borrow<RpoDigest>;
+
+    // SNAPSHOTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the current contents of this advice provider as [AdviceInputs], or None if this
+    /// advice provider does not support exporting its state.
+    ///
+    /// A new advice provider instantiated from the returned inputs must behave identically to
+    /// this advice provider for the remainder of a program's execution. This is used to include
+    /// the advice provider state into process snapshots.
+    fn snapshot(&self) -> Option<AdviceInputs> {
+        None
+    }
 }
 
 impl<'a, T> AdviceProvider for &'a mut T
@@ -804,4 +876,8 @@ where
     {
         T::get_store_subset(self, roots)
     }
+
+    fn snapshot(&self) -> Option<AdviceInputs> {
+        T::snapshot(self)
+    }
 }
@@ -223,6 +223,14 @@ where
     {
         self.store.subset(roots).into_inner().into_iter().collect()
     }
+
+    fn snapshot(&self) -> Option<AdviceInputs> {
+        let inputs = AdviceInputs::default()
+            .with_stack(self.stack.iter().rev().copied())
+            .with_map(self.map.iter().map(|(key, values)| (*key, values.clone())))
+            .with_merkle_store(self.store.inner_nodes().collect());
+        Some(inputs)
+    }
 }
 
 // MEMORY ADVICE PROVIDER
@@ -325,6 +333,10 @@ impl AdviceProvider for MemAdviceProvider {
         self.provider.get_store_subset(roots)
     }
 
+    fn snapshot(&self) -> Option<AdviceInputs> {
+        self.provider.snapshot()
+    }
+
 }
 
 impl MemAdviceProvider {
@@ -445,6 +457,10 @@ impl AdviceProvider for RecAdviceProvider {
             R: core::borrow::Borrow<RpoDigest> {
         self.provider.get_store_subset(roots)
     }
+
+    fn snapshot(&self) -> Option<AdviceInputs> {
+        self.provider.snapshot()
+    }
 }
 
 impl RecAdviceProvider {
@@ -1,5 +1,5 @@
 use super::{ExecutionError, Felt, ProcessState};
-use crate::MemAdviceProvider;
+use crate::{AdviceInputs, MemAdviceProvider};
 use vm_core::{crypto::merkle::MerklePath, AdviceInjector, DebugOptions, Word};
 
 pub(super) mod advice;
@@ -98,6 +98,18 @@ pub trait Host {
         Ok(HostResponse::None)
     }
 
+    /// Handles the `breakpoint` instruction reached by the VM.
+    ///
+    /// This is invoked only for programs compiled in debug mode, before the cycle at which the
+    /// breakpoint is located is executed. Returning an error aborts the execution of the program,
+    /// which allows the host to check invariants of the process state mid-execution.
+    fn on_breakpoint<S: ProcessState>(
+        &mut self,
+        _process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        Ok(HostResponse::None)
+    }
+
     /// Handles the failure of the assertion instruction.
     fn on_assert_failed<S: ProcessState>(&mut self, process: &S, err_code: u32) -> ExecutionError {
         ExecutionError::FailedAssertion {
@@ -107,6 +119,15 @@ pub trait Host {
         }
     }
 
+    /// Returns the current state of the advice provider of this host, or None if the host does
+    /// not support exporting its advice provider state.
+    ///
+    /// This is used to capture the advice provider state when a snapshot of a paused process is
+    /// taken.
+    fn advice_snapshot(&self) -> Option<AdviceInputs> {
+        None
+    }
+
     /// Pops an element from the advice stack and returns it.
     ///
     /// # Errors
@@ -206,9 +227,20 @@ where
         H::on_trace(self, process, trace_id)
     }
 
+    fn on_breakpoint<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        H::on_breakpoint(self, process)
+    }
+
     fn on_assert_failed<S: ProcessState>(&mut self, process: &S, err_code: u32) -> ExecutionError {
         H::on_assert_failed(self, process, err_code)
     }
+
+    fn advice_snapshot(&self) -> Option<AdviceInputs> {
+        H::advice_snapshot(self)
+    }
 }
 
 // HOST RESPONSE
@@ -312,4 +344,8 @@ impl<A: AdviceProvider> Host for DefaultHost<A> {
     ) -> Result<HostResponse, ExecutionError> {
         self.adv_provider.set_advice(process, &injector)
     }
+
+    fn advice_snapshot(&self) -> Option<AdviceInputs> {
+        self.adv_provider.snapshot()
+    }
 }
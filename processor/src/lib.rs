@@ -16,8 +16,9 @@ use miden_air::trace::{
 pub use miden_air::{ExecutionOptions, ExecutionOptionsError};
 pub use vm_core::{
     chiplets::hasher::Digest, crypto::merkle::SMT_DEPTH, errors::InputError,
-    utils::DeserializationError, AdviceInjector, AssemblyOp, Felt, Kernel, Operation, Program,
-    ProgramInfo, QuadExtension, StackInputs, StackOutputs, Word, EMPTY_WORD, ONE, ZERO,
+    utils::DeserializationError, AdviceInjector, AssemblyOp, Felt, Kernel, MastStats, Operation,
+    OutputRegion, Program, ProgramInfo, ProgramSerdeOptions, QuadExtension, SourceLocation,
+    StackInputs, StackOutputs, Word, EMPTY_WORD, ONE, ZERO,
 };
 use vm_core::{
     code_blocks::{
@@ -30,6 +31,11 @@ pub use winter_prover::matrix::ColMatrix;
 
 mod operations;
 
+mod components;
+use components::{
+    ChipletsComponent, ProcessComponents, StackComponent, SystemComponent, TraceComponents,
+};
+
 mod system;
 use system::System;
 pub use system::{ContextId, FMP_MIN, SYSCALL_FMP_MIN};
@@ -57,7 +63,10 @@ use chiplets::Chiplets;
 
 mod trace;
 use trace::TraceFragment;
-pub use trace::{ChipletsLengths, ExecutionTrace, TraceLenSummary};
+pub use trace::{
+    main_trace_column_names, ChipletsLengths, ExecutionTrace, ExportedTrace, TraceLenSummary,
+    AUX_TRACE_SEGMENTS, MAIN_TRACE_SEGMENTS, TRACE_EXPORT_MAGIC, TRACE_EXPORT_VERSION,
+};
 
 mod errors;
 pub use errors::{ExecutionError, Ext2InttError};
@@ -67,6 +76,13 @@ pub mod utils;
 mod debug;
 pub use debug::{AsmOpInfo, VmState, VmStateIterator};
 
+mod snapshot;
+use snapshot::ResumeStep;
+pub use snapshot::{execute_until, resume, resume_until, ExecutionStatus, ProcessSnapshot};
+
+mod fast;
+pub use fast::{execute_fast, ExecutionOutputs};
+
 // RE-EXPORTS
 // ================================================================================================
 
@@ -130,9 +146,7 @@ where
 {
     let mut process = Process::new(program.kernel().clone(), stack_inputs, host, options);
     let stack_outputs = process.execute(program)?;
-    let trace = ExecutionTrace::new(process, stack_outputs);
-    assert_eq!(&program.hash(), trace.program_hash(), "inconsistent program hash");
-    Ok(trace)
+    build_trace(process, program, stack_outputs, options.enable_output_region())
 }
 
 /// Returns an iterator which allows callers to step through the execution and inspect VM state at
@@ -153,22 +167,88 @@ where
     VmStateIterator::new(process, result)
 }
 
+/// Builds an execution trace from a process which has finished executing the provided program.
+fn build_trace<H>(
+    process: Process<H>,
+    program: &Program,
+    stack_outputs: StackOutputs,
+    enable_output_region: bool,
+) -> Result<ExecutionTrace, ExecutionError>
+where
+    H: Host,
+{
+    let output_region = if enable_output_region {
+        Some(process.build_output_region(&stack_outputs)?)
+    } else {
+        None
+    };
+    let trace = ExecutionTrace::new(process, stack_outputs, output_region);
+    assert_eq!(&program.hash(), trace.program_hash(), "inconsistent program hash");
+    Ok(trace)
+}
+
+/// Reads the contents of the output region committed to by the program using the provided
+/// function to look up words in the root context memory.
+///
+/// The stack outputs are expected to be in the state left by `std::sys::commit_output_region`,
+/// i.e., `[COM, start_addr, num_words, ...]`.
+///
+/// # Errors
+/// Returns an error if the stack outputs do not describe a valid output region, or if the
+/// commitment on the stack does not match the contents of the region.
+fn read_output_region<F>(
+    stack_outputs: &StackOutputs,
+    get_mem_value: F,
+) -> Result<OutputRegion, ExecutionError>
+where
+    F: Fn(u32) -> Option<Word>,
+{
+    let start_addr = stack_outputs.get_stack_item(4).expect("stack outputs too short");
+    let num_words = stack_outputs.get_stack_item(5).expect("stack outputs too short");
+    let invalid_region = || ExecutionError::InvalidOutputRegion {
+        start_addr,
+        num_words,
+    };
+
+    let start = u32::try_from(start_addr.as_int()).map_err(|_| invalid_region())?;
+    let len = u32::try_from(num_words.as_int()).map_err(|_| invalid_region())?;
+    // reject oversized regions before reading them from memory
+    if len as usize > OutputRegion::MAX_LEN {
+        return Err(invalid_region());
+    }
+    let words = (0..len)
+        .map(|offset| {
+            let addr = start.checked_add(offset).ok_or_else(invalid_region)?;
+            Ok(get_mem_value(addr).unwrap_or(EMPTY_WORD))
+        })
+        .collect::<Result<Vec<_>, ExecutionError>>()?;
+    let region = OutputRegion::new(start, words).map_err(|_| invalid_region())?;
+
+    if !region.is_committed_to(stack_outputs) {
+        return Err(ExecutionError::OutputRegionCommitmentMismatch(region.commitment()));
+    }
+    Ok(region)
+}
+
 // PROCESS
 // ================================================================================================
 
 #[cfg(not(any(test, feature = "internals")))]
-struct Process<H>
+struct Process<H, C = TraceComponents>
 where
     H: Host,
+    C: ProcessComponents,
 {
-    system: System,
-    decoder: Decoder,
-    stack: Stack,
-    range: RangeChecker,
-    chiplets: Chiplets,
+    system: C::System,
+    decoder: C::Decoder,
+    stack: C::Stack,
+    range: C::RangeChecker,
+    chiplets: C::Chiplets,
     host: RefCell<H>,
     max_cycles: u32,
     enable_tracing: bool,
+    pause_at: Option<u32>,
+    pause_path: Option<Vec<ResumeStep>>,
 }
 
 impl<H> Process<H>
@@ -213,6 +293,8 @@ where
             host: RefCell::new(host),
             max_cycles: execution_options.max_cycles(),
             enable_tracing: execution_options.enable_tracing(),
+            pause_at: None,
+            pause_path: None,
         }
     }
 
@@ -245,7 +327,16 @@ where
             CodeBlock::Loop(block) => self.execute_loop_block(block, cb_table),
             CodeBlock::Call(block) => self.execute_call_block(block, cb_table),
             CodeBlock::Dyn(block) => self.execute_dyn_block(block, cb_table),
-            CodeBlock::Span(block) => self.execute_span_block(block),
+            CodeBlock::Span(block) => {
+                // pause the process right before the SPAN block is started if the pause cycle
+                // has been reached
+                if self.pause_at.is_some_and(|clk| self.system.clk() >= clk) {
+                    self.pause_path = Some(Vec::new());
+                    Ok(())
+                } else {
+                    self.execute_span_block(block)
+                }
+            }
             CodeBlock::Proxy(_) => Err(ExecutionError::UnexecutableCodeBlock(block.clone())),
         }
     }
@@ -261,7 +352,13 @@ where
 
         // execute first and then second child of the join block
         self.execute_code_block(block.first(), cb_table)?;
+        if self.paused_in(ResumeStep::JoinFirst) {
+            return Ok(());
+        }
         self.execute_code_block(block.second(), cb_table)?;
+        if self.paused_in(ResumeStep::JoinSecond) {
+            return Ok(());
+        }
 
         self.end_join_block(block)
     }
@@ -279,8 +376,14 @@ where
         // execute either the true or the false branch of the split block based on the condition
         if condition == ONE {
             self.execute_code_block(block.on_true(), cb_table)?;
+            if self.paused_in(ResumeStep::SplitTrue) {
+                return Ok(());
+            }
         } else if condition == ZERO {
             self.execute_code_block(block.on_false(), cb_table)?;
+            if self.paused_in(ResumeStep::SplitFalse) {
+                return Ok(());
+            }
         } else {
             return Err(ExecutionError::NotBinaryValue(condition));
         }
@@ -302,18 +405,11 @@ where
         if condition == ONE {
             // execute the loop body at least once
             self.execute_code_block(block.body(), cb_table)?;
-
-            // keep executing the loop body until the condition on the top of the stack is no
-            // longer ONE; each iteration of the loop is preceded by executing REPEAT operation
-            // which drops the condition from the stack
-            while self.stack.peek() == ONE {
-                self.decoder.repeat();
-                self.execute_op(Operation::Drop)?;
-                self.execute_code_block(block.body(), cb_table)?;
+            if self.paused_in(ResumeStep::LoopBody) {
+                return Ok(());
             }
 
-            // end the LOOP block and drop the condition from the stack
-            self.end_loop_block(block, true)
+            self.continue_loop_block(block, cb_table)
         } else if condition == ZERO {
             // end the LOOP block, but don't drop the condition from the stack because it was
             // already dropped when we started the LOOP block
@@ -323,6 +419,31 @@ where
         }
     }
 
+    /// Keeps executing the body of the specified [Loop] block until the condition on the top of
+    /// the stack is no longer ONE, and then ends the block.
+    ///
+    /// This assumes that the body of the loop has already been executed at least once.
+    fn continue_loop_block(
+        &mut self,
+        block: &Loop,
+        cb_table: &CodeBlockTable,
+    ) -> Result<(), ExecutionError> {
+        // keep executing the loop body until the condition on the top of the stack is no
+        // longer ONE; each iteration of the loop is preceded by executing REPEAT operation
+        // which drops the condition from the stack
+        while self.stack.peek() == ONE {
+            self.decoder.repeat();
+            self.execute_op(Operation::Drop)?;
+            self.execute_code_block(block.body(), cb_table)?;
+            if self.paused_in(ResumeStep::LoopBody) {
+                return Ok(());
+            }
+        }
+
+        // end the LOOP block and drop the condition from the stack
+        self.end_loop_block(block, true)
+    }
+
     /// Executes the specified [Call] block.
     #[inline(always)]
     fn execute_call_block(
@@ -347,6 +468,9 @@ where
                 .ok_or_else(|| ExecutionError::CodeBlockNotFound(block.fn_hash()))?;
             self.execute_code_block(fn_body, cb_table)?;
         }
+        if self.paused_in(ResumeStep::CallBody) {
+            return Ok(());
+        }
 
         self.end_call_block(block)
     }
@@ -368,6 +492,9 @@ where
             .get(dyn_digest)
             .ok_or_else(|| ExecutionError::DynamicCodeBlockNotFound(dyn_digest))?;
         self.execute_code_block(dyn_code, cb_table)?;
+        if self.paused_in(ResumeStep::DynBody(dyn_hash)) {
+            return Ok(());
+        }
 
         self.end_dyn_block(block)
     }
@@ -507,6 +634,9 @@ where
                 if self.decoder.in_debug_mode() {
                     self.decoder.append_asmop(self.system.clk(), assembly_op.clone());
                 }
+                if assembly_op.should_break() {
+                    self.host.borrow_mut().on_breakpoint(self)?;
+                }
             }
             Decorator::Event(id) => {
                 self.host.borrow_mut().on_event(self, *id)?;
@@ -520,6 +650,24 @@ where
         Ok(())
     }
 
+    // OUTPUT REGION
+    // --------------------------------------------------------------------------------------------
+
+    /// Reads the contents of the output region committed to by the program from the root context
+    /// memory.
+    ///
+    /// # Errors
+    /// Returns an error if the stack outputs do not describe a valid output region, or if the
+    /// commitment on the stack does not match the contents of the region.
+    fn build_output_region(
+        &self,
+        stack_outputs: &StackOutputs,
+    ) -> Result<OutputRegion, ExecutionError> {
+        read_output_region(stack_outputs, |addr| {
+            self.chiplets.get_mem_value(ContextId::root(), addr)
+        })
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -584,7 +732,7 @@ pub trait ProcessState {
     fn get_mem_state(&self, ctx: ContextId) -> Vec<(u64, Word)>;
 }
 
-impl<H: Host> ProcessState for Process<H> {
+impl<H: Host, C: ProcessComponents> ProcessState for Process<H, C> {
     fn clk(&self) -> u32 {
         self.system.clk()
     }
@@ -622,16 +770,19 @@ impl<H: Host> ProcessState for Process<H> {
 // ================================================================================================
 
 #[cfg(any(test, feature = "internals"))]
-pub struct Process<H>
+pub struct Process<H, C = TraceComponents>
 where
     H: Host,
+    C: ProcessComponents,
 {
-    pub system: System,
-    pub decoder: Decoder,
-    pub stack: Stack,
-    pub range: RangeChecker,
-    pub chiplets: Chiplets,
+    pub system: C::System,
+    pub decoder: C::Decoder,
+    pub stack: C::Stack,
+    pub range: C::RangeChecker,
+    pub chiplets: C::Chiplets,
     pub host: RefCell<H>,
     pub max_cycles: u32,
     pub enable_tracing: bool,
+    pub pause_at: Option<u32>,
+    pub pause_path: Option<Vec<ResumeStep>>,
 }
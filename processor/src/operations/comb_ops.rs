@@ -1,13 +1,17 @@
 use vm_core::{Felt, Operation, ONE, ZERO};
 
+use super::{
+    ChipletsComponent, DecoderComponent, ProcessComponents, StackComponent, SystemComponent,
+};
 use crate::{ExecutionError, Host, Process, QuadFelt};
 
 // RANDOM LINEAR COMBINATION OPERATIONS
 // ================================================================================================
 
-impl<H> Process<H>
+impl<H, C> Process<H, C>
 where
     H: Host,
+    C: ProcessComponents,
 {
     // COMBINE VALUES USING RANDOMNESS
     // --------------------------------------------------------------------------------------------
@@ -1,13 +1,17 @@
-use super::{ExecutionError, Host, Operation, Process};
+use super::{
+    ChipletsComponent, DecoderComponent, ExecutionError, Host, Operation, Process,
+    ProcessComponents, StackComponent,
+};
 use crate::crypto::MerklePath;
 use vm_core::AdviceInjector;
 
 // CRYPTOGRAPHIC OPERATIONS
 // ================================================================================================
 
-impl<H> Process<H>
+impl<H, C> Process<H, C>
 where
     H: Host,
+    C: ProcessComponents,
 {
     // HASHING OPERATIONS
     // --------------------------------------------------------------------------------------------
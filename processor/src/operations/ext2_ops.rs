@@ -1,13 +1,14 @@
-use super::{ExecutionError, Felt, Host, Process};
+use super::{ExecutionError, Felt, Host, Process, ProcessComponents, StackComponent};
 
 // EXTENSION FIELD OPERATIONS
 // ================================================================================================
 
 const TWO: Felt = Felt::new(2);
 
-impl<H> Process<H>
+impl<H, C> Process<H, C>
 where
     H: Host,
+    C: ProcessComponents,
 {
     // ARITHMETIC OPERATIONS
     // --------------------------------------------------------------------------------------------
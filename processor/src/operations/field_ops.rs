@@ -1,12 +1,16 @@
-use super::{utils::assert_binary, ExecutionError, Felt, FieldElement, Host, Process};
+use super::{
+    utils::assert_binary, DecoderComponent, ExecutionError, Felt, FieldElement, Host, Process,
+    ProcessComponents, StackComponent, SystemComponent,
+};
 use vm_core::{Operation, ONE, ZERO};
 
 // FIELD OPERATIONS
 // ================================================================================================
 
-impl<H> Process<H>
+impl<H, C> Process<H, C>
 where
     H: Host,
+    C: ProcessComponents,
 {
     // ARITHMETIC OPERATIONS
     // --------------------------------------------------------------------------------------------
@@ -1,4 +1,7 @@
-use super::{super::QuadFelt, ExecutionError, Felt, Host, Operation, Process};
+use super::{
+    super::QuadFelt, DecoderComponent, ExecutionError, Felt, Host, Operation, Process,
+    ProcessComponents, StackComponent,
+};
 use vm_core::{ExtensionOf, FieldElement, StarkField, ONE, ZERO};
 
 // CONSTANTS
@@ -19,9 +22,10 @@ const TAU3_INV: Felt = Felt::new(281474976710656); // tau^{-3}
 // FRI OPERATIONS
 // ================================================================================================
 
-impl<H> Process<H>
+impl<H, C> Process<H, C>
 where
     H: Host,
+    C: ProcessComponents,
 {
     // FRI FOLDING OPERATION
     // --------------------------------------------------------------------------------------------
@@ -1,12 +1,16 @@
-use super::{ExecutionError, Felt, Host, Operation, Process};
+use super::{
+    ChipletsComponent, DecoderComponent, ExecutionError, Felt, Host, Operation, Process,
+    ProcessComponents, StackComponent, SystemComponent,
+};
 use crate::Word;
 
 // INPUT / OUTPUT OPERATIONS
 // ================================================================================================
 
-impl<H> Process<H>
+impl<H, C> Process<H, C>
 where
     H: Host,
+    C: ProcessComponents,
 {
     // CONSTANT INPUTS
     // --------------------------------------------------------------------------------------------
@@ -1,4 +1,10 @@
-use super::{ExecutionError, Felt, FieldElement, Host, Operation, Process};
+use super::{
+    components::{
+        ChipletsComponent, DecoderComponent, ProcessComponents, RangeCheckerComponent,
+        StackComponent, SystemComponent,
+    },
+    ExecutionError, Felt, FieldElement, Host, Operation, Process,
+};
 use vm_core::stack::STACK_TOP_SIZE;
 
 mod comb_ops;
@@ -18,9 +24,10 @@ use super::Kernel;
 // OPERATION DISPATCHER
 // ================================================================================================
 
-impl<H> Process<H>
+impl<H, C> Process<H, C>
 where
     H: Host,
+    C: ProcessComponents,
 {
     /// Executes the specified operation.
     pub(super) fn execute_op(&mut self, op: Operation) -> Result<(), ExecutionError> {
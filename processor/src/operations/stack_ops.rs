@@ -1,9 +1,10 @@
-use super::{ExecutionError, Host, Process, STACK_TOP_SIZE};
+use super::{ExecutionError, Host, Process, ProcessComponents, StackComponent, STACK_TOP_SIZE};
 use crate::ZERO;
 
-impl<H> Process<H>
+impl<H, C> Process<H, C>
 where
     H: Host,
+    C: ProcessComponents,
 {
     // STACK MANIPULATION
     // --------------------------------------------------------------------------------------------
@@ -3,15 +3,16 @@ use super::{
         system::{FMP_MAX, FMP_MIN},
         ONE,
     },
-    ExecutionError, Felt, Host, Process,
+    ExecutionError, Felt, Host, Process, ProcessComponents, StackComponent, SystemComponent,
 };
 
 // SYSTEM OPERATIONS
 // ================================================================================================
 
-impl<H> Process<H>
+impl<H, C> Process<H, C>
 where
     H: Host,
+    C: ProcessComponents,
 {
     /// Pops a value off the stack and asserts that it is equal to ONE.
     ///
@@ -1,12 +1,14 @@
 use super::{
     super::utils::{split_element, split_u32_into_u16},
-    ExecutionError, Felt, FieldElement, Host, Operation, Process,
+    ChipletsComponent, DecoderComponent, ExecutionError, Felt, FieldElement, Host, Operation,
+    Process, ProcessComponents, RangeCheckerComponent, StackComponent, SystemComponent,
 };
 use crate::ZERO;
 
-impl<H> Process<H>
+impl<H, C> Process<H, C>
 where
     H: Host,
+    C: ProcessComponents,
 {
     // CASTING OPERATIONS
     // --------------------------------------------------------------------------------------------
@@ -1,5 +1,7 @@
 use super::{trace::NUM_RAND_ROWS, Felt, FieldElement, RangeCheckTrace, ZERO};
-use crate::utils::uninit_vector;
+use crate::utils::{
+    uninit_vector, ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
+};
 use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
 
@@ -207,6 +209,25 @@ impl Default for RangeChecker {
     }
 }
 
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for RangeChecker {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.lookups.write_into(target);
+        self.cycle_lookups.write_into(target);
+    }
+}
+
+impl Deserializable for RangeChecker {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            lookups: source.read()?,
+            cycle_lookups: source.read()?,
+        })
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
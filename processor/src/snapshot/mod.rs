@@ -0,0 +1,429 @@
+use super::{
+    build_trace, AdviceInputs, Chiplets, CodeBlock, CodeBlockTable, Decoder, Digest, Dyn,
+    ExecutionError, ExecutionOptions, ExecutionTrace, Host, Process, Program, RangeChecker, Stack,
+    StackInputs, System, Word,
+};
+use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+use alloc::{string::ToString, vec::Vec};
+use core::cell::RefCell;
+
+#[cfg(test)]
+mod tests;
+
+// EXECUTORS
+// ================================================================================================
+
+/// Executes the provided program against the provided inputs until either the program completes,
+/// or the clock cycle `pause_at` is reached.
+///
+/// The process can be paused only right before a SPAN block is started. Thus, the execution is
+/// paused at the start of the first SPAN block beginning on or after the `pause_at` clock cycle.
+/// If the program completes before that, the resulting execution trace is returned.
+pub fn execute_until<H>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    host: H,
+    options: ExecutionOptions,
+    pause_at: u32,
+) -> Result<ExecutionStatus, ExecutionError>
+where
+    H: Host,
+{
+    let mut process = Process::new(program.kernel().clone(), stack_inputs, host, options);
+    process.pause_at = Some(pause_at);
+    process.execute_code_block(program.root(), program.cb_table())?;
+    process.into_status(program, options.enable_output_region())
+}
+
+/// Resumes execution of the program from the provided snapshot and runs it to completion.
+///
+/// The resulting execution trace is identical to the trace which would have been produced by
+/// executing the program without pausing it, provided that the host behaves identically to the
+/// host of the original process (e.g., it was instantiated from
+/// [ProcessSnapshot::advice_inputs()]).
+///
+/// # Errors
+/// Returns an error if the snapshot was not taken during the execution of the provided program,
+/// or if the execution fails for any reason.
+pub fn resume<H>(
+    program: &Program,
+    snapshot: ProcessSnapshot,
+    host: H,
+) -> Result<ExecutionTrace, ExecutionError>
+where
+    H: Host,
+{
+    match resume_process(program, snapshot, host, None)? {
+        ExecutionStatus::Completed(trace) => Ok(trace),
+        ExecutionStatus::Paused(_) => unreachable!("process paused without a pause cycle"),
+    }
+}
+
+/// Resumes execution of the program from the provided snapshot and runs it until either the
+/// program completes, or the clock cycle `pause_at` is reached.
+///
+/// At least one SPAN block is executed before the process can be paused again.
+///
+/// # Errors
+/// Returns an error if the snapshot was not taken during the execution of the provided program,
+/// or if the execution fails for any reason.
+pub fn resume_until<H>(
+    program: &Program,
+    snapshot: ProcessSnapshot,
+    host: H,
+    pause_at: u32,
+) -> Result<ExecutionStatus, ExecutionError>
+where
+    H: Host,
+{
+    resume_process(program, snapshot, host, Some(pause_at))
+}
+
+/// Resumes execution of the program from the provided snapshot, optionally pausing it again at
+/// the specified clock cycle.
+fn resume_process<H>(
+    program: &Program,
+    snapshot: ProcessSnapshot,
+    host: H,
+    pause_at: Option<u32>,
+) -> Result<ExecutionStatus, ExecutionError>
+where
+    H: Host,
+{
+    if snapshot.program_hash != program.hash() {
+        return Err(ExecutionError::InvalidProcessSnapshot(
+            "snapshot was taken during the execution of a different program".to_string(),
+        ));
+    }
+    if snapshot.chiplets.kernel() != program.kernel() {
+        return Err(ExecutionError::InvalidProcessSnapshot(
+            "snapshot was taken against a different kernel".to_string(),
+        ));
+    }
+
+    let ProcessSnapshot {
+        resume_path,
+        system,
+        decoder,
+        stack,
+        range,
+        chiplets,
+        max_cycles,
+        enable_tracing,
+        enable_output_region,
+        ..
+    } = snapshot;
+
+    let mut process = Process {
+        system,
+        decoder,
+        stack,
+        range,
+        chiplets,
+        host: RefCell::new(host),
+        max_cycles,
+        enable_tracing,
+        pause_at,
+        pause_path: None,
+    };
+    process.resume_code_block(program.root(), program.cb_table(), &resume_path)?;
+    process.into_status(program, enable_output_region)
+}
+
+// EXECUTION STATUS
+// ================================================================================================
+
+/// Result of executing a program which may be paused before it completes.
+#[allow(clippy::large_enum_variant)]
+pub enum ExecutionStatus {
+    /// The program was executed to completion.
+    Completed(ExecutionTrace),
+    /// The execution was paused; the execution can be resumed from the snapshot.
+    Paused(ProcessSnapshot),
+}
+
+// PROCESS SNAPSHOT
+// ================================================================================================
+
+/// A snapshot of a process paused right before the start of a SPAN block.
+///
+/// The snapshot contains the complete state of the VM components (system, decoder, stack, range
+/// checker and chiplets, including their execution traces), the location in the program at which
+/// the execution was paused, and (if supported by the host) the state of the advice provider.
+///
+/// A snapshot can be serialized and the execution can be resumed from it later in a different
+/// process via [resume()] or [resume_until()].
+pub struct ProcessSnapshot {
+    program_hash: Digest,
+    resume_path: Vec<ResumeStep>,
+    system: System,
+    decoder: Decoder,
+    stack: Stack,
+    range: RangeChecker,
+    chiplets: Chiplets,
+    max_cycles: u32,
+    enable_tracing: bool,
+    enable_output_region: bool,
+    advice_inputs: Option<AdviceInputs>,
+}
+
+impl ProcessSnapshot {
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the hash of the program during the execution of which this snapshot was taken.
+    pub fn program_hash(&self) -> Digest {
+        self.program_hash
+    }
+
+    /// Returns the clock cycle at which the process was paused.
+    pub fn clk(&self) -> u32 {
+        self.system.clk()
+    }
+
+    /// Returns the state of the advice provider at the time the process was paused, or None if
+    /// the host of the process did not support exporting its advice provider state.
+    pub fn advice_inputs(&self) -> Option<&AdviceInputs> {
+        self.advice_inputs.as_ref()
+    }
+}
+
+// RESUME STEP
+// ================================================================================================
+
+/// Describes which child of a control block was executing when the process was paused.
+///
+/// A sequence of resume steps (starting at the root of the program) describes the path from the
+/// root of the program to the SPAN block at which the process was paused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResumeStep {
+    JoinFirst,
+    JoinSecond,
+    SplitTrue,
+    SplitFalse,
+    LoopBody,
+    CallBody,
+    DynBody(Word),
+}
+
+// PROCESS EXTENSION
+// ================================================================================================
+
+impl<H> Process<H>
+where
+    H: Host,
+{
+    /// Returns true if the process has been paused; in this case, the specified step is also
+    /// recorded in the path to the block at which the process was paused.
+    #[inline(always)]
+    pub(super) fn paused_in(&mut self, step: ResumeStep) -> bool {
+        match self.pause_path.as_mut() {
+            Some(path) => {
+                path.push(step);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Resumes execution of the specified block from the location described by the provided path.
+    ///
+    /// Blocks along the path have already been started, and thus, only their remaining children
+    /// are executed before the blocks are ended. The SPAN block at the end of the path is executed
+    /// in full.
+    fn resume_code_block(
+        &mut self,
+        block: &CodeBlock,
+        cb_table: &CodeBlockTable,
+        path: &[ResumeStep],
+    ) -> Result<(), ExecutionError> {
+        let (step, path) = match path.split_first() {
+            Some((step, path)) => (*step, path),
+            None => {
+                return match block {
+                    CodeBlock::Span(block) => self.execute_span_block(block),
+                    _ => Err(invalid_resume_path()),
+                }
+            }
+        };
+
+        match (block, step) {
+            (CodeBlock::Join(block), ResumeStep::JoinFirst) => {
+                self.resume_code_block(block.first(), cb_table, path)?;
+                if self.paused_in(ResumeStep::JoinFirst) {
+                    return Ok(());
+                }
+                self.execute_code_block(block.second(), cb_table)?;
+                if self.paused_in(ResumeStep::JoinSecond) {
+                    return Ok(());
+                }
+                self.end_join_block(block)
+            }
+            (CodeBlock::Join(block), ResumeStep::JoinSecond) => {
+                self.resume_code_block(block.second(), cb_table, path)?;
+                if self.paused_in(ResumeStep::JoinSecond) {
+                    return Ok(());
+                }
+                self.end_join_block(block)
+            }
+            (CodeBlock::Split(block), ResumeStep::SplitTrue | ResumeStep::SplitFalse) => {
+                let branch = if step == ResumeStep::SplitTrue {
+                    block.on_true()
+                } else {
+                    block.on_false()
+                };
+                self.resume_code_block(branch, cb_table, path)?;
+                if self.paused_in(step) {
+                    return Ok(());
+                }
+                self.end_split_block(block)
+            }
+            (CodeBlock::Loop(block), ResumeStep::LoopBody) => {
+                self.resume_code_block(block.body(), cb_table, path)?;
+                if self.paused_in(ResumeStep::LoopBody) {
+                    return Ok(());
+                }
+                self.continue_loop_block(block, cb_table)
+            }
+            (CodeBlock::Call(block), ResumeStep::CallBody) => {
+                if block.fn_hash() == Dyn::dyn_hash() {
+                    self.resume_code_block(&CodeBlock::Dyn(Dyn::new()), cb_table, path)?;
+                } else {
+                    let fn_body = cb_table
+                        .get(block.fn_hash())
+                        .ok_or_else(|| ExecutionError::CodeBlockNotFound(block.fn_hash()))?;
+                    self.resume_code_block(fn_body, cb_table, path)?;
+                }
+                if self.paused_in(ResumeStep::CallBody) {
+                    return Ok(());
+                }
+                self.end_call_block(block)
+            }
+            (CodeBlock::Dyn(block), ResumeStep::DynBody(dyn_hash)) => {
+                let dyn_digest = dyn_hash.into();
+                let dyn_code = cb_table
+                    .get(dyn_digest)
+                    .ok_or(ExecutionError::DynamicCodeBlockNotFound(dyn_digest))?;
+                self.resume_code_block(dyn_code, cb_table, path)?;
+                if self.paused_in(step) {
+                    return Ok(());
+                }
+                self.end_dyn_block(block)
+            }
+            _ => Err(invalid_resume_path()),
+        }
+    }
+
+    /// Converts this process into an [ExecutionStatus] after the execution of the provided
+    /// program has either completed or been paused.
+    fn into_status(
+        mut self,
+        program: &Program,
+        enable_output_region: bool,
+    ) -> Result<ExecutionStatus, ExecutionError> {
+        let Some(mut resume_path) = self.pause_path.take() else {
+            let stack_outputs = self.stack.build_stack_outputs();
+            let trace = build_trace(self, program, stack_outputs, enable_output_region)?;
+            return Ok(ExecutionStatus::Completed(trace));
+        };
+
+        // the path was recorded while unwinding from the paused block; we need it to start at the
+        // root of the program
+        resume_path.reverse();
+        let advice_inputs = self.host.borrow().advice_snapshot();
+
+        Ok(ExecutionStatus::Paused(ProcessSnapshot {
+            program_hash: program.hash(),
+            resume_path,
+            system: self.system,
+            decoder: self.decoder,
+            stack: self.stack,
+            range: self.range,
+            chiplets: self.chiplets,
+            max_cycles: self.max_cycles,
+            enable_tracing: self.enable_tracing,
+            enable_output_region,
+            advice_inputs,
+        }))
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ProcessSnapshot {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.program_hash.write_into(target);
+        self.resume_path.write_into(target);
+        self.system.write_into(target);
+        self.decoder.write_into(target);
+        self.stack.write_into(target);
+        self.range.write_into(target);
+        self.chiplets.write_into(target);
+        target.write_u32(self.max_cycles);
+        target.write_bool(self.enable_tracing);
+        target.write_bool(self.enable_output_region);
+        self.advice_inputs.write_into(target);
+    }
+}
+
+impl Deserializable for ProcessSnapshot {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            program_hash: source.read()?,
+            resume_path: source.read()?,
+            system: source.read()?,
+            decoder: source.read()?,
+            stack: source.read()?,
+            range: source.read()?,
+            chiplets: source.read()?,
+            max_cycles: source.read_u32()?,
+            enable_tracing: source.read_bool()?,
+            enable_output_region: source.read_bool()?,
+            advice_inputs: source.read()?,
+        })
+    }
+}
+
+impl Serializable for ResumeStep {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Self::JoinFirst => target.write_u8(0),
+            Self::JoinSecond => target.write_u8(1),
+            Self::SplitTrue => target.write_u8(2),
+            Self::SplitFalse => target.write_u8(3),
+            Self::LoopBody => target.write_u8(4),
+            Self::CallBody => target.write_u8(5),
+            Self::DynBody(dyn_hash) => {
+                target.write_u8(6);
+                dyn_hash.write_into(target);
+            }
+        }
+    }
+}
+
+impl Deserializable for ResumeStep {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(Self::JoinFirst),
+            1 => Ok(Self::JoinSecond),
+            2 => Ok(Self::SplitTrue),
+            3 => Ok(Self::SplitFalse),
+            4 => Ok(Self::LoopBody),
+            5 => Ok(Self::CallBody),
+            6 => Ok(Self::DynBody(source.read()?)),
+            tag => {
+                Err(DeserializationError::InvalidValue(format!("invalid resume step tag {tag}")))
+            }
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn invalid_resume_path() -> ExecutionError {
+    ExecutionError::InvalidProcessSnapshot(
+        "resume path does not match the structure of the program".to_string(),
+    )
+}
@@ -0,0 +1,137 @@
+use super::{
+    execute_until, resume, resume_until, ExecutionError, ExecutionStatus, ProcessSnapshot, Program,
+};
+use crate::{
+    execute,
+    utils::{Deserializable, Serializable},
+    AdviceInputs, DefaultHost, ExecutionOptions, ExecutionTrace, MemAdviceProvider, StackInputs,
+};
+use miden_assembly::Assembler;
+use winter_prover::Trace;
+
+// TESTS
+// ================================================================================================
+
+const SOURCE: &str = "
+    proc.foo.1
+        dup loc_store.0
+        push.9 mem_store.4
+        loc_load.0 drop
+    end
+
+    proc.bar
+        dup push.3 u32and drop
+        dup mem_store.100
+        adv_push.1 mem_store.101
+        call.foo
+    end
+
+    begin
+        push.10 push.1
+        while.true
+            exec.bar
+            sub.1 dup neq.0
+        end
+        hperm
+        push.1
+        if.true
+            push.2 mul
+        else
+            push.3 mul
+        end
+        drop
+    end";
+
+#[test]
+fn resume_from_snapshot() {
+    let program = compile(SOURCE);
+    let expected = execute(&program, stack_inputs(), build_host(advice_inputs()), options());
+    let expected = expected.unwrap();
+
+    // pause the execution at several points, round-trip every snapshot through serialization, and
+    // make sure the resulting trace is identical to the one produced by uninterrupted execution
+    for step in [1, 10, 37, 100] {
+        let mut num_pauses = 0;
+        let mut status =
+            execute_until(&program, stack_inputs(), build_host(advice_inputs()), options(), step)
+                .unwrap();
+        let trace = loop {
+            let snapshot = match status {
+                ExecutionStatus::Completed(trace) => break trace,
+                ExecutionStatus::Paused(snapshot) => snapshot,
+            };
+            num_pauses += 1;
+
+            let snapshot = ProcessSnapshot::read_from_bytes(&snapshot.to_bytes()).unwrap();
+            assert_eq!(program.hash(), snapshot.program_hash());
+            let host = build_host(snapshot.advice_inputs().unwrap().clone());
+            let pause_at = snapshot.clk() + step;
+            status = resume_until(&program, snapshot, host, pause_at).unwrap();
+        };
+
+        assert!(num_pauses > 1);
+        assert_traces_eq(&expected, &trace);
+    }
+
+    // resume once and run the program to completion
+    let ExecutionStatus::Paused(snapshot) =
+        execute_until(&program, stack_inputs(), build_host(advice_inputs()), options(), 50)
+            .unwrap()
+    else {
+        panic!("execution was expected to pause");
+    };
+    let host = build_host(snapshot.advice_inputs().unwrap().clone());
+    let trace = resume(&program, snapshot, host).unwrap();
+    assert_traces_eq(&expected, &trace);
+}
+
+#[test]
+fn resume_different_program() {
+    let program = compile(SOURCE);
+    let other = compile("begin push.1 push.2 add end");
+
+    let ExecutionStatus::Paused(snapshot) =
+        execute_until(&program, stack_inputs(), build_host(advice_inputs()), options(), 20)
+            .unwrap()
+    else {
+        panic!("execution was expected to pause");
+    };
+
+    let result = resume(&other, snapshot, DefaultHost::default());
+    assert!(matches!(result, Err(ExecutionError::InvalidProcessSnapshot(_))));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn compile(source: &str) -> Program {
+    Assembler::default().compile(source).unwrap()
+}
+
+fn stack_inputs() -> StackInputs {
+    StackInputs::try_from_ints([1, 2, 3, 4]).unwrap()
+}
+
+fn advice_inputs() -> AdviceInputs {
+    AdviceInputs::default().with_stack_values(11..21).unwrap()
+}
+
+fn options() -> ExecutionOptions {
+    ExecutionOptions::default().with_debugging()
+}
+
+fn build_host(advice_inputs: AdviceInputs) -> DefaultHost<MemAdviceProvider> {
+    DefaultHost::new(MemAdviceProvider::from(advice_inputs))
+}
+
+fn assert_traces_eq(expected: &ExecutionTrace, actual: &ExecutionTrace) {
+    assert_eq!(expected.stack_outputs(), actual.stack_outputs());
+    assert_eq!(expected.trace_len_summary(), actual.trace_len_summary());
+
+    let expected = expected.main_segment();
+    let actual = actual.main_segment();
+    assert_eq!(expected.num_cols(), actual.num_cols());
+    for i in 0..expected.num_cols() {
+        assert_eq!(expected.get_column(i), actual.get_column(i), "column {i} differs");
+    }
+}
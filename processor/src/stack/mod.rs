@@ -1,4 +1,5 @@
 use super::{Felt, FieldElement, StackInputs, StackOutputs, ONE, STACK_TRACE_WIDTH, ZERO};
+use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 use alloc::vec::Vec;
 use core::cmp;
 use vm_core::{stack::STACK_TOP_SIZE, Word, WORD_SIZE};
@@ -341,3 +342,28 @@ impl Stack {
         self.trace.get_helpers_state_at(self.clk)
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for Stack {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u32(self.clk);
+        self.trace.write_into(target);
+        self.overflow.write_into(target);
+        target.write_usize(self.active_depth);
+        target.write_usize(self.full_depth);
+    }
+}
+
+impl Deserializable for Stack {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            clk: source.read_u32()?,
+            trace: source.read()?,
+            overflow: source.read()?,
+            active_depth: source.read_usize()?,
+            full_depth: source.read_usize()?,
+        })
+    }
+}
@@ -1,4 +1,5 @@
 use super::{AuxTraceBuilder, Felt, FieldElement, ZERO};
+use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
 use vm_core::{utils::uninit_vector, StarkField};
@@ -258,3 +259,48 @@ impl OverflowTableRow {
             + alphas[3].mul_base(self.prev)
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for OverflowTable {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.all_rows.write_into(target);
+        self.active_rows.write_into(target);
+        self.trace.write_into(target);
+        target.write_bool(self.trace_enabled);
+        target.write_usize(self.num_init_rows);
+        self.last_row_addr.write_into(target);
+    }
+}
+
+impl Deserializable for OverflowTable {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            all_rows: source.read()?,
+            active_rows: source.read()?,
+            trace: source.read()?,
+            trace_enabled: source.read_bool()?,
+            num_init_rows: source.read_usize()?,
+            last_row_addr: source.read()?,
+        })
+    }
+}
+
+impl Serializable for OverflowTableRow {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.val.write_into(target);
+        self.clk.write_into(target);
+        self.prev.write_into(target);
+    }
+}
+
+impl Deserializable for OverflowTableRow {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            val: source.read()?,
+            clk: source.read()?,
+            prev: source.read()?,
+        })
+    }
+}
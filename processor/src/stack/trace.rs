@@ -2,6 +2,7 @@ use super::{
     super::utils::get_trace_len, Felt, FieldElement, MAX_TOP_IDX, ONE, STACK_TRACE_WIDTH, ZERO,
 };
 use crate::utils::math::batch_inversion;
+use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 use alloc::vec::Vec;
 use miden_air::trace::stack::{H0_COL_IDX, NUM_STACK_HELPER_COLS, STACK_TOP_SIZE};
 
@@ -221,6 +222,25 @@ impl StackTrace {
     }
 }
 
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for StackTrace {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.stack.write_into(target);
+        self.helpers.write_into(target);
+    }
+}
+
+impl Deserializable for StackTrace {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            stack: source.read()?,
+            helpers: source.read()?,
+        })
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
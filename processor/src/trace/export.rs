@@ -0,0 +1,336 @@
+//! Export of execution traces into formats which can be consumed outside of the VM.
+//!
+//! Two formats are supported:
+//!
+//! - A binary format produced by [ExecutionTrace::write_binary] and read by [ExportedTrace]. All
+//!   integers are little-endian; strings are prefixed with their length as a single byte. The
+//!   layout is as follows:
+//!   - magic bytes `MVMT` followed by the format version (1 byte);
+//!   - [ProgramInfo] and [StackOutputs] of the execution (in their standard serialized form);
+//!   - trace length summary as six `u64` values: main trace length, range checker trace length,
+//!     and hash, bitwise, memory and kernel ROM chiplet lengths;
+//!   - number of rows at the end of the trace filled with random values (`u64`);
+//!   - main trace segments: number of segments (`u8`), followed by the name, first column index
+//!     (`u16`) and width (`u16`) of each segment;
+//!   - auxiliary trace segments in the same form, followed by the number of random elements
+//!     required to build the auxiliary trace (`u16`);
+//!   - main trace column names: number of columns (`u16`) followed by the name of each column;
+//!   - main trace data: number of rows (`u64`) followed by the rows of the trace, each row
+//!     consisting of one `u64` value per column.
+//! - A CSV format produced by [ExecutionTrace::write_csv] (requires `std`). The metadata is
+//!   written as a set of `# key: value` comment lines, followed by a header line with column names
+//!   and one line per trace row.
+//!
+//! Values of the auxiliary trace columns are not exported because they depend on the random
+//! elements drawn by the prover; only the auxiliary trace layout is included.
+
+use super::{ExecutionTrace, Felt, TraceLenSummary, NUM_RAND_ROWS};
+use crate::ChipletsLengths;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{ops::Range, str::from_utf8};
+use miden_air::trace::{
+    decoder::{NUM_HASHER_COLUMNS, NUM_OP_BATCH_FLAGS, NUM_OP_BITS},
+    stack::STACK_TOP_SIZE,
+    AUX_TRACE_RAND_ELEMENTS, CHIPLETS_AUX_TRACE_RANGE, CHIPLETS_RANGE, CHIPLETS_WIDTH,
+    DECODER_AUX_TRACE_RANGE, DECODER_TRACE_RANGE, HASHER_AUX_TRACE_RANGE,
+    RANGE_CHECK_AUX_TRACE_RANGE, RANGE_CHECK_TRACE_RANGE, STACK_AUX_TRACE_RANGE, STACK_TRACE_RANGE,
+    SYS_TRACE_RANGE, TRACE_WIDTH,
+};
+use vm_core::{
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    ProgramInfo, StackOutputs,
+};
+use winter_prover::Trace;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Magic bytes identifying an exported execution trace.
+pub const TRACE_EXPORT_MAGIC: &[u8; 4] = b"MVMT";
+
+/// Current version of the trace export format.
+pub const TRACE_EXPORT_VERSION: u8 = 1;
+
+/// Segments of the main execution trace in the order in which they appear in the trace.
+pub const MAIN_TRACE_SEGMENTS: [(&str, Range<usize>); 5] = [
+    ("system", SYS_TRACE_RANGE),
+    ("decoder", DECODER_TRACE_RANGE),
+    ("stack", STACK_TRACE_RANGE),
+    ("range", RANGE_CHECK_TRACE_RANGE),
+    ("chiplets", CHIPLETS_RANGE),
+];
+
+/// Segments of the auxiliary execution trace in the order in which they appear in the trace.
+pub const AUX_TRACE_SEGMENTS: [(&str, Range<usize>); 5] = [
+    ("decoder", DECODER_AUX_TRACE_RANGE),
+    ("stack", STACK_AUX_TRACE_RANGE),
+    ("range", RANGE_CHECK_AUX_TRACE_RANGE),
+    ("hasher", HASHER_AUX_TRACE_RANGE),
+    ("chiplets", CHIPLETS_AUX_TRACE_RANGE),
+];
+
+// COLUMN NAMES
+// ================================================================================================
+
+/// Returns names of all columns of the main execution trace.
+pub fn main_trace_column_names() -> Vec<String> {
+    let mut names = Vec::with_capacity(TRACE_WIDTH);
+
+    // system columns
+    names.extend(["clk", "fmp", "ctx", "in_syscall"].map(String::from));
+    names.extend((0..4).map(|i| format!("fn_hash_{i}")));
+
+    // decoder columns
+    names.push("addr".to_string());
+    names.extend((0..NUM_OP_BITS).map(|i| format!("op_bit_{i}")));
+    names.extend((0..NUM_HASHER_COLUMNS).map(|i| format!("decoder_hasher_{i}")));
+    names.extend(["in_span", "group_count", "op_idx"].map(String::from));
+    names.extend((0..NUM_OP_BATCH_FLAGS).map(|i| format!("op_batch_flag_{i}")));
+    names.extend((0..2).map(|i| format!("op_bit_extra_{i}")));
+
+    // stack columns
+    names.extend((0..STACK_TOP_SIZE).map(|i| format!("s{i}")));
+    names.extend(["b0", "b1", "h0"].map(String::from));
+
+    // range checker columns
+    names.extend(["range_m", "range_v"].map(String::from));
+
+    // chiplets columns
+    names.extend((0..CHIPLETS_WIDTH).map(|i| format!("chiplets_{i}")));
+
+    debug_assert_eq!(names.len(), TRACE_WIDTH);
+    names
+}
+
+// TRACE EXPORT
+// ================================================================================================
+
+impl ExecutionTrace {
+    /// Writes this trace into the specified target using the binary trace export format.
+    ///
+    /// See the [module-level documentation](self) for the description of the format.
+    pub fn write_binary<W: ByteWriter>(&self, target: &mut W) {
+        target.write_bytes(TRACE_EXPORT_MAGIC);
+        target.write_u8(TRACE_EXPORT_VERSION);
+        self.program_info.write_into(target);
+        self.stack_outputs.write_into(target);
+        write_trace_len_summary(&self.trace_len_summary, target);
+        target.write_u64(NUM_RAND_ROWS as u64);
+
+        write_segments(&MAIN_TRACE_SEGMENTS, target);
+        write_segments(&AUX_TRACE_SEGMENTS, target);
+        target.write_u16(AUX_TRACE_RAND_ELEMENTS as u16);
+
+        let column_names = main_trace_column_names();
+        target.write_u16(column_names.len() as u16);
+        column_names.iter().for_each(|name| write_str(name, target));
+
+        let mut row = [Felt::default(); TRACE_WIDTH];
+        target.write_u64(self.length() as u64);
+        for row_idx in 0..self.length() {
+            self.main_trace.read_row_into(row_idx, &mut row);
+            row.iter().for_each(|value| target.write_u64(value.as_int()));
+        }
+    }
+
+    /// Writes this trace into the specified target as CSV.
+    ///
+    /// See the [module-level documentation](self) for the description of the format.
+    #[cfg(feature = "std")]
+    pub fn write_csv<W: std::io::Write>(&self, target: &mut W) -> std::io::Result<()> {
+        use vm_core::utils::to_hex;
+
+        let summary = &self.trace_len_summary;
+        let chiplets = summary.chiplets_trace_len();
+        let program_hash = self.program_info.program_hash().as_bytes();
+        let kernel = self
+            .program_info
+            .kernel_procedures()
+            .iter()
+            .map(|proc| to_hex(&proc.as_bytes()).map(|hex| format!("0x{hex}")))
+            .collect::<Result<Vec<_>, _>>()
+            .expect("failed to convert kernel procedure hash to hex");
+        let stack_outputs = self.stack_outputs.stack().iter().map(|v| v.to_string());
+
+        writeln!(target, "# miden execution trace v{TRACE_EXPORT_VERSION}")?;
+        writeln!(target, "# program_hash: 0x{}", to_hex(&program_hash).expect("hex failed"))?;
+        writeln!(target, "# kernel: {}", kernel.join(" "))?;
+        writeln!(target, "# stack_outputs: {}", stack_outputs.collect::<Vec<_>>().join(" "))?;
+        writeln!(
+            target,
+            "# trace_len_summary: main={} range={} hash={} bitwise={} memory={} kernel_rom={}",
+            summary.main_trace_len(),
+            summary.range_trace_len(),
+            chiplets.hash_chiplet_len(),
+            chiplets.bitwise_chiplet_len(),
+            chiplets.memory_chiplet_len(),
+            chiplets.kernel_rom_len()
+        )?;
+        writeln!(target, "# num_rand_rows: {NUM_RAND_ROWS}")?;
+        writeln!(target, "# main_segments: {}", format_segments(&MAIN_TRACE_SEGMENTS))?;
+        writeln!(target, "# aux_segments: {}", format_segments(&AUX_TRACE_SEGMENTS))?;
+        writeln!(target, "# aux_rand_elements: {AUX_TRACE_RAND_ELEMENTS}")?;
+        writeln!(target, "{}", main_trace_column_names().join(","))?;
+
+        let mut row = [Felt::default(); TRACE_WIDTH];
+        for row_idx in 0..self.length() {
+            self.main_trace.read_row_into(row_idx, &mut row);
+            let values = row.iter().map(|value| value.as_int().to_string()).collect::<Vec<_>>();
+            writeln!(target, "{}", values.join(","))?;
+        }
+
+        Ok(())
+    }
+}
+
+// EXPORTED TRACE
+// ================================================================================================
+
+/// An execution trace read from the binary trace export format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportedTrace {
+    pub program_info: ProgramInfo,
+    pub stack_outputs: StackOutputs,
+    pub trace_len_summary: TraceLenSummary,
+    pub num_rand_rows: usize,
+    pub main_segments: Vec<(String, Range<usize>)>,
+    pub aux_segments: Vec<(String, Range<usize>)>,
+    pub aux_rand_elements: usize,
+    pub column_names: Vec<String>,
+    /// Rows of the main trace; each row contains one value per column.
+    pub rows: Vec<Vec<Felt>>,
+}
+
+impl ExportedTrace {
+    /// Returns values of the column with the specified name, or None if the trace does not
+    /// contain such a column.
+    pub fn column(&self, name: &str) -> Option<Vec<Felt>> {
+        let col_idx = self.column_names.iter().position(|n| n == name)?;
+        Some(self.rows.iter().map(|row| row[col_idx]).collect())
+    }
+}
+
+impl Deserializable for ExportedTrace {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let magic: [u8; 4] = source.read_array()?;
+        if &magic != TRACE_EXPORT_MAGIC {
+            return Err(DeserializationError::InvalidValue("invalid trace magic bytes".into()));
+        }
+        let version = source.read_u8()?;
+        if version != TRACE_EXPORT_VERSION {
+            return Err(DeserializationError::InvalidValue(format!(
+                "unsupported trace format version {version}; expected {TRACE_EXPORT_VERSION}"
+            )));
+        }
+
+        let program_info = ProgramInfo::read_from(source)?;
+        let stack_outputs = StackOutputs::read_from(source)?;
+        let trace_len_summary = read_trace_len_summary(source)?;
+        let num_rand_rows = source.read_u64()? as usize;
+
+        let main_segments = read_segments(source)?;
+        let aux_segments = read_segments(source)?;
+        let aux_rand_elements = source.read_u16()? as usize;
+
+        let num_columns = source.read_u16()? as usize;
+        let column_names = (0..num_columns).map(|_| read_str(source)).collect::<Result<_, _>>()?;
+
+        let num_rows = source.read_u64()?;
+        let mut rows = Vec::new();
+        for _ in 0..num_rows {
+            let row = (0..num_columns)
+                .map(|_| Felt::read_from(source))
+                .collect::<Result<Vec<_>, _>>()?;
+            rows.push(row);
+        }
+
+        Ok(Self {
+            program_info,
+            stack_outputs,
+            trace_len_summary,
+            num_rand_rows,
+            main_segments,
+            aux_segments,
+            aux_rand_elements,
+            column_names,
+            rows,
+        })
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn write_str<W: ByteWriter>(value: &str, target: &mut W) {
+    debug_assert!(value.len() <= u8::MAX as usize);
+    target.write_u8(value.len() as u8);
+    target.write_bytes(value.as_bytes());
+}
+
+fn read_str<R: ByteReader>(source: &mut R) -> Result<String, DeserializationError> {
+    let len = source.read_u8()? as usize;
+    let bytes = source.read_slice(len)?;
+    from_utf8(bytes)
+        .map(String::from)
+        .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+}
+
+fn write_segments<W: ByteWriter>(segments: &[(&str, Range<usize>)], target: &mut W) {
+    target.write_u8(segments.len() as u8);
+    for (name, range) in segments {
+        write_str(name, target);
+        target.write_u16(range.start as u16);
+        target.write_u16(range.len() as u16);
+    }
+}
+
+fn read_segments<R: ByteReader>(
+    source: &mut R,
+) -> Result<Vec<(String, Range<usize>)>, DeserializationError> {
+    let num_segments = source.read_u8()?;
+    (0..num_segments)
+        .map(|_| {
+            let name = read_str(source)?;
+            let start = source.read_u16()? as usize;
+            let width = source.read_u16()? as usize;
+            Ok((name, start..start + width))
+        })
+        .collect()
+}
+
+fn write_trace_len_summary<W: ByteWriter>(summary: &TraceLenSummary, target: &mut W) {
+    let chiplets = summary.chiplets_trace_len();
+    target.write_u64(summary.main_trace_len() as u64);
+    target.write_u64(summary.range_trace_len() as u64);
+    target.write_u64(chiplets.hash_chiplet_len() as u64);
+    target.write_u64(chiplets.bitwise_chiplet_len() as u64);
+    target.write_u64(chiplets.memory_chiplet_len() as u64);
+    target.write_u64(chiplets.kernel_rom_len() as u64);
+}
+
+fn read_trace_len_summary<R: ByteReader>(
+    source: &mut R,
+) -> Result<TraceLenSummary, DeserializationError> {
+    let main_trace_len = source.read_u64()? as usize;
+    let range_trace_len = source.read_u64()? as usize;
+    let chiplets = ChipletsLengths::from_parts(
+        source.read_u64()? as usize,
+        source.read_u64()? as usize,
+        source.read_u64()? as usize,
+        source.read_u64()? as usize,
+    );
+    Ok(TraceLenSummary::new(main_trace_len, range_trace_len, chiplets))
+}
+
+#[cfg(feature = "std")]
+fn format_segments(segments: &[(&str, Range<usize>)]) -> String {
+    segments
+        .iter()
+        .map(|(name, range)| format!("{name}={}..{}", range.start, range.end))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
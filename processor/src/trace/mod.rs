@@ -12,9 +12,15 @@ use miden_air::trace::{
     AUX_TRACE_RAND_ELEMENTS, AUX_TRACE_WIDTH, DECODER_TRACE_OFFSET, MIN_TRACE_LEN,
     STACK_TRACE_OFFSET, TRACE_WIDTH,
 };
-use vm_core::{stack::STACK_TOP_SIZE, ProgramInfo, StackOutputs, ZERO};
+use vm_core::{stack::STACK_TOP_SIZE, OutputRegion, ProgramInfo, StackOutputs, ZERO};
 use winter_prover::{crypto::RandomCoin, EvaluationFrame, Trace, TraceLayout};
 
+mod export;
+pub use export::{
+    main_trace_column_names, ExportedTrace, AUX_TRACE_SEGMENTS, MAIN_TRACE_SEGMENTS,
+    TRACE_EXPORT_MAGIC, TRACE_EXPORT_VERSION,
+};
+
 mod utils;
 pub use utils::{AuxColumnBuilder, ChipletsLengths, TraceFragment, TraceLenSummary};
 
@@ -53,6 +59,7 @@ pub struct ExecutionTrace {
     aux_trace_builders: AuxTraceBuilders,
     program_info: ProgramInfo,
     stack_outputs: StackOutputs,
+    output_region: Option<OutputRegion>,
     trace_len_summary: TraceLenSummary,
 }
 
@@ -66,7 +73,11 @@ impl ExecutionTrace {
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
     /// Builds an execution trace for the provided process.
-    pub(super) fn new<H>(process: Process<H>, stack_outputs: StackOutputs) -> Self
+    pub(super) fn new<H>(
+        process: Process<H>,
+        stack_outputs: StackOutputs,
+        output_region: Option<OutputRegion>,
+    ) -> Self
     where
         H: Host,
     {
@@ -89,6 +100,7 @@ impl ExecutionTrace {
             main_trace,
             program_info,
             stack_outputs,
+            output_region,
             trace_len_summary,
         }
     }
@@ -111,6 +123,12 @@ impl ExecutionTrace {
         &self.stack_outputs
     }
 
+    /// Returns the output region committed to by the program, if the extraction of the output
+    /// region was enabled in the execution options.
+    pub fn output_region(&self) -> Option<&OutputRegion> {
+        self.output_region.as_ref()
+    }
+
     /// Returns the initial state of the top 16 stack registers.
     pub fn init_stack_state(&self) -> StackTopState {
         let mut result = [ZERO; STACK_TOP_SIZE];
@@ -0,0 +1,77 @@
+use super::{build_trace_from_ops, Felt, Trace, NUM_RAND_ROWS};
+use crate::trace::{
+    main_trace_column_names, ExportedTrace, AUX_TRACE_SEGMENTS, MAIN_TRACE_SEGMENTS,
+};
+use alloc::vec::Vec;
+use miden_air::trace::{STACK_TRACE_OFFSET, TRACE_WIDTH};
+use vm_core::{
+    utils::{Deserializable, SliceReader},
+    Operation,
+};
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn binary_trace_export_round_trip() {
+    let ops = vec![Operation::Add, Operation::Mul, Operation::Pad];
+    let trace = build_trace_from_ops(ops, &[1, 2, 3]);
+
+    let mut bytes = Vec::new();
+    trace.write_binary(&mut bytes);
+    let exported = ExportedTrace::read_from(&mut SliceReader::new(&bytes)).unwrap();
+
+    assert_eq!(trace.program_info(), &exported.program_info);
+    assert_eq!(trace.stack_outputs(), &exported.stack_outputs);
+    assert_eq!(trace.trace_len_summary(), &exported.trace_len_summary);
+    assert_eq!(NUM_RAND_ROWS, exported.num_rand_rows);
+    assert_eq!(main_trace_column_names(), exported.column_names);
+    assert_eq!(MAIN_TRACE_SEGMENTS.len(), exported.main_segments.len());
+    assert_eq!(AUX_TRACE_SEGMENTS.len(), exported.aux_segments.len());
+    for ((name, range), (exported_name, exported_range)) in
+        MAIN_TRACE_SEGMENTS.iter().zip(exported.main_segments.iter())
+    {
+        assert_eq!(name, exported_name);
+        assert_eq!(range, exported_range);
+    }
+
+    // all rows of the main trace should be exported
+    assert_eq!(trace.length(), exported.rows.len());
+    let mut row = [Felt::default(); TRACE_WIDTH];
+    for (row_idx, exported_row) in exported.rows.iter().enumerate() {
+        trace.main_segment().read_row_into(row_idx, &mut row);
+        assert_eq!(&row[..], &exported_row[..]);
+    }
+
+    // columns can be looked up by name
+    let s0 = exported.column("s0").unwrap();
+    assert_eq!(trace.main_segment().get_column(STACK_TRACE_OFFSET), &s0[..]);
+    assert!(exported.column("not_a_column").is_none());
+
+    // truncated or corrupted data should be rejected
+    assert!(ExportedTrace::read_from(&mut SliceReader::new(&bytes[..bytes.len() - 1])).is_err());
+    let mut corrupted = bytes.clone();
+    corrupted[0] = b'X';
+    assert!(ExportedTrace::read_from(&mut SliceReader::new(&corrupted)).is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn csv_trace_export() {
+    let ops = vec![Operation::Add, Operation::Mul, Operation::Pad];
+    let trace = build_trace_from_ops(ops, &[1, 2, 3]);
+
+    let mut bytes = Vec::new();
+    trace.write_csv(&mut bytes).unwrap();
+    let csv = alloc::string::String::from_utf8(bytes).unwrap();
+
+    let (metadata, rows): (Vec<&str>, Vec<&str>) = csv.lines().partition(|l| l.starts_with('#'));
+    assert!(metadata.contains(&"# num_rand_rows: 1"));
+
+    // the first non-comment line is the header, followed by one line per trace row
+    assert_eq!(main_trace_column_names().join(","), rows[0]);
+    assert_eq!(trace.length(), rows.len() - 1);
+    let first_row: Vec<u64> = rows[1].split(',').map(|v| v.parse().unwrap()).collect();
+    assert_eq!(TRACE_WIDTH, first_row.len());
+    assert_eq!(3, first_row[STACK_TRACE_OFFSET]);
+}
@@ -11,6 +11,7 @@ use vm_core::{
 
 mod chiplets;
 mod decoder;
+mod export;
 mod hasher;
 mod range;
 mod stack;
@@ -25,7 +26,7 @@ pub fn build_trace_from_block(program: &CodeBlock, stack_inputs: &[u64]) -> Exec
     let mut process =
         Process::new(Kernel::default(), stack_inputs, host, ExecutionOptions::default());
     process.execute_code_block(program, &CodeBlockTable::default()).unwrap();
-    ExecutionTrace::new(process, StackOutputs::default())
+    ExecutionTrace::new(process, StackOutputs::default(), None)
 }
 
 /// Builds a sample trace by executing a span block containing the specified operations. This
@@ -49,5 +50,5 @@ pub fn build_trace_from_ops_with_inputs(
         Process::new(Kernel::default(), stack_inputs, host, ExecutionOptions::default());
     let program = CodeBlock::new_span(operations);
     process.execute_code_block(&program, &CodeBlockTable::default()).unwrap();
-    ExecutionTrace::new(process, StackOutputs::default())
+    ExecutionTrace::new(process, StackOutputs::default(), None)
 }
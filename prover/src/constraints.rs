@@ -0,0 +1,409 @@
+use air::{ProcessorAir, ProvingOptions, PublicInputs};
+use alloc::{string::String, vec::Vec};
+use core::{fmt, ops::Range};
+use processor::{
+    crypto::{RandomCoin, RpoRandomCoin},
+    main_trace_column_names,
+    math::{Felt, FieldElement},
+    ExecutionTrace, StackInputs, AUX_TRACE_SEGMENTS, MAIN_TRACE_SEGMENTS,
+};
+use winter_prover::{
+    matrix::ColMatrix, Air, AuxTraceRandElements, EvaluationFrame, Trace, TraceInfo,
+};
+
+// CONSTRAINT CHECKING
+// ================================================================================================
+
+/// Evaluates all constraints of [ProcessorAir] against the specified execution trace and returns
+/// a description of the first violated constraint, if any.
+///
+/// Boundary constraints (assertions) are checked first, followed by transition constraints which
+/// are checked row by row. Constraints against the auxiliary trace segment are evaluated over the
+/// base field using random elements derived from the program hash; thus, while a violation of an
+/// auxiliary constraint always indicates an invalid trace, a valid outcome of the check holds only
+/// with high probability.
+///
+/// This is an expensive operation intended for debugging traces which fail to be proven.
+pub fn check_constraints(
+    trace: &mut ExecutionTrace,
+    stack_inputs: StackInputs,
+) -> Result<(), ConstraintViolation> {
+    let air = build_air(trace, stack_inputs);
+
+    // build the auxiliary trace segment using random elements derived from the program hash
+    let mut coin = RpoRandomCoin::new(trace.program_hash().into());
+    let rand_elements = (0..air.trace_layout().get_aux_segment_rand_elements(0))
+        .map(|_| coin.draw().expect("failed to draw random element"))
+        .collect::<Vec<Felt>>();
+    let aux_trace = trace
+        .build_aux_segment(&[], &rand_elements)
+        .expect("failed to build auxiliary trace segment");
+    let mut aux_rand_elements = AuxTraceRandElements::new();
+    aux_rand_elements.add_segment_elements(rand_elements);
+
+    check_segments(&air, trace.main_segment(), Some((&aux_trace, &aux_rand_elements)))
+}
+
+// CONSTRAINT VIOLATION
+// ================================================================================================
+
+/// Type of a constraint violated by an execution trace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConstraintType {
+    /// Boundary constraint against the main trace segment.
+    MainAssertion,
+    /// Boundary constraint against the auxiliary trace segment.
+    AuxAssertion,
+    /// Transition constraint against the main trace segment.
+    MainTransition,
+    /// Transition constraint against the auxiliary trace segment.
+    AuxTransition,
+}
+
+impl fmt::Display for ConstraintType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MainAssertion => write!(f, "main trace assertion"),
+            Self::AuxAssertion => write!(f, "auxiliary trace assertion"),
+            Self::MainTransition => write!(f, "main trace transition constraint"),
+            Self::AuxTransition => write!(f, "auxiliary trace transition constraint"),
+        }
+    }
+}
+
+/// Values of a single trace column at the row where a constraint was violated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnValues {
+    pub name: String,
+    /// Value of the column at the row of the violation.
+    pub current: Felt,
+    /// Value of the column at the next row; set only for transition constraints.
+    pub next: Option<Felt>,
+}
+
+/// Description of a constraint violated by an execution trace.
+///
+/// For transition constraints, `group` refers to one of the groups returned by
+/// [ProcessorAir::main_transition_constraint_groups] or
+/// [ProcessorAir::aux_transition_constraint_groups], and `index` is the index of the constraint
+/// within this group. For assertions, `group` is the name of the trace segment containing the
+/// asserted column, and `index` is the index of the assertion in the list of assertions returned
+/// by the AIR.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConstraintViolation {
+    pub constraint_type: ConstraintType,
+    pub group: &'static str,
+    pub index: usize,
+    /// Index of the row at which the constraint was violated.
+    pub row: usize,
+    /// For assertions, the expected value of the asserted column; for transition constraints, the
+    /// value to which the constraint evaluated.
+    pub value: Felt,
+    /// Values of the trace columns relevant to the violated constraint.
+    pub columns: Vec<ColumnValues>,
+}
+
+impl fmt::Display for ConstraintViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}[{}] failed at row {}",
+            self.constraint_type, self.group, self.index, self.row
+        )?;
+        match self.constraint_type {
+            ConstraintType::MainAssertion | ConstraintType::AuxAssertion => {
+                writeln!(f, ": expected {}", self.value)?
+            }
+            ConstraintType::MainTransition | ConstraintType::AuxTransition => {
+                writeln!(f, ": evaluated to {}", self.value)?
+            }
+        }
+        for column in self.columns.iter() {
+            write!(f, "  {}: {}", column.name, column.current)?;
+            if let Some(next) = column.next {
+                write!(f, " -> {next}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Instantiates [ProcessorAir] for the specified trace.
+fn build_air(trace: &ExecutionTrace, stack_inputs: StackInputs) -> ProcessorAir {
+    let pub_inputs = PublicInputs::new(
+        trace.program_info().clone(),
+        stack_inputs,
+        trace.stack_outputs().clone(),
+    );
+    let trace_info =
+        TraceInfo::new_multi_segment(trace.layout().clone(), trace.length(), trace.meta().to_vec());
+    ProcessorAir::new(trace_info, pub_inputs, ProvingOptions::default().into())
+}
+
+/// Checks the specified main and (optionally) auxiliary trace segments against all assertions and
+/// transition constraints of the AIR.
+fn check_segments(
+    air: &ProcessorAir,
+    main_trace: &ColMatrix<Felt>,
+    aux: Option<(&ColMatrix<Felt>, &AuxTraceRandElements<Felt>)>,
+) -> Result<(), ConstraintViolation> {
+    let main_names = main_trace_column_names();
+    let aux_names = aux_trace_column_names();
+    let trace_len = main_trace.num_rows();
+
+    // --- check assertions -----------------------------------------------------------------------
+    let main_assertions = air
+        .get_assertions()
+        .into_iter()
+        .enumerate()
+        .map(|(i, a)| (i, a, main_trace, false));
+    let aux_assertions = aux
+        .map(|(aux_trace, rand_elements)| {
+            let assertions = air.get_aux_assertions(rand_elements).into_iter().enumerate();
+            assertions.map(move |(i, a)| (i, a, aux_trace, true))
+        })
+        .into_iter()
+        .flatten();
+
+    for (index, assertion, segment, is_aux) in main_assertions.chain(aux_assertions) {
+        let mut violation = None;
+        assertion.apply(trace_len, |step, value| {
+            let actual = segment.get(assertion.column(), step);
+            if violation.is_none() && actual != value {
+                violation = Some((step, value, actual));
+            }
+        });
+
+        if let Some((row, value, actual)) = violation {
+            let (constraint_type, segments, names) = if is_aux {
+                (ConstraintType::AuxAssertion, &AUX_TRACE_SEGMENTS, &aux_names)
+            } else {
+                (ConstraintType::MainAssertion, &MAIN_TRACE_SEGMENTS, &main_names)
+            };
+            return Err(ConstraintViolation {
+                constraint_type,
+                group: segment_name(segments, assertion.column()),
+                index,
+                row,
+                value,
+                columns: vec![ColumnValues {
+                    name: names[assertion.column()].clone(),
+                    current: actual,
+                    next: None,
+                }],
+            });
+        }
+    }
+
+    // --- check transition constraints -----------------------------------------------------------
+    let periodic_columns = air.get_periodic_column_values();
+    let mut periodic_values = vec![Felt::ZERO; periodic_columns.len()];
+
+    let mut main_frame = EvaluationFrame::new(main_trace.num_cols());
+    let mut main_evaluations = vec![Felt::ZERO; air.context().num_main_transition_constraints()];
+    let mut aux_frame = aux.map(|(aux_trace, _)| EvaluationFrame::new(aux_trace.num_cols()));
+    let mut aux_evaluations = vec![Felt::ZERO; air.context().num_aux_transition_constraints()];
+
+    for row in 0..trace_len - air.context().num_transition_exemptions() {
+        for (column, value) in periodic_columns.iter().zip(periodic_values.iter_mut()) {
+            *value = column[row % column.len()];
+        }
+
+        read_frame(main_trace, row, &mut main_frame);
+        air.evaluate_transition(&main_frame, &periodic_values, &mut main_evaluations);
+        if let Some(constraint_idx) = main_evaluations.iter().position(|&v| v != Felt::ZERO) {
+            let groups = ProcessorAir::main_transition_constraint_groups();
+            let (group, range) = find_group(&groups, constraint_idx);
+            let columns = frame_values(
+                &main_frame,
+                &main_names,
+                &MAIN_TRACE_SEGMENTS,
+                main_segments_for_group(group),
+            );
+            return Err(ConstraintViolation {
+                constraint_type: ConstraintType::MainTransition,
+                group,
+                index: constraint_idx - range.start,
+                row,
+                value: main_evaluations[constraint_idx],
+                columns,
+            });
+        }
+
+        if let (Some((aux_trace, rand_elements)), Some(aux_frame)) = (aux, aux_frame.as_mut()) {
+            read_frame(aux_trace, row, aux_frame);
+            air.evaluate_aux_transition(
+                &main_frame,
+                aux_frame,
+                &periodic_values,
+                rand_elements,
+                &mut aux_evaluations,
+            );
+            if let Some(constraint_idx) = aux_evaluations.iter().position(|&v| v != Felt::ZERO) {
+                let groups = ProcessorAir::aux_transition_constraint_groups();
+                let (group, range) = find_group(&groups, constraint_idx);
+                let mut columns =
+                    frame_values(aux_frame, &aux_names, &AUX_TRACE_SEGMENTS, &[group]);
+                columns.extend(frame_values(
+                    &main_frame,
+                    &main_names,
+                    &MAIN_TRACE_SEGMENTS,
+                    &[group],
+                ));
+                return Err(ConstraintViolation {
+                    constraint_type: ConstraintType::AuxTransition,
+                    group,
+                    index: constraint_idx - range.start,
+                    row,
+                    value: aux_evaluations[constraint_idx],
+                    columns,
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads the evaluation frame starting at the specified row from the specified trace segment.
+fn read_frame(segment: &ColMatrix<Felt>, row: usize, frame: &mut EvaluationFrame<Felt>) {
+    segment.read_row_into(row, frame.current_mut());
+    segment.read_row_into((row + 1) % segment.num_rows(), frame.next_mut());
+}
+
+/// Returns names of all columns of the auxiliary trace segment.
+fn aux_trace_column_names() -> Vec<String> {
+    AUX_TRACE_SEGMENTS
+        .iter()
+        .flat_map(|(name, range)| (0..range.len()).map(move |i| format!("aux_{name}_{i}")))
+        .collect()
+}
+
+/// Returns the name of the trace segment which contains the specified column.
+fn segment_name(segments: &[(&'static str, Range<usize>)], column: usize) -> &'static str {
+    segments
+        .iter()
+        .find(|(_, range)| range.contains(&column))
+        .map(|(name, _)| *name)
+        .expect("column not in any trace segment")
+}
+
+/// Returns the constraint group which contains the constraint with the specified index.
+fn find_group(
+    groups: &[(&'static str, Range<usize>)],
+    constraint_idx: usize,
+) -> (&'static str, Range<usize>) {
+    groups
+        .iter()
+        .find(|(_, range)| range.contains(&constraint_idx))
+        .cloned()
+        .expect("constraint not in any constraint group")
+}
+
+/// Returns names of the main trace segments whose columns are referenced by the constraints in
+/// the specified group.
+fn main_segments_for_group(group: &str) -> &'static [&'static str] {
+    match group.split('.').next() {
+        Some("system") => &["system"],
+        // stack constraints are selected by operation flags defined in the decoder
+        Some("stack") => &["decoder", "stack"],
+        Some("range") => &["range"],
+        _ => &["chiplets"],
+    }
+}
+
+/// Returns current and next values of all columns in the specified frame which belong to the
+/// specified trace segments.
+fn frame_values(
+    frame: &EvaluationFrame<Felt>,
+    names: &[String],
+    segment_table: &[(&'static str, Range<usize>)],
+    segments: &[&str],
+) -> Vec<ColumnValues> {
+    segment_table
+        .iter()
+        .filter(|(name, _)| segments.contains(name))
+        .flat_map(|(_, range)| range.clone())
+        .map(|col_idx| ColumnValues {
+            name: names[col_idx].clone(),
+            current: frame.current()[col_idx],
+            next: Some(frame.next()[col_idx]),
+        })
+        .collect()
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{build_air, check_constraints, check_segments, ConstraintType};
+    use air::{trace::STACK_TRACE_OFFSET, ProcessorAir};
+    use assembly::Assembler;
+    use processor::{
+        math::{Felt, FieldElement},
+        DefaultHost, ExecutionOptions, ExecutionTrace, StackInputs,
+    };
+    use winter_prover::{Air, Trace};
+
+    fn build_trace(source: &str, stack_inputs: StackInputs) -> ExecutionTrace {
+        let program = Assembler::default().compile(source).unwrap();
+        let host = DefaultHost::default();
+        processor::execute(&program, stack_inputs, host, ExecutionOptions::default()).unwrap()
+    }
+
+    #[test]
+    fn valid_trace_satisfies_constraints() {
+        let source = "begin add push.7 mul u32split mem_storew dropw repeat.3 hperm end end";
+        let stack_inputs = StackInputs::try_from_ints([1, 2, 3]).unwrap();
+        let mut trace = build_trace(source, stack_inputs.clone());
+        assert_eq!(Ok(()), check_constraints(&mut trace, stack_inputs));
+    }
+
+    #[test]
+    fn invalid_stack_inputs_violate_assertion() {
+        let mut trace =
+            build_trace("begin add end", StackInputs::try_from_ints([1, 2, 3]).unwrap());
+
+        let stack_inputs = StackInputs::try_from_ints([1, 2, 4]).unwrap();
+        let violation = check_constraints(&mut trace, stack_inputs).unwrap_err();
+        assert_eq!(ConstraintType::MainAssertion, violation.constraint_type);
+        assert_eq!("stack", violation.group);
+        assert_eq!(0, violation.row);
+        assert_eq!(Felt::new(4), violation.value);
+        assert_eq!("s0", violation.columns[0].name);
+        assert_eq!(Felt::new(3), violation.columns[0].current);
+    }
+
+    #[test]
+    fn tampered_trace_violates_transition_constraint() {
+        let stack_inputs = StackInputs::try_from_ints([1, 2, 3]).unwrap();
+        let trace = build_trace("begin add push.7 mul end", stack_inputs.clone());
+        let air = build_air(&trace, stack_inputs);
+
+        // find the row right after ADD is executed and change its result
+        let mut main_trace = trace.main_segment().clone();
+        let s0 = main_trace.get_column(STACK_TRACE_OFFSET).to_vec();
+        let row = s0.iter().position(|&v| v == Felt::new(5)).unwrap();
+        main_trace.set(STACK_TRACE_OFFSET, row, Felt::new(6));
+
+        let violation = check_segments(&air, &main_trace, None).unwrap_err();
+        assert_eq!(ConstraintType::MainTransition, violation.constraint_type);
+        assert_eq!("stack.field_ops", violation.group);
+        assert_eq!(row - 1, violation.row);
+        assert_ne!(Felt::ZERO, violation.value);
+
+        let s0 = violation.columns.iter().find(|c| c.name == "s0").unwrap();
+        assert_eq!(Felt::new(3), s0.current);
+        assert_eq!(Some(Felt::new(6)), s0.next);
+
+        // constraint groups cover all constraints of the AIR
+        let groups = ProcessorAir::main_transition_constraint_groups();
+        let num_constraints = groups.last().unwrap().1.end;
+        assert_eq!(air.context().num_main_transition_constraints(), num_constraints);
+    }
+}
@@ -1,5 +1,8 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[macro_use]
+extern crate alloc;
+
 use air::{ProcessorAir, PublicInputs};
 use core::marker::PhantomData;
 use processor::{
@@ -22,13 +25,16 @@ use {std::time::Instant, winter_prover::Trace};
 #[cfg(all(feature = "metal", target_arch = "aarch64", target_os = "macos"))]
 mod gpu;
 
+mod constraints;
+
 // EXPORTS
 // ================================================================================================
 
 pub use air::{DeserializationError, ExecutionProof, FieldExtension, HashFunction, ProvingOptions};
+pub use constraints::{check_constraints, ColumnValues, ConstraintType, ConstraintViolation};
 pub use processor::{
     crypto, math, utils, AdviceInputs, Digest, ExecutionError, Host, InputError, MemAdviceProvider,
-    Program, StackInputs, StackOutputs, Word,
+    OutputRegion, Program, StackInputs, StackOutputs, Word,
 };
 pub use winter_prover::StarkProof;
 
@@ -54,53 +60,116 @@ pub fn prove<H>(
 where
     H: Host,
 {
-    // execute the program to create an execution trace
-    #[cfg(feature = "std")]
-    let now = Instant::now();
-    let trace =
-        processor::execute(program, stack_inputs.clone(), host, *options.execution_options())?;
-    #[cfg(feature = "std")]
-    event!(
-        Level::INFO,
-        "Generated execution trace of {} columns and {} steps ({}% padded) in {} ms",
-        trace.layout().main_trace_width(),
-        trace.trace_len_summary().padded_trace_len(),
-        trace.trace_len_summary().padding_percentage(),
-        now.elapsed().as_millis()
-    );
+    let trace = execute(program, stack_inputs.clone(), host, &options)?;
+    let stack_outputs = trace.stack_outputs().clone();
+    let proof = prove_trace(trace, stack_inputs, options)?;
+
+    Ok((stack_outputs, proof))
+}
 
+/// Executes and proves the specified `program` which commits to an output region via
+/// `std::sys::commit_output_region`, and returns the result and the contents of the output region
+/// together with a STARK-based proof of the program's execution.
+///
+/// The output region is included in the public inputs of the proof, and thus the proof can be
+/// verified only via `miden_verifier::verify_with_output_region()`.
+///
+/// # Errors
+/// Returns an error if program execution or STARK proof generation fails for any reason, or if
+/// the program does not commit to a valid output region at the end of its execution.
+#[instrument("prove_program_with_output_region", skip_all)]
+pub fn prove_with_output_region<H>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    host: H,
+    options: ProvingOptions,
+) -> Result<(StackOutputs, OutputRegion, ExecutionProof), ExecutionError>
+where
+    H: Host,
+{
+    let exec_options = options.execution_options().with_output_region();
+    let options = options.with_execution_options(exec_options);
+
+    let trace = execute(program, stack_inputs.clone(), host, &options)?;
+    let stack_outputs = trace.stack_outputs().clone();
+    let output_region = trace.output_region().expect("output region was not extracted").clone();
+    let proof = prove_trace(trace, stack_inputs, options)?;
+
+    Ok((stack_outputs, output_region, proof))
+}
+
+/// Generates a STARK-based proof of the execution described by the specified execution trace.
+///
+/// This allows the trace to be inspected (e.g., via [check_constraints()]) before a proof for it
+/// is generated, without executing the program again.
+///
+/// * `stack_inputs` specifies the initial state of the stack the trace was generated with.
+/// * `options` defines parameters for STARK proof generation.
+///
+/// # Errors
+/// Returns an error if STARK proof generation fails for any reason.
+#[instrument("prove_trace", skip_all)]
+pub fn prove_trace(
+    trace: ExecutionTrace,
+    stack_inputs: StackInputs,
+    options: ProvingOptions,
+) -> Result<ExecutionProof, ExecutionError> {
     let stack_outputs = trace.stack_outputs().clone();
     let hash_fn = options.hash_fn();
 
-    // generate STARK proof
     let proof = match hash_fn {
         HashFunction::Blake3_192 => ExecutionProver::<Blake3_192, WinterRandomCoin<_>>::new(
             options,
             stack_inputs,
-            stack_outputs.clone(),
+            stack_outputs,
         )
         .prove(trace),
         HashFunction::Blake3_256 => ExecutionProver::<Blake3_256, WinterRandomCoin<_>>::new(
             options,
             stack_inputs,
-            stack_outputs.clone(),
+            stack_outputs,
         )
         .prove(trace),
         HashFunction::Rpo256 => {
-            let prover = ExecutionProver::<Rpo256, RpoRandomCoin>::new(
-                options,
-                stack_inputs,
-                stack_outputs.clone(),
-            );
+            let prover =
+                ExecutionProver::<Rpo256, RpoRandomCoin>::new(options, stack_inputs, stack_outputs);
             #[cfg(all(feature = "metal", target_arch = "aarch64", target_os = "macos"))]
             let prover = gpu::MetalRpoExecutionProver(prover);
             prover.prove(trace)
         }
     }
     .map_err(ExecutionError::ProverError)?;
-    let proof = ExecutionProof::new(proof, hash_fn);
 
-    Ok((stack_outputs, proof))
+    Ok(ExecutionProof::new(proof, hash_fn))
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Executes the specified `program` and returns the resulting execution trace.
+fn execute<H>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    host: H,
+    options: &ProvingOptions,
+) -> Result<ExecutionTrace, ExecutionError>
+where
+    H: Host,
+{
+    #[cfg(feature = "std")]
+    let now = Instant::now();
+    let trace = processor::execute(program, stack_inputs, host, *options.execution_options())?;
+    #[cfg(feature = "std")]
+    event!(
+        Level::INFO,
+        "Generated execution trace of {} columns and {} steps ({}% padded) in {} ms",
+        trace.layout().main_trace_width(),
+        trace.trace_len_summary().padded_trace_len(),
+        trace.trace_len_summary().padding_percentage(),
+        now.elapsed().as_millis()
+    );
+
+    Ok(trace)
 }
 
 // PROVER
@@ -187,7 +256,12 @@ where
         );
 
         let program_info = trace.program_info().clone();
-        PublicInputs::new(program_info, self.stack_inputs.clone(), self.stack_outputs.clone())
+        let pub_inputs =
+            PublicInputs::new(program_info, self.stack_inputs.clone(), self.stack_outputs.clone());
+        match trace.output_region() {
+            Some(output_region) => pub_inputs.with_output_region(output_region.clone()),
+            None => pub_inputs,
+        }
     }
 
     fn new_trace_lde<E: FieldElement<BaseField = Felt>>(
@@ -1,9 +1,12 @@
+use processor::ExecutionError;
 use test_utils::{
     crypto::{
-        init_merkle_leaf, init_merkle_leaves, MerkleError, MerkleStore, MerkleTree, Mmr, NodeIndex,
-        RpoDigest,
+        init_merkle_leaf, init_merkle_leaves, MerkleError, MerkleStore, MerkleTree, Mmr, MmrPeaks,
+        NodeIndex, PartialMmr, RpoDigest,
     },
-    hash_elements, stack_to_ints, Felt, StarkField, Word, EMPTY_WORD, ONE, ZERO,
+    hash_elements,
+    rand::rand_array,
+    stack_to_ints, Felt, StarkField, Word, EMPTY_WORD, ONE, ZERO,
 };
 
 // TESTS
@@ -679,9 +682,141 @@ fn test_mmr_large_add_roundtrip() {
     test.expect_stack_and_memory(&expect_stack, mmr_ptr, &expected_memory);
 }
 
+#[test]
+fn test_mmr_bag_peaks() {
+    let source = "
+        use.std::collections::mmr
+        begin exec.mmr::bag_peaks end
+    ";
+
+    // the peaks are padded to 16 words, and to an even number of words after that
+    for num_leaves in [1_usize, 2, 3, 0b10101, (1 << 16) - 1, (1 << 17) - 1, (1 << 18) - 1] {
+        let num_peaks = num_leaves.count_ones() as usize;
+        let peaks: Vec<RpoDigest> =
+            (0..num_peaks).map(|_| RpoDigest::new(rand_array::<Felt, 4>())).collect();
+        let peaks = MmrPeaks::new(num_leaves, peaks).unwrap();
+
+        let advice_stack = digests_to_ints(peaks.peaks());
+        let expect_stack: Vec<u64> = peaks.hash_peaks().iter().rev().map(|v| v.as_int()).collect();
+
+        build_test!(source, &[num_leaves as u64], &advice_stack).expect_stack(&expect_stack);
+    }
+}
+
+#[test]
+fn test_mmr_get_partial_and_verify_leaf() {
+    let mmr = build_mmr(0b10111);
+
+    let get_source = "
+        use.std::collections::mmr
+        begin exec.mmr::get_partial end
+    ";
+    let verify_source = "
+        use.std::collections::mmr
+        begin exec.mmr::verify_leaf end
+    ";
+
+    // open leaves of the current and older states of the MMR
+    for forest in [1, 2, 5, 8, 16, 17, 0b10111] {
+        let (peaks, store, advice_map) = build_partial_mmr_inputs(&mmr, forest, 0..forest);
+
+        for pos in 0..forest {
+            let leaf = mmr.get(pos).unwrap();
+            let mut stack = stack_to_ints(&*peaks.hash_peaks());
+            stack.push(forest as u64);
+            stack.push(pos as u64);
+
+            let expect_stack: Vec<u64> = leaf.iter().rev().map(|v| v.as_int()).collect();
+            build_test!(get_source, &stack, &[], store.clone(), advice_map.iter().cloned())
+                .expect_stack(&expect_stack);
+
+            stack.extend(stack_to_ints(&*leaf));
+            build_test!(verify_source, &stack, &[], store.clone(), advice_map.iter().cloned())
+                .expect_stack(&[]);
+        }
+    }
+}
+
+#[test]
+fn test_mmr_verify_leaf_invalid() {
+    let mmr = build_mmr(0b1011);
+    let forest = 0b1011;
+    let (peaks, store, advice_map) = build_partial_mmr_inputs(&mmr, forest, [3, 9, 10]);
+    let source = "
+        use.std::collections::mmr
+        begin exec.mmr::verify_leaf end
+    ";
+    let build_stack = |pos: u64, forest: u64, leaf: RpoDigest| {
+        let mut stack = stack_to_ints(&*peaks.hash_peaks());
+        stack.push(forest);
+        stack.push(pos);
+        stack.extend(stack_to_ints(&*leaf));
+        stack
+    };
+
+    // a leaf which is not at the position
+    let stack = build_stack(3, forest as u64, mmr.get(4).unwrap());
+    let result =
+        build_test!(source, &stack, &[], store.clone(), advice_map.iter().cloned()).execute();
+    assert!(matches!(result, Err(ExecutionError::FailedAssertion { .. })));
+
+    // a position which is not in the MMR
+    let stack = build_stack(forest as u64, forest as u64, mmr.get(forest - 1).unwrap());
+    let result =
+        build_test!(source, &stack, &[], store.clone(), advice_map.iter().cloned()).execute();
+    assert!(matches!(result, Err(ExecutionError::FailedAssertion { .. })));
+
+    // a forest which is not the one committed to in the advice map; the MMR with 0b1101 leaves
+    // has the same number of peaks, and leaf 9 would be in its peak of depth 2
+    let stack = build_stack(9, 0b1101, mmr.get(9).unwrap());
+    let result = build_test!(source, &stack, &[], store, advice_map.iter().cloned()).execute();
+    assert!(matches!(result, Err(ExecutionError::FailedAssertion { .. })));
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Returns an MMR with the specified number of leaves.
+fn build_mmr(num_leaves: u64) -> Mmr {
+    let mut mmr = Mmr::new();
+    for leaf in init_merkle_leaves(&(0..num_leaves).collect::<Vec<_>>()) {
+        mmr.add(leaf.into());
+    }
+    mmr
+}
+
+/// Returns the peaks of the MMR with `forest` leaves, together with a Merkle store holding the
+/// authentication paths of the specified leaves in a [PartialMmr] of these peaks, and an advice
+/// map holding the peaks under their hash, as done by `mmr::pack`.
+fn build_partial_mmr_inputs(
+    mmr: &Mmr,
+    forest: usize,
+    tracked_leaves: impl IntoIterator<Item = usize>,
+) -> (MmrPeaks, MerkleStore, Vec<(RpoDigest, Vec<Felt>)>) {
+    let peaks = mmr.peaks(forest).unwrap();
+    let mut partial_mmr = PartialMmr::from_peaks(peaks.clone());
+    let mut store = MerkleStore::new();
+
+    for pos in tracked_leaves {
+        let leaf = mmr.get(pos).unwrap();
+        let proof = mmr.open(pos, forest).unwrap();
+        partial_mmr.track(pos, leaf, &proof.merkle_path).unwrap();
+
+        let proof = partial_mmr.open(pos).unwrap().unwrap();
+        assert!(peaks.verify(leaf, proof.clone()));
+        if proof.merkle_path.depth() > 0 {
+            store
+                .add_merkle_path(proof.relative_pos() as u64, leaf, proof.merkle_path)
+                .unwrap();
+        }
+    }
+
+    let mut map_data = vec![Felt::new(forest as u64), ZERO, ZERO, ZERO];
+    map_data.extend(peaks.flatten_and_pad_peaks());
+
+    (peaks.clone(), store, vec![(peaks.hash_peaks(), map_data)])
+}
+
 fn digests_to_elements(digests: &[RpoDigest]) -> Vec<Felt> {
     digests.iter().map(|d| Word::from(d)).flatten().collect()
 }
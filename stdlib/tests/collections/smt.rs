@@ -1,4 +1,5 @@
 use super::*;
+use processor::ExecutionError;
 
 // TEST DATA
 // ================================================================================================
@@ -50,6 +51,13 @@ fn test_smt_get() {
         EMPTY_WORD,
         &smt,
     );
+
+    // Get a key which maps to a leaf holding another key
+    expect_value_from_get(
+        RpoDigest::new([Felt::new(1), Felt::new(102), Felt::new(103), Felt::new(104)]),
+        EMPTY_WORD,
+        &smt,
+    );
 }
 
 /// Tests inserting and removing key-value pairs to an SMT. We do the insert/removal twice to ensure
@@ -250,9 +258,265 @@ fn test_set_empty_key_in_non_empty_leaf() {
     build_test!(source, &init_stack, &[], store, advice_map).expect_stack(&final_stack);
 }
 
+/// Tests `get` on every key of leaves holding multiple key-value pairs, as well as on keys which map
+/// to such leaves but are not present in them
+#[test]
+fn test_smt_get_multiple_leaf() {
+    let source = "
+    use.std::collections::smt
+    begin
+      exec.smt::get
+    end
+    ";
+
+    let smt = Smt::with_entries(multiple_leaf_entries()).unwrap();
+    let (store, advice_map) = build_advice_inputs(&smt);
+
+    let absent_keys = [
+        multiple_leaf_key(0, 0, 0),
+        multiple_leaf_key(7, 1 << 40, 2),
+        multiple_leaf_key(u64::MAX - 1, 0, 0),
+    ];
+    let keys = multiple_leaf_entries().into_iter().map(|(key, _)| key).chain(absent_keys);
+
+    for key in keys {
+        let mut init_stack = Vec::new();
+        append_word_to_vec(&mut init_stack, smt.root().into());
+        append_word_to_vec(&mut init_stack, key.into());
+        let expected_output = build_expected_stack(smt.get_value(&key), smt.root().into());
+
+        build_test!(source, &init_stack, &[], store.clone(), advice_map.clone())
+            .expect_stack(&expected_output);
+    }
+}
+
+/// Tests inserting, updating and removing key-value pairs in leaves which hold (or come to hold)
+/// multiple key-value pairs, in every position of the leaf
+#[test]
+fn test_smt_set_multiple_leaf() {
+    let source = "
+    use.std::collections::smt
+    begin
+      exec.smt::set
+    end
+    ";
+
+    let mut smt = Smt::new();
+
+    // insert pairs in an order which exercises inserting in front of, between and after the pairs
+    // already in the leaf; the first insertion turns a single leaf into a multiple one
+    let entries = multiple_leaf_entries();
+    for &(key, value) in entries[1..].iter().chain(&entries[..1]) {
+        let (init_stack, final_stack, store, advice_map) =
+            prepare_insert_or_set(key, value, &mut smt);
+        build_test!(source, &init_stack, &[], store, advice_map).expect_stack(&final_stack);
+    }
+
+    // update values in place
+    for (key, _) in entries {
+        let value = [Felt::new(11), Felt::new(12), Felt::new(13), key[2]];
+        let (init_stack, final_stack, store, advice_map) =
+            prepare_insert_or_set(key, value, &mut smt);
+        build_test!(source, &init_stack, &[], store, advice_map).expect_stack(&final_stack);
+    }
+
+    // removing a key which is not in the leaf leaves the tree unchanged
+    let root = smt.root();
+    let (init_stack, final_stack, store, advice_map) =
+        prepare_insert_or_set(multiple_leaf_key(7, 1 << 40, 2), EMPTY_WORD, &mut smt);
+    build_test!(source, &init_stack, &[], store, advice_map).expect_stack(&final_stack);
+    assert_eq!(smt.root(), root);
+
+    // remove pairs until the leaf becomes single, and then empty
+    for &(key, _) in entries[2..].iter().chain(&entries[..2]).rev() {
+        let (init_stack, final_stack, store, advice_map) =
+            prepare_insert_or_set(key, EMPTY_WORD, &mut smt);
+        build_test!(source, &init_stack, &[], store, advice_map).expect_stack(&final_stack);
+    }
+
+    assert_eq!(smt.root(), Smt::new().root());
+}
+
+/// Tests that the advice map is updated with the pre-image of a multiple leaf after a `set`, so that
+/// the updated leaf can be read and updated again
+#[test]
+fn test_set_advice_map_multiple_leaf() {
+    let source = "
+    use.std::collections::smt
+    # Stack: [V, K, R]
+    begin
+        # copy K, and save lower on stack
+        dupw.1 movdnw.3
+        # => [V, K, R, K]
+
+        exec.smt::set dropw
+        # => [R_new, K]
+
+        # remove K again; this reads the updated leaf from the advice map
+        swapw padw exec.smt::set
+        # => [V_old, R_final]
+    end
+    ";
+
+    let entries = multiple_leaf_entries();
+    let mut smt = Smt::with_entries(entries[1..].iter().copied()).unwrap();
+    let old_root = smt.root();
+    let (key, value) = entries[0];
+
+    let (init_stack, _, store, advice_map) = prepare_insert_or_set(key, value, &mut smt);
+    let expected_output = build_expected_stack(value, old_root.into());
+    build_test!(source, &init_stack, &[], store, advice_map).expect_stack(&expected_output);
+}
+
+/// Tests inserting, updating and removing a batch of key-value pairs read from memory
+#[test]
+fn test_smt_set_many() {
+    let mut smt = Smt::with_entries(LEAVES).unwrap();
+    let (store, advice_map) = build_advice_inputs(&smt);
+
+    let mut updates = multiple_leaf_entries().to_vec();
+    // update and remove the existing entries
+    updates.push((LEAVES[0].0, [Felt::new(7); 4]));
+    updates.push((LEAVES[1].0, EMPTY_WORD));
+    // set the same key twice
+    updates.push((updates[0].0, [Felt::new(9); 4]));
+
+    let mut source = String::from("use.std::collections::smt\nbegin\n");
+    for (i, (key, value)) in updates.iter().enumerate() {
+        for (offset, word) in [Word::from(*key), *value].iter().enumerate() {
+            let elements = word.iter().map(|e| e.as_int().to_string());
+            let elements = elements.collect::<Vec<_>>().join(".");
+            source.push_str(&format!("push.{elements} mem_storew.{} dropw\n", 2 * i + offset));
+        }
+    }
+    source.push_str(&format!("push.0.{} exec.smt::set_many\nend", updates.len()));
+
+    let mut init_stack = Vec::new();
+    append_word_to_vec(&mut init_stack, smt.root().into());
+
+    for (key, value) in updates {
+        smt.insert(key, value);
+    }
+    let expected_output: Vec<u64> = smt.root().iter().rev().map(|e| e.as_int()).collect();
+
+    build_test!(&source, &init_stack, &[], store, advice_map).expect_stack(&expected_output);
+}
+
+/// Tests verifying membership and non-membership of keys in empty, single and multiple leaves
+#[test]
+fn test_smt_verify() {
+    let source = "
+    use.std::collections::smt
+    begin
+      exec.smt::verify
+    end
+    ";
+
+    let entries = LEAVES.into_iter().chain(multiple_leaf_entries());
+    let smt = Smt::with_entries(entries.clone()).unwrap();
+    let (store, advice_map) = build_advice_inputs(&smt);
+    let expected_output: Vec<u64> = smt.root().iter().rev().map(|e| e.as_int()).collect();
+
+    let absent_keys = [
+        RpoDigest::new([42_u32.into(), 42_u32.into(), 42_u32.into(), 42_u32.into()]),
+        RpoDigest::new([Felt::new(1), Felt::new(102), Felt::new(103), Felt::new(104)]),
+        multiple_leaf_key(0, 0, 0),
+    ];
+    let pairs = entries.chain(absent_keys.into_iter().map(|key| (key, EMPTY_WORD)));
+
+    for (key, value) in pairs {
+        // the value located under the key is verified
+        let mut init_stack = Vec::new();
+        append_word_to_vec(&mut init_stack, smt.root().into());
+        append_word_to_vec(&mut init_stack, key.into());
+        append_word_to_vec(&mut init_stack, value);
+        build_test!(source, &init_stack, &[], store.clone(), advice_map.clone())
+            .expect_stack(&expected_output);
+
+        // any other value is rejected
+        let mut init_stack = Vec::new();
+        append_word_to_vec(&mut init_stack, smt.root().into());
+        append_word_to_vec(&mut init_stack, key.into());
+        append_word_to_vec(&mut init_stack, [Felt::new(99); 4]);
+        let result =
+            build_test!(source, &init_stack, &[], store.clone(), advice_map.clone()).execute();
+        assert!(matches!(result, Err(ExecutionError::FailedAssertion { .. })));
+    }
+}
+
+/// Tests iterating over the key-value pairs of empty, single and multiple leaves, including indices
+/// past the last pair of the leaf
+#[test]
+fn test_smt_get_leaf_entry() {
+    let source = "
+    use.std::collections::smt
+    begin
+      exec.smt::get_leaf_entry
+    end
+    ";
+
+    let smt = Smt::with_entries(LEAVES.into_iter().chain(multiple_leaf_entries())).unwrap();
+    let (store, advice_map) = build_advice_inputs(&smt);
+
+    // the key does not need to be in the leaf; for the multiple leaf, it is not
+    let keys = [
+        RpoDigest::new([42_u32.into(), 42_u32.into(), 42_u32.into(), 42_u32.into()]),
+        LEAVES[0].0,
+        multiple_leaf_key(0, 0, 0),
+    ];
+    assert_eq!(smt.get_leaf(&keys[2]).num_entries(), 4, "sanity check");
+
+    for key in keys {
+        let leaf = smt.get_leaf(&key);
+        let num_entries = leaf.num_entries();
+        for i in 0..=num_entries {
+            let (entry_key, entry_value) = match leaf.entries().get(i as usize) {
+                Some(&&(entry_key, entry_value)) => (entry_key.into(), entry_value),
+                None => (EMPTY_WORD, EMPTY_WORD),
+            };
+
+            let mut init_stack = Vec::new();
+            append_word_to_vec(&mut init_stack, smt.root().into());
+            append_word_to_vec(&mut init_stack, key.into());
+            init_stack.push(i);
+
+            let mut expected_output = build_expected_stack(entry_key, entry_value);
+            expected_output.push(num_entries);
+            expected_output.extend(smt.root().iter().rev().map(|e| e.as_int()));
+
+            build_test!(source, &init_stack, &[], store.clone(), advice_map.clone())
+                .expect_stack(&expected_output);
+        }
+    }
+
+    // the pre-image of a non-empty leaf must be present in the advice map
+    let mut init_stack = Vec::new();
+    append_word_to_vec(&mut init_stack, smt.root().into());
+    append_word_to_vec(&mut init_stack, keys[2].into());
+    init_stack.push(0);
+    let result = build_test!(source, &init_stack, &[], store, Vec::new()).execute();
+    assert!(matches!(result, Err(ExecutionError::SmtNodeNotFound(_))));
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Returns a key which maps to the same leaf as the keys in [multiple_leaf_entries()].
+fn multiple_leaf_key(e0: u64, e1: u64, e2: u64) -> RpoDigest {
+    RpoDigest::new([Felt::new(e0), Felt::new(e1), Felt::new(e2), Felt::new(7)])
+}
+
+/// Returns key-value pairs which all map to the same leaf, sorted by key. Keys differ in each of
+/// their less significant elements, some of which exceed 32 bits.
+fn multiple_leaf_entries() -> [(RpoDigest, Word); 4] {
+    [
+        (multiple_leaf_key(5, 1 << 40, 1), [Felt::new(1); 4]),
+        (multiple_leaf_key(4, 1 << 40, 2), [Felt::new(2); 4]),
+        (multiple_leaf_key(6, 1 << 40, 2), [Felt::new(3); 4]),
+        (multiple_leaf_key(3, 5, u64::MAX - 1), [Felt::new(4); 4]),
+    ]
+}
+
 fn prepare_insert_or_set(
     key: RpoDigest,
     value: Word,
@@ -0,0 +1,122 @@
+use test_utils::{test_case, Test};
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Address of the four words overwritten by `verify` with R || A, which precede the message.
+const MSG_PTR: u64 = 10000;
+
+/// Decodes a hex string into bytes.
+fn from_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+/// Splits a 32-byte string into eight 32-bit limbs, reading each limb in little-endian byte order.
+fn to_limbs(bytes: &[u8]) -> Vec<u64> {
+    assert_eq!(bytes.len(), 32);
+    bytes
+        .chunks(4)
+        .map(|c| u32::from_le_bytes(c.try_into().unwrap()) as u64)
+        .collect()
+}
+
+/// Builds a test which writes the message to memory, in the format expected by
+/// `std::crypto::hashes::sha512::hash_memory`, and verifies the signature of the message.
+fn build_verify_test(public_key: &str, signature: &str, message: &str) -> Test {
+    let signature = from_hex(signature);
+    let mut message = from_hex(message);
+    let length = message.len() as u64;
+    message.resize(message.len() + (16 - message.len() % 16) % 16, 0);
+
+    let mut source = String::from("use.std::crypto::dsa::ed25519\nbegin\n");
+    for (i, block) in message.chunks(16).enumerate() {
+        let words = block
+            .chunks(4)
+            .rev()
+            .map(|c| u32::from_be_bytes(c.try_into().unwrap()).to_string())
+            .collect::<Vec<_>>();
+        source.push_str(&format!(
+            "push.{} mem_storew.{} dropw\n",
+            words.join("."),
+            MSG_PTR + 4 + i as u64
+        ));
+    }
+    source.push_str("exec.ed25519::verify\nend");
+
+    // A, R and S are placed at the top of the stack, followed by the message pointer and length
+    let mut stack = [
+        to_limbs(&from_hex(public_key)),
+        to_limbs(&signature[..32]),
+        to_limbs(&signature[32..]),
+        vec![MSG_PTR, length],
+    ]
+    .concat();
+    stack.reverse();
+
+    build_test!(&source, &stack)
+}
+
+// SIGNATURE VERIFICATION
+// ================================================================================================
+
+/// Because this test is pretty expensive, it's by default ignored. If you're interested in
+/// running this test, issue
+///
+/// cargo test --release -p miden-stdlib ed25519 -- --include-ignored
+///
+/// from root directory of Miden repository.
+///
+/// The first three test vectors are taken from section 7.1 of RFC 8032, while the last one signs
+/// a message spanning several SHA-512 blocks with the secret key of the first test vector.
+#[test_case("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a", "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b", "" ; "rfc8032_test_1")]
+#[test_case("3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c", "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00", "72" ; "rfc8032_test_2")]
+#[test_case("fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025", "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a", "af82" ; "rfc8032_test_3")]
+#[test_case("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a", "e247cf435ff424c574cda220166f83243736de7753044054a625e8f19c7e795784466d206aed8347b408e815b0fed34a389aab16acd5fab0f908c0ebf093d403", "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f60616263" ; "multi_block_message")]
+#[ignore]
+fn ed25519_verify(public_key: &str, signature: &str, message: &str) {
+    let test = build_verify_test(public_key, signature, message);
+    assert!(test.execute_process().is_ok());
+}
+
+/// Because this test is pretty expensive, it's by default ignored. If you're interested in
+/// running this test, issue
+///
+/// cargo test --release -p miden-stdlib ed25519 -- --include-ignored
+///
+/// from root directory of Miden repository.
+#[test]
+#[ignore]
+fn ed25519_verify_wrong_message() {
+    // the signature of the second test vector of RFC 8032, which signs the message 0x72
+    let test = build_verify_test(
+        "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+        "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+        "73",
+    );
+    assert!(test.execute_process().is_err());
+}
+
+#[test]
+fn ed25519_verify_non_canonical_s() {
+    // the signature of the first test vector of RFC 8032 with L added to S
+    let test = build_verify_test(
+        "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+        "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901554c8c7872aa064e049dbb3013fbf29380d25bf5f0595bbe24655141438e7a101b",
+        "",
+    );
+    assert!(test.execute_process().is_err());
+}
+
+#[test]
+fn ed25519_verify_invalid_public_key() {
+    // no point of the curve has y = 2
+    let test = build_verify_test(
+        "0200000000000000000000000000000000000000000000000000000000000000",
+        "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+        "",
+    );
+    assert!(test.execute_process().is_err());
+}
@@ -3,9 +3,11 @@ mod falcon;
 
 mod blake3;
 mod ecdsa_secp256k1;
+mod ed25519;
 mod elgamal;
 mod fri;
 mod keccak256;
 mod native;
 mod sha256;
+mod sha512;
 mod stark;
@@ -0,0 +1,120 @@
+use sha2::{Digest, Sha512};
+use test_utils::{
+    group_slice_elements,
+    rand::{rand_array, rand_value, rand_vector},
+};
+
+#[test]
+fn sha512_hash_memory() {
+    let source = "
+    use.std::crypto::hashes::sha512
+
+    begin
+        # mem.0 - input data address
+        push.10000 mem_store.0
+
+        # mem.1 - length in bytes
+        mem_store.1
+
+        # mem.2 - length in felts
+        mem_load.1 u32assert u32overflowing_add.3 assertz u32assert u32div.4 mem_store.2
+
+        # Load input data into memory address 10000, 10001, ...
+        mem_load.2 u32assert neq.0
+        while.true
+            mem_load.0 mem_storew dropw
+            mem_load.0 u32assert u32overflowing_add.1 assertz mem_store.0
+            mem_load.2 u32assert u32overflowing_sub.1 assertz dup mem_store.2 u32assert neq.0
+        end
+
+        # Compute hash of memory address 10000, 10001, ...
+        mem_load.1
+        push.10000
+        exec.sha512::hash_memory
+    end";
+
+    let length = rand_value::<u64>() & 1023; // length: 0-1023
+    let ibytes: Vec<u8> = rand_vector(length as usize);
+    let ipadding: Vec<u8> = vec![0; (4 - (length as usize % 4)) % 4];
+
+    let ifelts = [
+        group_slice_elements::<u8, 4>(&[ibytes.clone(), ipadding].concat())
+            .iter()
+            .map(|&bytes| u32::from_be_bytes(bytes) as u64)
+            .rev()
+            .collect::<Vec<u64>>(),
+        vec![length as u64; 1],
+    ]
+    .concat();
+
+    let mut hasher = Sha512::new();
+    hasher.update(ibytes);
+
+    let obytes = hasher.finalize();
+    let ofelts = group_slice_elements::<u8, 4>(&obytes)
+        .iter()
+        .map(|&bytes| u32::from_be_bytes(bytes) as u64)
+        .collect::<Vec<u64>>();
+
+    let test = build_test!(source, &ifelts);
+    test.expect_stack(&ofelts);
+}
+
+#[test]
+fn sha512_2_to_1_hash() {
+    let source = "
+    use.std::crypto::hashes::sha512
+
+    begin
+        exec.sha512::hash_2to1
+    end";
+
+    let ibytes = rand_array::<u8, 128>();
+
+    let ifelts = group_slice_elements::<u8, 4>(&ibytes)
+        .iter()
+        .map(|&bytes| u32::from_be_bytes(bytes) as u64)
+        .rev()
+        .collect::<Vec<u64>>();
+
+    let mut hasher = Sha512::new();
+    hasher.update(ibytes);
+
+    let obytes = hasher.finalize();
+    let ofelts = group_slice_elements::<u8, 4>(&obytes)
+        .iter()
+        .map(|&bytes| u32::from_be_bytes(bytes) as u64)
+        .collect::<Vec<u64>>();
+
+    let test = build_test!(source, &ifelts);
+    test.expect_stack(&ofelts);
+}
+
+#[test]
+fn sha512_1_to_1_hash() {
+    let source = "
+    use.std::crypto::hashes::sha512
+
+    begin
+        exec.sha512::hash_1to1
+    end";
+
+    let ibytes = rand_array::<u8, 64>();
+    let ifelts = group_slice_elements::<u8, 4>(&ibytes)
+        .iter()
+        .map(|&bytes| u32::from_be_bytes(bytes) as u64)
+        .rev()
+        .collect::<Vec<u64>>();
+
+    let mut hasher = Sha512::new();
+    hasher.update(ibytes);
+
+    let obytes = hasher.finalize();
+    let ofelts = group_slice_elements::<u8, 4>(&obytes)
+        .iter()
+        .map(|&bytes| u32::from_be_bytes(bytes) as u64)
+        .collect::<Vec<u64>>();
+
+    let test = build_test!(source, &ifelts);
+    test.expect_stack(&ofelts);
+}
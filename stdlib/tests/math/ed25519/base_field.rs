@@ -0,0 +1,165 @@
+use super::{base_modulus, execute_stack, rand_limbs, read_limbs, stack_inputs};
+use num_bigint::BigUint;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Executes the specified procedure of `std::math::ed25519::base_field` against the provided
+/// field elements, and returns the resulting field element.
+fn execute(procedure: &str, operands: &[&BigUint]) -> BigUint {
+    let source = format!(
+        "
+        use.std::math::ed25519::base_field
+        begin
+            exec.base_field::{procedure}
+        end"
+    );
+
+    let test = build_test!(&source, &stack_inputs(operands));
+    read_limbs(&execute_stack(&test), 0)
+}
+
+/// Returns field elements exercising the edge cases of the 256-bit representation.
+fn edge_values() -> Vec<BigUint> {
+    let p = base_modulus();
+    let max = (BigUint::from(1_u32) << 256) - 1_u32;
+    vec![
+        BigUint::from(0_u32),
+        BigUint::from(1_u32),
+        &p - 1_u32,
+        p.clone(),
+        &p + 1_u32,
+        max,
+    ]
+}
+
+// ARITHMETIC
+// ================================================================================================
+
+#[test]
+fn ed25519_base_field_add() {
+    let p = base_modulus();
+    let edge = edge_values();
+    let mut cases = vec![(rand_limbs(8), rand_limbs(8))];
+    cases.extend(edge.iter().flat_map(|a| edge.iter().map(|b| (a.clone(), b.clone()))));
+
+    for (a, b) in cases {
+        let c = execute("add", &[&a, &b]);
+        assert_eq!(c.clone() % &p, (a + b) % &p);
+    }
+}
+
+#[test]
+fn ed25519_base_field_sub() {
+    let p = base_modulus();
+    let edge = edge_values();
+    let mut cases = vec![(rand_limbs(8), rand_limbs(8))];
+    cases.extend(edge.iter().flat_map(|a| edge.iter().map(|b| (a.clone(), b.clone()))));
+
+    for (a, b) in cases {
+        let c = execute("sub", &[&a, &b]);
+        assert_eq!(c % &p, (a + 4_u32 * &p - b) % &p);
+    }
+}
+
+#[test]
+fn ed25519_base_field_neg() {
+    let p = base_modulus();
+    let mut cases = vec![rand_limbs(8)];
+    cases.extend(edge_values());
+
+    for a in cases {
+        let c = execute("neg", &[&a]);
+        assert_eq!(c % &p, (4_u32 * &p - a) % &p);
+    }
+}
+
+#[test]
+fn ed25519_base_field_mul() {
+    let p = base_modulus();
+    let edge = edge_values();
+    let mut cases = vec![(rand_limbs(8), rand_limbs(8)), (rand_limbs(8), rand_limbs(8))];
+    cases.extend(edge.iter().flat_map(|a| edge.iter().map(|b| (a.clone(), b.clone()))));
+
+    for (a, b) in cases {
+        let c = execute("mul", &[&a, &b]);
+        assert_eq!(c % &p, (a * b) % &p);
+    }
+
+    let a = rand_limbs(8);
+    let c = execute("square", &[&a]);
+    assert_eq!(c % &p, (&a * &a) % &p);
+}
+
+#[test]
+fn ed25519_base_field_inv() {
+    let p = base_modulus();
+    let a = rand_limbs(8);
+    let c = execute("inv", &[&a]);
+    assert_eq!((c * a) % &p, BigUint::from(1_u32));
+
+    let c = execute("inv", &[&p]);
+    assert_eq!(c % &p, BigUint::from(0_u32));
+}
+
+// CANONICAL FORM
+// ================================================================================================
+
+#[test]
+fn ed25519_base_field_reduce() {
+    let p = base_modulus();
+    let mut cases = vec![rand_limbs(8), &p - 19_u32, &p + 18_u32, &p * 2_u32 - 1_u32];
+    cases.extend(edge_values());
+
+    for a in cases {
+        assert_eq!(execute("reduce", &[&a]), a % &p);
+    }
+}
+
+#[test]
+fn ed25519_base_field_eq() {
+    let source = "
+        use.std::math::ed25519::base_field
+        begin
+            exec.base_field::eq
+        end";
+
+    let p = base_modulus();
+    let a = rand_limbs(7);
+
+    let test = build_test!(source, &stack_inputs(&[&a, &(&a + &p)]));
+    test.expect_stack(&[1]);
+
+    let test = build_test!(source, &stack_inputs(&[&a, &(&a + 1_u32)]));
+    test.expect_stack(&[0]);
+
+    let b = BigUint::from(1_u32) << 224;
+    let test = build_test!(source, &stack_inputs(&[&(&b + 1_u32), &b]));
+    test.expect_stack(&[0]);
+}
+
+#[test]
+fn ed25519_base_field_sqrt_ratio() {
+    let source = "
+        use.std::math::ed25519::base_field
+        begin
+            exec.base_field::sqrt_ratio
+        end";
+
+    let p = base_modulus();
+    let x = rand_limbs(8) % &p;
+    let v = rand_limbs(8) % &p;
+
+    // u / v is a square
+    let u = (&x * &x * &v) % &p;
+    let test = build_test!(source, &stack_inputs(&[&u, &v]));
+    let stack = execute_stack(&test);
+    assert_eq!(stack[0].as_int(), 1);
+    let r = read_limbs(&stack, 1) % &p;
+    assert!(r == x || r == &p - &x);
+
+    // 2 is not a square modulo p, and thus neither is 2 * x^2 / v
+    let u = (&u * 2_u32) % &p;
+    let test = build_test!(source, &stack_inputs(&[&u, &v]));
+    assert_eq!(execute_stack(&test)[0].as_int(), 0);
+}
@@ -0,0 +1,252 @@
+use super::{base_modulus, execute_stack, group_order, rand_limbs, read_limbs, stack_inputs};
+use num_bigint::BigUint;
+
+// EDWARDS25519 REFERENCE IMPLEMENTATION
+// ================================================================================================
+
+/// A point of Edwards25519 in affine coordinates.
+#[derive(Clone, Debug, PartialEq)]
+struct Point {
+    x: BigUint,
+    y: BigUint,
+}
+
+impl Point {
+    fn identity() -> Self {
+        Self {
+            x: BigUint::from(0_u32),
+            y: BigUint::from(1_u32),
+        }
+    }
+
+    fn generator() -> Self {
+        let x = BigUint::parse_bytes(
+            b"15112221349535400772501151409588531511454012693041857206046113283949847762202",
+            10,
+        )
+        .unwrap();
+        let y = BigUint::parse_bytes(
+            b"46316835694926478169428394003475163141307993866256225615783033603165251855960",
+            10,
+        )
+        .unwrap();
+        Self { x, y }
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        let p = base_modulus();
+        let t = curve_constant() * &self.x * &other.x % &p * &self.y * &other.y % &p;
+        let x = (&self.x * &other.y + &self.y * &other.x) * inv(&(BigUint::from(1_u32) + &t)) % &p;
+        let y = (&self.y * &other.y + &self.x * &other.x) * inv(&(&p + 1_u32 - &t)) % &p;
+        Self { x, y }
+    }
+
+    fn neg(&self) -> Self {
+        Self {
+            x: (base_modulus() - &self.x) % base_modulus(),
+            y: self.y.clone(),
+        }
+    }
+
+    fn mul(&self, scalar: &BigUint) -> Self {
+        let mut result = Self::identity();
+        for i in (0..scalar.bits()).rev() {
+            result = result.add(&result);
+            if scalar.bit(i) {
+                result = result.add(self);
+            }
+        }
+        result
+    }
+
+    /// Encodes the point as specified in section 5.1.2 of RFC 8032.
+    fn encode(&self) -> BigUint {
+        let mut encoding = self.y.clone();
+        encoding.set_bit(255, self.x.bit(0));
+        encoding
+    }
+}
+
+/// Returns the constant d = -121665 / 121666 of the curve equation.
+fn curve_constant() -> BigUint {
+    let p = base_modulus();
+    (&p - 121665_u32) * inv(&BigUint::from(121666_u32)) % &p
+}
+
+/// Returns the inverse of a non-zero element of the base field.
+fn inv(value: &BigUint) -> BigUint {
+    let p = base_modulus();
+    value.modpow(&(&p - 2_u32), &p)
+}
+
+/// Returns a random point of the prime-order subgroup.
+fn rand_point() -> Point {
+    Point::generator().mul(&(rand_limbs(8) % group_order()))
+}
+
+/// Executes the source, which is expected to leave an encoded point at the top of the stack,
+/// with the provided encodings placed at the top of the stack, and returns the resulting encoding.
+fn execute(source: &str, points: &[&Point]) -> BigUint {
+    let encodings = points.iter().map(|point| point.encode()).collect::<Vec<_>>();
+    let test = build_test!(source, &stack_inputs(&encodings.iter().collect::<Vec<_>>()));
+    read_limbs(&execute_stack(&test), 0)
+}
+
+// ENCODING
+// ================================================================================================
+
+#[test]
+fn ed25519_group_encode_decode() {
+    let source = "
+        use.std::math::ed25519::group
+        begin
+            push.0 movdn.8 exec.group::decode
+            push.0 exec.group::encode
+        end";
+
+    for point in [rand_point(), Point::identity(), Point::generator().neg()] {
+        assert_eq!(execute(source, &[&point]), point.encode());
+    }
+}
+
+#[test]
+fn ed25519_group_decode_invalid() {
+    let source = "
+        use.std::math::ed25519::group
+        begin
+            push.0 movdn.8 exec.group::decode
+        end";
+
+    let p = base_modulus();
+    let mut negative_zero = BigUint::from(1_u32);
+    negative_zero.set_bit(255, true);
+    let cases = [
+        // y = p + 1, a non-canonical encoding of the point with y = 1
+        &p + 1_u32,
+        // no point has y = 2
+        BigUint::from(2_u32),
+        // x = 0 with the sign bit set
+        negative_zero,
+    ];
+
+    for encoding in cases {
+        let test = build_test!(source, &stack_inputs(&[&encoding]));
+        assert!(test.execute_process().is_err());
+    }
+}
+
+// GROUP OPERATIONS
+// ================================================================================================
+
+#[test]
+fn ed25519_group_add() {
+    let source = "
+        use.std::math::ed25519::group
+        begin
+            push.0 movdn.8 exec.group::decode
+            push.8 movdn.8 exec.group::decode
+            push.16 push.8 push.0 exec.group::add
+            push.16 exec.group::encode
+        end";
+
+    let p = rand_point();
+    let q = rand_point();
+    for (a, b) in [(&p, &q), (&p, &p), (&p, &Point::identity()), (&p, &p.neg())] {
+        assert_eq!(execute(source, &[a, b]), a.add(b).encode());
+    }
+
+    // the result may overlap with the operands
+    let source = "
+        use.std::math::ed25519::group
+        begin
+            push.0 movdn.8 exec.group::decode
+            push.8 movdn.8 exec.group::decode
+            push.8 push.8 push.0 exec.group::add
+            push.8 exec.group::encode
+        end";
+    assert_eq!(execute(source, &[&p, &q]), p.add(&q).encode());
+}
+
+#[test]
+fn ed25519_group_double() {
+    // adding the point to its double makes sure all coordinates of the double are consistent
+    let source = "
+        use.std::math::ed25519::group
+        begin
+            push.0 movdn.8 exec.group::decode
+            push.8 push.0 exec.group::double
+            push.8 push.0 push.8 exec.group::add
+            push.8 exec.group::encode
+        end";
+
+    for point in [rand_point(), Point::identity()] {
+        assert_eq!(execute(source, &[&point]), point.mul(&BigUint::from(3_u32)).encode());
+    }
+}
+
+#[test]
+fn ed25519_group_neg() {
+    let source = "
+        use.std::math::ed25519::group
+        begin
+            push.0 movdn.8 exec.group::decode
+            push.8 push.0 exec.group::neg
+            push.8 exec.group::encode
+        end";
+
+    let point = rand_point();
+    assert_eq!(execute(source, &[&point]), point.neg().encode());
+}
+
+// SCALAR MULTIPLICATION
+// ================================================================================================
+
+/// Because this test is pretty expensive, it's by default ignored. If you're interested in
+/// running this test, issue
+///
+/// cargo test --release -p miden-stdlib ed25519 -- --include-ignored
+///
+/// from root directory of Miden repository.
+#[test]
+#[ignore]
+fn ed25519_group_double_scalar_mul() {
+    let source = "
+        use.std::math::ed25519::group
+        begin
+            push.0 movdn.8 exec.group::decode
+            exec.group::double_scalar_mul
+            push.8 exec.group::encode
+        end";
+
+    let point = rand_point();
+    let a = rand_limbs(8);
+    let b = rand_limbs(8);
+    // p_ptr = 0 and r_ptr = 8 are placed below the scalars
+    let inputs = [vec![8, 0], stack_inputs(&[&a, &b]), stack_inputs(&[&point.encode()])].concat();
+
+    let test = build_test!(source, &inputs);
+    let expected = point.mul(&a).add(&Point::generator().mul(&b));
+    assert_eq!(read_limbs(&execute_stack(&test), 0), expected.encode());
+}
+
+/// Because this test is pretty expensive, it's by default ignored. If you're interested in
+/// running this test, issue
+///
+/// cargo test --release -p miden-stdlib ed25519 -- --include-ignored
+///
+/// from root directory of Miden repository.
+#[test]
+#[ignore]
+fn ed25519_group_gen_mul() {
+    let source = "
+        use.std::math::ed25519::group
+        begin
+            push.0 movdn.8 exec.group::gen_mul
+            push.0 exec.group::encode
+        end";
+
+    let scalar = rand_limbs(8) % group_order();
+    let test = build_test!(source, &stack_inputs(&[&scalar]));
+    let expected = Point::generator().mul(&scalar);
+    assert_eq!(read_limbs(&execute_stack(&test), 0), expected.encode());
+}
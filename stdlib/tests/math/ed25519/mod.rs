@@ -0,0 +1,65 @@
+use num_bigint::BigUint;
+use test_utils::{rand::rand_vector, Felt, Test};
+
+mod base_field;
+mod group;
+mod scalar_field;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the order of the Curve25519 base field.
+fn base_modulus() -> BigUint {
+    (BigUint::from(1_u32) << 255) - 19_u32
+}
+
+/// Returns the order of the prime-order subgroup of Curve25519.
+fn group_order() -> BigUint {
+    (BigUint::from(1_u32) << 252)
+        + BigUint::parse_bytes(b"27742317777372353535851937790883648493", 10).unwrap()
+}
+
+/// Returns a random number which fits into the specified number of 32-bit limbs.
+fn rand_limbs(num_limbs: usize) -> BigUint {
+    let limbs = rand_vector::<u64>(num_limbs).iter().map(|&v| v as u32).collect::<Vec<_>>();
+    BigUint::new(limbs)
+}
+
+/// Returns the specified number of 32-bit limbs of the value, with the least significant limb
+/// first.
+fn to_limbs(value: &BigUint, num_limbs: usize) -> Vec<u64> {
+    let mut limbs = value.to_u32_digits().iter().map(|&v| v as u64).collect::<Vec<_>>();
+    assert!(limbs.len() <= num_limbs);
+    limbs.resize(num_limbs, 0);
+    limbs
+}
+
+/// Returns the stack inputs which place the 256-bit values at the top of the stack, with the
+/// first value at the top of the stack, and the least significant limb of each value first.
+fn stack_inputs(values: &[&BigUint]) -> Vec<u64> {
+    values
+        .iter()
+        .rev()
+        .flat_map(|value| to_limbs(value, 8).into_iter().rev())
+        .collect()
+}
+
+/// Reads a 256-bit value from the eight stack elements starting at the specified position,
+/// asserting that each of them is a 32-bit limb.
+fn read_limbs(stack: &[Felt], offset: usize) -> BigUint {
+    let limbs = stack[offset..offset + 8]
+        .iter()
+        .map(|v| u32::try_from(v.as_int()).expect("not a 32-bit limb"))
+        .collect::<Vec<_>>();
+    BigUint::new(limbs)
+}
+
+/// Executes the test without building its execution trace, and returns the resulting stack.
+///
+/// Procedures relying on field inversions and square roots run for hundreds of thousands of
+/// cycles, and building the full execution trace for them is prohibitively expensive.
+fn execute_stack(test: &Test) -> Vec<Felt> {
+    let process = test.execute_process().expect("failed to execute the test");
+    let (_, _, stack, ..) = process.into_parts();
+    stack.build_stack_outputs().stack().to_vec()
+}
@@ -0,0 +1,105 @@
+use super::{execute_stack, group_order, rand_limbs, read_limbs, stack_inputs, to_limbs};
+use num_bigint::BigUint;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Executes the specified procedure of `std::math::ed25519::scalar_field` against the provided
+/// operands, and returns the resulting field element.
+fn execute(procedure: &str, operands: &[&BigUint]) -> BigUint {
+    let source = format!(
+        "
+        use.std::math::ed25519::scalar_field
+        begin
+            exec.scalar_field::{procedure}
+        end"
+    );
+
+    let test = build_test!(&source, &stack_inputs(operands));
+    read_limbs(&execute_stack(&test), 0)
+}
+
+/// Returns a random element of the scalar field.
+fn rand_scalar() -> BigUint {
+    rand_limbs(8) % group_order()
+}
+
+// ARITHMETIC
+// ================================================================================================
+
+#[test]
+fn ed25519_scalar_field_reduce() {
+    let l = group_order();
+    let max = (BigUint::from(1_u32) << 256_usize) - 1_u32;
+    for a in [rand_limbs(8), BigUint::from(0_u32), &l - 1_u32, l.clone(), &l * 2_u32, max] {
+        assert_eq!(execute("reduce", &[&a]), a % &l);
+    }
+}
+
+#[test]
+fn ed25519_scalar_field_reduce_wide() {
+    let source = "
+        use.std::math::ed25519::scalar_field
+        begin
+            exec.scalar_field::reduce_wide
+        end";
+
+    let l = group_order();
+    let max = (BigUint::from(1_u32) << 512_usize) - 1_u32;
+    for a in [rand_limbs(16), rand_limbs(16), &l * &l - 1_u32, &l * &l, max] {
+        let mut inputs = to_limbs(&a, 16);
+        inputs.reverse();
+
+        let test = build_test!(source, &inputs);
+        assert_eq!(read_limbs(&execute_stack(&test), 0), a % &l);
+    }
+}
+
+#[test]
+fn ed25519_scalar_field_is_canonical() {
+    let source = "
+        use.std::math::ed25519::scalar_field
+        begin
+            exec.scalar_field::is_canonical
+        end";
+
+    let l = group_order();
+    for (a, expected) in [(rand_scalar(), 1), (&l - 1_u32, 1), (l.clone(), 0), (&l + 1_u32, 0)] {
+        let test = build_test!(source, &stack_inputs(&[&a]));
+        assert_eq!(execute_stack(&test)[0].as_int(), expected);
+    }
+}
+
+#[test]
+fn ed25519_scalar_field_add_sub_neg() {
+    let l = group_order();
+    let zero = BigUint::from(0_u32);
+    let cases = [
+        (rand_scalar(), rand_scalar()),
+        (&l - 1_u32, &l - 1_u32),
+        (zero.clone(), &l - 1_u32),
+        (&l - 1_u32, zero.clone()),
+    ];
+
+    for (a, b) in cases {
+        assert_eq!(execute("add", &[&a, &b]), (&a + &b) % &l);
+        assert_eq!(execute("sub", &[&a, &b]), (&a + &l - &b) % &l);
+        assert_eq!(execute("neg", &[&a]), (&l - &a) % &l);
+    }
+}
+
+#[test]
+fn ed25519_scalar_field_mul() {
+    let l = group_order();
+    let max = (BigUint::from(1_u32) << 256_usize) - 1_u32;
+    let cases = [
+        (rand_scalar(), rand_scalar()),
+        (rand_limbs(8), rand_limbs(8)),
+        (&l - 1_u32, &l - 1_u32),
+        (max.clone(), max),
+    ];
+
+    for (a, b) in cases {
+        assert_eq!(execute("mul", &[&a, &b]), (&a * &b) % &l);
+    }
+}
@@ -0,0 +1,125 @@
+use processor::ExecutionError;
+use test_utils::proptest::prelude::*;
+
+// ARITHMETIC OPERATIONS
+// ================================================================================================
+
+#[test]
+fn checked_arithmetic_overflow() {
+    let cases = [
+        ("checked_add", i32::MAX, 1),
+        ("checked_sub", i32::MIN, 1),
+        ("checked_mul", i32::MIN, -1),
+        ("checked_mul", 1 << 16, 1 << 15),
+        ("checked_div", i32::MIN, -1),
+    ];
+
+    for (procedure, a, b) in cases {
+        let result = build_test!(&source(procedure), &[encode(a), encode(b)]).execute();
+        assert!(matches!(result, Err(ExecutionError::FailedAssertion { .. })), "{procedure}");
+    }
+
+    let test = build_test!(&source("checked_mul"), &[encode(-(1 << 16)), encode(1 << 15)]);
+    test.expect_stack(&[encode(i32::MIN)]);
+}
+
+#[test]
+fn div_by_zero() {
+    for procedure in ["wrapping_div", "mod", "divmod"] {
+        let result = build_test!(&source(procedure), &[encode(-7), 0]).execute();
+        assert!(matches!(result, Err(ExecutionError::DivideByZero(_))), "{procedure}");
+    }
+}
+
+proptest! {
+    #[test]
+    fn add_sub_proptest(a in value(), b in value()) {
+        let inputs = [encode(a), encode(b)];
+
+        let (c, overflow) = a.overflowing_add(b);
+        build_test!(&source("overflowing_add"), &inputs)
+            .prop_expect_stack(&[overflow as u64, encode(c)])?;
+        build_test!(&source("wrapping_add"), &inputs).prop_expect_stack(&[encode(c)])?;
+
+        let (c, overflow) = a.overflowing_sub(b);
+        build_test!(&source("overflowing_sub"), &inputs)
+            .prop_expect_stack(&[overflow as u64, encode(c)])?;
+        build_test!(&source("wrapping_sub"), &inputs).prop_expect_stack(&[encode(c)])?;
+    }
+
+    #[test]
+    fn mul_proptest(a in value(), b in value()) {
+        let inputs = [encode(a), encode(b)];
+
+        let (c, overflow) = a.overflowing_mul(b);
+        build_test!(&source("overflowing_mul"), &inputs)
+            .prop_expect_stack(&[overflow as u64, encode(c)])?;
+        build_test!(&source("wrapping_mul"), &inputs).prop_expect_stack(&[encode(c)])?;
+    }
+
+    #[test]
+    fn div_proptest(a in value(), b in value().prop_filter("non-zero divisor", |&b| b != 0)) {
+        let inputs = [encode(a), encode(b)];
+
+        let (q, overflow) = a.overflowing_div(b);
+        let r = a.wrapping_rem(b);
+        build_test!(&source("overflowing_div"), &inputs)
+            .prop_expect_stack(&[overflow as u64, encode(q)])?;
+        build_test!(&source("wrapping_div"), &inputs).prop_expect_stack(&[encode(q)])?;
+        build_test!(&source("mod"), &inputs).prop_expect_stack(&[encode(r)])?;
+        build_test!(&source("divmod"), &inputs).prop_expect_stack(&[encode(r), encode(q)])?;
+    }
+
+    #[test]
+    fn comparison_proptest(a in value(), b in value()) {
+        let inputs = [encode(a), encode(b)];
+
+        build_test!(&source("lt"), &inputs).prop_expect_stack(&[(a < b) as u64])?;
+        build_test!(&source("lte"), &inputs).prop_expect_stack(&[(a <= b) as u64])?;
+        build_test!(&source("gt"), &inputs).prop_expect_stack(&[(a > b) as u64])?;
+        build_test!(&source("gte"), &inputs).prop_expect_stack(&[(a >= b) as u64])?;
+        build_test!(&source("min"), &inputs).prop_expect_stack(&[encode(a.min(b))])?;
+        build_test!(&source("max"), &inputs).prop_expect_stack(&[encode(a.max(b))])?;
+    }
+
+    #[test]
+    fn sign_proptest(a in value()) {
+        let inputs = [encode(a)];
+
+        build_test!(&source("is_neg"), &inputs).prop_expect_stack(&[(a < 0) as u64])?;
+        build_test!(&source("abs"), &inputs).prop_expect_stack(&[encode(a.wrapping_abs())])?;
+        build_test!(&source("neg"), &inputs).prop_expect_stack(&[encode(a.wrapping_neg())])?;
+        build_test!(&source("extend8"), &inputs)
+            .prop_expect_stack(&[encode(a as i8 as i32)])?;
+        build_test!(&source("extend16"), &inputs)
+            .prop_expect_stack(&[encode(a as i16 as i32)])?;
+    }
+
+    #[test]
+    fn shr_proptest(a in value(), n in 0_u32..32) {
+        build_test!(&source("shr"), &[encode(a), n as u64]).prop_expect_stack(&[encode(a >> n)])?;
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a strategy which generates random values, with the boundary values of i32 being
+/// generated more often than they would be otherwise.
+fn value() -> impl Strategy<Value = i32> {
+    prop_oneof![Just(i32::MIN), Just(i32::MAX), Just(-1), Just(0), any::<i32>(), any::<i32>()]
+}
+
+fn encode(value: i32) -> u64 {
+    value as u32 as u64
+}
+
+fn source(procedure: &str) -> String {
+    format!(
+        "
+        use.std::math::i32
+        begin
+            exec.i32::{procedure}
+        end"
+    )
+}
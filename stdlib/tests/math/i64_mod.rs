@@ -0,0 +1,141 @@
+use processor::ExecutionError;
+use test_utils::proptest::prelude::*;
+
+// ARITHMETIC OPERATIONS
+// ================================================================================================
+
+#[test]
+fn checked_arithmetic_overflow() {
+    let cases = [
+        ("checked_add", i64::MAX, 1),
+        ("checked_sub", i64::MIN, 1),
+        ("checked_mul", i64::MIN, -1),
+        ("checked_mul", 1 << 32, 1 << 31),
+        ("checked_div", i64::MIN, -1),
+    ];
+
+    for (procedure, a, b) in cases {
+        let result = build_test!(&source(procedure), &operands(&[a, b])).execute();
+        assert!(matches!(result, Err(ExecutionError::FailedAssertion { .. })), "{procedure}");
+    }
+
+    let test = build_test!(&source("checked_mul"), &operands(&[-(1 << 32), 1 << 31]));
+    test.expect_stack(&encode(i64::MIN));
+}
+
+#[test]
+fn div_by_zero() {
+    for procedure in ["wrapping_div", "mod", "divmod"] {
+        let result = build_test!(&source(procedure), &operands(&[-7, 0])).execute();
+        assert!(matches!(result, Err(ExecutionError::DivideByZero(_))), "{procedure}");
+    }
+}
+
+proptest! {
+    #[test]
+    fn add_sub_proptest(a in value(), b in value()) {
+        let inputs = operands(&[a, b]);
+
+        let (c, overflow) = a.overflowing_add(b);
+        build_test!(&source("overflowing_add"), &inputs)
+            .prop_expect_stack(&with_flag(overflow, c))?;
+        build_test!(&source("wrapping_add"), &inputs).prop_expect_stack(&encode(c))?;
+
+        let (c, overflow) = a.overflowing_sub(b);
+        build_test!(&source("overflowing_sub"), &inputs)
+            .prop_expect_stack(&with_flag(overflow, c))?;
+        build_test!(&source("wrapping_sub"), &inputs).prop_expect_stack(&encode(c))?;
+    }
+
+    #[test]
+    fn mul_proptest(a in value(), b in value()) {
+        let inputs = operands(&[a, b]);
+
+        let (c, overflow) = a.overflowing_mul(b);
+        build_test!(&source("overflowing_mul"), &inputs)
+            .prop_expect_stack(&with_flag(overflow, c))?;
+        build_test!(&source("wrapping_mul"), &inputs).prop_expect_stack(&encode(c))?;
+    }
+
+    #[test]
+    fn div_proptest(a in value(), b in value().prop_filter("non-zero divisor", |&b| b != 0)) {
+        let inputs = operands(&[a, b]);
+
+        let (q, overflow) = a.overflowing_div(b);
+        let r = a.wrapping_rem(b);
+        build_test!(&source("overflowing_div"), &inputs)
+            .prop_expect_stack(&with_flag(overflow, q))?;
+        build_test!(&source("wrapping_div"), &inputs).prop_expect_stack(&encode(q))?;
+        build_test!(&source("mod"), &inputs).prop_expect_stack(&encode(r))?;
+
+        let mut expected = encode(r).to_vec();
+        expected.extend_from_slice(&encode(q));
+        build_test!(&source("divmod"), &inputs).prop_expect_stack(&expected)?;
+    }
+
+    #[test]
+    fn comparison_proptest(a in value(), b in value()) {
+        let inputs = operands(&[a, b]);
+
+        build_test!(&source("lt"), &inputs).prop_expect_stack(&[(a < b) as u64])?;
+        build_test!(&source("lte"), &inputs).prop_expect_stack(&[(a <= b) as u64])?;
+        build_test!(&source("gt"), &inputs).prop_expect_stack(&[(a > b) as u64])?;
+        build_test!(&source("gte"), &inputs).prop_expect_stack(&[(a >= b) as u64])?;
+        build_test!(&source("min"), &inputs).prop_expect_stack(&encode(a.min(b)))?;
+        build_test!(&source("max"), &inputs).prop_expect_stack(&encode(a.max(b)))?;
+    }
+
+    #[test]
+    fn sign_proptest(a in value()) {
+        let inputs = operands(&[a]);
+
+        build_test!(&source("is_neg"), &inputs).prop_expect_stack(&[(a < 0) as u64])?;
+        build_test!(&source("abs"), &inputs).prop_expect_stack(&encode(a.wrapping_abs()))?;
+        build_test!(&source("neg"), &inputs).prop_expect_stack(&encode(a.wrapping_neg()))?;
+        build_test!(&source("from_i32"), &[a as i32 as u32 as u64])
+            .prop_expect_stack(&encode(a as i32 as i64))?;
+    }
+
+    #[test]
+    fn shr_proptest(a in value(), n in 0_u32..64) {
+        let mut inputs = operands(&[a]);
+        inputs.push(n as u64);
+        build_test!(&source("shr"), &inputs).prop_expect_stack(&encode(a >> n))?;
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a strategy which generates random values, with the boundary values of i64 being
+/// generated more often than they would be otherwise.
+fn value() -> impl Strategy<Value = i64> {
+    prop_oneof![Just(i64::MIN), Just(i64::MAX), Just(-1), Just(0), any::<i64>(), any::<i64>()]
+}
+
+/// Returns the limbs of the value in the order in which they are placed on the stack, i.e.,
+/// `[hi, lo]`.
+fn encode(value: i64) -> [u64; 2] {
+    let value = value as u64;
+    [value >> 32, value as u32 as u64]
+}
+
+/// Returns the inputs for the test, with the last value placed at the top of the stack.
+fn operands(values: &[i64]) -> Vec<u64> {
+    values.iter().flat_map(|&v| encode(v).into_iter().rev()).collect()
+}
+
+fn with_flag(flag: bool, value: i64) -> [u64; 3] {
+    let [hi, lo] = encode(value);
+    [flag as u64, hi, lo]
+}
+
+fn source(procedure: &str) -> String {
+    format!(
+        "
+        use.std::math::i64
+        begin
+            exec.i64::{procedure}
+        end"
+    )
+}
@@ -1,4 +1,7 @@
 pub mod ecgfp5;
+mod ed25519;
+mod i32_mod;
+mod i64_mod;
 mod secp256k1;
 mod u256_mod;
 mod u64_mod;
@@ -1,5 +1,121 @@
 use num_bigint::BigUint;
-use test_utils::rand::rand_vector;
+use processor::ExecutionError;
+use test_utils::{rand::rand_vector, TestError};
+
+// ADDITION
+// ================================================================================================
+
+#[test]
+fn overflowing_add() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::overflowing_add
+        end";
+
+    let a = rand_u256() >> 1;
+    let b = rand_u256() >> 1;
+    let mut expected = vec![0];
+    expected.extend(to_stack(&(&a + &b)));
+    build_test!(source, &operands(&a, &b)).expect_stack(&expected);
+
+    let a = max_u256();
+    let b = rand_u256();
+    let mut expected = vec![1];
+    expected.extend(to_stack(&((&a + &b) % modulus())));
+    build_test!(source, &operands(&a, &b)).expect_stack(&expected);
+}
+
+#[test]
+fn add_unsafe() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::add_unsafe
+        end";
+
+    let a = rand_u256();
+    let b = rand_u256();
+    let expected = to_stack(&((&a + &b) % modulus()));
+    build_test!(source, &operands(&a, &b)).expect_stack(&expected);
+}
+
+#[test]
+fn checked_add() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::checked_add
+        end";
+
+    let a = rand_u256() >> 1;
+    let b = rand_u256() >> 1;
+    let expected = to_stack(&(&a + &b));
+    build_test!(source, &operands(&a, &b)).expect_stack(&expected);
+
+    let a = max_u256();
+    let b = BigUint::from(1_u32);
+    let result = build_test!(source, &operands(&a, &b)).execute();
+    assert!(matches!(result, Err(ExecutionError::FailedAssertion { .. })));
+}
+
+// SUBTRACTION
+// ================================================================================================
+
+#[test]
+fn overflowing_sub() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::overflowing_sub
+        end";
+
+    let (a, b) = ordered_pair();
+    let mut expected = vec![0];
+    expected.extend(to_stack(&(&a - &b)));
+    build_test!(source, &operands(&a, &b)).expect_stack(&expected);
+
+    let mut expected = vec![1];
+    expected.extend(to_stack(&(&b + modulus() - &a)));
+    build_test!(source, &operands(&b, &a)).expect_stack(&expected);
+
+    // the most significant limbs are equal, but the lower limbs underflow
+    let a = max_u256() - BigUint::from(1_u32);
+    let b = max_u256();
+    let mut expected = vec![1];
+    expected.extend(to_stack(&max_u256()));
+    build_test!(source, &operands(&a, &b)).expect_stack(&expected);
+}
+
+#[test]
+fn sub_unsafe() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::sub_unsafe
+        end";
+
+    let a = rand_u256();
+    let b = rand_u256();
+    let expected = to_stack(&((&a + modulus() - &b) % modulus()));
+    build_test!(source, &operands(&a, &b)).expect_stack(&expected);
+}
+
+#[test]
+fn checked_sub() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::checked_sub
+        end";
+
+    let (a, b) = ordered_pair();
+    let expected = to_stack(&(&a - &b));
+    build_test!(source, &operands(&a, &b)).expect_stack(&expected);
+
+    let result = build_test!(source, &operands(&b, &(&a + 1_u32))).execute();
+    assert!(matches!(result, Err(ExecutionError::FailedAssertion { .. })));
+}
 
 // MULTIPLICATION
 // ================================================================================================
@@ -32,6 +148,203 @@ fn mul_unsafe() {
     build_test!(source, &operands).expect_stack(&result);
 }
 
+#[test]
+fn overflowing_mul() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::overflowing_mul
+        end";
+
+    let a = rand_u256() >> 128;
+    let b = rand_u256() >> 128;
+    let mut expected = vec![0];
+    expected.extend(to_stack(&(&a * &b)));
+    build_test!(source, &operands(&a, &b)).expect_stack(&expected);
+
+    let a = rand_u256() | (BigUint::from(1_u32) << 255);
+    let b = rand_u256() | BigUint::from(2_u32);
+    let mut expected = vec![1];
+    expected.extend(to_stack(&((&a * &b) % modulus())));
+    build_test!(source, &operands(&a, &b)).expect_stack(&expected);
+
+    // the product of the largest values overflows into every limb of the upper half
+    let a = max_u256();
+    let mut expected = vec![1];
+    expected.extend(to_stack(&((&a * &a) % modulus())));
+    build_test!(source, &operands(&a, &a)).expect_stack(&expected);
+}
+
+#[test]
+fn checked_mul() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::checked_mul
+        end";
+
+    let a = rand_u256() >> 128;
+    let b = rand_u256() >> 129;
+    let expected = to_stack(&(&a * &b));
+    build_test!(source, &operands(&a, &b)).expect_stack(&expected);
+
+    let a = BigUint::from(1_u32) << 128;
+    let result = build_test!(source, &operands(&a, &a)).execute();
+    assert!(matches!(result, Err(ExecutionError::FailedAssertion { .. })));
+}
+
+// COMPARISONS
+// ================================================================================================
+
+#[test]
+fn comparisons() {
+    let (small, large) = ordered_pair();
+    // values which differ only in the least significant limb
+    let close = &large - 1_u32;
+
+    let cases = [
+        (&small, &large),
+        (&large, &small),
+        (&large, &large),
+        (&close, &large),
+        (&large, &close),
+    ];
+
+    for (a, b) in cases {
+        for (procedure, expected) in
+            [("lt", a < b), ("lte", a <= b), ("gt", a > b), ("gte", a >= b)]
+        {
+            let source = format!(
+                "
+                use.std::math::u256
+                begin
+                    exec.u256::{procedure}
+                end"
+            );
+            build_test!(&source, &operands(a, b)).expect_stack(&[expected as u64]);
+        }
+    }
+}
+
+#[test]
+fn min_max() {
+    let (small, large) = ordered_pair();
+
+    for (a, b) in [(&small, &large), (&large, &small), (&large, &large)] {
+        let source = "
+            use.std::math::u256
+            begin
+                exec.u256::min
+            end";
+        build_test!(source, &operands(a, b)).expect_stack(&to_stack(a.min(b)));
+
+        let source = "
+            use.std::math::u256
+            begin
+                exec.u256::max
+            end";
+        build_test!(source, &operands(a, b)).expect_stack(&to_stack(a.max(b)));
+    }
+}
+
+// DIVISION
+// ================================================================================================
+
+#[test]
+fn divmod() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::divmod
+        end";
+
+    for (a, b) in div_test_cases() {
+        let mut expected = to_stack(&(&a % &b));
+        expected.extend(to_stack(&(&a / &b)));
+        build_test!(source, &operands(&a, &b)).expect_stack(&expected);
+    }
+}
+
+#[test]
+fn div() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::div
+        end";
+
+    for (a, b) in div_test_cases() {
+        build_test!(source, &operands(&a, &b)).expect_stack(&to_stack(&(&a / &b)));
+    }
+}
+
+#[test]
+fn modulo() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::mod
+        end";
+
+    for (a, b) in div_test_cases() {
+        build_test!(source, &operands(&a, &b)).expect_stack(&to_stack(&(&a % &b)));
+    }
+}
+
+#[test]
+fn div_by_zero() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::div
+        end";
+
+    let a = rand_u256();
+    let b = BigUint::from(0_u32);
+    let test = build_test!(source, &operands(&a, &b));
+    test.expect_error(TestError::ExecutionError(ExecutionError::DivideByZero(3)));
+}
+
+// SHIFT OPERATIONS
+// ================================================================================================
+
+#[test]
+fn shifts() {
+    let a = rand_u256();
+
+    for n in [0_usize, 1, 31, 32, 100, 255] {
+        let mut inputs = operands(&a, &BigUint::from(0_u32))[..8].to_vec();
+        inputs.push(n as u64);
+
+        let shl = (&a << n) % modulus();
+        let shr = &a >> n;
+        let rotl = (&shl | (&a >> ((256 - n) % 256))) % modulus();
+        let rotr = &shr | ((&a << ((256 - n) % 256)) % modulus());
+
+        for (procedure, expected) in [("shl", shl), ("shr", shr), ("rotl", rotl), ("rotr", rotr)] {
+            let source = format!(
+                "
+                use.std::math::u256
+                begin
+                    exec.u256::{procedure}
+                end"
+            );
+            build_test!(&source, &inputs).expect_stack(&to_stack(&expected));
+        }
+    }
+
+    // shifting by 256 bits or more is not allowed
+    let mut inputs = operands(&a, &BigUint::from(0_u32))[..8].to_vec();
+    inputs.push(256);
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::shl
+        end";
+    let result = build_test!(source, &inputs).execute();
+    assert!(matches!(result, Err(ExecutionError::FailedAssertion { .. })));
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -39,3 +352,54 @@ fn rand_u256() -> BigUint {
     let limbs = rand_vector::<u64>(8).iter().map(|&v| v as u32).collect::<Vec<_>>();
     BigUint::new(limbs)
 }
+
+fn modulus() -> BigUint {
+    BigUint::from(1_u32) << 256
+}
+
+fn max_u256() -> BigUint {
+    modulus() - 1_u32
+}
+
+/// Returns a pair of random 256-bit values where the first value is greater than the second one.
+fn ordered_pair() -> (BigUint, BigUint) {
+    let a = rand_u256();
+    let b = rand_u256();
+    if a > b {
+        (a, b)
+    } else {
+        (b + 1_u32, a)
+    }
+}
+
+/// Returns the 32-bit limbs of the value, with the least significant limb first.
+fn to_limbs(value: &BigUint) -> Vec<u64> {
+    let mut limbs = value.to_u32_digits().iter().map(|&v| v as u64).collect::<Vec<_>>();
+    limbs.resize(8, 0);
+    limbs
+}
+
+/// Returns the input operands for a binary operation such that b is at the top of the stack.
+fn operands(a: &BigUint, b: &BigUint) -> Vec<u64> {
+    let mut operands = to_limbs(a);
+    operands.extend(to_limbs(b));
+    operands
+}
+
+/// Returns the expected state of the stack holding the value, with the most significant limb at
+/// the top of the stack.
+fn to_stack(value: &BigUint) -> Vec<u64> {
+    to_limbs(value).into_iter().rev().collect()
+}
+
+fn div_test_cases() -> Vec<(BigUint, BigUint)> {
+    let (a, b) = ordered_pair();
+    vec![
+        (a.clone(), b.clone()),
+        (b.clone(), a.clone()),
+        (a.clone(), &b >> 160),
+        (a.clone(), BigUint::from(1_u32)),
+        (a.clone(), a.clone()),
+        (max_u256(), max_u256() >> 1),
+    ]
+}
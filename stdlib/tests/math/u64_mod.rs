@@ -701,6 +701,15 @@ fn unchecked_shr() {
     let (c1, c0) = split_u64(c);
 
     build_test!(source, &[5, a0, a1, b as u64]).expect_stack(&[c1, c0, 5]);
+
+    // shift by 32 when all bits of the lower limb are set
+    let a = u64::MAX >> 1;
+    let (a1, a0) = split_u64(a);
+    let b: u32 = 32;
+    let c = a.wrapping_shr(b);
+    let (c1, c0) = split_u64(c);
+
+    build_test!(source, &[5, a0, a1, b as u64]).expect_stack(&[c1, c0, 5]);
 }
 
 #[test]
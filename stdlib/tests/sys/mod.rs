@@ -1,4 +1,8 @@
-use test_utils::{proptest::prelude::*, rand::rand_vector, STACK_TOP_SIZE};
+use test_utils::{
+    proptest::prelude::*, prove_with_output_region, rand::rand_vector, verify,
+    verify_with_output_region, DefaultHost, ExecutionError, ExecutionOptions, MemAdviceProvider,
+    OutputRegion, ProgramInfo, ProvingOptions, VerificationError, EMPTY_WORD, ONE, STACK_TOP_SIZE,
+};
 
 #[test]
 fn truncate_stack() {
@@ -26,3 +30,115 @@ proptest! {
         build_test!(&source, &test_values).prop_expect_stack(&expected_values)?;
     }
 }
+
+#[test]
+fn commit_output_region() {
+    let source = "
+    use.std::mem
+    use.std::sys
+
+    begin
+        # copy 100 words from the advice stack to memory starting at address 1000
+        push.1000 push.100 exec.mem::pipe_words_to_memory
+        movup.4 drop
+        # => [HASH, ...]
+
+        push.100 push.1000 exec.sys::commit_output_region
+        # => [COM, 1000, 100, HASH, ...]
+    end";
+    let advice_stack = (0..400).map(|i| i * 3 + 1).collect::<Vec<u64>>();
+    let test = build_test!(source, &[], &advice_stack);
+    let program = test.compile().unwrap();
+    let host = DefaultHost::new(MemAdviceProvider::from(test.advice_inputs.clone()));
+    let options = ExecutionOptions::default().with_output_region();
+    let trace = processor::execute(&program, test.stack_inputs.clone(), host, options).unwrap();
+
+    // the commitment matches the hash computed while copying the words to memory
+    let stack_outputs = trace.stack_outputs();
+    let output_region = trace.output_region().unwrap();
+    assert_eq!(output_region.start_addr(), 1000);
+    assert_eq!(output_region.num_words(), 100);
+    assert!(output_region.is_committed_to(stack_outputs));
+    assert_eq!(stack_outputs.get_stack_word(0), stack_outputs.get_stack_word(6));
+
+    // the words are stored in the region in the order in which they were read from the advice stack
+    let words = output_region
+        .words()
+        .iter()
+        .map(|word| word.iter().map(|v| v.as_int()).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let expected_words = advice_stack.chunks(4).map(|word| word.to_vec()).collect::<Vec<_>>();
+    assert_eq!(words, expected_words);
+}
+
+#[test]
+fn commit_output_region_missing() {
+    let source = "begin push.1 push.1000 mem_store end";
+    let test = build_test!(source, &[]);
+    let program = test.compile().unwrap();
+    let host = DefaultHost::new(MemAdviceProvider::default());
+    let options = ExecutionOptions::default().with_output_region();
+    let result = processor::execute(&program, test.stack_inputs.clone(), host, options);
+    assert!(matches!(result, Err(ExecutionError::InvalidOutputRegion { .. })));
+}
+
+#[test]
+fn commit_output_region_too_long() {
+    // the length is rejected before any of the words of the region are read from memory
+    let source = format!("begin push.{} push.1000 padw end", u32::MAX);
+    let test = build_test!(&source, &[]);
+    let program = test.compile().unwrap();
+    let host = DefaultHost::new(MemAdviceProvider::default());
+    let options = ExecutionOptions::default().with_output_region();
+    let result = processor::execute(&program, test.stack_inputs.clone(), host, options);
+    assert!(matches!(result, Err(ExecutionError::InvalidOutputRegion { .. })));
+}
+
+#[test]
+fn output_region_prove_verify() {
+    // the region is never written to, and thus its commitment can be pushed onto the stack as an
+    // immediate value instead of being computed via `std::sys::commit_output_region`
+    let zero_region = OutputRegion::new(1000, vec![EMPTY_WORD; 20]).unwrap();
+    let commitment = zero_region.commitment();
+    let source = format!(
+        "begin push.20 push.1000 push.{} end",
+        commitment.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(".")
+    );
+    let test = build_test!(&source, &[]);
+    let program = test.compile().unwrap();
+    let host = DefaultHost::new(MemAdviceProvider::default());
+    let (stack_outputs, output_region, proof) = prove_with_output_region(
+        &program,
+        test.stack_inputs.clone(),
+        host,
+        ProvingOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(output_region, zero_region);
+
+    let program_info = ProgramInfo::from(program);
+    let result = verify_with_output_region(
+        program_info.clone(),
+        test.stack_inputs.clone(),
+        stack_outputs.clone(),
+        output_region.clone(),
+        proof.clone(),
+    );
+    assert!(result.is_ok(), "error: {result:?}");
+
+    // the proof does not verify against a different output region
+    let mut words = output_region.words().to_vec();
+    words[19][3] += ONE;
+    let tampered_region = OutputRegion::new(1000, words).unwrap();
+    let result = verify_with_output_region(
+        program_info.clone(),
+        test.stack_inputs.clone(),
+        stack_outputs.clone(),
+        tampered_region,
+        proof.clone(),
+    );
+    assert_eq!(result, Err(VerificationError::OutputRegionNotCommitted));
+
+    // the output region is a part of the public inputs
+    assert!(verify(program_info, test.stack_inputs.clone(), stack_outputs, proof).is_err());
+}
@@ -0,0 +1,554 @@
+//! Differential testing of the operation semantics implemented by the processor against the
+//! transition constraints of the AIR.
+//!
+//! For every [Operation], random initial states of the VM are generated, a program executing the
+//! operation is run by the processor, and the transition from the row at which the operation is
+//! executed to the next row is checked against the system and stack transition constraints. In
+//! addition, a single value of the stack at the next row is mutated to make sure that the
+//! constraints reject the mutated transition.
+//!
+//! The AIR does not define transition constraints for the decoder yet; thus, operation flags are
+//! taken from the trace as they were set by the processor. Likewise, the stack transitions of
+//! some operations (e.g., DYN and SYSCALL) are not constrained by the AIR yet, and for these
+//! operations only the stack depth is checked against mutations.
+
+use super::{
+    crypto::{init_merkle_leaves, MerkleStore, MerkleTree},
+    AdviceInputs, DefaultHost, ExecutionOptions, ExecutionTrace, Felt, MemAdviceProvider, Program,
+    StackInputs, StarkField, Word, ONE, STACK_TOP_SIZE, ZERO,
+};
+use air::{
+    stack,
+    trace::{decoder::OP_BITS_RANGE, CLK_COL_IDX, DECODER_TRACE_OFFSET, STACK_TRACE_OFFSET},
+};
+use alloc::{format, string::String, vec::Vec};
+use proptest::{
+    prelude::{any, Strategy},
+    test_runner::{Config, TestCaseError, TestError, TestRunner},
+};
+use vm_core::{code_blocks::CodeBlock, CodeBlockTable, Kernel, Operation};
+use winter_prover::{EvaluationFrame, Trace};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Index of the stack depth column in the main trace.
+const B0_COL_IDX: usize = STACK_TRACE_OFFSET + STACK_TOP_SIZE;
+
+/// Number of no-op operations needed for a SPAN block to consist of two operation batches.
+const NUM_RESPAN_OPS: usize = 80;
+
+// TEST CASES
+// ================================================================================================
+
+/// Initial state of the VM and a mutation of the next row for a single test case of an operation.
+#[derive(Debug, Clone)]
+pub struct OpTestCase {
+    /// Initial values of the stack, the first value being on the top of the stack.
+    pub stack: Vec<Felt>,
+    /// Advice inputs consumed by the operation.
+    pub advice: AdviceInputs,
+    /// Selects the stack position which is mutated at the next row.
+    pub mutated_pos: usize,
+    /// Non-zero value added to the mutated stack position.
+    pub mutation: Felt,
+}
+
+/// Returns all variants of [Operation]; operations with immediate values are represented by a
+/// single value.
+pub fn all_operations() -> Vec<Operation> {
+    use Operation::*;
+    vec![
+        Noop,
+        Assert(1),
+        FmpAdd,
+        FmpUpdate,
+        SDepth,
+        Caller,
+        Clk,
+        Join,
+        Split,
+        Loop,
+        Call,
+        Dyn,
+        SysCall,
+        Span,
+        End,
+        Repeat,
+        Respan,
+        Halt,
+        Add,
+        Neg,
+        Mul,
+        Inv,
+        Incr,
+        And,
+        Or,
+        Not,
+        Eq,
+        Eqz,
+        Expacc,
+        Ext2Mul,
+        U32split,
+        U32add,
+        U32assert2(ZERO),
+        U32add3,
+        U32sub,
+        U32mul,
+        U32madd,
+        U32div,
+        U32and,
+        U32xor,
+        Pad,
+        Drop,
+        Dup0,
+        Dup1,
+        Dup2,
+        Dup3,
+        Dup4,
+        Dup5,
+        Dup6,
+        Dup7,
+        Dup9,
+        Dup11,
+        Dup13,
+        Dup15,
+        Swap,
+        SwapW,
+        SwapW2,
+        SwapW3,
+        SwapDW,
+        MovUp2,
+        MovUp3,
+        MovUp4,
+        MovUp5,
+        MovUp6,
+        MovUp7,
+        MovUp8,
+        MovDn2,
+        MovDn3,
+        MovDn4,
+        MovDn5,
+        MovDn6,
+        MovDn7,
+        MovDn8,
+        CSwap,
+        CSwapW,
+        Push(Felt::new(7)),
+        AdvPop,
+        AdvPopW,
+        MLoadW,
+        MStoreW,
+        MLoad,
+        MStore,
+        MStream,
+        Pipe,
+        HPerm,
+        MpVerify,
+        MrUpdate,
+        FriE2F4,
+        RCombBase,
+    ]
+}
+
+/// Returns a strategy generating random test cases for the specified operation.
+///
+/// The generated stack values satisfy the preconditions of the operation (e.g., operands of u32
+/// operations are valid u32 values), and the advice inputs contain the values the operation
+/// requests from the advice provider.
+pub fn op_test_cases(op: Operation) -> impl Strategy<Value = OpTestCase> {
+    let stack = proptest::collection::vec(any::<u64>().prop_map(Felt::new), 17..=20);
+    (stack, any::<[u64; 8]>(), any::<usize>(), 1..Felt::MODULUS).prop_map(
+        move |(stack, seeds, mutated_pos, mutation)| {
+            let (stack, advice) = prepare_inputs(op, stack, seeds);
+            OpTestCase {
+                stack,
+                advice,
+                mutated_pos,
+                mutation: Felt::new(mutation),
+            }
+        },
+    )
+}
+
+/// Checks the specified operation against the given number of random test cases.
+///
+/// If the check fails, the returned error describes the failure and the initial stack of the
+/// minimal failing test case.
+pub fn test_operation(op: Operation, num_cases: u32) -> Result<(), String> {
+    let mut runner = TestRunner::new(Config::with_cases(num_cases));
+    runner
+        .run(&op_test_cases(op), |test_case| check_operation(op, &test_case))
+        .map_err(|err| match err {
+            TestError::Fail(reason, test_case) => {
+                format!("{reason}; initial stack: {:?}", test_case.stack)
+            }
+            TestError::Abort(reason) => format!("{op}: {reason}"),
+        })
+}
+
+// CONSTRAINT CHECKS
+// ================================================================================================
+
+/// Executes the specified operation and checks the resulting transition against the AIR.
+///
+/// The check fails if:
+/// - The execution of the operation fails.
+/// - Any system or stack transition constraint does not evaluate to zero over the transition from
+///   the row at which the operation is executed to the next row.
+/// - All constraints evaluate to zero after the stack value at the mutated position of the next
+///   row is changed. Positions which the AIR leaves to be constrained by other components (e.g.,
+///   values read from memory) are never mutated.
+pub fn check_operation(op: Operation, test_case: &OpTestCase) -> Result<(), TestCaseError> {
+    let trace = execute_op(op, test_case)?;
+    let main_trace = trace.main_segment();
+    let row = find_op_row(&trace, op)
+        .ok_or_else(|| TestCaseError::fail(format!("{op} was not found in the trace")))?;
+
+    let mut current = vec![ZERO; main_trace.num_cols()];
+    let mut next = vec![ZERO; main_trace.num_cols()];
+    main_trace.read_row_into(row, &mut current);
+    main_trace.read_row_into(row + 1, &mut next);
+
+    let evaluations = evaluate_constraints(current.clone(), next.clone());
+    if let Some(idx) = evaluations.iter().position(|&value| value != ZERO) {
+        return Err(TestCaseError::fail(format!(
+            "{op}: constraint {} evaluated to {} at row {row}",
+            constraint_name(idx),
+            evaluations[idx]
+        )));
+    }
+
+    // change a constrained value of the stack at the next row and make sure that the transition
+    // is rejected
+    let shifts_left = next[B0_COL_IDX].as_int() < current[B0_COL_IDX].as_int();
+    let constrained = constrained_columns(op, shifts_left);
+    let col_idx = constrained[test_case.mutated_pos % constrained.len()];
+    next[col_idx] += test_case.mutation;
+    let evaluations = evaluate_constraints(current, next);
+    if evaluations.iter().all(|&value| value == ZERO) {
+        return Err(TestCaseError::fail(format!(
+            "{op}: mutation of column {col_idx} at row {} was not detected",
+            row + 1
+        )));
+    }
+
+    Ok(())
+}
+
+/// Evaluates system and stack transition constraints over the specified two rows; the first
+/// value is the evaluation of the clock constraint.
+fn evaluate_constraints(current: Vec<Felt>, next: Vec<Felt>) -> Vec<Felt> {
+    let frame = EvaluationFrame::from_rows(current, next);
+    let mut result = vec![ZERO; 1 + stack::get_transition_constraint_count()];
+    result[0] = frame.next()[CLK_COL_IDX] - (frame.current()[CLK_COL_IDX] + ONE);
+    stack::enforce_constraints(&frame, &mut result[1..]);
+    result
+}
+
+/// Returns the name of the constraint at the specified index of the evaluations returned by
+/// [evaluate_constraints].
+fn constraint_name(idx: usize) -> String {
+    if idx == 0 {
+        return String::from("system.clk");
+    }
+
+    let mut offset = 1;
+    for (name, count) in stack::get_transition_constraint_groups() {
+        if idx < offset + count {
+            return format!("{name}[{}]", idx - offset);
+        }
+        offset += count;
+    }
+    unreachable!("constraint index {idx} is out of bounds")
+}
+
+/// Returns the trace columns of the next row which are fully determined by the stack
+/// constraints for the specified operation.
+///
+/// Values of the other stack positions are set by the operation from sources which the AIR
+/// constrains via the multiset checks of the chiplets bus and the overflow table, or which are
+/// not constrained at all (e.g., immediate values and the values from the advice provider). When
+/// the stack is shifted to the left, the last position of the stack is taken from the overflow
+/// table.
+fn constrained_columns(op: Operation, shifts_left: bool) -> Vec<usize> {
+    let unconstrained: &[usize] = match op {
+        // the values are provided by the decoder or the system
+        Operation::Push(_) | Operation::Clk => &[0],
+        // the values are provided by the advice provider
+        Operation::AdvPop => &[0],
+        Operation::AdvPopW => &[0, 1, 2, 3],
+        // the values are read from memory or computed by the chiplets
+        Operation::MLoad | Operation::U32and | Operation::U32xor => &[0],
+        Operation::MLoadW | Operation::MrUpdate => &[0, 1, 2, 3],
+        Operation::HPerm => &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11],
+        // the AIR does not define stack transition constraints for these operations yet; only
+        // the stack depth is constrained
+        Operation::Dyn
+        | Operation::SysCall
+        | Operation::Caller
+        | Operation::Repeat
+        | Operation::MStream
+        | Operation::Pipe
+        | Operation::FriE2F4
+        | Operation::RCombBase => &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+        _ => &[],
+    };
+
+    (0..STACK_TOP_SIZE)
+        .filter(|&pos| !unconstrained.contains(&pos))
+        .filter(|&pos| !(shifts_left && pos == STACK_TOP_SIZE - 1))
+        .map(|pos| STACK_TRACE_OFFSET + pos)
+        .chain([B0_COL_IDX])
+        .collect()
+}
+
+// EXECUTION
+// ================================================================================================
+
+/// Builds a program executing the specified operation and executes it.
+fn execute_op(op: Operation, test_case: &OpTestCase) -> Result<ExecutionTrace, TestCaseError> {
+    let program = build_program(op);
+    let stack_inputs = StackInputs::new(test_case.stack.iter().rev().copied().collect())
+        .map_err(|err| TestCaseError::fail(format!("invalid stack inputs: {err}")))?;
+    let host = DefaultHost::new(MemAdviceProvider::from(test_case.advice.clone()));
+
+    processor::execute(&program, stack_inputs, host, ExecutionOptions::default())
+        .map_err(|err| TestCaseError::fail(format!("{op}: execution failed with {err}")))
+}
+
+/// Returns the index of the first row of the trace at which the specified operation is executed.
+fn find_op_row(trace: &ExecutionTrace, op: Operation) -> Option<usize> {
+    let main_trace = trace.main_segment();
+    let op_code = op.op_code();
+    (0..main_trace.num_rows() - 1).find(|&row| {
+        let row_op_code = OP_BITS_RANGE.enumerate().fold(0, |acc, (i, col_idx)| {
+            let bit = main_trace.get(DECODER_TRACE_OFFSET + col_idx, row).as_int() as u8;
+            acc | (bit << i)
+        });
+        row_op_code == op_code
+    })
+}
+
+/// Returns a program which executes the specified operation.
+///
+/// User operations are executed in a SPAN block of their own, while control flow operations are
+/// executed by blocks which invoke SPAN blocks consisting of a single NOOP.
+fn build_program(op: Operation) -> Program {
+    let noop = || CodeBlock::new_span(vec![Operation::Noop]);
+    let mut cb_table = CodeBlockTable::default();
+    let mut kernel = Kernel::default();
+
+    let root = match op {
+        Operation::Span | Operation::End | Operation::Halt => noop(),
+        Operation::Noop
+        | Operation::Assert(_)
+        | Operation::FmpAdd
+        | Operation::FmpUpdate
+        | Operation::SDepth
+        | Operation::Clk
+        | Operation::Add
+        | Operation::Neg
+        | Operation::Mul
+        | Operation::Inv
+        | Operation::Incr
+        | Operation::And
+        | Operation::Or
+        | Operation::Not
+        | Operation::Eq
+        | Operation::Eqz
+        | Operation::Expacc
+        | Operation::Ext2Mul
+        | Operation::U32split
+        | Operation::U32add
+        | Operation::U32assert2(_)
+        | Operation::U32add3
+        | Operation::U32sub
+        | Operation::U32mul
+        | Operation::U32madd
+        | Operation::U32div
+        | Operation::U32and
+        | Operation::U32xor
+        | Operation::Pad
+        | Operation::Drop
+        | Operation::Dup0
+        | Operation::Dup1
+        | Operation::Dup2
+        | Operation::Dup3
+        | Operation::Dup4
+        | Operation::Dup5
+        | Operation::Dup6
+        | Operation::Dup7
+        | Operation::Dup9
+        | Operation::Dup11
+        | Operation::Dup13
+        | Operation::Dup15
+        | Operation::Swap
+        | Operation::SwapW
+        | Operation::SwapW2
+        | Operation::SwapW3
+        | Operation::SwapDW
+        | Operation::MovUp2
+        | Operation::MovUp3
+        | Operation::MovUp4
+        | Operation::MovUp5
+        | Operation::MovUp6
+        | Operation::MovUp7
+        | Operation::MovUp8
+        | Operation::MovDn2
+        | Operation::MovDn3
+        | Operation::MovDn4
+        | Operation::MovDn5
+        | Operation::MovDn6
+        | Operation::MovDn7
+        | Operation::MovDn8
+        | Operation::CSwap
+        | Operation::CSwapW
+        | Operation::Push(_)
+        | Operation::AdvPop
+        | Operation::AdvPopW
+        | Operation::MLoadW
+        | Operation::MStoreW
+        | Operation::MLoad
+        | Operation::MStore
+        | Operation::MStream
+        | Operation::Pipe
+        | Operation::HPerm
+        | Operation::MpVerify
+        | Operation::MrUpdate
+        | Operation::FriE2F4
+        | Operation::RCombBase => CodeBlock::new_span(vec![op]),
+        Operation::Respan => CodeBlock::new_span(vec![Operation::Noop; NUM_RESPAN_OPS]),
+        Operation::Join => CodeBlock::new_join([noop(), noop()]),
+        Operation::Split => CodeBlock::new_split(noop(), noop()),
+        Operation::Loop | Operation::Repeat => CodeBlock::new_loop(noop()),
+        Operation::Call => {
+            let callee = noop();
+            let root = CodeBlock::new_call(callee.hash());
+            cb_table.insert(callee);
+            root
+        }
+        Operation::Dyn => {
+            cb_table.insert(noop());
+            CodeBlock::new_dyn()
+        }
+        Operation::SysCall | Operation::Caller => {
+            let callee = if op == Operation::Caller {
+                CodeBlock::new_span(vec![Operation::Caller])
+            } else {
+                noop()
+            };
+            kernel = Kernel::new(&[callee.hash()]).expect("kernel is valid");
+            let root = CodeBlock::new_syscall(callee.hash());
+            cb_table.insert(callee);
+            root
+        }
+    };
+
+    Program::with_kernel(root, kernel, cb_table)
+}
+
+/// Adjusts the random stack values to satisfy the preconditions of the specified operation, and
+/// builds the advice inputs required by the operation. The returned stack has the top of the
+/// stack at the first position.
+fn prepare_inputs(
+    op: Operation,
+    mut stack: Vec<Felt>,
+    seeds: [u64; 8],
+) -> (Vec<Felt>, AdviceInputs) {
+    let bit = |seed: u64| Felt::from(seed & 1 == 1);
+    let u32_value = |seed: u64| Felt::from(seed as u32);
+    let mut advice = AdviceInputs::default();
+
+    match op {
+        Operation::Assert(_) => stack[0] = ONE,
+        Operation::Expacc => stack[0] = bit(seeds[0]),
+        Operation::FmpUpdate => stack[0] = Felt::new(seeds[0] % 1024),
+        Operation::Inv if stack[0] == ZERO => stack[0] = ONE,
+        Operation::And | Operation::Or => {
+            stack[0] = bit(seeds[0]);
+            stack[1] = bit(seeds[1]);
+        }
+        Operation::Not | Operation::CSwap | Operation::CSwapW | Operation::Split => {
+            stack[0] = bit(seeds[0])
+        }
+        Operation::Eq if seeds[0] & 1 == 1 => stack[1] = stack[0],
+        Operation::Eqz if seeds[0] & 1 == 1 => stack[0] = ZERO,
+        Operation::U32add
+        | Operation::U32sub
+        | Operation::U32mul
+        | Operation::U32and
+        | Operation::U32xor
+        | Operation::U32assert2(_) => {
+            stack[0] = u32_value(seeds[0]);
+            stack[1] = u32_value(seeds[1]);
+        }
+        Operation::U32add3 | Operation::U32madd => {
+            stack[0] = u32_value(seeds[0]);
+            stack[1] = u32_value(seeds[1]);
+            stack[2] = u32_value(seeds[2]);
+        }
+        Operation::U32div => {
+            stack[0] = Felt::from((seeds[0] as u32).max(1));
+            stack[1] = u32_value(seeds[1]);
+        }
+        Operation::MLoad | Operation::MLoadW | Operation::MStore | Operation::MStoreW => {
+            stack[0] = u32_value(seeds[0])
+        }
+        Operation::MStream => stack[12] = Felt::from(seeds[0] as u32 >> 1),
+        Operation::Pipe => {
+            stack[12] = Felt::from(seeds[0] as u32 >> 1);
+            advice = advice.with_stack(stack[..8].iter().copied());
+        }
+        Operation::AdvPop => advice = advice.with_stack([stack[0]]),
+        Operation::AdvPopW => advice = advice.with_stack(stack[..4].iter().copied()),
+        Operation::MpVerify | Operation::MrUpdate => {
+            let leaves = init_merkle_leaves(&seeds[..4]);
+            let tree = MerkleTree::new(leaves.clone()).expect("tree is valid");
+            let index = (seeds[4] % 4) as usize;
+            set_word(&mut stack, 0, leaves[index]);
+            stack[4] = Felt::new(2);
+            stack[5] = Felt::new(index as u64);
+            set_word(&mut stack, 6, tree.root().into());
+            advice = advice.with_merkle_store(MerkleStore::from(&tree));
+        }
+        Operation::FriE2F4 => {
+            // the query value at the domain segment must be equal to the previously folded value
+            let d_seg = (seeds[0] % 4) as usize;
+            stack[9] = Felt::new(d_seg as u64);
+            stack[12] = stack[7 - 2 * d_seg];
+            stack[11] = stack[6 - 2 * d_seg];
+            if stack[10] == ZERO {
+                stack[10] = ONE;
+            }
+        }
+        Operation::RCombBase => {
+            stack[13] = u32_value(seeds[0]);
+            stack[14] = u32_value(seeds[1]);
+        }
+        Operation::Loop => {
+            stack[0] = bit(seeds[0]);
+            stack[1] = ZERO;
+        }
+        Operation::Repeat => {
+            stack[0] = ONE;
+            stack[1] = ONE;
+            stack[2] = ZERO;
+        }
+        Operation::Dyn => {
+            let target: Word = CodeBlock::new_span(vec![Operation::Noop]).hash().into();
+            set_word(&mut stack, 0, target);
+        }
+        _ => (),
+    }
+
+    (stack, advice)
+}
+
+/// Writes the word to the stack starting at the specified position, such that the last element
+/// of the word is located at this position.
+fn set_word(stack: &mut [Felt], pos: usize, word: Word) {
+    for (i, &value) in word.iter().rev().enumerate() {
+        stack[pos + i] = value;
+    }
+}
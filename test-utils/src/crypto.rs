@@ -9,7 +9,7 @@ pub use vm_core::crypto::{
     hash::{Rpo256, RpoDigest},
     merkle::{
         EmptySubtreeRoots, LeafIndex, MerkleError, MerklePath, MerkleStore, MerkleTree, Mmr,
-        MmrPeaks, NodeIndex, PartialMerkleTree, SimpleSmt, Smt,
+        MmrPeaks, MmrProof, NodeIndex, PartialMerkleTree, PartialMmr, SimpleSmt, Smt,
     },
 };
 
@@ -22,14 +22,17 @@ pub use processor::{
     AdviceInputs, AdviceProvider, ContextId, DefaultHost, ExecutionError, ExecutionOptions,
     ExecutionTrace, Process, ProcessState, StackInputs, VmStateIterator,
 };
-pub use prover::{prove, MemAdviceProvider, ProvingOptions};
+pub use prover::{prove, prove_with_output_region, MemAdviceProvider, ProvingOptions};
 pub use test_case::test_case;
-pub use verifier::{verify, AcceptableOptions, ProgramInfo, VerifierError};
+pub use verifier::{
+    verify, verify_with_output_region, AcceptableOptions, ProgramInfo, VerificationError,
+    VerifierError,
+};
 pub use vm_core::{
     chiplets::hasher::{hash_elements, STATE_WIDTH},
     stack::STACK_TOP_SIZE,
     utils::{collections, group_slice_elements, group_vector_elements, IntoBytes, ToElements},
-    Felt, FieldElement, Program, StarkField, Word, EMPTY_WORD, ONE, WORD_SIZE, ZERO,
+    Felt, FieldElement, OutputRegion, Program, StarkField, Word, EMPTY_WORD, ONE, WORD_SIZE, ZERO,
 };
 
 pub mod math {
@@ -44,6 +47,9 @@ pub mod serde {
     };
 }
 
+#[cfg(not(target_family = "wasm"))]
+pub mod constraints;
+
 pub mod crypto;
 
 #[cfg(not(target_family = "wasm"))]
@@ -17,7 +17,9 @@ use winter_verifier::verify as verify_proof;
 // EXPORTS
 // ================================================================================================
 
-pub use vm_core::{chiplets::hasher::Digest, Kernel, ProgramInfo, StackInputs, StackOutputs, Word};
+pub use vm_core::{
+    chiplets::hasher::Digest, Kernel, OutputRegion, ProgramInfo, StackInputs, StackOutputs, Word,
+};
 pub use winter_verifier::{AcceptableOptions, VerifierError};
 pub mod math {
     pub use vm_core::{Felt, FieldElement, StarkField};
@@ -60,12 +62,54 @@ pub fn verify(
     stack_inputs: StackInputs,
     stack_outputs: StackOutputs,
     proof: ExecutionProof,
+) -> Result<u32, VerificationError> {
+    let pub_inputs = PublicInputs::new(program_info, stack_inputs, stack_outputs);
+    verify_pub_inputs(pub_inputs, proof)
+}
+
+/// Returns the security level of the proof if the specified program was executed correctly against
+/// the specified inputs and outputs, and committed to the specified output region.
+///
+/// This works the same way as [verify()], but in addition verifies that the program designated
+/// `output_region` as a part of its public outputs via `std::sys::commit_output_region`. That is,
+/// the stack outputs are expected to start with `[COM, start_addr, num_words, ...]`, where `COM`
+/// is the commitment to the contents of the output region.
+///
+/// # Errors
+/// Returns an error if:
+/// - The stack outputs do not commit to the provided output region.
+/// - The provided proof does not prove a correct execution of the program.
+/// - The the protocol parameters used to generate the proof is not in the set of acceptable
+///   parameters.
+#[tracing::instrument("verify_program_with_output_region", skip_all)]
+pub fn verify_with_output_region(
+    program_info: ProgramInfo,
+    stack_inputs: StackInputs,
+    stack_outputs: StackOutputs,
+    output_region: OutputRegion,
+    proof: ExecutionProof,
+) -> Result<u32, VerificationError> {
+    if !output_region.is_committed_to(&stack_outputs) {
+        return Err(VerificationError::OutputRegionNotCommitted);
+    }
+
+    let pub_inputs = PublicInputs::new(program_info, stack_inputs, stack_outputs)
+        .with_output_region(output_region);
+    verify_pub_inputs(pub_inputs, proof)
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Verifies the proof against the specified public inputs and returns its security level.
+fn verify_pub_inputs(
+    pub_inputs: PublicInputs,
+    proof: ExecutionProof,
 ) -> Result<u32, VerificationError> {
     // get security level of the proof
     let security_level = proof.security_level();
 
-    // build public inputs and try to verify the proof
-    let pub_inputs = PublicInputs::new(program_info, stack_inputs, stack_outputs);
+    // try to verify the proof
     let (hash_fn, proof) = proof.into_parts();
     match hash_fn {
         HashFunction::Blake3_192 => {
@@ -98,6 +142,7 @@ pub enum VerificationError {
     VerifierError(VerifierError),
     InputNotFieldElement(u64),
     OutputNotFieldElement(u64),
+    OutputRegionNotCommitted,
 }
 
 impl fmt::Display for VerificationError {
@@ -107,6 +152,9 @@ impl fmt::Display for VerificationError {
             VerifierError(e) => write!(f, "{e}"),
             InputNotFieldElement(i) => write!(f, "the input {i} is not a valid field element!"),
             OutputNotFieldElement(o) => write!(f, "the output {o} is not a valid field element!"),
+            OutputRegionNotCommitted => {
+                write!(f, "the stack outputs do not commit to the provided output region")
+            }
         }
     }
 }